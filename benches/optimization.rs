@@ -39,6 +39,7 @@ fn benchmark_nightblade_bow_2h_with_cp(c: &mut Criterion) {
                 set_names: Vec::new(),
                 extra_bonuses: Vec::new(),
                 armor_passive_bonuses: Vec::new(),
+                single_bar: false,
                 parallelism: 4,
                 max_pool_size: None,
             });
@@ -70,6 +71,7 @@ fn benchmark_nightblade_bow_2h_multi_cp(c: &mut Criterion) {
                 set_names: Vec::new(),
                 extra_bonuses: Vec::new(),
                 armor_passive_bonuses: Vec::new(),
+                single_bar: false,
                 parallelism: 4,
                 max_pool_size: None,
             });
@@ -548,7 +548,7 @@ vec![BonusData::new(
 vec![BonusData::new(
                 "Long Shots",
                 BonusSource::Passive,
-                BonusTrigger::BowEquipped,
+                BonusTrigger::AtRange,
                 BonusValue::new("Long Shots (Damage)", BonusTarget::Damage, 0.05),
             )
             .with_alternative(BonusValue::new(
@@ -763,7 +763,7 @@ vec![EMPOWER
 
 UNDAUNTED_EXTRA_CODE = """\
 /// Returns Undaunted Mettle bonuses based on number of distinct armor weights worn.
-/// Each armor type grants +2% MaxMagicka and +2% MaxStamina.
+/// Each armor type grants +2% MaxMagicka, +2% MaxStamina and +2% MaxHealth.
 /// Standard 5/1/1 builds use 3 armor types → +6% each.
 pub fn undaunted_mettle_bonuses(armor_types: u8) -> Vec<BonusData> {
     if armor_types == 0 {
@@ -783,11 +783,79 @@ pub fn undaunted_mettle_bonuses(armor_types: u8) -> Vec<BonusData> {
             BonusTrigger::Passive,
             BonusValue::new("Max Stamina", BonusTarget::MaxStamina, pct),
         ),
+        BonusData::new(
+            "Undaunted Mettle",
+            BonusSource::Passive,
+            BonusTrigger::Passive,
+            BonusValue::new("Max Health", BonusTarget::MaxHealth, pct),
+        ),
     ]
 }
 
 """
 
+# ---------------------------------------------------------------------------
+# Dual Wield extra code (emitted before the static)
+# ---------------------------------------------------------------------------
+
+DUAL_WIELD_EXTRA_CODE = """\
+/// Returns Twin Blade and Blunt bonuses for the one-handers held in each hand.
+/// Each axe grants +6% Critical Damage, each mace +1487 Penetration, each sword
+/// +129 Weapon and Spell Damage and each dagger +657 Critical Rating.
+pub fn twin_blade_and_blunt_bonuses(main_hand: WeaponType, off_hand: WeaponType) -> Vec<BonusData> {
+    [("Main Hand", main_hand), ("Off Hand", off_hand)]
+        .into_iter()
+        .filter_map(|(hand, weapon)| {
+            let (kind, target, value) = match weapon {
+                WeaponType::DualWieldAxe => ("Axe", BonusTarget::CriticalDamage, 0.06),
+                WeaponType::DualWieldMace => {
+                    ("Mace", BonusTarget::PhysicalAndSpellPenetration, 1487.0)
+                }
+                WeaponType::DualWieldSword => {
+                    ("Sword", BonusTarget::WeaponAndSpellDamageFlat, 129.0)
+                }
+                WeaponType::DualWieldDagger => ("Dagger", BonusTarget::CriticalRating, 657.0),
+                _ => return None,
+            };
+            let name = format!("Twin Blade and Blunt ({}, {})", kind, hand);
+            Some(BonusData::new(
+                name.clone(),
+                BonusSource::Passive,
+                BonusTrigger::DualWieldEquipped,
+                BonusValue::new(name, target, value),
+            ))
+        })
+        .collect()
+}
+
+/// Replaces the best-of Twin Blade and Blunt passive with the bonuses of the
+/// equipped pair. Passives without the dual wield line are returned unchanged.
+pub fn with_twin_blade_and_blunt_hands(
+    passives: &[BonusData],
+    main_hand: WeaponType,
+    off_hand: WeaponType,
+) -> Vec<BonusData> {
+    let is_generic =
+        |b: &BonusData| b.name.starts_with("Twin Blade and Blunt") && b.has_alternative();
+    if !passives.iter().any(is_generic) {
+        return passives.to_vec();
+    }
+    let mut bonuses: Vec<BonusData> = passives
+        .iter()
+        .filter(|b| !is_generic(b))
+        .cloned()
+        .collect();
+    bonuses.extend(twin_blade_and_blunt_bonuses(main_hand, off_hand));
+    bonuses
+}
+
+"""
+
+EXTRA_CODE = {
+    "undaunted": UNDAUNTED_EXTRA_CODE,
+    "dual_wield": DUAL_WIELD_EXTRA_CODE,
+}
+
 
 # ---------------------------------------------------------------------------
 # Helpers
@@ -879,6 +947,8 @@ def detect_domain_imports(code: str) -> set[str]:
         types.add("BonusTrigger")
     if "BonusValue::" in code or "BonusValue::new" in code:
         types.add("BonusValue")
+    if "WeaponType" in code:
+        types.add("WeaponType")
     return types
 
 
@@ -937,17 +1007,18 @@ def generate_file(passives: list[tuple[str, str | None, str, str]],
     # Static name
     static_name = f"{file_stem.upper()}_PASSIVES"
 
-    # Extra code (for undaunted)
+    # Extra code (undaunted, dual wield)
     extra = ""
-    if file_stem == "undaunted":
-        extra = "\n" + UNDAUNTED_EXTRA_CODE
+    if file_stem in EXTRA_CODE:
+        extra_code = EXTRA_CODE[file_stem]
+        extra = "\n" + extra_code
         # The extra code uses BonusData, BonusSource, etc. - detect those too
-        extra_domain = detect_domain_imports(UNDAUNTED_EXTRA_CODE)
+        extra_domain = detect_domain_imports(extra_code)
         domain_types |= extra_domain
         # Rebuild domain import line
         domain_sorted = sorted(domain_types)
         # Also re-check for bonus imports in extra code
-        extra_reexported, extra_unique = detect_bonus_imports(UNDAUNTED_EXTRA_CODE, is_class)
+        extra_reexported, extra_unique = detect_bonus_imports(extra_code, is_class)
         reexported_bonuses |= extra_reexported
         unique_bonuses |= extra_unique
 
@@ -30,17 +30,89 @@ TYPE_TO_CATEGORY = {
     "Arena": ("Arena", "arena"),
 }
 
-# Map parsed stat names to BonusTarget variants (DPS-relevant only)
+# Map parsed stat names to BonusTarget variants (DPS-relevant, incl. health for
+# health-scaling skills)
 STAT_MAP = {
     "Maximum Stamina": ("MaxStaminaFlat", "Max Stamina"),
     "Maximum Magicka": ("MaxMagickaFlat", "Max Magicka"),
+    "Maximum Health": ("MaxHealthFlat", "Max Health"),
+    "Health Recovery": ("HealthRecovery", "Health Recovery"),
     "Weapon and Spell Damage": ("WeaponAndSpellDamageFlat", "Weapon and Spell Damage"),
     "Critical Chance": ("CriticalRating", "Critical Chance"),
     "Offensive Penetration": ("PhysicalAndSpellPenetration", "Offensive Penetration"),
 }
 
+# Hand-written bonuses for "effect" text the parser can't turn into stats, keyed
+# by set name -> (piece_count, raw Rust BonusData code per bonus). Damage
+# bonuses whose value differs against players get one bonus per combat mode;
+# ones that hinge on the target's actions (e.g. Languor of Peryite's damage to
+# blocking players) are left out.
+EFFECT_BONUSES: dict[str, tuple[int, list[str]]] = {
+    # Assumes no permanent pet; reduced to 5% under Battle Spirit
+    "Beacon of Oblivion": (5, [
+        """\
+BonusData::new(
+                    "Beacon of Oblivion 5pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Damage Done", BonusTarget::Damage, 0.15),
+                )
+                .with_combat_mode(CombatMode::Pve)""",
+        """\
+BonusData::new(
+                    "Beacon of Oblivion 5pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Damage Done vs Players", BonusTarget::Damage, 0.05),
+                )
+                .with_combat_mode(CombatMode::Pvp)""",
+    ]),
+    # Only against players; the 5% Stamina cost reduction is not modeled
+    "Marksman's Crest": (5, [
+        """\
+BonusData::new(
+                    "Marksman's Crest 5pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Bow Damage vs Players", BonusTarget::Damage, 0.08),
+                )
+                .with_skill_line_filter(SkillLineName::Bow)
+                .with_combat_mode(CombatMode::Pvp)""",
+    ]),
+}
+
 # Proc effects keyed by set name -> (piece_count, raw Rust vec![...] code)
 PROC_EFFECTS: dict[str, tuple[int, str]] = {
+    # Assumes the Burning Light area is up; the spear is limited by its 6s area cooldown
+    "Aetheric Lancer": (5, """\
+vec![SetProcEffect {
+                    name: "Aetheric Lancer Spear".to_string(),
+                    trigger: SetProcTrigger::OnSkillLineCast(SkillLineName::AedricSpear),
+                    action: SetProcAction::DamageProc {
+                        hit_damage: 1684.0,
+                        hit_flags: DamageFlags::MAGIC | DamageFlags::SINGLE_TARGET | DamageFlags::DIRECT,
+                        dot_total_damage: 0.0,
+                        dot_duration: 0.0,
+                        dot_flags: DamageFlags::empty(),
+                    },
+                    cooldown: 6.0,
+                    proc_chance: 1.0,
+                }]"""),
+    # Martial melee crits only in game; modeled on any critical hit
+    "Aegis Caller": (5, """\
+vec![SetProcEffect {
+                    name: "Lesser Aegis".to_string(),
+                    trigger: SetProcTrigger::OnCriticalHit,
+                    action: SetProcAction::SummonedEntity {
+                        damage_per_attack: 478.0,
+                        attack_interval: 1.0,
+                        duration: 8.5,
+                        delay: 2.5,
+                        flags: DamageFlags::BLEED | DamageFlags::AOE | DamageFlags::DIRECT,
+                    },
+                    cooldown: 12.0,
+                    proc_chance: 1.0,
+                }]"""),
     "Ansuul's Torment": (5, """\
 vec![
                     SetProcEffect {
@@ -54,6 +126,7 @@ vec![
                             dot_flags: DamageFlags::empty(),
                         },
                         cooldown: 0.0,
+                        proc_chance: 1.0,
                     },
                     SetProcEffect {
                         name: "Ansuul's Fury".to_string(),
@@ -70,6 +143,7 @@ vec![
                             at_max_buff_duration: 0.0,
                         },
                         cooldown: 0.0,
+                        proc_chance: 1.0,
                     },
                 ]"""),
     "Arms of Relequen": (5, """\
@@ -85,6 +159,21 @@ vec![SetProcEffect {
                         flags: DamageFlags::PHYSICAL | DamageFlags::SINGLE_TARGET | DamageFlags::DOT,
                     },
                     cooldown: 0.0,
+                    proc_chance: 1.0,
+                }]"""),
+    "Auroran's Thunder": (5, """\
+vec![SetProcEffect {
+                    name: "Auroran's Thunder".to_string(),
+                    trigger: SetProcTrigger::OnDirectDamage,
+                    action: SetProcAction::SummonedEntity {
+                        damage_per_attack: 396.0,
+                        attack_interval: 1.0,
+                        duration: 5.0,
+                        delay: 0.0,
+                        flags: DamageFlags::SHOCK | DamageFlags::AOE | DamageFlags::DIRECT,
+                    },
+                    cooldown: 10.0,
+                    proc_chance: 1.0,
                 }]"""),
     "Bahsei's Mania": (5, """\
 vec![SetProcEffect {
@@ -96,6 +185,40 @@ vec![SetProcEffect {
                         threshold_pct: None,
                     },
                     cooldown: 0.0,
+                    proc_chance: 1.0,
+                }]"""),
+    "Briarheart": (5, """\
+vec![SetProcEffect {
+                    name: "Briarheart".to_string(),
+                    trigger: SetProcTrigger::OnCriticalHit,
+                    action: SetProcAction::StackingBuff {
+                        per_stack_target: None,
+                        per_stack_value: 0.0,
+                        max_stacks: 1,
+                        stack_duration: 10.0,
+                        stack_cooldown: 15.0,
+                        at_max_buff_name: "Briarheart".to_string(),
+                        at_max_buff_target: BonusTarget::WeaponAndSpellDamageFlat,
+                        at_max_buff_value: 450.0,
+                        at_max_buff_duration: 10.0,
+                    },
+                    cooldown: 0.0,
+                    proc_chance: 1.0,
+                }]"""),
+    # Random element; modeled as Flame
+    "Caluurion's Legacy": (5, """\
+vec![SetProcEffect {
+                    name: "Caluurion's Legacy Projectile".to_string(),
+                    trigger: SetProcTrigger::OnCriticalHit,
+                    action: SetProcAction::DamageProc {
+                        hit_damage: 894.0,
+                        hit_flags: DamageFlags::FLAME | DamageFlags::SINGLE_TARGET | DamageFlags::DIRECT,
+                        dot_total_damage: 0.0,
+                        dot_duration: 0.0,
+                        dot_flags: DamageFlags::empty(),
+                    },
+                    cooldown: 5.0,
+                    proc_chance: 1.0,
                 }]"""),
     "Coral Riptide": (5, """\
 vec![SetProcEffect {
@@ -107,6 +230,35 @@ vec![SetProcEffect {
                         threshold_pct: Some(50.0),
                     },
                     cooldown: 0.0,
+                    proc_chance: 1.0,
+                }]"""),
+    "Defiler": (5, """\
+vec![SetProcEffect {
+                    name: "Defiler Hunger".to_string(),
+                    trigger: SetProcTrigger::OnCriticalHit,
+                    action: SetProcAction::DamageProc {
+                        hit_damage: 990.0,
+                        hit_flags: DamageFlags::POISON | DamageFlags::AOE | DamageFlags::DIRECT,
+                        dot_total_damage: 0.0,
+                        dot_duration: 0.0,
+                        dot_flags: DamageFlags::empty(),
+                    },
+                    cooldown: 5.0,
+                    proc_chance: 1.0,
+                }]"""),
+    "Gryphon's Reprisal": (5, """\
+vec![SetProcEffect {
+                    name: "Gryphon's Reprisal".to_string(),
+                    trigger: SetProcTrigger::OnCriticalHit,
+                    action: SetProcAction::DamageProc {
+                        hit_damage: 0.0,
+                        hit_flags: DamageFlags::empty(),
+                        dot_total_damage: 4758.0,
+                        dot_duration: 10.0,
+                        dot_flags: DamageFlags::BLEED | DamageFlags::AOE | DamageFlags::DOT,
+                    },
+                    cooldown: 20.0,
+                    proc_chance: 1.0,
                 }]"""),
     "Kinras's Wrath": (5, """\
 vec![SetProcEffect {
@@ -124,6 +276,48 @@ vec![SetProcEffect {
                         at_max_buff_duration: 5.0,
                     },
                     cooldown: 0.0,
+                    proc_chance: 1.0,
+                }]"""),
+    "Morkuldin": (5, """\
+vec![SetProcEffect {
+                    name: "Morkuldin Animated Weapon".to_string(),
+                    trigger: SetProcTrigger::OnLightAttack,
+                    action: SetProcAction::SummonedEntity {
+                        damage_per_attack: 422.0,
+                        attack_interval: 1.0,
+                        duration: 15.0,
+                        delay: 0.0,
+                        flags: DamageFlags::PHYSICAL | DamageFlags::SINGLE_TARGET | DamageFlags::DIRECT,
+                    },
+                    cooldown: 15.0,
+                    proc_chance: 1.0,
+                }]"""),
+    "Night Mother's Gaze": (5, """\
+vec![SetProcEffect {
+                    name: "Night Mother's Gaze".to_string(),
+                    trigger: SetProcTrigger::OnCriticalHit,
+                    action: SetProcAction::EnemyDebuff {
+                        debuff_name: "Major Breach".to_string(),
+                        target: BonusTarget::EnemyResistanceReduction,
+                        value: 5948.0,
+                        duration: 4.0,
+                    },
+                    cooldown: 0.0,
+                    proc_chance: 1.0,
+                }]"""),
+    "Noxious Boulder": (5, """\
+vec![SetProcEffect {
+                    name: "Noxious Boulder".to_string(),
+                    trigger: SetProcTrigger::OnHeavyAttack,
+                    action: SetProcAction::DamageProc {
+                        hit_damage: 1049.0,
+                        hit_flags: DamageFlags::POISON | DamageFlags::AOE | DamageFlags::DIRECT,
+                        dot_total_damage: 3650.0,
+                        dot_duration: 5.0,
+                        dot_flags: DamageFlags::POISON | DamageFlags::AOE | DamageFlags::DOT,
+                    },
+                    cooldown: 20.0,
+                    proc_chance: 1.0,
                 }]"""),
     "Perfected Ansuul's Torment": (5, """\
 vec![
@@ -138,6 +332,7 @@ vec![
                             dot_flags: DamageFlags::empty(),
                         },
                         cooldown: 0.0,
+                        proc_chance: 1.0,
                     },
                     SetProcEffect {
                         name: "Perfected Ansuul's Fury".to_string(),
@@ -154,6 +349,7 @@ vec![
                             at_max_buff_duration: 0.0,
                         },
                         cooldown: 0.0,
+                        proc_chance: 1.0,
                     },
                 ]"""),
     "Perfected Arms of Relequen": (5, """\
@@ -169,6 +365,7 @@ vec![SetProcEffect {
                         flags: DamageFlags::PHYSICAL | DamageFlags::SINGLE_TARGET | DamageFlags::DOT,
                     },
                     cooldown: 0.0,
+                    proc_chance: 1.0,
                 }]"""),
     "Perfected Bahsei's Mania": (5, """\
 vec![SetProcEffect {
@@ -180,6 +377,7 @@ vec![SetProcEffect {
                         threshold_pct: None,
                     },
                     cooldown: 0.0,
+                    proc_chance: 1.0,
                 }]"""),
     "Perfected Coral Riptide": (5, """\
 vec![SetProcEffect {
@@ -191,6 +389,7 @@ vec![SetProcEffect {
                         threshold_pct: Some(50.0),
                     },
                     cooldown: 0.0,
+                    proc_chance: 1.0,
                 }]"""),
     "Perfected Whorl of the Depths": (5, """\
 vec![SetProcEffect {
@@ -204,6 +403,7 @@ vec![SetProcEffect {
                         dot_flags: DamageFlags::FROST | DamageFlags::AOE | DamageFlags::DOT,
                     },
                     cooldown: 6.0,
+                    proc_chance: 1.0,
                 }]"""),
     "Pillar of Nirn": (5, """\
 vec![SetProcEffect {
@@ -217,11 +417,89 @@ vec![SetProcEffect {
                         dot_flags: DamageFlags::BLEED | DamageFlags::SINGLE_TARGET | DamageFlags::DOT,
                     },
                     cooldown: 10.0,
+                    proc_chance: 1.0,
+                }]"""),
+    # 209 Flame every third DoT tick, averaged over every tick
+    "Runecarver's Blaze": (5, """\
+vec![SetProcEffect {
+                    name: "Runecarver's Blaze".to_string(),
+                    trigger: SetProcTrigger::OnDotTick,
+                    action: SetProcAction::DamageProc {
+                        hit_damage: 69.7,
+                        hit_flags: DamageFlags::FLAME | DamageFlags::SINGLE_TARGET | DamageFlags::DOT,
+                        dot_total_damage: 0.0,
+                        dot_duration: 0.0,
+                        dot_flags: DamageFlags::empty(),
+                    },
+                    cooldown: 0.0,
+                    proc_chance: 1.0,
+                }]"""),
+    # Execute abilities only in game; assumes one is slotted and cast on cooldown
+    "Sheer Venom": (5, """\
+vec![SetProcEffect {
+                    name: "Sheer Venom".to_string(),
+                    trigger: SetProcTrigger::OnDirectDamage,
+                    action: SetProcAction::ExecuteScalingDamage {
+                        hit_damage: 0.0,
+                        dot_total_damage: 948.0,
+                        dot_duration: 6.0,
+                        flags: DamageFlags::POISON | DamageFlags::SINGLE_TARGET | DamageFlags::DOT,
+                        max_bonus: 1.0,
+                        threshold_pct: 1.0,
+                    },
+                    cooldown: 6.0,
+                    proc_chance: 1.0,
+                }]"""),
+    "Sunderflame": (5, """\
+vec![SetProcEffect {
+                    name: "Sunderflame".to_string(),
+                    trigger: SetProcTrigger::OnHeavyAttack,
+                    action: SetProcAction::DamageProc {
+                        hit_damage: 0.0,
+                        hit_flags: DamageFlags::empty(),
+                        dot_total_damage: 1925.0,
+                        dot_duration: 10.0,
+                        dot_flags: DamageFlags::FLAME | DamageFlags::SINGLE_TARGET | DamageFlags::DOT,
+                    },
+                    cooldown: 10.0,
+                    proc_chance: 1.0,
+                }]"""),
+    "Tarnished Nightmare": (5, """\
+vec![SetProcEffect {
+                    name: "Tarnished Nightmare Shards".to_string(),
+                    trigger: SetProcTrigger::OnCriticalHit,
+                    action: SetProcAction::DamageProc {
+                        hit_damage: 1129.0,
+                        hit_flags: DamageFlags::PHYSICAL | DamageFlags::AOE | DamageFlags::DIRECT,
+                        dot_total_damage: 0.0,
+                        dot_duration: 0.0,
+                        dot_flags: DamageFlags::empty(),
+                    },
+                    cooldown: 8.0,
+                    proc_chance: 1.0,
+                }]"""),
+    "Tharriker's Strike": (5, """\
+vec![SetProcEffect {
+                    name: "Tharriker's Strike".to_string(),
+                    trigger: SetProcTrigger::OnHeavyAttack,
+                    action: SetProcAction::StackingBuff {
+                        per_stack_target: None,
+                        per_stack_value: 0.0,
+                        max_stacks: 1,
+                        stack_duration: 4.0,
+                        stack_cooldown: 1.0,
+                        at_max_buff_name: "Major Berserk".to_string(),
+                        at_max_buff_target: BonusTarget::Damage,
+                        at_max_buff_value: 0.10,
+                        at_max_buff_duration: 4.0,
+                    },
+                    cooldown: 0.0,
+                    proc_chance: 1.0,
                 }]"""),
     "Tzogvin's Warband": (5, """\
 vec![SetProcEffect {
                     name: "Tzogvin's Warband".to_string(),
-                    trigger: SetProcTrigger::OnDirectDamage,
+                    trigger: SetProcTrigger::OnCriticalHit,
                     action: SetProcAction::StackingBuff {
                         per_stack_target: Some(BonusTarget::CriticalRating),
                         per_stack_value: 177.0,
@@ -234,7 +512,32 @@ vec![SetProcEffect {
                         at_max_buff_duration: 5.0,
                     },
                     cooldown: 0.0,
+                    proc_chance: 1.0,
                 }]"""),
+    # Restores are only tracked as sustain. Swapping restarts the 15 second charge
+    "Vivec's Duality": (5, """\
+vec![
+                    SetProcEffect {
+                        name: "Vivec's Duality Magicka".to_string(),
+                        trigger: SetProcTrigger::OnBarSwap,
+                        action: SetProcAction::RestoreResource {
+                            resource: Resource::Magicka,
+                            amount: 6636.0,
+                        },
+                        cooldown: 15.0,
+                        proc_chance: 1.0,
+                    },
+                    SetProcEffect {
+                        name: "Vivec's Duality Stamina".to_string(),
+                        trigger: SetProcTrigger::OnBarSwap,
+                        action: SetProcAction::RestoreResource {
+                            resource: Resource::Stamina,
+                            amount: 6636.0,
+                        },
+                        cooldown: 15.0,
+                        proc_chance: 1.0,
+                    },
+                ]"""),
     "Whorl of the Depths": (5, """\
 vec![SetProcEffect {
                     name: "Whorl of the Depths".to_string(),
@@ -247,8 +550,77 @@ vec![SetProcEffect {
                         dot_flags: DamageFlags::FROST | DamageFlags::AOE | DamageFlags::DOT,
                     },
                     cooldown: 6.0,
+                    proc_chance: 1.0,
                 }]"""),
     # Monster sets
+    "Anthelmir's Construct": (2, """\
+vec![SetProcEffect {
+                    name: "Anthelmir's Construct Axe".to_string(),
+                    trigger: SetProcTrigger::OnHeavyAttack,
+                    action: SetProcAction::DamageProc {
+                        hit_damage: 1572.0,
+                        hit_flags: DamageFlags::PHYSICAL | DamageFlags::SINGLE_TARGET | DamageFlags::DIRECT,
+                        dot_total_damage: 0.0,
+                        dot_duration: 0.0,
+                        dot_flags: DamageFlags::empty(),
+                    },
+                    cooldown: 10.0,
+                    proc_chance: 1.0,
+                }]"""),
+    "Archdruid Devyric": (2, """\
+vec![
+                    SetProcEffect {
+                        name: "Archdruid Devyric Lightning".to_string(),
+                        trigger: SetProcTrigger::OnHeavyAttack,
+                        action: SetProcAction::DamageProc {
+                            hit_damage: 650.0,
+                            hit_flags: DamageFlags::SHOCK | DamageFlags::AOE | DamageFlags::DIRECT,
+                            dot_total_damage: 0.0,
+                            dot_duration: 0.0,
+                            dot_flags: DamageFlags::empty(),
+                        },
+                        cooldown: 15.0,
+                        proc_chance: 1.0,
+                    },
+                    SetProcEffect {
+                        name: "Archdruid Devyric Vulnerability".to_string(),
+                        trigger: SetProcTrigger::OnHeavyAttack,
+                        action: SetProcAction::EnemyDebuff {
+                            debuff_name: "Major Vulnerability".to_string(),
+                            target: BonusTarget::EnemyDamageTaken,
+                            value: 0.10,
+                            duration: 7.0,
+                        },
+                        cooldown: 15.0,
+                        proc_chance: 1.0,
+                    },
+                ]"""),
+    "Maw of the Infernal": (2, """\
+vec![SetProcEffect {
+                    name: "Maw of the Infernal Daedroth".to_string(),
+                    trigger: SetProcTrigger::OnLightAttack,
+                    action: SetProcAction::SummonedEntity {
+                        damage_per_attack: 599.0,
+                        attack_interval: 2.0,
+                        duration: 15.0,
+                        delay: 0.0,
+                        flags: DamageFlags::FLAME | DamageFlags::SINGLE_TARGET | DamageFlags::DIRECT,
+                    },
+                    cooldown: 15.0,
+                    proc_chance: 0.33,
+                }]"""),
+    # Every third status effect completes the 3 stacks of Zaudrus's Ambition
+    "Baron Zaudrus": (2, """\
+vec![SetProcEffect {
+                    name: "Zaudrus's Ambition".to_string(),
+                    trigger: SetProcTrigger::OnStatusEffectApplied,
+                    action: SetProcAction::RestoreResource {
+                        resource: Resource::Ultimate,
+                        amount: 4.0,
+                    },
+                    cooldown: 1.0,
+                    proc_chance: 1.0 / 3.0,
+                }]"""),
     "Kjalnar's Nightmare": (2, """\
 vec![SetProcEffect {
                     name: "Bone Colossus".to_string(),
@@ -261,6 +633,7 @@ vec![SetProcEffect {
                         dot_flags: DamageFlags::empty(),
                     },
                     cooldown: 10.0,
+                    proc_chance: 1.0,
                 }]"""),
     "Stormfist": (2, """\
 vec![SetProcEffect {
@@ -274,19 +647,35 @@ vec![SetProcEffect {
                         dot_flags: DamageFlags::empty(),
                     },
                     cooldown: 8.0,
+                    proc_chance: 1.0,
+                }]"""),
+    "Valkyn Skoria": (2, """\
+vec![SetProcEffect {
+                    name: "Valkyn Skoria Meteor".to_string(),
+                    trigger: SetProcTrigger::OnDotTick,
+                    action: SetProcAction::DamageProc {
+                        hit_damage: 1342.0,
+                        hit_flags: DamageFlags::FLAME | DamageFlags::SINGLE_TARGET | DamageFlags::DIRECT,
+                        dot_total_damage: 0.0,
+                        dot_duration: 0.0,
+                        dot_flags: DamageFlags::empty(),
+                    },
+                    cooldown: 5.0,
+                    proc_chance: 0.08,
                 }]"""),
     "Zaan": (2, """\
 vec![SetProcEffect {
                     name: "Zaan Fire Beam".to_string(),
-                    trigger: SetProcTrigger::OnDealDamage,
-                    action: SetProcAction::DamageProc {
-                        hit_damage: 0.0,
-                        hit_flags: DamageFlags::empty(),
-                        dot_total_damage: 9000.0,
-                        dot_duration: 6.0,
-                        dot_flags: DamageFlags::FLAME | DamageFlags::SINGLE_TARGET | DamageFlags::DOT,
+                    trigger: SetProcTrigger::OnCriticalHit,
+                    action: SetProcAction::RampingBeam {
+                        base_damage_per_tick: 145.0,
+                        ramp_per_tick: 1.0,
+                        tick_interval: 1.0,
+                        duration: 10.0,
+                        flags: DamageFlags::FLAME | DamageFlags::SINGLE_TARGET | DamageFlags::DOT,
                     },
-                    cooldown: 18.0,
+                    cooldown: 20.0,
+                    proc_chance: 0.33,
                 }]"""),
     # Mythic sets
     "Belharza's Band": (1, """\
@@ -296,6 +685,7 @@ vec![
                         trigger: SetProcTrigger::OnLightAttack,
                         action: SetProcAction::FlatLightAttackBonus { value: 900.0 },
                         cooldown: 0.0,
+                        proc_chance: 1.0,
                     },
                     SetProcEffect {
                         name: "Belharza's Band Proc".to_string(),
@@ -308,6 +698,7 @@ vec![
                             dot_flags: DamageFlags::empty(),
                         },
                         cooldown: 10.0,
+                        proc_chance: 1.0,
                     },
                 ]"""),
 }
@@ -356,6 +747,9 @@ def generate_set(entry: dict, rust_type: str) -> str:
         if code is not None:
             pc = bonus["pieces"]
             bonuses_by_piece.setdefault(pc, []).append(code)
+    if name in EFFECT_BONUSES:
+        pc, codes = EFFECT_BONUSES[name]
+        bonuses_by_piece.setdefault(pc, []).extend(codes)
 
     # Build the chain
     parts = [f'        SetData::new("{escaped_name}", SetType::{rust_type})']
@@ -406,7 +800,7 @@ def generate_file(sets: list[dict], rust_type: str, category: str) -> str:
         bonus.get("type") == "stat" and bonus["stat"] in STAT_MAP
         for entry in sets
         for bonus in entry.get("bonuses", [])
-    )
+    ) or any(entry["name"] in EFFECT_BONUSES for entry in sets)
 
     has_procs = any(entry["name"] in PROC_EFFECTS for entry in sets)
 
@@ -417,6 +811,11 @@ def generate_file(sets: list[dict], rust_type: str, category: str) -> str:
         if entry["name"] in PROC_EFFECTS
     )
     procs_use_bonus_target = "BonusTarget::" in proc_code_for_file
+    effect_code_for_file = "".join(
+        "".join(EFFECT_BONUSES[entry["name"]][1])
+        for entry in sets
+        if entry["name"] in EFFECT_BONUSES
+    )
 
     # Build import list
     domain_types = []
@@ -430,6 +829,12 @@ def generate_file(sets: list[dict], rust_type: str, category: str) -> str:
     domain_types.extend(["SetData", "SetType"])
     if has_procs:
         domain_types.extend(["SetProcAction", "SetProcEffect", "SetProcTrigger"])
+    if "Resource::" in proc_code_for_file:
+        domain_types.append("Resource")
+    if "SkillLineName::" in proc_code_for_file + effect_code_for_file:
+        domain_types.append("SkillLineName")
+    if "CombatMode::" in effect_code_for_file:
+        domain_types.append("CombatMode")
     domain_types.sort()
 
     imports = (
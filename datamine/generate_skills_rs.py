@@ -472,14 +472,50 @@ DOT_DURATION_OVERRIDES: dict[str, float] = {
 # Contains raw Rust snippets for builder calls not derivable from JSON.
 # ---------------------------------------------------------------------------
 
+# Arcanist Crux generators: +1 Crux per cast, up to 3, lasting 30s
+CRUX_GENERATOR = """\
+vec![StackMechanic::new("Crux")
+            .with_gain(StackGain::Cast, 1)
+            .with_max_stacks(3)
+            .with_decay(30.0)]"""
+
+# Ground areas re-read stats, buffs and debuffs on every tick
+DYNAMIC_DOT = "DotSnapshot::empty()"
+
+# Back-to-back Aedric Spear jabs hit harder while the flurry keeps going
+JABS_STACKS = """\
+vec![StackMechanic::new("Jabs")
+            .with_gain(StackGain::Cast, 1)
+            .with_max_stacks(3)
+            .with_decay(4.0)
+            .with_damage_per_stack(0.05)]"""
+
+# Skeletal pets and Blastbones leave a corpse behind; Boneyard consumes them
+CORPSE_GENERATOR = """\
+vec![StackMechanic::new("Corpse")
+            .with_gain(StackGain::Cast, 1)
+            .with_decay(20.0)]"""
+
+CORPSE_CONSUMER = 'vec![StackMechanic::new("Corpse").consuming()]'
+
+
+def dot_tick_ramp(name: str, increase: float) -> str:
+    """Stacks for a DoT whose every tick deals `increase` more than the first."""
+    return f"""\
+vec![StackMechanic::new("{name}")
+            .with_gain(StackGain::DotTick, 1)
+            .with_damage_per_stack({increase})]"""
+
+
 SKILL_OVERRIDES: dict[str, dict] = {
     # ---- Templar ----
-    "Puncturing Strikes": {"channel_time": 0.8},
+    "Puncturing Strikes": {"channel_time": 0.8, "stacks": JABS_STACKS},
     "Biting Jabs": {
         "channel_time": 0.8,
+        "stacks": JABS_STACKS,
         "bonuses": 'vec![MAJOR_BRUTALITY.clone().with_duration(10.0), MAJOR_SORCERY.clone().with_duration(10.0)]',
     },
-    "Puncturing Sweep": {"channel_time": 0.8},
+    "Puncturing Sweep": {"channel_time": 0.8, "stacks": JABS_STACKS},
     "Solar Flare": {"spammable": True, "bonuses": 'vec![EMPOWER.clone()]'},
     "Dark Flare": {"spammable": True, "bonuses": 'vec![EMPOWER.clone()]'},
     "Solar Barrage": {"bonuses": 'vec![EMPOWER.clone().with_duration(20.0)]'},
@@ -508,21 +544,35 @@ SkillDamage::new().with_hits(vec![
     "Rite of Passage": {"channel_time": 4.0},
     "Practiced Incantation": {"channel_time": 8.0},
     "Remembrance": {"channel_time": 4.0},
-    "Ritual of Retribution": {"damage": """\
-SkillDamage::new().with_dots(vec![DotDamage::new(
-            20.0,
-            DamageFlags::magic_aoe(),
-            0.018782,
-            0.19721,
-        )
-        .with_interval(2.0)
-        .with_increase_per_tick(0.12)])"""},
+    "Ritual of Retribution": {
+        "damage": """\
+SkillDamage::new().with_dots(vec![
+            DotDamage::new(20.0, DamageFlags::magic_aoe(), 0.018782, 0.19721).with_interval(2.0),
+        ])""",
+        "stacks": dot_tick_ramp("Ritual of Retribution", 0.12),
+    },
 
     # ---- Dragonknight ----
     "Lava Whip": {"spammable": True},
     "Flame Lash": {"spammable": True},
-    "Molten Whip": {"spammable": True},
-    "Venomous Claw": {"damage": """\
+    "Molten Whip": {
+        "spammable": True,
+        "stacks": """\
+vec![StackMechanic::new("Seething Fury")
+            .with_gain(StackGain::SkillLineCast(SkillLineName::ArdentFlame), 1)
+            .with_max_stacks(3)
+            .with_decay(15.0)
+            .consuming()
+            .with_damage_per_stack(0.2)
+            .with_bonuses_per_stack(vec![BonusData::new(
+                "Seething Fury",
+                BonusSource::Skill,
+                BonusTrigger::Passive,
+                BonusValue::new("Seething Fury", BonusTarget::WeaponAndSpellDamageFlat, 100.0),
+            )])]""",
+    },
+    "Venomous Claw": {
+        "damage": """\
 SkillDamage::new()
                 .with_hits(vec![HitDamage::new(
                     DamageFlags::poison_single(),
@@ -535,8 +585,9 @@ SkillDamage::new()
                     0.015495,
                     0.162697,
                 )
-                .with_interval(2.0)
-                .with_increase_per_tick(0.12)])"""},
+                .with_interval(2.0)])""",
+        "stacks": dot_tick_ramp("Venomous Claw", 0.12),
+    },
     "Noxious Breath": {"bonuses": 'vec![MAJOR_BREACH.clone()]'},
     "Engulfing Flames": {},  # auto-generated damage is fine
     "Inferno": {"bonuses": """\
@@ -649,7 +700,11 @@ vec![
             MAJOR_BERSERK.clone().with_duration(10.0),
         ]"""},
     "Grim Focus": {
-        "proc_light_attacks": 4,
+        "stacks": """\
+vec![StackMechanic::new("Grim Focus")
+            .with_gain(StackGain::LightAttack, 1)
+            .with_required_stacks(4)
+            .consuming()]""",
         "bonuses": """\
 vec![
             MAJOR_PROPHECY
@@ -661,7 +716,11 @@ vec![
         ]""",
     },
     "Merciless Resolve": {
-        "proc_light_attacks": 4,
+        "stacks": """\
+vec![StackMechanic::new("Merciless Resolve")
+            .with_gain(StackGain::LightAttack, 1)
+            .with_required_stacks(4)
+            .consuming()]""",
         "bonuses": """\
 vec![
             MAJOR_PROPHECY
@@ -673,7 +732,11 @@ vec![
         ]""",
     },
     "Relentless Focus": {
-        "proc_light_attacks": 4,
+        "stacks": """\
+vec![StackMechanic::new("Relentless Focus")
+            .with_gain(StackGain::LightAttack, 1)
+            .with_required_stacks(4)
+            .consuming()]""",
         "bonuses": """\
 vec![
             MAJOR_PROPHECY
@@ -704,6 +767,9 @@ vec![
         ]"""},
 
     # ---- Sorcerer ----
+    "Lightning Splash": {"dot_snapshot": DYNAMIC_DOT},
+    "Liquid Lightning": {"dot_snapshot": DYNAMIC_DOT},
+    "Lightning Flood": {"dot_snapshot": DYNAMIC_DOT},
     "Crystal Shard": {"spammable": True},
     "Crystal Fragments": {"spammable": True},
     "Crystal Weapon": {
@@ -796,15 +862,13 @@ SkillDamage::new().with_hits(vec![
             HitDamage::new(DamageFlags::shock_single(), 0.09297, 0.976185).with_execute_threshold(0.20),
         ])""",
     },
-    "Hurricane": {"damage": """\
-SkillDamage::new().with_dots(vec![DotDamage::new(
-            20.0,
-            DamageFlags::physical_aoe(),
-            0.018782,
-            0.19721,
-        )
-        .with_interval(2.0)
-        .with_increase_per_tick(0.12)])"""},
+    "Hurricane": {
+        "damage": """\
+SkillDamage::new().with_dots(vec![
+            DotDamage::new(20.0, DamageFlags::physical_aoe(), 0.018782, 0.19721).with_interval(2.0),
+        ])""",
+        "stacks": dot_tick_ramp("Hurricane", 0.12),
+    },
     "Surge": {"bonuses": """\
 vec![
             MAJOR_BRUTALITY.clone(),
@@ -861,10 +925,13 @@ vec![BonusData::new(
             BonusValue::new("Sacrificial Bones", BonusTarget::Damage, 0.15),
         )]""",
     },
-    "Blighted Blastbones": {"damage": """\
+    "Blighted Blastbones": {
+        "damage": """\
 SkillDamage::new().with_hits(vec![
             HitDamage::new(DamageFlags::disease_aoe(), 0.12, 1.26).with_delay(2.5),
-        ])"""},
+        ])""",
+        "stacks": CORPSE_GENERATOR,
+    },
     "Grave Lord's Sacrifice": {
         "bonuses": """\
 vec![BonusData::new(
@@ -875,6 +942,8 @@ vec![BonusData::new(
         )]""",
     },
     "Boneyard": {
+        "stacks": CORPSE_CONSUMER,
+        "dot_snapshot": DYNAMIC_DOT,
         "bonuses": """\
 vec![BonusData::new(
             "Boneyard",
@@ -885,6 +954,8 @@ vec![BonusData::new(
         .with_duration(10.0)]""",
     },
     "Avid Boneyard": {
+        "stacks": CORPSE_CONSUMER,
+        "dot_snapshot": DYNAMIC_DOT,
         "bonuses": """\
 vec![BonusData::new(
             "Avid Boneyard",
@@ -895,6 +966,8 @@ vec![BonusData::new(
         .with_duration(10.0)]""",
     },
     "Unnerving Boneyard": {
+        "stacks": CORPSE_CONSUMER,
+        "dot_snapshot": DYNAMIC_DOT,
         "bonuses": """\
 vec![
             BonusData::new(
@@ -908,6 +981,7 @@ vec![
         ]""",
     },
     "Skeletal Mage": {
+        "stacks": CORPSE_GENERATOR,
         "bonuses": """\
 vec![BonusData::new(
             "Skeletal Mage",
@@ -918,6 +992,7 @@ vec![BonusData::new(
         .with_duration(20.0)]""",
     },
     "Skeletal Arcanist": {
+        "stacks": CORPSE_GENERATOR,
         "bonuses": """\
 vec![BonusData::new(
             "Skeletal Arcanist",
@@ -943,8 +1018,16 @@ SkillDamage::new().with_dots(vec![DotDamage::new(
             0.02066,
             0.21693,
         )
-        .with_interval(2.0)
-        .with_increase_per_tick(0.15)])""",
+        .with_interval(2.0)])""",
+        "stacks": """\
+vec![
+            StackMechanic::new("Skeletal Archer")
+                .with_gain(StackGain::DotTick, 1)
+                .with_damage_per_stack(0.15),
+            StackMechanic::new("Corpse")
+                .with_gain(StackGain::Cast, 1)
+                .with_decay(20.0),
+        ]""",
     },
     "Shocking Siphon": {
         "bonuses": """\
@@ -1086,21 +1169,29 @@ SkillDamage::new().with_hits(vec![
         ])"""},
 
     # ---- Arcanist ----
-    "The Languid Eye": {"damage": """\
-SkillDamage::new().with_dots(vec![DotDamage::new(
-            10.0,
-            DamageFlags::magic_aoe(),
-            0.055222,
-            0.57983,
-        )
-        .with_interval(1.0)
-        .with_increase_per_tick(0.05)])"""},
-    "Runeblades": {"spammable": True},
-    "Escalating Runeblades": {"spammable": True},
-    "Writhing Runeblades": {"spammable": True},
-    "Fatecarver": {"channel_time": 4.0},
-    "Exhausting Fatecarver": {"channel_time": 4.0},
-    "Pragmatic Fatecarver": {"channel_time": 4.0},
+    "The Languid Eye": {
+        "damage": """\
+SkillDamage::new().with_dots(vec![
+            DotDamage::new(10.0, DamageFlags::magic_aoe(), 0.055222, 0.57983).with_interval(1.0),
+        ])""",
+        "stacks": dot_tick_ramp("The Languid Eye", 0.05),
+    },
+    "Runeblades": {"spammable": True, "stacks": CRUX_GENERATOR},
+    "Escalating Runeblades": {"spammable": True, "stacks": CRUX_GENERATOR},
+    "Writhing Runeblades": {"spammable": True, "stacks": CRUX_GENERATOR},
+    "Fatecarver": {"channel_time": 4.0, "stacks": """\
+vec![StackMechanic::new("Crux")
+            .consuming()
+            .with_damage_per_stack(0.33)]"""},
+    "Exhausting Fatecarver": {"channel_time": 4.0, "stacks": """\
+vec![StackMechanic::new("Crux")
+            .consuming()
+            .with_damage_per_stack(0.33)
+            .with_duration_per_stack(0.3)]"""},
+    "Pragmatic Fatecarver": {"channel_time": 4.0, "stacks": """\
+vec![StackMechanic::new("Crux")
+            .consuming()
+            .with_damage_per_stack(0.33)]"""},
     "Abyssal Impact": {
         "spammable": True,
         "bonuses": """\
@@ -1114,6 +1205,7 @@ vec![BonusData::new(
     },
     "Cephaliarch's Flail": {
         "spammable": True,
+        "stacks": CRUX_GENERATOR,
         "bonuses": """\
 vec![BonusData::new(
             "Abyssal Ink",
@@ -1125,6 +1217,10 @@ vec![BonusData::new(
     },
     "Tentacular Dread": {
         "spammable": True,
+        "stacks": """\
+vec![StackMechanic::new("Crux")
+            .consuming()
+            .with_damage_per_stack(0.33)]""",
         "bonuses": """\
 vec![BonusData::new(
             "Abyssal Ink",
@@ -1159,10 +1255,11 @@ vec![
 SkillDamage::new().with_hits(vec![
             HitDamage::new(DamageFlags::magic_aoe(), 0.12912, 1.35581).with_delay(2.0),
         ])"""},
-    "Runic Jolt": {"spammable": True},
-    "Runic Embrace": {"spammable": True},
+    "Runic Jolt": {"spammable": True, "stacks": CRUX_GENERATOR},
+    "Runic Embrace": {"spammable": True, "stacks": CRUX_GENERATOR},
     "Runic Sunder": {
         "spammable": True,
+        "stacks": CRUX_GENERATOR,
         "bonuses": """\
 vec![BonusData::new(
             "Runic Sunder Debuff",
@@ -1191,6 +1288,12 @@ vec![
             MAJOR_PROPHECY.clone().with_trigger(BonusTrigger::AbilitySlotted),
             MAJOR_SAVAGERY.clone().with_trigger(BonusTrigger::AbilitySlotted),
         ]"""},
+    "Runespite Ward": {"stacks": """\
+vec![StackMechanic::new("Crux").consuming()]"""},
+    "Impervious Runeward": {"stacks": """\
+vec![StackMechanic::new("Crux").consuming()]"""},
+    "Spiteward of the Lucid Mind": {"stacks": """\
+vec![StackMechanic::new("Crux").consuming()]"""},
     "Remedy Cascade": {"channel_time": 4.5},
     "Cascading Fortune": {"channel_time": 4.5},
     "Curative Surge": {"channel_time": 4.5},
@@ -1206,7 +1309,7 @@ SkillDamage::new().with_dots(vec![
         ])""",
     },
     "Focused Aim": {"bonuses": 'vec![MAJOR_BREACH.clone()]'},
-    "Volley": {"damage": """\
+    "Volley": {"dot_snapshot": DYNAMIC_DOT, "damage": """\
 SkillDamage::new().with_dots(vec![DotDamage::new(
             8.0,
             DamageFlags::physical_aoe(),
@@ -1215,7 +1318,7 @@ SkillDamage::new().with_dots(vec![DotDamage::new(
         )
         .with_delay(2.0)
         .with_interval(1.0)])"""},
-    "Arrow Barrage": {"damage": """\
+    "Arrow Barrage": {"dot_snapshot": DYNAMIC_DOT, "damage": """\
 SkillDamage::new().with_dots(vec![DotDamage::new(
             8.0,
             DamageFlags::physical_aoe(),
@@ -1224,7 +1327,7 @@ SkillDamage::new().with_dots(vec![DotDamage::new(
         )
         .with_delay(2.0)
         .with_interval(1.0)])"""},
-    "Endless Hail": {"damage": """\
+    "Endless Hail": {"dot_snapshot": DYNAMIC_DOT, "damage": """\
 SkillDamage::new().with_dots(vec![DotDamage::new(
             13.0,
             DamageFlags::physical_aoe(),
@@ -1263,10 +1366,12 @@ SkillDamage::new().with_hits(vec![
         ])"""},
 
     # ---- Destruction Staff ----
+    "Wall of Elements": {"dot_snapshot": DYNAMIC_DOT},
+    "Elemental Blockade": {"dot_snapshot": DYNAMIC_DOT},
     "Force Shock": {"spammable": True},
     "Crushing Shock": {"spammable": True},
     "Force Pulse": {"spammable": True},
-    "Unstable Wall of Elements": {"damage": """\
+    "Unstable Wall of Elements": {"dot_snapshot": DYNAMIC_DOT, "damage": """\
 SkillDamage::new()
                 .with_dots(vec![DotDamage::new(
                     8.0,
@@ -1328,6 +1433,16 @@ vec![
         ]""",
     },
     "Rally": {"bonuses": 'vec![MAJOR_BRUTALITY.clone(), MAJOR_SORCERY.clone()]'},
+    "Carve": {"stacks": """\
+vec![StackMechanic::new("Carve Bleed")
+            .with_gain(StackGain::Cast, 1)
+            .with_max_stacks(3)
+            .with_decay(12.0)
+            .with_damage_per_stack(0.1)]"""},
+    "Stampede": {"stacks": """\
+vec![
+            StackMechanic::new("Carve Bleed").with_damage_per_stack(0.1)
+        ]"""},
 
     # ---- Fighters Guild ----
     "Flawless Dawnbreaker": {
@@ -1558,9 +1673,13 @@ def generate_skill(entry: dict, skill_line_variant: str, class_variant: str) ->
         mult, thresh, scaling = overrides["execute"]
         parts.append(f"        .with_execute({mult}, {thresh}, ExecuteScaling::{scaling})")
 
-    # Proc light attacks
-    if "proc_light_attacks" in overrides:
-        parts.append(f"        .with_proc_light_attacks({overrides['proc_light_attacks']})")
+    # Stack mechanics (Crux, Grim Focus, Seething Fury...)
+    if "stacks" in overrides:
+        parts.append(f'        .with_stacks({overrides["stacks"]})')
+
+    # DoT parts locked in at cast time (default: the simulator's setting)
+    if "dot_snapshot" in overrides:
+        parts.append(f'        .with_dot_snapshot({overrides["dot_snapshot"]})')
 
     # Bonuses
     if "bonuses" in overrides:
@@ -1620,6 +1739,12 @@ def detect_domain_imports(code: str) -> set[str]:
         types.add("SkillDamage")
     if "ExecuteScaling::" in code:
         types.add("ExecuteScaling")
+    if "StackMechanic::" in code:
+        types.add("StackMechanic")
+    if "StackGain::" in code:
+        types.add("StackGain")
+    if "DotSnapshot::" in code:
+        types.add("DotSnapshot")
     if "BonusData::" in code or "BonusData::new" in code:
         types.add("BonusData")
     if "BonusSource::" in code:
@@ -1,5 +1,4 @@
-use crate::domain::CharacterStats;
-use crate::domain::formulas::crit_rating_to_chance;
+use crate::domain::{CharacterStats, CombatMode, Enemy};
 use crate::infrastructure::{format as fmt, logger, table};
 use crate::services::breakpoints_pipeline::{BreakpointGrid, BreakpointStat, BreakpointsPipeline};
 use clap::Args;
@@ -19,9 +18,26 @@ pub struct BreakpointsArgs {
     #[arg(long)]
     pub max_power: Option<f64>,
 
+    /// Overrides weapon damage (after --max-power)
+    #[arg(long)]
+    pub weapon_damage: Option<f64>,
+
+    /// Overrides spell damage (after --max-power)
+    #[arg(long)]
+    pub spell_damage: Option<f64>,
+
+    /// Rating shared by weapon and spell critical
     #[arg(long)]
     pub crit_rating: Option<f64>,
 
+    /// Extra rating for weapon critical only (e.g. Savagery)
+    #[arg(long)]
+    pub weapon_crit_rating: Option<f64>,
+
+    /// Extra rating for spell critical only (e.g. Prophecy)
+    #[arg(long)]
+    pub spell_crit_rating: Option<f64>,
+
     /// As multiplier, e.g. 1.75
     #[arg(long)]
     pub crit_damage: Option<f64>,
@@ -31,6 +47,19 @@ pub struct BreakpointsArgs {
 
     #[arg(long)]
     pub target_armor: Option<f64>,
+
+    /// Overrides the target's critical resistance
+    #[arg(long)]
+    pub target_crit_resistance: Option<f64>,
+
+    /// Target preset: trial-dummy, target-dummy, pvp-player or pvp-tank
+    /// (--target-armor and --target-crit-resistance override its values)
+    #[arg(long, value_parser = Enemy::parse)]
+    pub enemy: Option<Enemy>,
+
+    /// Against players: Battle Spirit, PvP player target unless --enemy is given
+    #[arg(long)]
+    pub pvp: bool,
 }
 
 impl BreakpointsArgs {
@@ -43,6 +72,14 @@ impl BreakpointsArgs {
         }
 
         let mut stats = CharacterStats::default();
+        if let Some(enemy) = &self.enemy {
+            enemy.apply_to(&mut stats);
+        } else if self.pvp {
+            Enemy::pvp_player().apply_to(&mut stats);
+        }
+        if self.pvp {
+            stats.combat_mode = CombatMode::Pvp;
+        }
         if let Some(v) = self.max_stat {
             stats.max_magicka = v;
             stats.max_stamina = v;
@@ -51,9 +88,21 @@ impl BreakpointsArgs {
             stats.weapon_damage = v;
             stats.spell_damage = v;
         }
+        if let Some(v) = self.weapon_damage {
+            stats.weapon_damage = v;
+        }
+        if let Some(v) = self.spell_damage {
+            stats.spell_damage = v;
+        }
         if let Some(v) = self.crit_rating {
             stats.critical_rating = v;
         }
+        if let Some(v) = self.weapon_crit_rating {
+            stats.weapon_critical_rating = v;
+        }
+        if let Some(v) = self.spell_crit_rating {
+            stats.spell_critical_rating = v;
+        }
         if let Some(v) = self.crit_damage {
             stats.critical_damage = v;
         }
@@ -63,6 +112,9 @@ impl BreakpointsArgs {
         if let Some(v) = self.target_armor {
             stats.target_armor = v;
         }
+        if let Some(v) = self.target_crit_resistance {
+            stats.target_critical_resistance = v;
+        }
 
         let grid = BreakpointsPipeline::run(&stats);
         self.display(&stats, &grid);
@@ -77,12 +129,14 @@ impl BreakpointsArgs {
             .map(|(i, (stat, edc))| {
                 let unit_desc = match stat {
                     BreakpointStat::CritDamage => "per 1%".to_string(),
-                    BreakpointStat::WeaponSpellDamage => "per 1 pt".to_string(),
+                    BreakpointStat::WeaponDamage | BreakpointStat::SpellDamage => {
+                        "per 1 pt".to_string()
+                    }
                     _ => "per 1000".to_string(),
                 };
                 let edc_per_unit = match stat {
                     BreakpointStat::CritDamage => edc * 100.0,
-                    BreakpointStat::WeaponSpellDamage => *edc,
+                    BreakpointStat::WeaponDamage | BreakpointStat::SpellDamage => *edc,
                     _ => edc * 1000.0,
                 };
                 vec![
@@ -123,9 +177,13 @@ impl BreakpointsArgs {
             sim_stats = step.stat.apply_delta(&sim_stats, step.amount);
 
             let detail = match step.stat {
-                BreakpointStat::CritRating => {
-                    let new_chance = crit_rating_to_chance(sim_stats.critical_rating);
-                    format!(" / to {:.0}% crit", new_chance * 100.0)
+                BreakpointStat::WeaponCritRating => {
+                    let new_chance = sim_stats.weapon_critical_chance();
+                    format!(" / to {:.0}% weapon crit", new_chance * 100.0)
+                }
+                BreakpointStat::SpellCritRating => {
+                    let new_chance = sim_stats.spell_critical_chance();
+                    format!(" / to {:.0}% spell crit", new_chance * 100.0)
                 }
                 BreakpointStat::CritDamage => {
                     let bonus_pct = (sim_stats.critical_damage - 1.0) * 100.0;
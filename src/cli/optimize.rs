@@ -1,10 +1,16 @@
+use crate::data::bonuses::OAKENSOUL_RING;
 use crate::domain::{
-    ArmorDistribution, ArmorTrait, AttributeChoice, BonusData, BuildConfig, Food, JewelryTrait,
-    MundusStone, Potion, Race, SetData, SkillData, SkillTree, WeaponChoice, WeaponEnchant,
-    WeaponTrait, BUILD_CONSTRAINTS,
+    parse_gear_level, ArmorDistribution, ArmorGlyph, ArmorTrait, AttributeChoice, BonusData,
+    BuildConfig, BuildConstraints, CombatMode, DotSnapshot, Encounter, EncounterPhase, Enemy,
+    FightTarget, Food, GearQuality, GroupConfig, JewelryGlyph, JewelryTrait, MundusStone, Opener,
+    Poison, PositionalProfile, Potion, Race, SetData, SkillData, SkillTree, SynergyProfile,
+    WeaponChoice, WeaponEnchant, WeaponTrait, WeaponType, BUILD_CONSTRAINTS, MAX_GEAR_LEVEL,
 };
 use crate::infrastructure::logger;
-use crate::services::{OptimizePipeline, OptimizePipelineOptions, DEFAULT_AVG_RESOURCE_PCT};
+use crate::services::{
+    GroupOptimizer, GroupOptimizerOptions, OptimizePipeline, OptimizePipelineOptions,
+    DEFAULT_AVG_RESOURCE_PCT,
+};
 use clap::Args;
 use std::fs;
 use std::io::{self, Write};
@@ -37,6 +43,10 @@ pub struct OptimizeArgs {
     #[arg(long, conflicts_with = "magicka")]
     pub stamina: bool,
 
+    /// Pin attributes as none, magicka, stamina or a magicka/stamina/health split (e.g. 40/24/0, sum ≤ 64)
+    #[arg(long, value_parser = AttributeChoice::parse, conflicts_with_all = ["magicka", "stamina"])]
+    pub attributes: Option<AttributeChoice>,
+
     /// Pin gear sets (comma-separated). Auto-grouped by type: max 2 normal/arena, 2 monster, 1 mythic.
     #[arg(long, value_delimiter = ',', value_parser = SetData::parse)]
     pub set: Option<Vec<&'static SetData>>,
@@ -75,11 +85,70 @@ pub struct OptimizeArgs {
     #[arg(long, value_delimiter = ',', value_parser = WeaponTrait::parse)]
     pub weapon_trait: Option<Vec<WeaponTrait>>,
 
+    /// Off-hand one-handers of dual wield bars (comma-separated: bar1,bar2; sword, axe, mace, dagger)
+    #[arg(long, value_delimiter = ',', value_parser = WeaponType::parse_off_hand)]
+    pub off_hand: Option<Vec<WeaponType>>,
+
+    /// Pin off-hand weapon traits of dual wield bars (comma-separated, max 2: bar1, bar2)
+    #[arg(long, value_delimiter = ',', value_parser = WeaponTrait::parse)]
+    pub off_hand_trait: Option<Vec<WeaponTrait>>,
+
+    /// Pin armor glyphs per slot (comma-separated, max 7: chest, head, legs, shoulders, hands, waist, feet). Rest optimized.
+    #[arg(long, value_delimiter = ',', value_parser = ArmorGlyph::parse)]
+    pub armor_glyph: Option<Vec<ArmorGlyph>>,
+
+    /// Pin jewelry glyphs per slot (comma-separated, max 3). Pins first N slots, rest optimized.
+    #[arg(long, value_delimiter = ',', value_parser = JewelryGlyph::parse)]
+    pub jewelry_glyph: Option<Vec<JewelryGlyph>>,
+
+    /// Quality of every gear piece (normal, fine, superior, epic, legendary). Default legendary.
+    #[arg(long, value_parser = GearQuality::parse)]
+    pub quality: Option<GearQuality>,
+
+    /// Armor quality per slot (comma-separated, max 7). Unlisted slots use --quality.
+    #[arg(long, value_delimiter = ',', value_parser = GearQuality::parse)]
+    pub armor_quality: Option<Vec<GearQuality>>,
+
+    /// Jewelry quality per slot (comma-separated, max 3). Unlisted slots use --quality.
+    #[arg(long, value_delimiter = ',', value_parser = GearQuality::parse)]
+    pub jewelry_quality: Option<Vec<GearQuality>>,
+
+    /// Weapon quality per bar (comma-separated: bar1,bar2). Unlisted bars use --quality.
+    #[arg(long, value_delimiter = ',', value_parser = GearQuality::parse)]
+    pub weapon_quality: Option<Vec<GearQuality>>,
+
+    /// Champion item level of the gear: 0 (level 50) to 160 in steps of 10
+    #[arg(long, value_parser = parse_gear_level, default_value_t = MAX_GEAR_LEVEL)]
+    pub gear_level: u16,
+
+    /// Rank which slots are worth upgrading to legendary first
+    #[arg(long)]
+    pub budget: bool,
+
+    /// Report the DPS cost of moving attribute points into health
+    #[arg(long)]
+    pub health_cost: bool,
+
+    /// Enemy to optimize against: trial-dummy (default), target-dummy (no armor debuffs),
+    /// pvp-player or pvp-tank
+    #[arg(long, value_parser = Enemy::parse)]
+    pub enemy: Option<Enemy>,
+
+    /// Optimize against players: Battle Spirit, PvP-only set bonuses, no trial buffs,
+    /// PvP player enemy unless --enemy is given
+    #[arg(long)]
+    pub pvp: bool,
+
+    /// Also optimize a one-bar Oakensoul build and keep it if it beats the two-bar build.
+    /// Pinning the Oakensoul Ring with --set optimizes one-bar builds only.
+    #[arg(long)]
+    pub oakensoul: bool,
+
     /// Armor piece counts as light,medium,heavy (e.g. 1,5,1). Free slots optimized.
     #[arg(long, value_parser = ArmorDistribution::parse, default_value = "1,5,1")]
     pub armor: ArmorDistribution,
 
-    /// Potion buff (weapon-power, spell-power; defaults to weapon-power)
+    /// Pin potion (weapon-power, spell-power) - optimized in the fight simulation if omitted
     #[arg(long, value_parser = Potion::parse)]
     pub potion: Option<Potion>,
 
@@ -87,14 +156,76 @@ pub struct OptimizeArgs {
     #[arg(long, value_delimiter = ',', value_parser = WeaponEnchant::parse)]
     pub enchant: Option<Vec<WeaponEnchant>>,
 
+    /// Off-hand weapon enchants of dual wield bars (comma-separated: bar1,bar2)
+    #[arg(long, value_delimiter = ',', value_parser = WeaponEnchant::parse)]
+    pub off_hand_enchant: Option<Vec<WeaponEnchant>>,
+
+    /// Weapon poisons per bar (comma-separated: bar1,bar2). Unpinned bars optimized.
+    #[arg(long, value_delimiter = ',', value_parser = Poison::parse)]
+    pub poison: Option<Vec<Poison>>,
+
     /// Average resource percentage for resource-scaling sets like Bahsei's (0-100, default 50)
     #[arg(long, default_value_t = DEFAULT_AVG_RESOURCE_PCT)]
     pub avg_resource_pct: f64,
 
+    /// Target HP for the simulation phase: dummy preset (3M, 6M, 21M) or a number (default 21M)
+    #[arg(long, value_parser = FightTarget::parse_target_hp, conflicts_with = "duration")]
+    pub target_hp: Option<FightTarget>,
+
+    /// Optimize for a fixed encounter length instead of a target HP (e.g. 90s, 2m)
+    #[arg(long, value_parser = FightTarget::parse_duration)]
+    pub duration: Option<FightTarget>,
+
+    /// Boss encounter script for the simulation phase: rockgrove-oax, sunspire-lokkestiiz
+    /// or a JSON file path. Uses the boss HP and resistances instead of --target-hp and --enemy.
+    #[arg(
+        long,
+        value_parser = Encounter::parse,
+        conflicts_with_all = ["target_hp", "duration", "enemy", "pvp"]
+    )]
+    pub encounter: Option<Encounter>,
+
+    /// Downtime windows for the simulation phase (comma-separated) as KIND@START+DURATION[/EVERY][:clear-dots].
+    /// Kinds: immune, movement, bar-locked. START is a time or boss health (e.g. 50%).
+    /// Example: movement@45s+4s/50s,immune@50%+20s
+    #[arg(long, value_delimiter = ',', value_parser = EncounterPhase::parse)]
+    pub downtime: Vec<EncounterPhase>,
+
+    /// Flanking and melee uptime as FLANKING%,MELEE% (e.g. 60%,100%). Flanking bonuses,
+    /// range-dependent bonuses and Zaan's tether are weighted by it (default: always met).
+    /// Skill damage is not: ranged-only skills still hit in full from melee range
+    #[arg(long, value_parser = PositionalProfile::parse)]
+    pub position: Option<PositionalProfile>,
+
+    /// Synergies the rest of the group offers during the simulation phase:
+    /// none (default), dungeon or trial
+    #[arg(long, value_parser = SynergyProfile::parse)]
+    pub synergies: Option<SynergyProfile>,
+
+    /// Pre-pull opener for the fight simulation: "auto" or comma-separated skill names
+    #[arg(long, value_parser = Opener::parse)]
+    pub opener: Option<Opener>,
+
+    /// DoT parts locked in at cast time for the fight simulation: snapshot (default),
+    /// dynamic, stats or parts joined with '+' (power, done, taken, armor, crit)
+    #[arg(long, value_parser = DotSnapshot::parse)]
+    pub dot_snapshot: Option<DotSnapshot>,
+
     /// Disable trial dummy buffs/debuffs (enabled by default)
     #[arg(long = "no-trial")]
     pub no_trial: bool,
 
+    /// Optimize a group of 4-8 DPS players from a JSON file: {"players": [{"name": ...,
+    /// build pins}]}. Minor Brittle, Major Slayer, Elemental Catalyst and Alkosh are each
+    /// provided by one player, chosen to maximize the group's total DPS. Teammates' duties
+    /// reach each player through the trial buffs; with --no-trial they are not modelled.
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["race", "class", "set", "skill", "weapon", "oakensoul"]
+    )]
+    pub group: Option<PathBuf>,
+
     /// Export build to this file without prompting
     #[arg(short = 'o', long)]
     pub output: Option<PathBuf>,
@@ -110,6 +241,10 @@ pub struct OptimizeArgs {
 
 impl OptimizeArgs {
     pub fn run(&self) {
+        if let Some(path) = &self.group {
+            self.run_group(path);
+            return;
+        }
         self.validate();
         let options = self.build_pipeline_options();
         let result = OptimizePipeline::run(options);
@@ -120,6 +255,34 @@ impl OptimizeArgs {
         }
     }
 
+    fn run_group(&self, path: &PathBuf) {
+        let group = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read file '{}': {}", path.display(), e))
+            .and_then(|json| GroupConfig::from_json(&json))
+            .unwrap_or_else(|e| {
+                logger::error(&e);
+                std::process::exit(1);
+            });
+        let options = GroupOptimizerOptions {
+            group,
+            pipeline: self.build_pipeline_options(),
+        };
+
+        match GroupOptimizer::run(options) {
+            Ok(result) => {
+                logger::info(&result.to_string());
+                let path = self.output.clone().or_else(Self::prompt_export);
+                if let Some(path) = &path {
+                    Self::export_to_file(&result.group_config(), path);
+                }
+            }
+            Err(e) => {
+                logger::error(&e);
+                std::process::exit(1);
+            }
+        }
+    }
+
     fn validate(&self) {
         if let Some(classes) = &self.class {
             if classes.len() > BUILD_CONSTRAINTS.class_skill_line_count {
@@ -142,17 +305,22 @@ impl OptimizeArgs {
         }
 
         if let Some(skills) = &self.skill {
-            if skills.len() > BUILD_CONSTRAINTS.skill_count {
+            let constraints = BuildConstraints::for_bars(self.single_bar());
+            if skills.len() > constraints.skill_count {
                 logger::error(&format!(
                     "Maximum {} required skills allowed",
-                    BUILD_CONSTRAINTS.skill_count
+                    constraints.skill_count
                 ));
                 std::process::exit(1);
             }
         }
 
         if let Some(cp) = &self.champion_point {
-            if cp.len() > BUILD_CONSTRAINTS.champion_point_count {
+            let warfare_count = cp
+                .iter()
+                .filter(|cp| !cp.is_fitness_champion_point())
+                .count();
+            if warfare_count > BUILD_CONSTRAINTS.champion_point_count {
                 logger::error(&format!(
                     "Maximum {} champion points allowed",
                     BUILD_CONSTRAINTS.champion_point_count
@@ -173,12 +341,65 @@ impl OptimizeArgs {
                 std::process::exit(1);
             }
         }
+        if let Some(glyphs) = &self.armor_glyph {
+            if glyphs.len() > 7 {
+                logger::error("Maximum 7 armor glyph values allowed (one per piece)");
+                std::process::exit(1);
+            }
+        }
+        if let Some(glyphs) = &self.jewelry_glyph {
+            if glyphs.len() > 3 {
+                logger::error("Maximum 3 jewelry glyph values allowed (one per piece)");
+                std::process::exit(1);
+            }
+        }
         if let Some(traits) = &self.weapon_trait {
             if traits.len() > 2 {
                 logger::error("Maximum 2 weapon trait values allowed (bar1, bar2)");
                 std::process::exit(1);
             }
         }
+        if let Some(traits) = &self.off_hand_trait {
+            if traits.len() > 2 {
+                logger::error("Maximum 2 off-hand trait values allowed (bar1, bar2)");
+                std::process::exit(1);
+            }
+        }
+        if let Some(off_hands) = &self.off_hand {
+            if off_hands.len() > 2 {
+                logger::error("Maximum 2 off-hand values allowed (bar1, bar2)");
+                std::process::exit(1);
+            }
+            let pinned = self.weapon.as_deref().unwrap_or_default();
+            for (bar, _) in off_hands.iter().enumerate() {
+                let main = pinned.get(bar).and_then(|w| w.weapon_type());
+                if main.is_some_and(|w| !w.is_dual_wield()) {
+                    logger::error(&format!(
+                        "Bar {} weapon is not dual wield; an off-hand needs a dual wield bar",
+                        bar + 1
+                    ));
+                    std::process::exit(1);
+                }
+            }
+        }
+        if let Some(qualities) = &self.armor_quality {
+            if qualities.len() > 7 {
+                logger::error("Maximum 7 armor quality values allowed (one per piece)");
+                std::process::exit(1);
+            }
+        }
+        if let Some(qualities) = &self.jewelry_quality {
+            if qualities.len() > 3 {
+                logger::error("Maximum 3 jewelry quality values allowed (one per piece)");
+                std::process::exit(1);
+            }
+        }
+        if let Some(qualities) = &self.weapon_quality {
+            if qualities.len() > 2 {
+                logger::error("Maximum 2 weapon quality values allowed (bar1, bar2)");
+                std::process::exit(1);
+            }
+        }
 
         if let Some(sets) = &self.set {
             let (normals, monsters, mythics) = SetData::split_by_type(sets);
@@ -207,7 +428,7 @@ impl OptimizeArgs {
         } else if self.stamina {
             Some(AttributeChoice::Stamina)
         } else {
-            None
+            self.attributes
         };
 
         // Derive bar weapons and required skill lines from --weapon values
@@ -228,6 +449,26 @@ impl OptimizeArgs {
             _ => (None, None),
         };
 
+        // Derive bar poisons from positional --poison values
+        let (bar1_poison, bar2_poison) = match self.poison.as_deref() {
+            Some([p1, p2, ..]) => (Some(*p1), Some(*p2)),
+            Some([p1]) => (Some(*p1), None),
+            _ => (None, None),
+        };
+
+        // Derive dual wield off-hands and their enchants from positional values
+        let (bar1_off_hand, bar2_off_hand) = match self.off_hand.as_deref() {
+            Some([w1, w2, ..]) => (Some(*w1), Some(*w2)),
+            Some([w1]) => (Some(*w1), None),
+            _ => (None, None),
+        };
+        let (bar1_off_hand_enchant, bar2_off_hand_enchant) = match self.off_hand_enchant.as_deref()
+        {
+            Some([e1, e2, ..]) => (Some(*e1), Some(*e2)),
+            Some([e1]) => (Some(*e1), None),
+            _ => (None, None),
+        };
+
         let baseline = BuildConfig {
             skills: self
                 .skill
@@ -251,12 +492,28 @@ impl OptimizeArgs {
             armor_traits: self.armor_trait.clone().unwrap_or_default(),
             jewelry_traits: self.jewelry_trait.clone().unwrap_or_default(),
             weapon_traits: self.weapon_trait.clone().unwrap_or_default(),
+            off_hand_traits: self.off_hand_trait.clone().unwrap_or_default(),
+            armor_glyphs: self.armor_glyph.clone().unwrap_or_default(),
+            jewelry_glyphs: self.jewelry_glyph.clone().unwrap_or_default(),
             attributes,
+            enemy: self.enemy(),
+            position: self.position,
             armor: self.armor,
             bar1_weapon,
             bar2_weapon,
+            bar1_off_hand,
+            bar2_off_hand,
             bar1_enchant,
             bar2_enchant,
+            bar1_off_hand_enchant,
+            bar2_off_hand_enchant,
+            bar1_poison,
+            bar2_poison,
+            armor_quality: self.slot_qualities(&self.armor_quality, 7),
+            jewelry_quality: self.slot_qualities(&self.jewelry_quality, 3),
+            weapon_quality: self.slot_qualities(&self.weapon_quality, 2),
+            gear_level: self.gear_level,
+            single_bar: self.single_bar(),
             potion: self.potion,
             ..BuildConfig::default()
         };
@@ -267,10 +524,49 @@ impl OptimizeArgs {
             parallelism,
             max_pool_size: self.max_pool_size,
             baseline,
-            trial: !self.no_trial,
+            trial: !self.no_trial && !self.pvp,
             avg_resource_pct: self.avg_resource_pct,
+            fight_target: self.target_hp.or(self.duration).unwrap_or_default(),
+            encounter: self.encounter.clone(),
+            downtime: self.downtime.clone(),
+            synergies: self.synergies.unwrap_or_default(),
+            opener: self.opener.clone().unwrap_or_default(),
+            dot_snapshot: self.dot_snapshot.unwrap_or_default(),
             required_weapon_skill_lines,
+            budget: self.budget,
+            health_cost: self.health_cost,
+            oakensoul: self.oakensoul,
+            excluded_sets: Vec::new(),
+        }
+    }
+
+    /// `--enemy`, forced into PvP (defaulting to a PvP player) with `--pvp`, or the
+    /// `--encounter` boss.
+    fn enemy(&self) -> Option<Enemy> {
+        if let Some(encounter) = &self.encounter {
+            return Some(encounter.enemy.clone());
+        }
+        if !self.pvp {
+            return self.enemy.clone();
+        }
+        let enemy = self.enemy.clone().unwrap_or_else(Enemy::pvp_player);
+        Some(enemy.with_combat_mode(CombatMode::Pvp))
+    }
+
+    /// A pinned Oakensoul Ring makes this a one-bar build.
+    fn single_bar(&self) -> bool {
+        self.set
+            .as_ref()
+            .is_some_and(|sets| sets.iter().any(|s| s.name == OAKENSOUL_RING))
+    }
+
+    /// Per-slot qualities: listed values first, remaining slots filled with --quality.
+    fn slot_qualities(&self, listed: &Option<Vec<GearQuality>>, slots: usize) -> Vec<GearQuality> {
+        let mut qualities = listed.clone().unwrap_or_default();
+        if let Some(quality) = self.quality {
+            qualities.resize(slots, quality);
         }
+        qualities
     }
 
     fn prompt_export() -> Option<PathBuf> {
@@ -291,7 +587,7 @@ impl OptimizeArgs {
         Some(PathBuf::from(input))
     }
 
-    fn export_to_file(config: &crate::domain::BuildConfig, path: &PathBuf) {
+    fn export_to_file(config: &impl serde::Serialize, path: &PathBuf) {
         match serde_json::to_string_pretty(config) {
             Ok(json) => match fs::write(path, json) {
                 Ok(_) => logger::info(&format!("Build exported to {}", path.display())),
@@ -1,4 +1,7 @@
-use crate::domain::BuildConfig;
+use crate::domain::{
+    BuildConfig, CombatMode, DotSnapshot, Encounter, EncounterPhase, Enemy, FightTarget, Opener,
+    PositionalProfile, SynergyProfile,
+};
 use crate::infrastructure::logger;
 use crate::services::{SimulatePipeline, SimulatePipelineOptions, DEFAULT_AVG_RESOURCE_PCT};
 use clap::Args;
@@ -20,21 +23,105 @@ pub struct SimulateArgs {
     #[arg(long = "no-trial")]
     pub no_trial: bool,
 
+    /// Enemy to simulate against: trial-dummy, target-dummy, pvp-player or pvp-tank (default from file)
+    #[arg(long, value_parser = Enemy::parse)]
+    pub enemy: Option<Enemy>,
+
+    /// Simulate against players: Battle Spirit, no trial buffs, PvP player enemy unless --enemy is given
+    #[arg(long)]
+    pub pvp: bool,
+
     /// Average resource percentage for resource-scaling sets like Bahsei's (0-100, default from file)
     #[arg(long)]
     pub avg_resource_pct: Option<f64>,
+
+    /// Target HP: dummy preset (3M, 6M, 21M) or a number (default 21M)
+    #[arg(long, value_parser = FightTarget::parse_target_hp, conflicts_with = "duration")]
+    pub target_hp: Option<FightTarget>,
+
+    /// Fixed fight length instead of a target HP (e.g. 90s, 2m, 1m30s)
+    #[arg(long, value_parser = FightTarget::parse_duration)]
+    pub duration: Option<FightTarget>,
+
+    /// Boss encounter script: rockgrove-oax, sunspire-lokkestiiz or a JSON file path.
+    /// Uses the boss HP and resistances instead of --target-hp and --enemy.
+    #[arg(
+        long,
+        value_parser = Encounter::parse,
+        conflicts_with_all = ["target_hp", "duration", "enemy", "pvp"]
+    )]
+    pub encounter: Option<Encounter>,
+
+    /// Downtime windows (comma-separated) as KIND@START+DURATION[/EVERY][:clear-dots].
+    /// Kinds: immune, movement, bar-locked. START is a time or boss health (e.g. 50%).
+    /// Example: movement@45s+4s/50s,immune@50%+20s
+    #[arg(long, value_delimiter = ',', value_parser = EncounterPhase::parse)]
+    pub downtime: Vec<EncounterPhase>,
+
+    /// Flanking and melee uptime as FLANKING%,MELEE% (e.g. 60%,100%). Flanking bonuses,
+    /// range-dependent bonuses and Zaan's tether are weighted by it (default: always met).
+    /// Skill damage is not: ranged-only skills still hit in full from melee range
+    #[arg(long, value_parser = PositionalProfile::parse)]
+    pub position: Option<PositionalProfile>,
+
+    /// Synergies the rest of the group offers: none (default), dungeon or trial
+    #[arg(long, value_parser = SynergyProfile::parse)]
+    pub synergies: Option<SynergyProfile>,
+
+    /// Pre-pull opener: "auto" (long-duration cast buffs) or comma-separated skill names
+    #[arg(long, value_parser = Opener::parse)]
+    pub opener: Option<Opener>,
+
+    /// DoT parts locked in at cast time: snapshot (default), dynamic, stats (power and crit)
+    /// or parts joined with '+' (power, done, taken, armor, crit). Skills with their own
+    /// setting keep it; the others re-read each tick.
+    #[arg(long, value_parser = DotSnapshot::parse)]
+    pub dot_snapshot: Option<DotSnapshot>,
+
+    /// Print the full action timeline (opener and fight), including per-tick DoT modifiers
+    /// (* marks parts snapshotted at cast)
+    #[arg(long)]
+    pub timeline: bool,
+
+    /// Roll proc chances for real over this many runs instead of using expected values
+    #[arg(long, value_name = "RUNS")]
+    pub monte_carlo: Option<usize>,
+
+    /// Random seed for Monte Carlo runs
+    #[arg(long, default_value_t = 0, requires = "monte_carlo")]
+    pub seed: u64,
 }
 
 impl SimulateArgs {
     pub fn run(&self) {
-        let config = self.load_config();
+        let mut config = self.load_config();
+        if self.pvp {
+            let enemy = self.enemy.clone().unwrap_or_else(Enemy::pvp_player);
+            config.enemy = Some(enemy.with_combat_mode(CombatMode::Pvp));
+        } else if let Some(enemy) = &self.enemy {
+            config.enemy = Some(enemy.clone());
+        } else if let Some(encounter) = &self.encounter {
+            config.enemy = Some(encounter.enemy.clone());
+        }
+        if self.position.is_some() {
+            config.position = self.position;
+        }
         let avg_resource_pct = self.avg_resource_pct.unwrap_or(DEFAULT_AVG_RESOURCE_PCT);
 
         let options = SimulatePipelineOptions {
             config,
-            trial: !self.no_trial,
+            trial: !self.no_trial && !self.pvp,
             verbose: self.verbose,
             avg_resource_pct,
+            fight_target: self.target_hp.or(self.duration).unwrap_or_default(),
+            encounter: self.encounter.clone(),
+            downtime: self.downtime.clone(),
+            synergies: self.synergies.unwrap_or_default(),
+            opener: self.opener.clone().unwrap_or_default(),
+            dot_snapshot: self.dot_snapshot.unwrap_or_default(),
+            timeline: self.timeline,
+            monte_carlo_runs: self.monte_carlo,
+            seed: self.seed,
         };
 
         logger::info("Calculating build damage...");
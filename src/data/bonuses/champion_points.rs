@@ -9,16 +9,25 @@ impl BonusData {
 
         CHAMPION_POINTS
             .iter()
+            .chain(FITNESS_CHAMPION_POINTS.iter())
             .find(|cp| cp.name.to_lowercase().replace('-', " ") == normalized)
             .cloned()
             .ok_or_else(|| {
                 format!(
                     "Invalid champion point '{}'. Valid options: backstabber, biting-aura, deadly-aim, \
-                    exploiter, fighting-finesse, master-at-arms, thaumaturge",
+                    exploiter, fighting-finesse, master-at-arms, thaumaturge, boundless-vitality",
                     s
                 )
             })
     }
+
+    /// Fitness (red) stars use their own slots and do not count towards the
+    /// warfare champion point limit.
+    pub fn is_fitness_champion_point(&self) -> bool {
+        FITNESS_CHAMPION_POINTS
+            .iter()
+            .any(|cp| cp.name == self.name)
+    }
 }
 
 pub static CHAMPION_POINTS: Lazy<Vec<BonusData>> = Lazy::new(|| {
@@ -67,3 +76,17 @@ pub static CHAMPION_POINTS: Lazy<Vec<BonusData>> = Lazy::new(|| {
         ),
     ]
 });
+
+/// Fitness slottables. Only pinned with --cp, never searched by the optimizer.
+pub static FITNESS_CHAMPION_POINTS: Lazy<Vec<BonusData>> = Lazy::new(|| {
+    vec![BonusData::new(
+        "Boundless Vitality",
+        BonusSource::ChampionPointSlottable,
+        BonusTrigger::Passive,
+        BonusValue::new(
+            "Boundless Vitality",
+            BonusTarget::MaxHealthFlat,
+            28.0 * 50.0,
+        ),
+    )]
+});
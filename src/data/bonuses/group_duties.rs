@@ -0,0 +1,23 @@
+use crate::domain::{DutyProvider, GroupDuty, WeaponType};
+
+/// Unique buffs and debuffs a DPS roster has to provide itself. The trial dummy
+/// grants all of them for free; the group optimizer assigns each to one player.
+pub const GROUP_DUTIES: &[GroupDuty] = &[
+    // Chilled from an Ice Staff applies Minor Brittle
+    GroupDuty {
+        name: "Minor Brittle",
+        provider: DutyProvider::Weapon(WeaponType::IceStaff),
+    },
+    GroupDuty {
+        name: "Major Slayer",
+        provider: DutyProvider::Sets(&["Roaring Opportunist", "Master Architect", "War Machine"]),
+    },
+    GroupDuty {
+        name: "Elemental Catalyst",
+        provider: DutyProvider::Sets(&["Elemental Catalyst"]),
+    },
+    GroupDuty {
+        name: "Roar of Alkosh",
+        provider: DutyProvider::Sets(&["Roar of Alkosh"]),
+    },
+];
@@ -1,8 +1,12 @@
 pub mod champion_points;
+pub mod group_duties;
+pub mod oakensoul;
 pub mod trial_buffs;
 pub mod unique;
 
-pub use champion_points::CHAMPION_POINTS;
+pub use champion_points::{CHAMPION_POINTS, FITNESS_CHAMPION_POINTS};
+pub use group_duties::GROUP_DUTIES;
+pub use oakensoul::{with_oakensoul_buffs, OAKENSOUL_BUFFS, OAKENSOUL_RING};
 pub use trial_buffs::{TRIAL_BUFF_NAMES, TRIAL_DUMMY_BUFFS};
 pub use unique::{
     EMPOWER, MAJOR_BERSERK, MAJOR_BREACH, MAJOR_BRUTALITY, MAJOR_PROPHECY, MAJOR_SAVAGERY,
@@ -0,0 +1,69 @@
+use crate::domain::{BonusData, BonusSource, BonusTarget, BonusTrigger, BonusValue};
+use once_cell::sync::Lazy;
+use std::collections::HashSet;
+
+/// Mythic ring whose buff package only works while the build runs a single bar.
+pub const OAKENSOUL_RING: &str = "Oakensoul Ring";
+
+/// DPS-relevant buffs the Oakensoul Ring keeps up permanently on a one-bar build.
+/// Injected as passive bonuses, like the trial dummy buffs.
+pub static OAKENSOUL_BUFFS: Lazy<Vec<BonusData>> = Lazy::new(|| {
+    vec![
+        // Empower: +80% Light Attack damage
+        BonusData::new(
+            "Empower",
+            BonusSource::Buff,
+            BonusTrigger::Passive,
+            BonusValue::new("Empower", BonusTarget::LightAttackDamage, 0.80),
+        ),
+        // Minor Berserk: +5% Damage
+        BonusData::new(
+            "Minor Berserk",
+            BonusSource::Buff,
+            BonusTrigger::Passive,
+            BonusValue::new("Minor Berserk", BonusTarget::Damage, 0.05),
+        ),
+        // Major Brutality: +20% Weapon Damage
+        BonusData::new(
+            "Major Brutality",
+            BonusSource::Buff,
+            BonusTrigger::Passive,
+            BonusValue::new("Major Brutality", BonusTarget::WeaponDamage, 0.20),
+        ),
+        // Major Sorcery: +20% Spell Damage
+        BonusData::new(
+            "Major Sorcery",
+            BonusSource::Buff,
+            BonusTrigger::Passive,
+            BonusValue::new("Major Sorcery", BonusTarget::SpellDamage, 0.20),
+        ),
+        // Major Savagery: +2629 Weapon Critical Rating
+        BonusData::new(
+            "Major Savagery",
+            BonusSource::Buff,
+            BonusTrigger::Passive,
+            BonusValue::new("Major Savagery", BonusTarget::WeaponCriticalRating, 2629.0),
+        ),
+        // Major Prophecy: +2629 Spell Critical Rating
+        BonusData::new(
+            "Major Prophecy",
+            BonusSource::Buff,
+            BonusTrigger::Passive,
+            BonusValue::new("Major Prophecy", BonusTarget::SpellCriticalRating, 2629.0),
+        ),
+    ]
+});
+
+/// Append the Oakensoul buffs to `extra_bonuses`, skipping buffs already provided
+/// (e.g. Minor Berserk from the trial dummy) so nothing is counted twice.
+pub fn with_oakensoul_buffs(extra_bonuses: &[BonusData]) -> Vec<BonusData> {
+    let present: HashSet<&str> = extra_bonuses.iter().map(|b| b.name.as_str()).collect();
+    let mut bonuses = extra_bonuses.to_vec();
+    bonuses.extend(
+        OAKENSOUL_BUFFS
+            .iter()
+            .filter(|b| !present.contains(b.name.as_str()))
+            .cloned(),
+    );
+    bonuses
+}
@@ -5,6 +5,7 @@ use std::collections::HashSet;
 /// All DPS-relevant buffs/debuffs provided by the 21M trial dummy.
 /// These are injected as passive bonuses so they apply to stat resolution
 /// and damage modifiers without needing the simulator's buff system.
+/// Armor debuffs (Breach, Crusher, Alkosh) live on `Enemy::trial_dummy()`.
 pub static TRIAL_DUMMY_BUFFS: Lazy<Vec<BonusData>> = Lazy::new(|| {
     vec![
         // Major Force: +15% Critical Damage
@@ -78,28 +79,6 @@ pub static TRIAL_DUMMY_BUFFS: Lazy<Vec<BonusData>> = Lazy::new(|| {
             BonusTrigger::Passive,
             BonusValue::new("Minor Berserk", BonusTarget::Damage, 0.05),
         ),
-        // Major Breach: +5948 Enemy Resistance Reduction
-        BonusData::new(
-            "Major Breach",
-            BonusSource::Buff,
-            BonusTrigger::Passive,
-            BonusValue::new(
-                "Major Breach",
-                BonusTarget::EnemyResistanceReduction,
-                5948.0,
-            ),
-        ),
-        // Minor Breach: +2974 Enemy Resistance Reduction
-        BonusData::new(
-            "Minor Breach",
-            BonusSource::Buff,
-            BonusTrigger::Passive,
-            BonusValue::new(
-                "Minor Breach",
-                BonusTarget::EnemyResistanceReduction,
-                2974.0,
-            ),
-        ),
         // Major Vulnerability: +10% Enemy Damage Taken
         BonusData::new(
             "Major Vulnerability",
@@ -128,28 +107,6 @@ pub static TRIAL_DUMMY_BUFFS: Lazy<Vec<BonusData>> = Lazy::new(|| {
             BonusTrigger::Passive,
             BonusValue::new("Engulfing Flames", BonusTarget::FlameDamage, 0.10),
         ),
-        // Infused Crusher: +2108 Enemy Resistance Reduction
-        BonusData::new(
-            "Infused Crusher",
-            BonusSource::Buff,
-            BonusTrigger::Passive,
-            BonusValue::new(
-                "Infused Crusher",
-                BonusTarget::EnemyResistanceReduction,
-                2108.0,
-            ),
-        ),
-        // Roar of Alkosh: +6000 Enemy Resistance Reduction
-        BonusData::new(
-            "Roar of Alkosh",
-            BonusSource::Buff,
-            BonusTrigger::Passive,
-            BonusValue::new(
-                "Roar of Alkosh",
-                BonusTarget::EnemyResistanceReduction,
-                6000.0,
-            ),
-        ),
         // Elemental Catalyst (x3): +5% each for Flame, Frost, Shock
         BonusData::new(
             "Elemental Catalyst (Flame)",
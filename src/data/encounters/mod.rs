@@ -0,0 +1,41 @@
+use crate::domain::Encounter;
+use once_cell::sync::Lazy;
+use std::path::Path;
+
+/// Built-in encounter scripts. Health, debuff uptimes and mechanic timings are
+/// approximations of veteran fights, meant for comparing builds rather than
+/// predicting exact kill times.
+const ENCOUNTER_FILES: &[&str] = &[
+    include_str!("rockgrove_oax.json"),
+    include_str!("sunspire_lokkestiiz.json"),
+];
+
+pub static ENCOUNTERS: Lazy<Vec<Encounter>> = Lazy::new(|| {
+    ENCOUNTER_FILES
+        .iter()
+        .map(|json| Encounter::from_json(json).unwrap_or_else(|e| panic!("{}", e)))
+        .collect()
+});
+
+impl Encounter {
+    /// Parse a built-in encounter id (e.g. `rockgrove-oax`) or the path to an
+    /// encounter JSON file.
+    pub fn parse(s: &str) -> Result<Encounter, String> {
+        let s = s.trim();
+        let normalized = s.to_lowercase().replace(' ', "-");
+        if let Some(encounter) = ENCOUNTERS.iter().find(|e| e.id == normalized) {
+            return Ok(encounter.clone());
+        }
+        if Path::new(s).is_file() {
+            let json = std::fs::read_to_string(s)
+                .map_err(|e| format!("Failed to read encounter '{}': {}", s, e))?;
+            return Encounter::from_json(&json);
+        }
+        let ids: Vec<_> = ENCOUNTERS.iter().map(|e| e.id.as_str()).collect();
+        Err(format!(
+            "Unknown encounter '{}'. Valid: {} or the path to an encounter JSON file",
+            s,
+            ids.join(", ")
+        ))
+    }
+}
@@ -1,4 +1,5 @@
 pub mod bonuses;
+pub mod encounters;
 pub mod light_attacks;
 pub mod sets;
 pub mod skill_trees;
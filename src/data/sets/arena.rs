@@ -95,6 +95,15 @@ pub static ARENA_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
             )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Explosive Rebuke 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -108,13 +117,17 @@ pub static ARENA_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             ),
-        SetData::new("Footman's Fortune", SetType::Arena).with_item_slots(vec![
-            "Shield",
-            "Weapons(All)",
-            "Heavy(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Footman's Fortune", SetType::Arena)
+            .with_item_slots(vec!["Shield", "Weapons(All)", "Heavy(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Footman's Fortune 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Force Overflow", SetType::Arena).with_item_slots(vec!["Weapons(Resto)"]),
         SetData::new("Frenzied Momentum", SetType::Arena)
             .with_item_slots(vec!["Weapons(Greatsword Maul Battleaxe)"]),
@@ -122,6 +135,24 @@ pub static ARENA_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
             .with_item_slots(vec!["Shield", "Weapons(Dagger Sword Mace Axe)"]),
         SetData::new("Glorious Defender", SetType::Arena)
             .with_item_slots(vec!["Shield", "Weapons(All)", "Heavy(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Glorious Defender 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Glorious Defender 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -265,6 +296,15 @@ pub static ARENA_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
             )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Para Bellum 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -324,6 +364,15 @@ pub static ARENA_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
             .with_item_slots(vec!["Weapons(Lightning Frost Flame)"]),
         SetData::new("Permafrost", SetType::Arena)
             .with_item_slots(vec!["Shield", "Weapons(All)", "Heavy(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Permafrost 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 3,
                 vec![BonusData::new(
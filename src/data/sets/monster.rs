@@ -1,7 +1,7 @@
 // Auto-generated by datamine/generate_sets_rs.py - do not edit manually.
 // Proc effects are embedded inline from the PROC_EFFECTS dict in the generator.
 use crate::domain::{
-    BonusData, BonusSource, BonusTarget, BonusTrigger, BonusValue, DamageFlags, SetData,
+    BonusData, BonusSource, BonusTarget, BonusTrigger, BonusValue, DamageFlags, Resource, SetData,
     SetProcAction, SetProcEffect, SetProcTrigger, SetType,
 };
 use once_cell::sync::Lazy;
@@ -26,6 +26,24 @@ pub static MONSTER_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                         129.0,
                     ),
                 )],
+            )
+            .with_proc_effects(
+                2,
+                vec![SetProcEffect {
+                    name: "Anthelmir's Construct Axe".to_string(),
+                    trigger: SetProcTrigger::OnHeavyAttack,
+                    action: SetProcAction::DamageProc {
+                        hit_damage: 1572.0,
+                        hit_flags: DamageFlags::PHYSICAL
+                            | DamageFlags::SINGLE_TARGET
+                            | DamageFlags::DIRECT,
+                        dot_total_damage: 0.0,
+                        dot_duration: 0.0,
+                        dot_flags: DamageFlags::empty(),
+                    },
+                    cooldown: 10.0,
+                    proc_chance: 1.0,
+                }],
             ),
         SetData::new("Archdruid Devyric", SetType::Monster)
             .with_item_slots(vec![
@@ -45,6 +63,36 @@ pub static MONSTER_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                         1487.0,
                     ),
                 )],
+            )
+            .with_proc_effects(
+                2,
+                vec![
+                    SetProcEffect {
+                        name: "Archdruid Devyric Lightning".to_string(),
+                        trigger: SetProcTrigger::OnHeavyAttack,
+                        action: SetProcAction::DamageProc {
+                            hit_damage: 650.0,
+                            hit_flags: DamageFlags::SHOCK | DamageFlags::AOE | DamageFlags::DIRECT,
+                            dot_total_damage: 0.0,
+                            dot_duration: 0.0,
+                            dot_flags: DamageFlags::empty(),
+                        },
+                        cooldown: 15.0,
+                        proc_chance: 1.0,
+                    },
+                    SetProcEffect {
+                        name: "Archdruid Devyric Vulnerability".to_string(),
+                        trigger: SetProcTrigger::OnHeavyAttack,
+                        action: SetProcAction::EnemyDebuff {
+                            debuff_name: "Major Vulnerability".to_string(),
+                            target: BonusTarget::EnemyDamageTaken,
+                            value: 0.10,
+                            duration: 7.0,
+                        },
+                        cooldown: 15.0,
+                        proc_chance: 1.0,
+                    },
+                ],
             ),
         SetData::new("Balorgh", SetType::Monster)
             .with_item_slots(vec![
@@ -91,7 +139,26 @@ pub static MONSTER_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                         BonusTrigger::Passive,
                         BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 548.0),
                     ),
+                    BonusData::new(
+                        "Baron Zaudrus 1pc",
+                        BonusSource::GearSet,
+                        BonusTrigger::Passive,
+                        BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 603.0),
+                    ),
                 ],
+            )
+            .with_proc_effects(
+                2,
+                vec![SetProcEffect {
+                    name: "Zaudrus's Ambition".to_string(),
+                    trigger: SetProcTrigger::OnStatusEffectApplied,
+                    action: SetProcAction::RestoreResource {
+                        resource: Resource::Ultimate,
+                        amount: 4.0,
+                    },
+                    cooldown: 1.0,
+                    proc_chance: 1.0 / 3.0,
+                }],
             ),
         SetData::new("Bloodspawn", SetType::Monster).with_item_slots(vec![
             "Light(Shoulder Head)",
@@ -146,21 +213,41 @@ pub static MONSTER_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 1096.0),
                 )],
             ),
-        SetData::new("Engine Guardian", SetType::Monster).with_item_slots(vec![
-            "Light(Shoulder Head)",
-            "Medium(Shoulder Head)",
-            "Heavy(Shoulder Head)",
-        ]),
+        SetData::new("Engine Guardian", SetType::Monster)
+            .with_item_slots(vec![
+                "Light(Shoulder Head)",
+                "Medium(Shoulder Head)",
+                "Heavy(Shoulder Head)",
+            ])
+            .with_threshold(
+                1,
+                vec![BonusData::new(
+                    "Engine Guardian 1pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Health Recovery", BonusTarget::HealthRecovery, 129.0),
+                )],
+            ),
         SetData::new("Euphotic Gatekeeper", SetType::Monster).with_item_slots(vec![
             "Light(Shoulder Head)",
             "Medium(Shoulder Head)",
             "Heavy(Shoulder Head)",
         ]),
-        SetData::new("Glorgoloch the Destroyer", SetType::Monster).with_item_slots(vec![
-            "Light(Shoulder Head)",
-            "Medium(Shoulder Head)",
-            "Heavy(Shoulder Head)",
-        ]),
+        SetData::new("Glorgoloch the Destroyer", SetType::Monster)
+            .with_item_slots(vec![
+                "Light(Shoulder Head)",
+                "Medium(Shoulder Head)",
+                "Heavy(Shoulder Head)",
+            ])
+            .with_threshold(
+                1,
+                vec![BonusData::new(
+                    "Glorgoloch the Destroyer 1pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Grothdarr", SetType::Monster)
             .with_item_slots(vec![
                 "Medium(Shoulder Head)",
@@ -221,11 +308,21 @@ pub static MONSTER_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 1096.0),
                 )],
             ),
-        SetData::new("Immolator Charr", SetType::Monster).with_item_slots(vec![
-            "Light(Shoulder Head)",
-            "Medium(Shoulder Head)",
-            "Heavy(Shoulder Head)",
-        ]),
+        SetData::new("Immolator Charr", SetType::Monster)
+            .with_item_slots(vec![
+                "Light(Shoulder Head)",
+                "Medium(Shoulder Head)",
+                "Heavy(Shoulder Head)",
+            ])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Immolator Charr 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Infernal Guardian", SetType::Monster)
             .with_item_slots(vec![
                 "Medium(Shoulder Head)",
@@ -296,6 +393,7 @@ pub static MONSTER_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                         dot_flags: DamageFlags::empty(),
                     },
                     cooldown: 10.0,
+                    proc_chance: 1.0,
                 }],
             ),
         SetData::new("Kra'gh", SetType::Monster)
@@ -336,11 +434,21 @@ pub static MONSTER_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             ),
-        SetData::new("Lady Thorn", SetType::Monster).with_item_slots(vec![
-            "Light(Shoulder Head)",
-            "Medium(Shoulder Head)",
-            "Heavy(Shoulder Head)",
-        ]),
+        SetData::new("Lady Thorn", SetType::Monster)
+            .with_item_slots(vec![
+                "Light(Shoulder Head)",
+                "Medium(Shoulder Head)",
+                "Heavy(Shoulder Head)",
+            ])
+            .with_threshold(
+                1,
+                vec![BonusData::new(
+                    "Lady Thorn 1pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Lord Warden", SetType::Monster).with_item_slots(vec![
             "Light(Shoulder Head)",
             "Medium(Shoulder Head)",
@@ -384,12 +492,40 @@ pub static MONSTER_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                         129.0,
                     ),
                 )],
+            )
+            .with_proc_effects(
+                2,
+                vec![SetProcEffect {
+                    name: "Maw of the Infernal Daedroth".to_string(),
+                    trigger: SetProcTrigger::OnLightAttack,
+                    action: SetProcAction::SummonedEntity {
+                        damage_per_attack: 599.0,
+                        attack_interval: 2.0,
+                        duration: 15.0,
+                        delay: 0.0,
+                        flags: DamageFlags::FLAME
+                            | DamageFlags::SINGLE_TARGET
+                            | DamageFlags::DIRECT,
+                    },
+                    cooldown: 15.0,
+                    proc_chance: 0.33,
+                }],
+            ),
+        SetData::new("Mighty Chudan", SetType::Monster)
+            .with_item_slots(vec![
+                "Light(Shoulder Head)",
+                "Medium(Shoulder Head)",
+                "Heavy(Shoulder Head)",
+            ])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Mighty Chudan 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
             ),
-        SetData::new("Mighty Chudan", SetType::Monster).with_item_slots(vec![
-            "Light(Shoulder Head)",
-            "Medium(Shoulder Head)",
-            "Heavy(Shoulder Head)",
-        ]),
         SetData::new("Molag Kena", SetType::Monster)
             .with_item_slots(vec![
                 "Light(Shoulder Head)",
@@ -424,11 +560,21 @@ pub static MONSTER_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 1096.0),
                 )],
             ),
-        SetData::new("Nazaray", SetType::Monster).with_item_slots(vec![
-            "Light(Shoulder Head)",
-            "Medium(Shoulder Head)",
-            "Heavy(Shoulder Head)",
-        ]),
+        SetData::new("Nazaray", SetType::Monster)
+            .with_item_slots(vec![
+                "Light(Shoulder Head)",
+                "Medium(Shoulder Head)",
+                "Heavy(Shoulder Head)",
+            ])
+            .with_threshold(
+                1,
+                vec![BonusData::new(
+                    "Nazaray 1pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Nerien'eth", SetType::Monster)
             .with_item_slots(vec![
                 "Light(Shoulder Head)",
@@ -497,16 +643,36 @@ pub static MONSTER_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             ),
-        SetData::new("Roksa the Warped", SetType::Monster).with_item_slots(vec![
-            "Light(Shoulder Head)",
-            "Medium(Shoulder Head)",
-            "Heavy(Shoulder Head)",
-        ]),
-        SetData::new("Scourge Harvester", SetType::Monster).with_item_slots(vec![
-            "Light(Shoulder Head)",
-            "Medium(Shoulder Head)",
-            "Heavy(Shoulder Head)",
-        ]),
+        SetData::new("Roksa the Warped", SetType::Monster)
+            .with_item_slots(vec![
+                "Light(Shoulder Head)",
+                "Medium(Shoulder Head)",
+                "Heavy(Shoulder Head)",
+            ])
+            .with_threshold(
+                1,
+                vec![BonusData::new(
+                    "Roksa the Warped 1pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Health Recovery", BonusTarget::HealthRecovery, 70.0),
+                )],
+            ),
+        SetData::new("Scourge Harvester", SetType::Monster)
+            .with_item_slots(vec![
+                "Light(Shoulder Head)",
+                "Medium(Shoulder Head)",
+                "Heavy(Shoulder Head)",
+            ])
+            .with_threshold(
+                1,
+                vec![BonusData::new(
+                    "Scourge Harvester 1pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Selene", SetType::Monster)
             .with_item_slots(vec![
                 "Medium(Shoulder Head)",
@@ -626,6 +792,12 @@ pub static MONSTER_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                         BonusTrigger::Passive,
                         BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 548.0),
                     ),
+                    BonusData::new(
+                        "Stonekeeper 1pc",
+                        BonusSource::GearSet,
+                        BonusTrigger::Passive,
+                        BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 603.0),
+                    ),
                 ],
             ),
         SetData::new("Stormfist", SetType::Monster)
@@ -647,6 +819,7 @@ pub static MONSTER_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                         dot_flags: DamageFlags::empty(),
                     },
                     cooldown: 8.0,
+                    proc_chance: 1.0,
                 }],
             ),
         SetData::new("Swarm Mother", SetType::Monster)
@@ -697,11 +870,21 @@ pub static MONSTER_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
             "Light(Shoulder Head)",
             "Heavy(Shoulder Head)",
         ]),
-        SetData::new("Thurvokun", SetType::Monster).with_item_slots(vec![
-            "Medium(Shoulder Head)",
-            "Light(Shoulder Head)",
-            "Heavy(Shoulder Head)",
-        ]),
+        SetData::new("Thurvokun", SetType::Monster)
+            .with_item_slots(vec![
+                "Medium(Shoulder Head)",
+                "Light(Shoulder Head)",
+                "Heavy(Shoulder Head)",
+            ])
+            .with_threshold(
+                1,
+                vec![BonusData::new(
+                    "Thurvokun 1pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Tremorscale", SetType::Monster)
             .with_item_slots(vec![
                 "Medium(Shoulder Head)",
@@ -735,6 +918,24 @@ pub static MONSTER_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                         1487.0,
                     ),
                 )],
+            )
+            .with_proc_effects(
+                2,
+                vec![SetProcEffect {
+                    name: "Valkyn Skoria Meteor".to_string(),
+                    trigger: SetProcTrigger::OnDotTick,
+                    action: SetProcAction::DamageProc {
+                        hit_damage: 1342.0,
+                        hit_flags: DamageFlags::FLAME
+                            | DamageFlags::SINGLE_TARGET
+                            | DamageFlags::DIRECT,
+                        dot_total_damage: 0.0,
+                        dot_duration: 0.0,
+                        dot_flags: DamageFlags::empty(),
+                    },
+                    cooldown: 5.0,
+                    proc_chance: 0.08,
+                }],
             ),
         SetData::new("Velidreth", SetType::Monster)
             .with_item_slots(vec![
@@ -779,17 +980,16 @@ pub static MONSTER_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                 2,
                 vec![SetProcEffect {
                     name: "Zaan Fire Beam".to_string(),
-                    trigger: SetProcTrigger::OnDealDamage,
-                    action: SetProcAction::DamageProc {
-                        hit_damage: 0.0,
-                        hit_flags: DamageFlags::empty(),
-                        dot_total_damage: 9000.0,
-                        dot_duration: 6.0,
-                        dot_flags: DamageFlags::FLAME
-                            | DamageFlags::SINGLE_TARGET
-                            | DamageFlags::DOT,
+                    trigger: SetProcTrigger::OnCriticalHit,
+                    action: SetProcAction::RampingBeam {
+                        base_damage_per_tick: 145.0,
+                        ramp_per_tick: 1.0,
+                        tick_interval: 1.0,
+                        duration: 10.0,
+                        flags: DamageFlags::FLAME | DamageFlags::SINGLE_TARGET | DamageFlags::DOT,
                     },
-                    cooldown: 18.0,
+                    cooldown: 20.0,
+                    proc_chance: 0.33,
                 }],
             ),
         SetData::new("Zoal the Ever-Wakeful", SetType::Monster)
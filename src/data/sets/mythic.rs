@@ -15,6 +15,7 @@ pub static MYTHIC_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                         trigger: SetProcTrigger::OnLightAttack,
                         action: SetProcAction::FlatLightAttackBonus { value: 900.0 },
                         cooldown: 0.0,
+                        proc_chance: 1.0,
                     },
                     SetProcEffect {
                         name: "Belharza's Band Proc".to_string(),
@@ -29,6 +30,7 @@ pub static MYTHIC_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                             dot_flags: DamageFlags::empty(),
                         },
                         cooldown: 10.0,
+                        proc_chance: 1.0,
                     },
                 ],
             ),
@@ -1,8 +1,8 @@
 // Auto-generated by datamine/generate_sets_rs.py - do not edit manually.
 // Proc effects are embedded inline from the PROC_EFFECTS dict in the generator.
 use crate::domain::{
-    BonusData, BonusSource, BonusTarget, BonusTrigger, BonusValue, DamageFlags, SetData,
-    SetProcAction, SetProcEffect, SetProcTrigger, SetType,
+    BonusData, BonusSource, BonusTarget, BonusTrigger, BonusValue, CombatMode, DamageFlags,
+    Resource, SetData, SetProcAction, SetProcEffect, SetProcTrigger, SetType, SkillLineName,
 };
 use once_cell::sync::Lazy;
 
@@ -27,23 +27,73 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusTrigger::Passive,
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Abyssal Brace 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                5,
+                vec![BonusData::new(
+                    "Abyssal Brace 5pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1710.0),
+                )],
+            ),
+        SetData::new("Adamant Lurker", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Weapons(All)", "Neck", "Ring", "Shield"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Adamant Lurker 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Health Recovery", BonusTarget::HealthRecovery, 129.0),
+                )],
+            )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Adamant Lurker 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Adamant Lurker 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Health Recovery", BonusTarget::HealthRecovery, 129.0),
+                )],
+            ),
+        SetData::new("Adept Rider", SetType::Normal)
+            .with_item_slots(vec![
+                "Neck",
+                "Ring",
+                "Medium(All)",
+                "Light(All)",
+                "Heavy(All)",
+                "Shield",
+                "Weapons(All)",
+            ])
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Adept Rider 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
             ),
-        SetData::new("Adamant Lurker", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-            "Shield",
-        ]),
-        SetData::new("Adept Rider", SetType::Normal).with_item_slots(vec![
-            "Neck",
-            "Ring",
-            "Medium(All)",
-            "Light(All)",
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-        ]),
         SetData::new("Aegis Caller", SetType::Normal)
             .with_item_slots(vec![
                 "Medium(All)",
@@ -86,14 +136,34 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                         129.0,
                     ),
                 )],
+            )
+            .with_proc_effects(
+                5,
+                vec![SetProcEffect {
+                    name: "Lesser Aegis".to_string(),
+                    trigger: SetProcTrigger::OnCriticalHit,
+                    action: SetProcAction::SummonedEntity {
+                        damage_per_attack: 478.0,
+                        attack_interval: 1.0,
+                        duration: 8.5,
+                        delay: 2.5,
+                        flags: DamageFlags::BLEED | DamageFlags::AOE | DamageFlags::DIRECT,
+                    },
+                    cooldown: 12.0,
+                    proc_chance: 1.0,
+                }],
+            ),
+        SetData::new("Aegis of Galenwe", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Aegis of Galenwe 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
             ),
-        SetData::new("Aegis of Galenwe", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
         SetData::new("Aerie's Cry", SetType::Normal)
             .with_item_slots(vec![
                 "Heavy(All)",
@@ -143,15 +213,25 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             ),
-        SetData::new("Aetherial Ascension", SetType::Normal).with_item_slots(vec![
-            "Medium(All)",
-            "Light(All)",
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Aetherial Ascension", SetType::Normal)
+            .with_item_slots(vec![
+                "Medium(All)",
+                "Light(All)",
+                "Heavy(All)",
+                "Shield",
+                "Weapons(All)",
+                "Neck",
+                "Ring",
+            ])
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Aetherial Ascension 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Aetheric Lancer", SetType::Normal)
             .with_item_slots(vec![
                 "Heavy(All)",
@@ -196,6 +276,24 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                         129.0,
                     ),
                 )],
+            )
+            .with_proc_effects(
+                5,
+                vec![SetProcEffect {
+                    name: "Aetheric Lancer Spear".to_string(),
+                    trigger: SetProcTrigger::OnSkillLineCast(SkillLineName::AedricSpear),
+                    action: SetProcAction::DamageProc {
+                        hit_damage: 1684.0,
+                        hit_flags: DamageFlags::MAGIC
+                            | DamageFlags::SINGLE_TARGET
+                            | DamageFlags::DIRECT,
+                        dot_total_damage: 0.0,
+                        dot_duration: 0.0,
+                        dot_flags: DamageFlags::empty(),
+                    },
+                    cooldown: 6.0,
+                    proc_chance: 1.0,
+                }],
             ),
         SetData::new("Affliction", SetType::Normal)
             .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
@@ -245,38 +343,75 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             ),
-        SetData::new("Akaviri Dragonguard", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
-        SetData::new("Alessia's Bulwark", SetType::Normal).with_item_slots(vec![
-            "Neck",
-            "Ring",
-            "Shield",
-            "Medium(All)",
-            "Light(All)",
-            "Heavy(All)",
-            "Weapons(All)",
-        ]),
-        SetData::new("Alessian Order", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-            "Medium(Hand Chest Leg Head)",
-            "Light(Hand Chest Leg Head)",
-        ]),
-        SetData::new("Almalexia's Mercy", SetType::Normal).with_item_slots(vec![
-            "Light(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Akaviri Dragonguard", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Akaviri Dragonguard 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                5,
+                vec![BonusData::new(
+                    "Akaviri Dragonguard 5pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Health Recovery", BonusTarget::HealthRecovery, 158.0),
+                )],
+            ),
+        SetData::new("Alessia's Bulwark", SetType::Normal)
+            .with_item_slots(vec![
+                "Neck",
+                "Ring",
+                "Shield",
+                "Medium(All)",
+                "Light(All)",
+                "Heavy(All)",
+                "Weapons(All)",
+            ])
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Alessia's Bulwark 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
+        SetData::new("Alessian Order", SetType::Normal)
+            .with_item_slots(vec![
+                "Heavy(All)",
+                "Shield",
+                "Weapons(All)",
+                "Neck",
+                "Ring",
+                "Medium(Hand Chest Leg Head)",
+                "Light(Hand Chest Leg Head)",
+            ])
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Alessian Order 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
+        SetData::new("Almalexia's Mercy", SetType::Normal)
+            .with_item_slots(vec!["Light(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Almalexia's Mercy 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Amber Plasm", SetType::Normal)
             .with_item_slots(vec!["Light(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
             .with_threshold(
@@ -309,6 +444,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                         129.0,
                     ),
                 )],
+            )
+            .with_threshold(
+                5,
+                vec![BonusData::new(
+                    "Amber Plasm 5pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Health Recovery", BonusTarget::HealthRecovery, 245.0),
+                )],
             ),
         SetData::new("Ancient Dragonguard", SetType::Normal)
             .with_item_slots(vec![
@@ -320,6 +464,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                 "Neck",
                 "Ring",
             ])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Ancient Dragonguard 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 3,
                 vec![BonusData::new(
@@ -388,6 +541,7 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                             dot_flags: DamageFlags::empty(),
                         },
                         cooldown: 0.0,
+                        proc_chance: 1.0,
                     },
                     SetProcEffect {
                         name: "Ansuul's Fury".to_string(),
@@ -404,6 +558,7 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                             at_max_buff_duration: 0.0,
                         },
                         cooldown: 0.0,
+                        proc_chance: 1.0,
                     },
                 ],
             ),
@@ -437,13 +592,26 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             ),
-        SetData::new("Arkasis's Genius", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Arkasis's Genius", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Arkasis's Genius 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Arkasis's Genius 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Arkay's Charity", SetType::Normal)
             .with_item_slots(vec!["Light(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
             .with_threshold(
@@ -455,15 +623,25 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 1096.0),
                 )],
             ),
-        SetData::new("Armor Master", SetType::Normal).with_item_slots(vec![
-            "Neck",
-            "Ring",
-            "Shield",
-            "Medium(All)",
-            "Light(All)",
-            "Heavy(All)",
-            "Weapons(All)",
-        ]),
+        SetData::new("Armor Master", SetType::Normal)
+            .with_item_slots(vec![
+                "Neck",
+                "Ring",
+                "Shield",
+                "Medium(All)",
+                "Light(All)",
+                "Heavy(All)",
+                "Weapons(All)",
+            ])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Armor Master 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Armor of Truth", SetType::Normal)
             .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
             .with_threshold(
@@ -546,6 +724,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                 "Light(All)",
                 "Heavy(All)",
             ])
+            .with_threshold(
+                1,
+                vec![BonusData::new(
+                    "Armor of the Trainee 1pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1454.0),
+                )],
+            )
             .with_threshold(
                 2,
                 vec![BonusData::new(
@@ -644,6 +831,7 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                             | DamageFlags::DOT,
                     },
                     cooldown: 0.0,
+                    proc_chance: 1.0,
                 }],
             ),
         SetData::new("Arms of the Ancestors", SetType::Normal)
@@ -671,6 +859,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                 "Heavy(All)",
                 "Weapons(All)",
             ])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Ashen Grip 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 3,
                 vec![BonusData::new(
@@ -695,6 +892,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
             ),
         SetData::new("Aspect of Mazzatun", SetType::Normal)
             .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Aspect of Mazzatun 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 3,
                 vec![BonusData::new(
@@ -756,6 +962,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
             ),
         SetData::new("Auroran's Thunder", SetType::Normal)
             .with_item_slots(vec!["Light(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Auroran's Thunder 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 3,
                 vec![BonusData::new(
@@ -781,14 +996,34 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                         1487.0,
                     ),
                 )],
+            )
+            .with_proc_effects(
+                5,
+                vec![SetProcEffect {
+                    name: "Auroran's Thunder".to_string(),
+                    trigger: SetProcTrigger::OnDirectDamage,
+                    action: SetProcAction::SummonedEntity {
+                        damage_per_attack: 396.0,
+                        attack_interval: 1.0,
+                        duration: 5.0,
+                        delay: 0.0,
+                        flags: DamageFlags::SHOCK | DamageFlags::AOE | DamageFlags::DIRECT,
+                    },
+                    cooldown: 10.0,
+                    proc_chance: 1.0,
+                }],
+            ),
+        SetData::new("Automated Defense", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Automated Defense 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
             ),
-        SetData::new("Automated Defense", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
         SetData::new("Ayleid Refuge", SetType::Normal)
             .with_item_slots(vec!["Heavy(All)", "Weapons(All)", "Ring", "Neck", "Shield"])
             .with_threshold(
@@ -799,6 +1034,24 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusTrigger::Passive,
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
+            )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Ayleid Refuge 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Ayleid Refuge 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
             ),
         SetData::new("Azureblight Reaper", SetType::Normal)
             .with_item_slots(vec![
@@ -847,13 +1100,26 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             ),
-        SetData::new("Baan Dar's Blessing", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Baan Dar's Blessing", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Baan Dar's Blessing 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Baan Dar's Blessing 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Back-Alley Gourmand", SetType::Normal)
             .with_item_slots(vec!["Ring", "Light(All)", "Weapons(All)", "Shield", "Neck"])
             .with_threshold(
@@ -897,6 +1163,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                 "Neck",
                 "Ring",
             ])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Bahraha's Curse 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 3,
                 vec![BonusData::new(
@@ -950,10 +1225,20 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                         threshold_pct: None,
                     },
                     cooldown: 0.0,
+                    proc_chance: 1.0,
                 }],
             ),
         SetData::new("Bani's Torment", SetType::Normal)
             .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Bani's Torment 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -963,18 +1248,38 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
             ),
-        SetData::new("Bar-Sakka", SetType::Normal).with_item_slots(vec![
-            "Light(Shoulder Head)",
-            "Medium(Shoulder Head)",
-            "Heavy(Shoulder Head)",
-        ]),
-        SetData::new("Barkskin", SetType::Normal).with_item_slots(vec![
-            "Medium(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Bar-Sakka", SetType::Normal)
+            .with_item_slots(vec![
+                "Light(Shoulder Head)",
+                "Medium(Shoulder Head)",
+                "Heavy(Shoulder Head)",
+            ])
+            .with_threshold(
+                1,
+                vec![BonusData::new(
+                    "Bar-Sakka 1pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
+        SetData::new("Barkskin", SetType::Normal)
+            .with_item_slots(vec![
+                "Medium(All)",
+                "Shield",
+                "Weapons(All)",
+                "Neck",
+                "Ring",
+            ])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Barkskin 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Basalt-Blooded Warrior", SetType::Normal)
             .with_item_slots(vec![
                 "Heavy(All)",
@@ -985,6 +1290,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                 "Neck",
                 "Ring",
             ])
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Basalt-Blooded Warrior 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -1009,6 +1323,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
             )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Bastion of the Draoife 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -1018,15 +1341,28 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
             ),
-        SetData::new("Bastion of the Heartland", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Bastion of the Heartland", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Bastion of the Heartland 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Battalion Defender", SetType::Normal)
             .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Battalion Defender 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 3,
                 vec![BonusData::new(
@@ -1064,32 +1400,95 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                 "Ring",
             ])
             .with_threshold(
-                3,
+                3,
+                vec![BonusData::new(
+                    "Beacon of Oblivion 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new(
+                        "Weapon and Spell Damage",
+                        BonusTarget::WeaponAndSpellDamageFlat,
+                        129.0,
+                    ),
+                )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Beacon of Oblivion 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                5,
+                vec![
+                    BonusData::new(
+                        "Beacon of Oblivion 5pc",
+                        BonusSource::GearSet,
+                        BonusTrigger::Passive,
+                        BonusValue::new("Damage Done", BonusTarget::Damage, 0.15),
+                    )
+                    .with_combat_mode(CombatMode::Pve),
+                    BonusData::new(
+                        "Beacon of Oblivion 5pc",
+                        BonusSource::GearSet,
+                        BonusTrigger::Passive,
+                        BonusValue::new("Damage Done vs Players", BonusTarget::Damage, 0.05),
+                    )
+                    .with_combat_mode(CombatMode::Pvp),
+                ],
+            ),
+        SetData::new("Beckoning Steel", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Beckoning Steel 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
+        SetData::new("Beekeeper's Gear", SetType::Normal)
+            .with_item_slots(vec!["Ring", "Heavy(All)", "Shield", "Weapons(All)", "Neck"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Beekeeper's Gear 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Beekeeper's Gear 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Beekeeper's Gear 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                5,
                 vec![BonusData::new(
-                    "Beacon of Oblivion 3pc",
+                    "Beekeeper's Gear 5pc",
                     BonusSource::GearSet,
                     BonusTrigger::Passive,
-                    BonusValue::new(
-                        "Weapon and Spell Damage",
-                        BonusTarget::WeaponAndSpellDamageFlat,
-                        129.0,
-                    ),
+                    BonusValue::new("Health Recovery", BonusTarget::HealthRecovery, 900.0),
                 )],
             ),
-        SetData::new("Beckoning Steel", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
-        SetData::new("Beekeeper's Gear", SetType::Normal).with_item_slots(vec![
-            "Ring",
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-        ]),
         SetData::new("Berserking Warrior", SetType::Normal)
             .with_item_slots(vec!["Weapons(All)", "Heavy(All)", "Shield", "Neck", "Ring"])
             .with_threshold(
@@ -1130,6 +1529,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
             ),
         SetData::new("Black Foundry Steel", SetType::Normal)
             .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Black Foundry Steel 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 3,
                 vec![BonusData::new(
@@ -1169,6 +1577,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
             ),
         SetData::new("Black Rose", SetType::Normal)
             .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Black Rose 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 3,
                 vec![BonusData::new(
@@ -1200,20 +1617,37 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             ),
-        SetData::new("Black-Glove Grounding", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
-        SetData::new("Blackfeather Flight", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Black-Glove Grounding", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Black-Glove Grounding 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Black-Glove Grounding 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
+        SetData::new("Blackfeather Flight", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Blackfeather Flight 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Blessing of High Isle", SetType::Normal)
             .with_item_slots(vec![
                 "Weapons(All)",
@@ -1410,6 +1844,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 1096.0),
                 )],
             )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Bog Raider 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -1445,13 +1888,26 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
             ),
-        SetData::new("Brands of Imperium", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Brands of Imperium", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Brands of Imperium 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Brands of Imperium 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Briarheart", SetType::Normal)
             .with_item_slots(vec![
                 "Shield",
@@ -1486,6 +1942,26 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusTrigger::Passive,
                     BonusValue::new("Critical Chance", BonusTarget::CriticalRating, 657.0),
                 )],
+            )
+            .with_proc_effects(
+                5,
+                vec![SetProcEffect {
+                    name: "Briarheart".to_string(),
+                    trigger: SetProcTrigger::OnCriticalHit,
+                    action: SetProcAction::StackingBuff {
+                        per_stack_target: None,
+                        per_stack_value: 0.0,
+                        max_stacks: 1,
+                        stack_duration: 10.0,
+                        stack_cooldown: 15.0,
+                        at_max_buff_name: "Briarheart".to_string(),
+                        at_max_buff_target: BonusTarget::WeaponAndSpellDamageFlat,
+                        at_max_buff_value: 450.0,
+                        at_max_buff_duration: 10.0,
+                    },
+                    cooldown: 0.0,
+                    proc_chance: 1.0,
+                }],
             ),
         SetData::new("Bright-Throat's Boast", SetType::Normal)
             .with_item_slots(vec!["Light(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
@@ -1507,7 +1983,17 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 1096.0),
                 )],
             ),
-        SetData::new("Broken Soul", SetType::Normal).with_item_slots(vec!["Ring"]),
+        SetData::new("Broken Soul", SetType::Normal)
+            .with_item_slots(vec!["Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Broken Soul 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Buffer of the Swift", SetType::Normal)
             .with_item_slots(vec!["Light(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
             .with_threshold(
@@ -1585,13 +2071,26 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Critical Chance", BonusTarget::CriticalRating, 657.0),
                 )],
             ),
-        SetData::new("Call of the Undertaker", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Call of the Undertaker", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Call of the Undertaker 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                5,
+                vec![BonusData::new(
+                    "Call of the Undertaker 5pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Caluurion's Legacy", SetType::Normal)
             .with_item_slots(vec!["Light(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
             .with_threshold(
@@ -1624,6 +2123,24 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusTrigger::Passive,
                     BonusValue::new("Critical Chance", BonusTarget::CriticalRating, 657.0),
                 )],
+            )
+            .with_proc_effects(
+                5,
+                vec![SetProcEffect {
+                    name: "Caluurion's Legacy Projectile".to_string(),
+                    trigger: SetProcTrigger::OnCriticalHit,
+                    action: SetProcAction::DamageProc {
+                        hit_damage: 894.0,
+                        hit_flags: DamageFlags::FLAME
+                            | DamageFlags::SINGLE_TARGET
+                            | DamageFlags::DIRECT,
+                        dot_total_damage: 0.0,
+                        dot_duration: 0.0,
+                        dot_flags: DamageFlags::empty(),
+                    },
+                    cooldown: 5.0,
+                    proc_chance: 1.0,
+                }],
             ),
         SetData::new("Camonna Tong", SetType::Normal)
             .with_item_slots(vec![
@@ -1704,6 +2221,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusTrigger::Passive,
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
+            )
+            .with_threshold(
+                5,
+                vec![BonusData::new(
+                    "Champion of the Hist 5pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1600.0),
+                )],
             ),
         SetData::new("Chaotic Whirlwind", SetType::Normal)
             .with_item_slots(vec!["Weapons(Dagger Sword Mace Axe)"]),
@@ -1726,6 +2252,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 1096.0),
                 )],
             )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Chimera's Rebuke 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -1778,6 +2313,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
             ),
         SetData::new("Claw of Yolnahkriin", SetType::Normal)
             .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Claw of Yolnahkriin 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -1838,6 +2382,24 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                 "Medium(All)",
                 "Heavy(All)",
             ])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Clever Alchemist 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Clever Alchemist 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -1983,6 +2545,7 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                         threshold_pct: Some(50.0),
                     },
                     cooldown: 0.0,
+                    proc_chance: 1.0,
                 }],
             ),
         SetData::new("Corpseburster", SetType::Normal)
@@ -2039,115 +2602,165 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                 "Ring",
             ])
             .with_threshold(
-                2,
+                2,
+                vec![BonusData::new(
+                    "Coup De Grâce 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new(
+                        "Weapon and Spell Damage",
+                        BonusTarget::WeaponAndSpellDamageFlat,
+                        129.0,
+                    ),
+                )],
+            )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Coup De Grâce 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
+                )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Coup De Grâce 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new(
+                        "Weapon and Spell Damage",
+                        BonusTarget::WeaponAndSpellDamageFlat,
+                        129.0,
+                    ),
+                )],
+            ),
+        SetData::new("Coward's Gear", SetType::Normal)
+            .with_item_slots(vec![
+                "Medium(All)",
+                "Shield",
+                "Weapons(All)",
+                "Neck",
+                "Ring",
+            ])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Coward's Gear 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
+        SetData::new("Crafty Alfiq", SetType::Normal)
+            .with_item_slots(vec!["Neck", "Light(All)", "Weapons(All)", "Shield", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Crafty Alfiq 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 1096.0),
+                )],
+            )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Crafty Alfiq 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 1096.0),
+                )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Crafty Alfiq 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 1096.0),
+                )],
+            )
+            .with_threshold(
+                5,
                 vec![BonusData::new(
-                    "Coup De Grâce 2pc",
+                    "Crafty Alfiq 5pc",
                     BonusSource::GearSet,
                     BonusTrigger::Passive,
-                    BonusValue::new(
-                        "Weapon and Spell Damage",
-                        BonusTarget::WeaponAndSpellDamageFlat,
-                        129.0,
-                    ),
+                    BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 2550.0),
                 )],
-            )
+            ),
+        SetData::new("Crest of Cyrodiil", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
             .with_threshold(
                 3,
                 vec![BonusData::new(
-                    "Coup De Grâce 3pc",
+                    "Crest of Cyrodiil 3pc",
                     BonusSource::GearSet,
                     BonusTrigger::Passive,
-                    BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
                 )],
             )
             .with_threshold(
                 4,
                 vec![BonusData::new(
-                    "Coup De Grâce 4pc",
+                    "Crest of Cyrodiil 4pc",
                     BonusSource::GearSet,
                     BonusTrigger::Passive,
-                    BonusValue::new(
-                        "Weapon and Spell Damage",
-                        BonusTarget::WeaponAndSpellDamageFlat,
-                        129.0,
-                    ),
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
                 )],
             ),
-        SetData::new("Coward's Gear", SetType::Normal).with_item_slots(vec![
-            "Medium(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
-        SetData::new("Crafty Alfiq", SetType::Normal)
-            .with_item_slots(vec!["Neck", "Light(All)", "Weapons(All)", "Shield", "Ring"])
+        SetData::new("Crimson Oath's Rive", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
             .with_threshold(
-                2,
+                3,
                 vec![BonusData::new(
-                    "Crafty Alfiq 2pc",
+                    "Crimson Oath's Rive 3pc",
                     BonusSource::GearSet,
                     BonusTrigger::Passive,
-                    BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 1096.0),
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
                 )],
-            )
+            ),
+        SetData::new("Crimson Twilight", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
             .with_threshold(
-                3,
+                2,
                 vec![BonusData::new(
-                    "Crafty Alfiq 3pc",
+                    "Crimson Twilight 2pc",
                     BonusSource::GearSet,
                     BonusTrigger::Passive,
-                    BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 1096.0),
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
                 )],
             )
             .with_threshold(
                 4,
                 vec![BonusData::new(
-                    "Crafty Alfiq 4pc",
+                    "Crimson Twilight 4pc",
                     BonusSource::GearSet,
                     BonusTrigger::Passive,
-                    BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 1096.0),
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
                 )],
-            )
+            ),
+        SetData::new("Critical Riposte", SetType::Normal)
+            .with_item_slots(vec![
+                "Medium(All)",
+                "Light(All)",
+                "Heavy(All)",
+                "Shield",
+                "Weapons(All)",
+                "Neck",
+                "Ring",
+            ])
             .with_threshold(
-                5,
+                3,
                 vec![BonusData::new(
-                    "Crafty Alfiq 5pc",
+                    "Critical Riposte 3pc",
                     BonusSource::GearSet,
                     BonusTrigger::Passive,
-                    BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 2550.0),
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
                 )],
             ),
-        SetData::new("Crest of Cyrodiil", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
-        SetData::new("Crimson Oath's Rive", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
-        SetData::new("Crimson Twilight", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
-        SetData::new("Critical Riposte", SetType::Normal).with_item_slots(vec![
-            "Medium(All)",
-            "Light(All)",
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
         SetData::new("Crusader", SetType::Normal)
             .with_item_slots(vec![
                 "Medium(All)",
@@ -2208,6 +2821,24 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
             ),
         SetData::new("Curse of Doylemish", SetType::Normal)
             .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Curse of Doylemish 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Curse of Doylemish 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -2231,6 +2862,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                 "Shield",
                 "Weapons(All)",
             ])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Daedric Trickery 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 3,
                 vec![BonusData::new(
@@ -2393,6 +3033,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
             )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Dauntless Combatant 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -2401,6 +3050,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusTrigger::Passive,
                     BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 1096.0),
                 )],
+            )
+            .with_threshold(
+                5,
+                vec![BonusData::new(
+                    "Dauntless Combatant 5pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
             ),
         SetData::new("Dead-Water's Guile", SetType::Normal)
             .with_item_slots(vec![
@@ -2423,6 +3081,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Dead-Water's Guile 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -2585,15 +3252,34 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             ),
-        SetData::new("Death's Wind", SetType::Normal).with_item_slots(vec![
-            "Neck",
-            "Ring",
-            "Shield",
-            "Medium(All)",
-            "Light(All)",
-            "Heavy(All)",
-            "Weapons(All)",
-        ]),
+        SetData::new("Death's Wind", SetType::Normal)
+            .with_item_slots(vec![
+                "Neck",
+                "Ring",
+                "Shield",
+                "Medium(All)",
+                "Light(All)",
+                "Heavy(All)",
+                "Weapons(All)",
+            ])
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Death's Wind 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Death's Wind 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Death-Dancer", SetType::Normal)
             .with_item_slots(vec![
                 "Weapons(All)",
@@ -2639,14 +3325,27 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusTrigger::Passive,
                     BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 1096.0),
                 )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Deeproot Zeal 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
+        SetData::new("Defending Warrior", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Defending Warrior 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
             ),
-        SetData::new("Defending Warrior", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
         SetData::new("Defensive Position", SetType::Normal)
             .with_item_slots(vec!["Weapons(Axe Dagger Sword Mace)", "Shield"]),
         SetData::new("Defiler", SetType::Normal)
@@ -2687,9 +3386,34 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusTrigger::Passive,
                     BonusValue::new("Critical Chance", BonusTarget::CriticalRating, 657.0),
                 )],
+            )
+            .with_proc_effects(
+                5,
+                vec![SetProcEffect {
+                    name: "Defiler Hunger".to_string(),
+                    trigger: SetProcTrigger::OnCriticalHit,
+                    action: SetProcAction::DamageProc {
+                        hit_damage: 990.0,
+                        hit_flags: DamageFlags::POISON | DamageFlags::AOE | DamageFlags::DIRECT,
+                        dot_total_damage: 0.0,
+                        dot_duration: 0.0,
+                        dot_flags: DamageFlags::empty(),
+                    },
+                    cooldown: 5.0,
+                    proc_chance: 1.0,
+                }],
             ),
         SetData::new("Desert Rose", SetType::Normal)
             .with_item_slots(vec!["Light(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Desert Rose 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -2783,13 +3507,17 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
             ),
         SetData::new("Disciplined Slash", SetType::Normal)
             .with_item_slots(vec!["Weapons(Greatsword Maul Battleaxe)"]),
-        SetData::new("Dolorous Arena", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Dolorous Arena", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Dolorous Arena 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Dragon's Appetite", SetType::Normal)
             .with_item_slots(vec![
                 "Medium(All)",
@@ -2832,6 +3560,24 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusTrigger::Passive,
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Dragon's Defilement 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                5,
+                vec![BonusData::new(
+                    "Dragon's Defilement 5pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
             ),
         SetData::new("Dragonguard Elite", SetType::Normal)
             .with_item_slots(vec![
@@ -2865,6 +3611,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
             ),
         SetData::new("Drake's Rush", SetType::Normal)
             .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Drake's Rush 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 3,
                 vec![BonusData::new(
@@ -2912,29 +3667,48 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
             .with_threshold(
                 4,
                 vec![BonusData::new(
-                    "Draugr Hulk 4pc",
+                    "Draugr Hulk 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
+                )],
+            )
+            .with_threshold(
+                5,
+                vec![BonusData::new(
+                    "Draugr Hulk 5pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 2550.0),
+                )],
+            ),
+        SetData::new("Draugr's Heritage", SetType::Normal)
+            .with_item_slots(vec![
+                "Heavy(All)",
+                "Shield",
+                "Weapons(All)",
+                "Neck",
+                "Ring",
+                "Medium(Feet)",
+            ])
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Draugr's Heritage 3pc",
                     BonusSource::GearSet,
                     BonusTrigger::Passive,
-                    BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
                 )],
             )
             .with_threshold(
-                5,
+                4,
                 vec![BonusData::new(
-                    "Draugr Hulk 5pc",
+                    "Draugr's Heritage 4pc",
                     BonusSource::GearSet,
                     BonusTrigger::Passive,
-                    BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 2550.0),
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
                 )],
             ),
-        SetData::new("Draugr's Heritage", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-            "Medium(Feet)",
-        ]),
         SetData::new("Draugr's Rest", SetType::Normal)
             .with_item_slots(vec!["Light(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
             .with_threshold(
@@ -2972,6 +3746,24 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
             ),
         SetData::new("Dreamer's Mantle", SetType::Normal)
             .with_item_slots(vec!["Light(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Dreamer's Mantle 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Dreamer's Mantle 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -3071,6 +3863,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                 "Neck",
                 "Ring",
             ])
+            .with_threshold(
+                1,
+                vec![BonusData::new(
+                    "Druid's Braid 1pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 2,
                 vec![BonusData::new(
@@ -3089,6 +3890,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
             )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Druid's Braid 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 5,
                 vec![BonusData::new(
@@ -3107,6 +3917,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1565.0),
                 )],
             )
+            .with_threshold(
+                7,
+                vec![BonusData::new(
+                    "Druid's Braid 7pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1722.0),
+                )],
+            )
             .with_threshold(
                 8,
                 vec![BonusData::new(
@@ -3125,6 +3944,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1565.0),
                 )],
             )
+            .with_threshold(
+                10,
+                vec![BonusData::new(
+                    "Druid's Braid 10pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1722.0),
+                )],
+            )
             .with_threshold(
                 11,
                 vec![BonusData::new(
@@ -3143,20 +3971,37 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1565.0),
                 )],
             ),
-        SetData::new("Duneripper's Scales", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
-        SetData::new("Durok's Bane", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Duneripper's Scales", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Duneripper's Scales 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
+        SetData::new("Durok's Bane", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Durok's Bane 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Durok's Bane 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Health Recovery", BonusTarget::HealthRecovery, 129.0),
+                )],
+            ),
         SetData::new("Eagle Eye", SetType::Normal)
             .with_item_slots(vec![
                 "Weapons(Resto Lightning Frost Flame Bow)",
@@ -3172,13 +4017,26 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Critical Chance", BonusTarget::CriticalRating, 657.0),
                 )],
             ),
-        SetData::new("Ebon Armory", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Ebon Armory", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Ebon Armory 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Ebon Armory 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Elemental Catalyst", SetType::Normal)
             .with_item_slots(vec!["Light(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
             .with_threshold(
@@ -3245,26 +4103,57 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             ),
-        SetData::new("Embershield", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
-        SetData::new("Endurance", SetType::Normal).with_item_slots(vec![
-            "Neck",
-            "Ring",
-            "Shield",
-            "Weapons(All)",
-        ]),
-        SetData::new("Enervating Aura", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Embershield", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Embershield 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Embershield 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
+        SetData::new("Endurance", SetType::Normal)
+            .with_item_slots(vec!["Neck", "Ring", "Shield", "Weapons(All)"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Endurance 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1928.0),
+                )],
+            )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Endurance 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Health Recovery", BonusTarget::HealthRecovery, 618.0),
+                )],
+            ),
+        SetData::new("Enervating Aura", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Enervating Aura 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Essence Thief", SetType::Normal)
             .with_item_slots(vec![
                 "Medium(All)",
@@ -3323,20 +4212,28 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
             ),
-        SetData::new("Eternal Vigor", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
-        SetData::new("Eternal Warrior", SetType::Normal).with_item_slots(vec![
-            "Neck",
-            "Ring",
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-        ]),
+        SetData::new("Eternal Vigor", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Eternal Vigor 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Health Recovery", BonusTarget::HealthRecovery, 129.0),
+                )],
+            ),
+        SetData::new("Eternal Warrior", SetType::Normal)
+            .with_item_slots(vec!["Neck", "Ring", "Heavy(All)", "Shield", "Weapons(All)"])
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Eternal Warrior 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Eye of Nahviintaas", SetType::Normal)
             .with_item_slots(vec!["Light(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
             .with_threshold(
@@ -3459,22 +4356,45 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Critical Chance", BonusTarget::CriticalRating, 657.0),
                 )],
             ),
-        SetData::new("Fasalla's Guile", SetType::Normal).with_item_slots(vec![
-            "Weapons(All)",
-            "Shield",
-            "Heavy(All)",
-            "Neck",
-            "Ring",
-        ]),
-        SetData::new("Fellowship's Fortitude", SetType::Normal).with_item_slots(vec![
-            "Medium(All)",
-            "Light(All)",
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Fasalla's Guile", SetType::Normal)
+            .with_item_slots(vec!["Weapons(All)", "Shield", "Heavy(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Fasalla's Guile 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Fasalla's Guile 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
+        SetData::new("Fellowship's Fortitude", SetType::Normal)
+            .with_item_slots(vec![
+                "Medium(All)",
+                "Light(All)",
+                "Heavy(All)",
+                "Shield",
+                "Weapons(All)",
+                "Neck",
+                "Ring",
+            ])
+            .with_threshold(
+                10,
+                vec![BonusData::new(
+                    "Fellowship's Fortitude 10pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 6020.0),
+                )],
+            ),
         SetData::new("Fiord's Legacy", SetType::Normal)
             .with_item_slots(vec![
                 "Weapons(All)",
@@ -3586,37 +4506,51 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
             .with_threshold(
                 3,
                 vec![BonusData::new(
-                    "Fledgling's Nest 3pc",
+                    "Fledgling's Nest 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 1096.0),
+                )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Fledgling's Nest 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 1096.0),
+                )],
+            ),
+        SetData::new("Foolkiller's Ward", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Foolkiller's Ward 4pc",
                     BonusSource::GearSet,
                     BonusTrigger::Passive,
-                    BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 1096.0),
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
                 )],
-            )
+            ),
+        SetData::new("Fortified Brass", SetType::Normal)
+            .with_item_slots(vec![
+                "Neck",
+                "Ring",
+                "Medium(All)",
+                "Light(All)",
+                "Heavy(All)",
+                "Shield",
+                "Weapons(All)",
+            ])
             .with_threshold(
-                4,
+                2,
                 vec![BonusData::new(
-                    "Fledgling's Nest 4pc",
+                    "Fortified Brass 2pc",
                     BonusSource::GearSet,
                     BonusTrigger::Passive,
-                    BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 1096.0),
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
                 )],
             ),
-        SetData::new("Foolkiller's Ward", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
-        SetData::new("Fortified Brass", SetType::Normal).with_item_slots(vec![
-            "Neck",
-            "Ring",
-            "Medium(All)",
-            "Light(All)",
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-        ]),
         SetData::new("Frostbite", SetType::Normal)
             .with_item_slots(vec!["Weapons(All)", "Ring", "Light(All)", "Shield", "Neck"])
             .with_threshold(
@@ -3665,6 +4599,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
             )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Frozen Watcher 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -3676,6 +4619,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
             ),
         SetData::new("Full Belly Barricade", SetType::Normal)
             .with_item_slots(vec!["Heavy(All)", "Shield", "Neck", "Weapons(All)", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Full Belly Barricade 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 3,
                 vec![BonusData::new(
@@ -3764,6 +4716,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusTrigger::Passive,
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Glacial Guardian 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Health Recovery", BonusTarget::HealthRecovery, 129.0),
+                )],
             ),
         SetData::new("Gossamer", SetType::Normal)
             .with_item_slots(vec!["Light(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
@@ -3785,13 +4746,26 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 1096.0),
                 )],
             ),
-        SetData::new("Grace of Gloom", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Grace of Gloom", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Grace of Gloom 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Grace of Gloom 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Grace of the Ancients", SetType::Normal)
             .with_item_slots(vec!["Shield", "Weapons(All)", "Neck", "Ring"])
             .with_threshold(
@@ -3822,6 +4796,24 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusTrigger::Passive,
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
+            )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Grave Guardian 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Grave Guardian 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
             ),
         SetData::new("Grave Inevitability", SetType::Normal)
             .with_item_slots(vec!["Light(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
@@ -3883,14 +4875,45 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusTrigger::Passive,
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Grave-Stake Collector 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
+        SetData::new("Green Pact", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Green Pact 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Green Pact 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Health Recovery", BonusTarget::HealthRecovery, 129.0),
+                )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Green Pact 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
             ),
-        SetData::new("Green Pact", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
         SetData::new("Grisly Gourmet", SetType::Normal)
             .with_item_slots(vec![
                 "Medium(All)",
@@ -4020,21 +5043,54 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusTrigger::Passive,
                     BonusValue::new("Critical Chance", BonusTarget::CriticalRating, 657.0),
                 )],
+            )
+            .with_proc_effects(
+                5,
+                vec![SetProcEffect {
+                    name: "Gryphon's Reprisal".to_string(),
+                    trigger: SetProcTrigger::OnCriticalHit,
+                    action: SetProcAction::DamageProc {
+                        hit_damage: 0.0,
+                        hit_flags: DamageFlags::empty(),
+                        dot_total_damage: 4758.0,
+                        dot_duration: 10.0,
+                        dot_flags: DamageFlags::BLEED | DamageFlags::AOE | DamageFlags::DOT,
+                    },
+                    cooldown: 20.0,
+                    proc_chance: 1.0,
+                }],
+            ),
+        SetData::new("Hagraven's Garden", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Hagraven's Garden 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Hagraven's Garden 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Health Recovery", BonusTarget::HealthRecovery, 129.0),
+                )],
+            ),
+        SetData::new("Hand of Mephala", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Hand of Mephala 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
             ),
-        SetData::new("Hagraven's Garden", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
-        SetData::new("Hand of Mephala", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
         SetData::new("Hanu's Compassion", SetType::Normal)
             .with_item_slots(vec!["Light(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
             .with_threshold(
@@ -4096,20 +5152,37 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             ),
-        SetData::new("Hatchling's Shell", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
-        SetData::new("Haven of Ursus", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Hatchling's Shell", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Hatchling's Shell 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
+        SetData::new("Haven of Ursus", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Haven of Ursus 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Haven of Ursus 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Hawk's Eye", SetType::Normal)
             .with_item_slots(vec![
                 "Medium(All)",
@@ -4250,6 +5323,24 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusTrigger::Passive,
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
+            )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Heroic Unity 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Heroic Unity 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
             ),
         SetData::new("Hew and Sunder", SetType::Normal)
             .with_item_slots(vec![
@@ -4331,6 +5422,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
             ),
         SetData::new("Hide of Morihaus", SetType::Normal)
             .with_item_slots(vec!["Neck", "Ring", "Heavy(All)", "Weapons(All)", "Shield"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Hide of Morihaus 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -4348,6 +5448,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                 "Neck",
                 "Ring",
             ])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Hide of the Werewolf 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 3,
                 vec![BonusData::new(
@@ -4427,40 +5536,59 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
             .with_threshold(
                 3,
                 vec![BonusData::new(
-                    "Hircine's Veneer 3pc",
+                    "Hircine's Veneer 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
+                )],
+            ),
+        SetData::new("Hist Bark", SetType::Normal)
+            .with_item_slots(vec![
+                "Neck",
+                "Ring",
+                "Shield",
+                "Medium(All)",
+                "Light(All)",
+                "Heavy(All)",
+                "Weapons(All)",
+            ])
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Hist Bark 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
+                )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Hist Bark 4pc",
                     BonusSource::GearSet,
                     BonusTrigger::Passive,
-                    BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
                 )],
             ),
-        SetData::new("Hist Bark", SetType::Normal)
+        SetData::new("Hist Whisperer", SetType::Normal)
             .with_item_slots(vec![
-                "Neck",
-                "Ring",
-                "Shield",
                 "Medium(All)",
                 "Light(All)",
                 "Heavy(All)",
+                "Shield",
                 "Weapons(All)",
+                "Neck",
+                "Ring",
             ])
             .with_threshold(
-                3,
+                2,
                 vec![BonusData::new(
-                    "Hist Bark 3pc",
+                    "Hist Whisperer 2pc",
                     BonusSource::GearSet,
                     BonusTrigger::Passive,
-                    BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
+                    BonusValue::new("Health Recovery", BonusTarget::HealthRecovery, 129.0),
                 )],
             ),
-        SetData::new("Hist Whisperer", SetType::Normal).with_item_slots(vec![
-            "Medium(All)",
-            "Light(All)",
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
         SetData::new("Hiti's Hearth", SetType::Normal)
             .with_item_slots(vec!["Light(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
             .with_threshold(
@@ -4503,6 +5631,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
             ),
         SetData::new("Hrothgar's Chill", SetType::Normal)
             .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Hrothgar's Chill 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -4604,13 +5741,17 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             ),
-        SetData::new("Immortal Warrior", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Immortal Warrior", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Immortal Warrior 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Imperial Physique", SetType::Normal)
             .with_item_slots(vec![
                 "Light(All)",
@@ -4644,6 +5785,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                 "Neck",
                 "Ring",
             ])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Impregnable Armor 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 3,
                 vec![BonusData::new(
@@ -4771,22 +5921,45 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
             "Neck",
             "Ring",
         ]),
-        SetData::new("Iron Flask", SetType::Normal).with_item_slots(vec![
-            "Medium(All)",
-            "Light(All)",
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
-        SetData::new("Ironblood", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Iron Flask", SetType::Normal)
+            .with_item_slots(vec![
+                "Medium(All)",
+                "Light(All)",
+                "Heavy(All)",
+                "Shield",
+                "Weapons(All)",
+                "Neck",
+                "Ring",
+            ])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Iron Flask 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Iron Flask 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
+        SetData::new("Ironblood", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Ironblood 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Jailbreaker", SetType::Normal)
             .with_item_slots(vec![
                 "Medium(All)",
@@ -4804,13 +5977,17 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
             ),
-        SetData::new("Jailer's Tenacity", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Jailer's Tenacity", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Jailer's Tenacity 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Jerall Mountains Warchief", SetType::Normal)
             .with_item_slots(vec![
                 "Light(Shoulder Head)",
@@ -4877,13 +6054,17 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             ),
-        SetData::new("Jolting Arms", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Jolting Arms", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Jolting Arms 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Jorvuld's Guidance", SetType::Normal).with_item_slots(vec![
             "Light(All)",
             "Shield",
@@ -4905,6 +6086,24 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                         129.0,
                     ),
                 )],
+            )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Judgment of Akatosh 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Judgment of Akatosh 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
             ),
         SetData::new("Kagrenac's Hope", SetType::Normal)
             .with_item_slots(vec![
@@ -4925,6 +6124,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 1096.0),
                 )],
             )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Kagrenac's Hope 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 5,
                 vec![BonusData::new(
@@ -5032,6 +6240,7 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                         at_max_buff_duration: 5.0,
                     },
                     cooldown: 0.0,
+                    proc_chance: 1.0,
                 }],
             ),
         SetData::new("Knight Slayer", SetType::Normal)
@@ -5057,9 +6266,27 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                         129.0,
                     ),
                 )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Knight Slayer 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
             ),
         SetData::new("Knight-errant's Mail", SetType::Normal)
             .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Knight-errant's Mail 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 3,
                 vec![BonusData::new(
@@ -5082,13 +6309,26 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             ),
-        SetData::new("Knightmare", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Knightmare", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Knightmare 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                5,
+                vec![BonusData::new(
+                    "Knightmare 5pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Kraglen's Howl", SetType::Normal)
             .with_item_slots(vec![
                 "Medium(All)",
@@ -5193,13 +6433,17 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
             "Neck",
             "Ring",
         ]),
-        SetData::new("Kynmarcher's Cruelty", SetType::Normal).with_item_slots(vec![
-            "Neck",
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Ring",
-        ]),
+        SetData::new("Kynmarcher's Cruelty", SetType::Normal)
+            .with_item_slots(vec!["Neck", "Heavy(All)", "Shield", "Weapons(All)", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Kynmarcher's Cruelty 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Lamia's Song", SetType::Normal)
             .with_item_slots(vec!["Light(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
             .with_threshold(
@@ -5337,13 +6581,26 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             ),
-        SetData::new("Leeching Plate", SetType::Normal).with_item_slots(vec![
-            "Shield",
-            "Heavy(All)",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Leeching Plate", SetType::Normal)
+            .with_item_slots(vec!["Shield", "Heavy(All)", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Leeching Plate 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Leeching Plate 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Legacy of Karth", SetType::Normal)
             .with_item_slots(vec![
                 "Medium(All)",
@@ -5529,40 +6786,70 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 1096.0),
                 )],
             ),
-        SetData::new("Livewire", SetType::Normal).with_item_slots(vec![
-            "Neck",
-            "Ring",
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-        ]),
-        SetData::new("Lucent Echoes", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Livewire", SetType::Normal)
+            .with_item_slots(vec!["Neck", "Ring", "Heavy(All)", "Shield", "Weapons(All)"])
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Livewire 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
+        SetData::new("Lucent Echoes", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Lucent Echoes 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Lucilla's Windshield", SetType::Normal)
             .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Lucilla's Windshield 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Lucilla's Windshield 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
+                )],
+            ),
+        SetData::new("Lunar Bastion", SetType::Normal)
+            .with_item_slots(vec!["Weapons(All)", "Shield", "Heavy(All)", "Neck", "Ring"])
             .with_threshold(
                 4,
                 vec![BonusData::new(
-                    "Lucilla's Windshield 4pc",
+                    "Lunar Bastion 4pc",
                     BonusSource::GearSet,
                     BonusTrigger::Passive,
-                    BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
                 )],
             ),
-        SetData::new("Lunar Bastion", SetType::Normal).with_item_slots(vec![
-            "Weapons(All)",
-            "Shield",
-            "Heavy(All)",
-            "Neck",
-            "Ring",
-        ]),
         SetData::new("Lustrous Soulwell", SetType::Normal)
             .with_item_slots(vec!["Light(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Lustrous Soulwell 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Health Recovery", BonusTarget::HealthRecovery, 129.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -5806,13 +7093,17 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 1096.0),
                 )],
             ),
-        SetData::new("Mark of the Pariah", SetType::Normal).with_item_slots(vec![
-            "Shield",
-            "Heavy(All)",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Mark of the Pariah", SetType::Normal)
+            .with_item_slots(vec!["Shield", "Heavy(All)", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Mark of the Pariah 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Marksman's Crest", SetType::Normal)
             .with_item_slots(vec![
                 "Weapons(All)",
@@ -5833,6 +7124,17 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                         129.0,
                     ),
                 )],
+            )
+            .with_threshold(
+                5,
+                vec![BonusData::new(
+                    "Marksman's Crest 5pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Bow Damage vs Players", BonusTarget::Damage, 0.08),
+                )
+                .with_skill_line_filter(SkillLineName::Bow)
+                .with_combat_mode(CombatMode::Pvp)],
             ),
         SetData::new("Master Architect", SetType::Normal)
             .with_item_slots(vec!["Light(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
@@ -5946,6 +7248,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
         ]),
         SetData::new("Meritorious Service", SetType::Normal)
             .with_item_slots(vec!["Neck", "Ring", "Light(All)", "Shield", "Weapons(All)"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Meritorious Service 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 3,
                 vec![BonusData::new(
@@ -6008,6 +7319,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                         129.0,
                     ),
                 )],
+            )
+            .with_threshold(
+                5,
+                vec![BonusData::new(
+                    "Might of the Lost Legion 5pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
             ),
         SetData::new("Mighty Glacier", SetType::Normal)
             .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
@@ -6204,6 +7524,24 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                         129.0,
                     ),
                 )],
+            )
+            .with_proc_effects(
+                5,
+                vec![SetProcEffect {
+                    name: "Morkuldin Animated Weapon".to_string(),
+                    trigger: SetProcTrigger::OnLightAttack,
+                    action: SetProcAction::SummonedEntity {
+                        damage_per_attack: 422.0,
+                        attack_interval: 1.0,
+                        duration: 15.0,
+                        delay: 0.0,
+                        flags: DamageFlags::PHYSICAL
+                            | DamageFlags::SINGLE_TARGET
+                            | DamageFlags::DIRECT,
+                    },
+                    cooldown: 15.0,
+                    proc_chance: 1.0,
+                }],
             ),
         SetData::new("Mother's Sorrow", SetType::Normal)
             .with_item_slots(vec!["Weapons(All)", "Light(All)", "Shield", "Neck", "Ring"])
@@ -6302,6 +7640,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
             )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Netch Oil 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -6478,6 +7825,21 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusTrigger::Passive,
                     BonusValue::new("Critical Chance", BonusTarget::CriticalRating, 657.0),
                 )],
+            )
+            .with_proc_effects(
+                5,
+                vec![SetProcEffect {
+                    name: "Night Mother's Gaze".to_string(),
+                    trigger: SetProcTrigger::OnCriticalHit,
+                    action: SetProcAction::EnemyDebuff {
+                        debuff_name: "Major Breach".to_string(),
+                        target: BonusTarget::EnemyResistanceReduction,
+                        value: 5948.0,
+                        duration: 4.0,
+                    },
+                    cooldown: 0.0,
+                    proc_chance: 1.0,
+                }],
             ),
         SetData::new("Night Terror", SetType::Normal)
             .with_item_slots(vec![
@@ -6495,6 +7857,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusTrigger::Passive,
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Night Terror 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
             ),
         SetData::new("Night's Silence", SetType::Normal)
             .with_item_slots(vec![
@@ -6524,13 +7895,17 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Critical Chance", BonusTarget::CriticalRating, 657.0),
                 )],
             ),
-        SetData::new("Nikulas' Heavy Armor", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Nikulas' Heavy Armor", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Nikulas' Heavy Armor 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Nix-Hound's Howl", SetType::Normal)
             .with_item_slots(vec![
                 "Medium(All)",
@@ -6578,15 +7953,34 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             ),
-        SetData::new("Nobility in Decay", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Medium(All)",
-            "Light(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Nobility in Decay", SetType::Normal)
+            .with_item_slots(vec![
+                "Heavy(All)",
+                "Medium(All)",
+                "Light(All)",
+                "Shield",
+                "Weapons(All)",
+                "Neck",
+                "Ring",
+            ])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Nobility in Decay 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Nobility in Decay 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Noble Duelist's Silks", SetType::Normal)
             .with_item_slots(vec!["Light(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
             .with_threshold(
@@ -6625,6 +8019,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                 "Heavy(All)",
                 "Weapons(All)",
             ])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Noble's Conquest 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 3,
                 vec![BonusData::new(
@@ -6634,15 +8037,34 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
             ),
-        SetData::new("Nocturnal's Favor", SetType::Normal).with_item_slots(vec![
-            "Neck",
-            "Ring",
-            "Medium(All)",
-            "Light(All)",
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-        ]),
+        SetData::new("Nocturnal's Favor", SetType::Normal)
+            .with_item_slots(vec![
+                "Neck",
+                "Ring",
+                "Medium(All)",
+                "Light(All)",
+                "Heavy(All)",
+                "Shield",
+                "Weapons(All)",
+            ])
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Nocturnal's Favor 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Nocturnal's Favor 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Nocturnal's Ploy", SetType::Normal)
             .with_item_slots(vec![
                 "Medium(All)",
@@ -6706,6 +8128,22 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                         1487.0,
                     ),
                 )],
+            )
+            .with_proc_effects(
+                5,
+                vec![SetProcEffect {
+                    name: "Noxious Boulder".to_string(),
+                    trigger: SetProcTrigger::OnHeavyAttack,
+                    action: SetProcAction::DamageProc {
+                        hit_damage: 1049.0,
+                        hit_flags: DamageFlags::POISON | DamageFlags::AOE | DamageFlags::DIRECT,
+                        dot_total_damage: 3650.0,
+                        dot_duration: 5.0,
+                        dot_flags: DamageFlags::POISON | DamageFlags::AOE | DamageFlags::DOT,
+                    },
+                    cooldown: 20.0,
+                    proc_chance: 1.0,
+                }],
             ),
         SetData::new("Oakfather's Retribution", SetType::Normal)
             .with_item_slots(vec!["Light(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
@@ -6849,22 +8287,45 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             ),
-        SetData::new("Old Growth Brewer", SetType::Normal).with_item_slots(vec![
-            "Medium(All)",
-            "Light(All)",
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
-        SetData::new("Order of Diagna", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Old Growth Brewer", SetType::Normal)
+            .with_item_slots(vec![
+                "Medium(All)",
+                "Light(All)",
+                "Heavy(All)",
+                "Shield",
+                "Weapons(All)",
+                "Neck",
+                "Ring",
+            ])
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Old Growth Brewer 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Health Recovery", BonusTarget::HealthRecovery, 129.0),
+                )],
+            ),
+        SetData::new("Order of Diagna", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Order of Diagna 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Order of Diagna 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Order's Wrath", SetType::Normal)
             .with_item_slots(vec![
                 "Medium(All)",
@@ -6915,15 +8376,43 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Critical Chance", BonusTarget::CriticalRating, 943.0),
                 )],
             ),
-        SetData::new("Orgnum's Scales", SetType::Normal).with_item_slots(vec![
-            "Neck",
-            "Ring",
-            "Weapons(All)",
-            "Shield",
-            "Medium(All)",
-            "Light(All)",
-            "Heavy(All)",
-        ]),
+        SetData::new("Orgnum's Scales", SetType::Normal)
+            .with_item_slots(vec![
+                "Neck",
+                "Ring",
+                "Weapons(All)",
+                "Shield",
+                "Medium(All)",
+                "Light(All)",
+                "Heavy(All)",
+            ])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Orgnum's Scales 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Health Recovery", BonusTarget::HealthRecovery, 129.0),
+                )],
+            )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Orgnum's Scales 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Orgnum's Scales 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Orpheon the Tactician", SetType::Normal)
             .with_item_slots(vec![
                 "Light(Shoulder Head)",
@@ -6982,6 +8471,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
             ),
         SetData::new("Pangrit Denmother", SetType::Normal)
             .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Pangrit Denmother 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Health Recovery", BonusTarget::HealthRecovery, 129.0),
+                )],
+            )
             .with_threshold(
                 5,
                 vec![BonusData::new(
@@ -7015,13 +8513,17 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             ),
-        SetData::new("Pearlescent Ward", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Pearlescent Ward", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Pearlescent Ward 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Pelinal's Wrath", SetType::Normal)
             .with_item_slots(vec![
                 "Neck",
@@ -7071,13 +8573,17 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             ),
-        SetData::new("Perfected Aegis of Galenwe", SetType::Normal).with_item_slots(vec![
-            "Shield",
-            "Weapons(All)",
-            "Heavy(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Perfected Aegis of Galenwe", SetType::Normal)
+            .with_item_slots(vec!["Shield", "Weapons(All)", "Heavy(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Perfected Aegis of Galenwe 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Perfected Ansuul's Torment", SetType::Normal)
             .with_item_slots(vec![
                 "Neck",
@@ -7133,6 +8639,7 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                             dot_flags: DamageFlags::empty(),
                         },
                         cooldown: 0.0,
+                        proc_chance: 1.0,
                     },
                     SetProcEffect {
                         name: "Perfected Ansuul's Fury".to_string(),
@@ -7149,6 +8656,7 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                             at_max_buff_duration: 0.0,
                         },
                         cooldown: 0.0,
+                        proc_chance: 1.0,
                     },
                 ],
             ),
@@ -7215,6 +8723,7 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                             | DamageFlags::DOT,
                     },
                     cooldown: 0.0,
+                    proc_chance: 1.0,
                 }],
             ),
         SetData::new("Perfected Bahsei's Mania", SetType::Normal)
@@ -7265,12 +8774,22 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                         threshold_pct: None,
                     },
                     cooldown: 0.0,
+                    proc_chance: 1.0,
                 }],
             ),
         SetData::new("Perfected Chaotic Whirlwind", SetType::Normal)
             .with_item_slots(vec!["Weapons(Dagger Sword Mace Axe)"]),
         SetData::new("Perfected Claw of Yolnahkriin", SetType::Normal)
             .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Perfected Claw of Yolnahkriin 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -7279,6 +8798,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusTrigger::Passive,
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
+            )
+            .with_threshold(
+                5,
+                vec![BonusData::new(
+                    "Perfected Claw of Yolnahkriin 5pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
             ),
         SetData::new("Perfected Concentrated Force", SetType::Normal)
             .with_item_slots(vec!["Weapons(Lightning Frost Flame)"]),
@@ -7332,6 +8860,7 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                         threshold_pct: Some(50.0),
                     },
                     cooldown: 0.0,
+                    proc_chance: 1.0,
                 }],
             ),
         SetData::new("Perfected Defensive Position", SetType::Normal)
@@ -7340,6 +8869,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
             .with_item_slots(vec!["Weapons(Greatsword Maul Battleaxe)"]),
         SetData::new("Perfected Dolorous Arena", SetType::Normal)
             .with_item_slots(vec!["Neck", "Ring", "Heavy(All)", "Shield", "Weapons(All)"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Perfected Dolorous Arena 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 5,
                 vec![BonusData::new(
@@ -7509,13 +9047,26 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
             "Neck",
             "Ring",
         ]),
-        SetData::new("Perfected Lucent Echoes", SetType::Normal).with_item_slots(vec![
-            "Neck",
-            "Ring",
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-        ]),
+        SetData::new("Perfected Lucent Echoes", SetType::Normal)
+            .with_item_slots(vec!["Neck", "Ring", "Heavy(All)", "Shield", "Weapons(All)"])
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Perfected Lucent Echoes 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                5,
+                vec![BonusData::new(
+                    "Perfected Lucent Echoes 5pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Perfected Mantle of Siroria", SetType::Normal)
             .with_item_slots(vec!["Shield", "Weapons(All)", "Light(All)", "Neck", "Ring"])
             .with_threshold(
@@ -7639,13 +9190,17 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             ),
-        SetData::new("Perfected Pearlescent Ward", SetType::Normal).with_item_slots(vec![
-            "Neck",
-            "Ring",
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-        ]),
+        SetData::new("Perfected Pearlescent Ward", SetType::Normal)
+            .with_item_slots(vec!["Neck", "Ring", "Heavy(All)", "Shield", "Weapons(All)"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Perfected Pearlescent Ward 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Perfected Piercing Spray", SetType::Normal)
             .with_item_slots(vec!["Weapons(Bow)"]),
         SetData::new("Perfected Pillager's Profit", SetType::Normal)
@@ -7825,13 +9380,17 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Critical Chance", BonusTarget::CriticalRating, 657.0),
                 )],
             ),
-        SetData::new("Perfected Test of Resolve", SetType::Normal).with_item_slots(vec![
-            "Neck",
-            "Ring",
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-        ]),
+        SetData::new("Perfected Test of Resolve", SetType::Normal)
+            .with_item_slots(vec!["Neck", "Ring", "Heavy(All)", "Shield", "Weapons(All)"])
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Perfected Test of Resolve 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Perfected Timeless Blessing", SetType::Normal)
             .with_item_slots(vec!["Weapons(Resto)"]),
         SetData::new("Perfected Tooth of Lokkestiiz", SetType::Normal)
@@ -7905,6 +9464,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusTrigger::Passive,
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Perfected Vrol's Command 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
             ),
         SetData::new("Perfected Whorl of the Depths", SetType::Normal)
             .with_item_slots(vec!["Neck", "Ring", "Light(All)", "Weapons(All)", "Shield"])
@@ -7956,6 +9524,7 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                         dot_flags: DamageFlags::FROST | DamageFlags::AOE | DamageFlags::DOT,
                     },
                     cooldown: 6.0,
+                    proc_chance: 1.0,
                 }],
             ),
         SetData::new("Perfected Xoryn's Masterpiece", SetType::Normal)
@@ -8173,15 +9742,47 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                             | DamageFlags::DOT,
                     },
                     cooldown: 10.0,
+                    proc_chance: 1.0,
                 }],
             ),
-        SetData::new("Plague Doctor", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Plague Doctor", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Plague Doctor 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Plague Doctor 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Plague Doctor 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                5,
+                vec![BonusData::new(
+                    "Plague Doctor 5pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 2804.0),
+                )],
+            ),
         SetData::new("Plague Slinger", SetType::Normal)
             .with_item_slots(vec![
                 "Medium(All)",
@@ -8203,6 +9804,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Plague Slinger 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -8314,6 +9924,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                 "Shield",
                 "Weapons(All)",
             ])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Powerful Assault 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 3,
                 vec![BonusData::new(
@@ -8371,14 +9990,24 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
             ),
-        SetData::new("Prophet's", SetType::Normal).with_item_slots(vec![
-            "Ring",
-            "Heavy(Chest)",
-            "Medium(Chest)",
-            "Light(Chest)",
-            "Weapons(Axe Resto Lightning Bow Maul Flame Greatsword Dagger)",
-            "Shield",
-        ]),
+        SetData::new("Prophet's", SetType::Normal)
+            .with_item_slots(vec![
+                "Ring",
+                "Heavy(Chest)",
+                "Medium(Chest)",
+                "Light(Chest)",
+                "Weapons(Axe Resto Lightning Bow Maul Flame Greatsword Dagger)",
+                "Shield",
+            ])
+            .with_threshold(
+                1,
+                vec![BonusData::new(
+                    "Prophet's 1pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Pyrebrand", SetType::Normal)
             .with_item_slots(vec![
                 "Heavy(All)",
@@ -8498,6 +10127,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
             ),
         SetData::new("Radiant Bastion", SetType::Normal)
             .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Radiant Bastion 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 3,
                 vec![BonusData::new(
@@ -8510,6 +10148,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                         129.0,
                     ),
                 )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Radiant Bastion 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
             ),
         SetData::new("Rage of the Ursauk", SetType::Normal)
             .with_item_slots(vec![
@@ -8613,6 +10260,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
             ),
         SetData::new("Rattlecage", SetType::Normal)
             .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Rattlecage 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 3,
                 vec![BonusData::new(
@@ -8663,6 +10319,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Critical Chance", BonusTarget::CriticalRating, 657.0),
                 )],
             )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Ravager 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -8686,6 +10351,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusTrigger::Passive,
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Reactive Armor 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
             ),
         SetData::new("Reawakened Hierophant", SetType::Normal)
             .with_item_slots(vec![
@@ -8814,6 +10488,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                 "Heavy(All)",
                 "Weapons(All)",
             ])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Redistributor 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 3,
                 vec![BonusData::new(
@@ -8835,37 +10518,74 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
         SetData::new("Reflected Fury", SetType::Normal)
             .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
             .with_threshold(
-                4,
+                2,
+                vec![BonusData::new(
+                    "Reflected Fury 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Reflected Fury 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Reflected Fury 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
+                )],
+            ),
+        SetData::new("Relics of the Physician, Ansur", SetType::Normal)
+            .with_item_slots(vec!["Ring", "Weapons(Resto)"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Relics of the Physician, Ansur 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 1096.0),
+                )],
+            ),
+        SetData::new("Relics of the Rebellion", SetType::Normal)
+            .with_item_slots(vec!["Neck", "Weapons(Mace)", "Shield"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Relics of the Rebellion 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
+        SetData::new("Renald's Resolve", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
                 vec![BonusData::new(
-                    "Reflected Fury 4pc",
+                    "Renald's Resolve 2pc",
                     BonusSource::GearSet,
                     BonusTrigger::Passive,
-                    BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
                 )],
-            ),
-        SetData::new("Relics of the Physician, Ansur", SetType::Normal)
-            .with_item_slots(vec!["Ring", "Weapons(Resto)"])
+            )
             .with_threshold(
-                2,
+                4,
                 vec![BonusData::new(
-                    "Relics of the Physician, Ansur 2pc",
+                    "Renald's Resolve 4pc",
                     BonusSource::GearSet,
                     BonusTrigger::Passive,
-                    BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 1096.0),
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
                 )],
             ),
-        SetData::new("Relics of the Rebellion", SetType::Normal).with_item_slots(vec![
-            "Neck",
-            "Weapons(Mace)",
-            "Shield",
-        ]),
-        SetData::new("Renald's Resolve", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
         SetData::new("Ritemaster's Bond", SetType::Normal)
             .with_item_slots(vec!["Light(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
             .with_threshold(
@@ -8959,6 +10679,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
             ),
         SetData::new("Robes of the Hist", SetType::Normal)
             .with_item_slots(vec!["Light(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Robes of the Hist 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 3,
                 vec![BonusData::new(
@@ -8991,6 +10720,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusTrigger::Passive,
                     BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 1096.0),
                 )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Robes of the Withered Hand 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
             ),
         SetData::new("Runecarver's Blaze", SetType::Normal)
             .with_item_slots(vec!["Light(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
@@ -9032,6 +10770,24 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                         1487.0,
                     ),
                 )],
+            )
+            .with_proc_effects(
+                5,
+                vec![SetProcEffect {
+                    name: "Runecarver's Blaze".to_string(),
+                    trigger: SetProcTrigger::OnDotTick,
+                    action: SetProcAction::DamageProc {
+                        hit_damage: 69.7,
+                        hit_flags: DamageFlags::FLAME
+                            | DamageFlags::SINGLE_TARGET
+                            | DamageFlags::DOT,
+                        dot_total_damage: 0.0,
+                        dot_duration: 0.0,
+                        dot_flags: DamageFlags::empty(),
+                    },
+                    cooldown: 0.0,
+                    proc_chance: 1.0,
+                }],
             ),
         SetData::new("Rush of Agony", SetType::Normal)
             .with_item_slots(vec![
@@ -9095,6 +10851,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
             ),
         SetData::new("Sanctuary", SetType::Normal)
             .with_item_slots(vec!["Light(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Sanctuary 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -9256,6 +11021,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                 "Neck",
                 "Ring",
             ])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Seeker Synthesis 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 3,
                 vec![BonusData::new(
@@ -9285,6 +11059,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
             )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Senchal Defender 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -9320,15 +11103,25 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
             ),
-        SetData::new("Senche-raht's Grit", SetType::Normal).with_item_slots(vec![
-            "Medium(All)",
-            "Light(All)",
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Senche-raht's Grit", SetType::Normal)
+            .with_item_slots(vec![
+                "Medium(All)",
+                "Light(All)",
+                "Heavy(All)",
+                "Shield",
+                "Weapons(All)",
+                "Neck",
+                "Ring",
+            ])
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Senche-raht's Grit 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Sentry", SetType::Normal)
             .with_item_slots(vec![
                 "Medium(All)",
@@ -9383,6 +11176,24 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
             ),
         SetData::new("Sergeant's Mail", SetType::Normal)
             .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Sergeant's Mail 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Sergeant's Mail 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Health Recovery", BonusTarget::HealthRecovery, 129.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -9415,6 +11226,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
             )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Serpent's Disdain 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -9439,6 +11259,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Seventh Legion Brute 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Health Recovery", BonusTarget::HealthRecovery, 129.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -9590,17 +11419,45 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Critical Chance", BonusTarget::CriticalRating, 657.0),
                 )],
             ),
-        SetData::new("Shalidor's Curse", SetType::Normal).with_item_slots(vec![
-            "Neck",
-            "Ring",
-            "Weapons(All)",
-            "Shield",
-            "Medium(All)",
-            "Light(All)",
-            "Heavy(All)",
-        ]),
+        SetData::new("Shalidor's Curse", SetType::Normal)
+            .with_item_slots(vec![
+                "Neck",
+                "Ring",
+                "Weapons(All)",
+                "Shield",
+                "Medium(All)",
+                "Light(All)",
+                "Heavy(All)",
+            ])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Shalidor's Curse 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Shalidor's Curse 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Shalk Exoskeleton", SetType::Normal)
             .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Shalk Exoskeleton 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 3,
                 vec![BonusData::new(
@@ -9655,6 +11512,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
             )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Shared Burden 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -9790,6 +11656,23 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                         129.0,
                     ),
                 )],
+            )
+            .with_proc_effects(
+                5,
+                vec![SetProcEffect {
+                    name: "Sheer Venom".to_string(),
+                    trigger: SetProcTrigger::OnDirectDamage,
+                    action: SetProcAction::ExecuteScalingDamage {
+                        hit_damage: 0.0,
+                        dot_total_damage: 948.0,
+                        dot_duration: 6.0,
+                        flags: DamageFlags::POISON | DamageFlags::SINGLE_TARGET | DamageFlags::DOT,
+                        max_bonus: 1.0,
+                        threshold_pct: 1.0,
+                    },
+                    cooldown: 6.0,
+                    proc_chance: 1.0,
+                }],
             ),
         SetData::new("Shell Splitter", SetType::Normal)
             .with_item_slots(vec![
@@ -9868,13 +11751,23 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             ),
-        SetData::new("Shield of the Valiant", SetType::Normal).with_item_slots(vec![
-            "Medium(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Shield of the Valiant", SetType::Normal)
+            .with_item_slots(vec![
+                "Medium(All)",
+                "Shield",
+                "Weapons(All)",
+                "Neck",
+                "Ring",
+            ])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Shield of the Valiant 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Shroud of the Lich", SetType::Normal)
             .with_item_slots(vec!["Light(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
             .with_threshold(
@@ -9896,6 +11789,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusTrigger::Passive,
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
+            )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Siegemaster's Focus 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
             ),
         SetData::new("Silks of the Sun", SetType::Normal)
             .with_item_slots(vec!["Weapons(All)", "Light(All)", "Shield", "Neck", "Ring"])
@@ -9941,6 +11843,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
             )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Silver Rose Vigil 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -10183,15 +12094,25 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             ),
-        SetData::new("Song of Lamae", SetType::Normal).with_item_slots(vec![
-            "Neck",
-            "Ring",
-            "Shield",
-            "Medium(All)",
-            "Light(All)",
-            "Heavy(All)",
-            "Weapons(All)",
-        ]),
+        SetData::new("Song of Lamae", SetType::Normal)
+            .with_item_slots(vec![
+                "Neck",
+                "Ring",
+                "Shield",
+                "Medium(All)",
+                "Light(All)",
+                "Heavy(All)",
+                "Weapons(All)",
+            ])
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Song of Lamae 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Soulcleaver", SetType::Normal)
             .with_item_slots(vec![
                 "Heavy(All)",
@@ -10333,6 +12254,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusTrigger::Passive,
                     BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 1096.0),
                 )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Spectre's Eye 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
             ),
         SetData::new("Spell Parasite", SetType::Normal)
             .with_item_slots(vec![
@@ -10682,18 +12612,32 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             ),
-        SetData::new("Squall of Retribution", SetType::Normal).with_item_slots(vec![
-            "Light(Shoulder Head)",
-            "Medium(Shoulder Head)",
-            "Heavy(Shoulder Head)",
-        ]),
-        SetData::new("Steadfast Hero", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Squall of Retribution", SetType::Normal)
+            .with_item_slots(vec![
+                "Light(Shoulder Head)",
+                "Medium(Shoulder Head)",
+                "Heavy(Shoulder Head)",
+            ])
+            .with_threshold(
+                1,
+                vec![BonusData::new(
+                    "Squall of Retribution 1pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
+        SetData::new("Steadfast Hero", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Steadfast Hero 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Steadfast's Mettle", SetType::Normal)
             .with_item_slots(vec![
                 "Weapons(All)",
@@ -10788,13 +12732,17 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
             ),
-        SetData::new("Storm Knight's Plate", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Storm Knight's Plate", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Storm Knight's Plate 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Storm Master", SetType::Normal)
             .with_item_slots(vec![
                 "Medium(All)",
@@ -11074,6 +13022,24 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusTrigger::Passive,
                     BonusValue::new("Critical Chance", BonusTarget::CriticalRating, 657.0),
                 )],
+            )
+            .with_proc_effects(
+                5,
+                vec![SetProcEffect {
+                    name: "Sunderflame".to_string(),
+                    trigger: SetProcTrigger::OnHeavyAttack,
+                    action: SetProcAction::DamageProc {
+                        hit_damage: 0.0,
+                        hit_flags: DamageFlags::empty(),
+                        dot_total_damage: 1925.0,
+                        dot_duration: 10.0,
+                        dot_flags: DamageFlags::FLAME
+                            | DamageFlags::SINGLE_TARGET
+                            | DamageFlags::DOT,
+                    },
+                    cooldown: 10.0,
+                    proc_chance: 1.0,
+                }],
             ),
         SetData::new("Swamp Raider", SetType::Normal)
             .with_item_slots(vec![
@@ -11264,6 +13230,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
             )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Systres' Scowl 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -11283,6 +13258,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                 "Neck",
                 "Ring",
             ])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Syvarra's Scales 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 3,
                 vec![BonusData::new(
@@ -11368,16 +13352,42 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusTrigger::Passive,
                     BonusValue::new("Critical Chance", BonusTarget::CriticalRating, 657.0),
                 )],
+            )
+            .with_proc_effects(
+                5,
+                vec![SetProcEffect {
+                    name: "Tarnished Nightmare Shards".to_string(),
+                    trigger: SetProcTrigger::OnCriticalHit,
+                    action: SetProcAction::DamageProc {
+                        hit_damage: 1129.0,
+                        hit_flags: DamageFlags::PHYSICAL | DamageFlags::AOE | DamageFlags::DIRECT,
+                        dot_total_damage: 0.0,
+                        dot_duration: 0.0,
+                        dot_flags: DamageFlags::empty(),
+                    },
+                    cooldown: 8.0,
+                    proc_chance: 1.0,
+                }],
+            ),
+        SetData::new("Tava's Favor", SetType::Normal)
+            .with_item_slots(vec![
+                "Neck",
+                "Ring",
+                "Weapons(All)",
+                "Shield",
+                "Light(All)",
+                "Medium(All)",
+                "Heavy(All)",
+            ])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Tava's Favor 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
             ),
-        SetData::new("Tava's Favor", SetType::Normal).with_item_slots(vec![
-            "Neck",
-            "Ring",
-            "Weapons(All)",
-            "Shield",
-            "Light(All)",
-            "Medium(All)",
-            "Heavy(All)",
-        ]),
         SetData::new("Telvanni Efficiency", SetType::Normal)
             .with_item_slots(vec![
                 "Medium(All)",
@@ -11397,6 +13407,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
             )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Telvanni Efficiency 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -11417,6 +13436,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
             )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Telvanni Enforcer 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -11426,13 +13454,17 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 1096.0),
                 )],
             ),
-        SetData::new("Test of Resolve", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Test of Resolve", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Test of Resolve 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Tharriker's Strike", SetType::Normal)
             .with_item_slots(vec![
                 "Medium(All)",
@@ -11481,6 +13513,26 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                         129.0,
                     ),
                 )],
+            )
+            .with_proc_effects(
+                5,
+                vec![SetProcEffect {
+                    name: "Tharriker's Strike".to_string(),
+                    trigger: SetProcTrigger::OnHeavyAttack,
+                    action: SetProcAction::StackingBuff {
+                        per_stack_target: None,
+                        per_stack_value: 0.0,
+                        max_stacks: 1,
+                        stack_duration: 4.0,
+                        stack_cooldown: 1.0,
+                        at_max_buff_name: "Major Berserk".to_string(),
+                        at_max_buff_target: BonusTarget::Damage,
+                        at_max_buff_value: 0.10,
+                        at_max_buff_duration: 4.0,
+                    },
+                    cooldown: 0.0,
+                    proc_chance: 1.0,
+                }],
             ),
         SetData::new("The Arch-Mage", SetType::Normal)
             .with_item_slots(vec!["Light(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
@@ -11541,13 +13593,26 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             ),
-        SetData::new("The Juggernaut", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("The Juggernaut", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "The Juggernaut 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "The Juggernaut 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("The Morag Tong", SetType::Normal)
             .with_item_slots(vec![
                 "Medium(All)",
@@ -11612,6 +13677,24 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                 "Medium(Hand Leg)",
                 "Light(Hand Leg)",
             ])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Thews of the Harbinger 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Thews of the Harbinger 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -11961,22 +14044,36 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             ),
-        SetData::new("Tormentor", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
-        SetData::new("Torug's Pact", SetType::Normal).with_item_slots(vec![
-            "Neck",
-            "Ring",
-            "Heavy(All)",
-            "Weapons(All)",
-            "Shield",
-            "Medium(All)",
-            "Light(All)",
-        ]),
+        SetData::new("Tormentor", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Tormentor 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
+        SetData::new("Torug's Pact", SetType::Normal)
+            .with_item_slots(vec![
+                "Neck",
+                "Ring",
+                "Heavy(All)",
+                "Weapons(All)",
+                "Shield",
+                "Medium(All)",
+                "Light(All)",
+            ])
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Torug's Pact 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Tracker's Lash", SetType::Normal)
             .with_item_slots(vec!["Light(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
             .with_threshold(
@@ -12082,15 +14179,25 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
             ),
         SetData::new("Treasures of the Earthforge", SetType::Normal)
             .with_item_slots(vec!["Ring", "Weapons(Maul)"]),
-        SetData::new("Trial by Fire", SetType::Normal).with_item_slots(vec![
-            "Neck",
-            "Ring",
-            "Weapons(All)",
-            "Shield",
-            "Light(All)",
-            "Medium(All)",
-            "Heavy(All)",
-        ]),
+        SetData::new("Trial by Fire", SetType::Normal)
+            .with_item_slots(vec![
+                "Neck",
+                "Ring",
+                "Weapons(All)",
+                "Shield",
+                "Light(All)",
+                "Medium(All)",
+                "Heavy(All)",
+            ])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Trial by Fire 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Trinimac's Valor", SetType::Normal)
             .with_item_slots(vec!["Shield", "Light(All)", "Weapons(All)", "Neck", "Ring"])
             .with_threshold(
@@ -12137,6 +14244,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
             ),
         SetData::new("Turning Tide", SetType::Normal)
             .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Turning Tide 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 3,
                 vec![BonusData::new(
@@ -12145,6 +14261,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusTrigger::Passive,
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Turning Tide 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
             ),
         SetData::new("Twice-Born Star", SetType::Normal)
             .with_item_slots(vec![
@@ -12156,6 +14281,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                 "Medium(All)",
                 "Heavy(All)",
             ])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Twice-Born Star 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 3,
                 vec![BonusData::new(
@@ -12355,7 +14489,7 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                 5,
                 vec![SetProcEffect {
                     name: "Tzogvin's Warband".to_string(),
-                    trigger: SetProcTrigger::OnDirectDamage,
+                    trigger: SetProcTrigger::OnCriticalHit,
                     action: SetProcAction::StackingBuff {
                         per_stack_target: Some(BonusTarget::CriticalRating),
                         per_stack_value: 177.0,
@@ -12368,15 +14502,20 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                         at_max_buff_duration: 5.0,
                     },
                     cooldown: 0.0,
+                    proc_chance: 1.0,
                 }],
             ),
-        SetData::new("Ulfnor's Favor", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Ulfnor's Favor", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Ulfnor's Favor 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Umbral Edge", SetType::Normal)
             .with_item_slots(vec![
                 "Heavy(All)",
@@ -12387,6 +14526,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                 "Neck",
                 "Ring",
             ])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Umbral Edge 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -12428,15 +14576,25 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             ),
-        SetData::new("Undaunted Bastion", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-            "Medium(All)",
-            "Light(All)",
-        ]),
+        SetData::new("Undaunted Bastion", SetType::Normal)
+            .with_item_slots(vec![
+                "Heavy(All)",
+                "Shield",
+                "Weapons(All)",
+                "Neck",
+                "Ring",
+                "Medium(All)",
+                "Light(All)",
+            ])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Undaunted Bastion 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Undaunted Infiltrator", SetType::Normal)
             .with_item_slots(vec![
                 "Medium(All)",
@@ -12544,13 +14702,26 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             ),
-        SetData::new("Unflinching Ultimate", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Unflinching Ultimate", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Unflinching Ultimate 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Unflinching Ultimate 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Unleashed Ritualist", SetType::Normal)
             .with_item_slots(vec!["Light(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
             .with_threshold(
@@ -12629,6 +14800,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
             ),
         SetData::new("Vampire Cloak", SetType::Normal)
             .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Vampire Cloak 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 3,
                 vec![BonusData::new(
@@ -12642,6 +14822,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Vampire Cloak 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 5,
                 vec![BonusData::new(
@@ -12783,13 +14972,26 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             ),
-        SetData::new("Vanguard's Challenge", SetType::Normal).with_item_slots(vec![
-            "Heavy(All)",
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Vanguard's Challenge", SetType::Normal)
+            .with_item_slots(vec!["Heavy(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Vanguard's Challenge 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Vanguard's Challenge 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Varen's Legacy", SetType::Normal)
             .with_item_slots(vec![
                 "Neck",
@@ -12800,6 +15002,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                 "Shield",
                 "Weapons(All)",
             ])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Varen's Legacy 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 3,
                 vec![BonusData::new(
@@ -12828,6 +15039,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
             )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Vastarie's Tutelage 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -12837,12 +15057,17 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 1096.0),
                 )],
             ),
-        SetData::new("Vengeance Leech", SetType::Normal).with_item_slots(vec![
-            "Shield",
-            "Weapons(All)",
-            "Neck",
-            "Ring",
-        ]),
+        SetData::new("Vengeance Leech", SetType::Normal)
+            .with_item_slots(vec!["Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Vengeance Leech 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            ),
         SetData::new("Venomous Smite", SetType::Normal)
             .with_item_slots(vec![
                 "Medium(All)",
@@ -13087,9 +15312,43 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusTrigger::Passive,
                     BonusValue::new("Max Magicka", BonusTarget::MaxMagickaFlat, 1096.0),
                 )],
+            )
+            .with_proc_effects(
+                5,
+                vec![
+                    SetProcEffect {
+                        name: "Vivec's Duality Magicka".to_string(),
+                        trigger: SetProcTrigger::OnBarSwap,
+                        action: SetProcAction::RestoreResource {
+                            resource: Resource::Magicka,
+                            amount: 6636.0,
+                        },
+                        cooldown: 15.0,
+                        proc_chance: 1.0,
+                    },
+                    SetProcEffect {
+                        name: "Vivec's Duality Stamina".to_string(),
+                        trigger: SetProcTrigger::OnBarSwap,
+                        action: SetProcAction::RestoreResource {
+                            resource: Resource::Stamina,
+                            amount: 6636.0,
+                        },
+                        cooldown: 15.0,
+                        proc_chance: 1.0,
+                    },
+                ],
             ),
         SetData::new("Voidcaller", SetType::Normal)
             .with_item_slots(vec!["Light(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Voidcaller 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 3,
                 vec![BonusData::new(
@@ -13122,6 +15381,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusTrigger::Passive,
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Vrol's Command 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
             ),
         SetData::new("Vykand's Soulfury", SetType::Normal)
             .with_item_slots(vec![
@@ -13252,6 +15520,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
             ),
         SetData::new("Warrior's Fury", SetType::Normal)
             .with_item_slots(vec!["Weapons(All)", "Shield", "Heavy(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Warrior's Fury 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 3,
                 vec![BonusData::new(
@@ -13274,13 +15551,26 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             ),
-        SetData::new("Warrior-Poet", SetType::Normal).with_item_slots(vec![
-            "Neck",
-            "Heavy(All)",
-            "Weapons(All)",
-            "Shield",
-            "Ring",
-        ]),
+        SetData::new("Warrior-Poet", SetType::Normal)
+            .with_item_slots(vec!["Neck", "Heavy(All)", "Weapons(All)", "Shield", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Warrior-Poet 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                5,
+                vec![BonusData::new(
+                    "Warrior-Poet 5pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1600.0),
+                )],
+            ),
         SetData::new("Way of Air", SetType::Normal).with_item_slots(vec![
             "Medium(All)",
             "Shield",
@@ -13303,6 +15593,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Way of Fire 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -13359,6 +15658,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                 "Heavy(All)",
                 "Weapons(All)",
             ])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Way of the Arena 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 3,
                 vec![BonusData::new(
@@ -13390,15 +15698,34 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     ),
                 )],
             ),
-        SetData::new("Whitestrake's Retribution", SetType::Normal).with_item_slots(vec![
-            "Neck",
-            "Ring",
-            "Shield",
-            "Medium(All)",
-            "Light(All)",
-            "Heavy(All)",
-            "Weapons(All)",
-        ]),
+        SetData::new("Whitestrake's Retribution", SetType::Normal)
+            .with_item_slots(vec![
+                "Neck",
+                "Ring",
+                "Shield",
+                "Medium(All)",
+                "Light(All)",
+                "Heavy(All)",
+                "Weapons(All)",
+            ])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Whitestrake's Retribution 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Whitestrake's Retribution 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Health Recovery", BonusTarget::HealthRecovery, 129.0),
+                )],
+            ),
         SetData::new("Whorl of the Depths", SetType::Normal)
             .with_item_slots(vec!["Light(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
             .with_threshold(
@@ -13440,6 +15767,7 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                         dot_flags: DamageFlags::FROST | DamageFlags::AOE | DamageFlags::DOT,
                     },
                     cooldown: 6.0,
+                    proc_chance: 1.0,
                 }],
             ),
         SetData::new("Widowmaker", SetType::Normal)
@@ -13503,15 +15831,25 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
             ),
-        SetData::new("Willow's Path", SetType::Normal).with_item_slots(vec![
-            "Neck",
-            "Ring",
-            "Shield",
-            "Medium(All)",
-            "Light(All)",
-            "Heavy(All)",
-            "Weapons(All)",
-        ]),
+        SetData::new("Willow's Path", SetType::Normal)
+            .with_item_slots(vec![
+                "Neck",
+                "Ring",
+                "Shield",
+                "Medium(All)",
+                "Light(All)",
+                "Heavy(All)",
+                "Weapons(All)",
+            ])
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Willow's Path 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Health Recovery", BonusTarget::HealthRecovery, 129.0),
+                )],
+            ),
         SetData::new("Willpower", SetType::Normal)
             .with_item_slots(vec!["Neck", "Ring", "Shield", "Weapons(All)"])
             .with_threshold(
@@ -13689,9 +16027,27 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                         129.0,
                     ),
                 )],
+            )
+            .with_threshold(
+                4,
+                vec![BonusData::new(
+                    "Witchman Armor 4pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
             ),
         SetData::new("Wizard's Riposte", SetType::Normal)
             .with_item_slots(vec!["Light(All)", "Shield", "Weapons(All)", "Neck", "Ring"])
+            .with_threshold(
+                2,
+                vec![BonusData::new(
+                    "Wizard's Riposte 2pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 3,
                 vec![BonusData::new(
@@ -13822,6 +16178,15 @@ pub static NORMAL_SETS: Lazy<Vec<SetData>> = Lazy::new(|| {
                     BonusValue::new("Max Stamina", BonusTarget::MaxStaminaFlat, 1096.0),
                 )],
             )
+            .with_threshold(
+                3,
+                vec![BonusData::new(
+                    "Xanmeer Genesis 3pc",
+                    BonusSource::GearSet,
+                    BonusTrigger::Passive,
+                    BonusValue::new("Max Health", BonusTarget::MaxHealthFlat, 1206.0),
+                )],
+            )
             .with_threshold(
                 4,
                 vec![BonusData::new(
@@ -3,7 +3,8 @@
 use crate::data::bonuses::{MAJOR_BRUTALITY, MAJOR_PROPHECY, MAJOR_SAVAGERY, MAJOR_SORCERY};
 use crate::domain::{
     BonusData, BonusSource, BonusTarget, BonusTrigger, BonusValue, SkillTree, DamageFlags,
-    DotDamage, HitDamage, Resource, SkillDamage, SkillData, SkillLineName,
+    DotDamage, HitDamage, Resource, SkillDamage, SkillData, SkillLineName, StackGain,
+    StackMechanic,
 };
 use once_cell::sync::Lazy;
 
@@ -180,6 +181,10 @@ pub static ARCANIST_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
             0.905625,
         )]))
         .with_spammable()
+        .with_stacks(vec![StackMechanic::new("Crux")
+            .with_gain(StackGain::Cast, 1)
+            .with_max_stacks(3)
+            .with_decay(30.0)])
         .with_bonuses(vec![BonusData::new(
             "Abyssal Ink",
             BonusSource::Skill,
@@ -212,7 +217,11 @@ pub static ARCANIST_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
                 0.429701,
             ),
         ]))
-        .with_spammable(),
+        .with_spammable()
+        .with_stacks(vec![StackMechanic::new("Crux")
+            .with_gain(StackGain::Cast, 1)
+            .with_max_stacks(3)
+            .with_decay(30.0)]),
         SkillData::new(
             "Exhausting Fatecarver",
             "Fatecarver",
@@ -226,7 +235,11 @@ pub static ARCANIST_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
             0.039099,
             0.41054,
         )]))
-        .with_channel_time(4.0),
+        .with_channel_time(4.0)
+        .with_stacks(vec![StackMechanic::new("Crux")
+            .consuming()
+            .with_damage_per_stack(0.33)
+            .with_duration_per_stack(0.3)]),
         SkillData::new(
             "Fatecarver",
             "Fatecarver",
@@ -240,7 +253,10 @@ pub static ARCANIST_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
             0.03785,
             0.397425,
         )]))
-        .with_channel_time(4.0),
+        .with_channel_time(4.0)
+        .with_stacks(vec![StackMechanic::new("Crux")
+            .consuming()
+            .with_damage_per_stack(0.33)]),
         SkillData::new(
             "Fulminating Rune",
             "The Imperfect Ring",
@@ -279,7 +295,10 @@ pub static ARCANIST_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
             0.039099,
             0.41054,
         )]))
-        .with_channel_time(4.0),
+        .with_channel_time(4.0)
+        .with_stacks(vec![StackMechanic::new("Crux")
+            .consuming()
+            .with_damage_per_stack(0.33)]),
         SkillData::new(
             "Recuperative Treatise",
             "Tome-Bearer's Inspiration",
@@ -330,7 +349,11 @@ pub static ARCANIST_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
             0.03,
             0.315,
         )]))
-        .with_spammable(),
+        .with_spammable()
+        .with_stacks(vec![StackMechanic::new("Crux")
+            .with_gain(StackGain::Cast, 1)
+            .with_max_stacks(3)
+            .with_decay(30.0)]),
         SkillData::new(
             "Tentacular Dread",
             "Abyssal Impact",
@@ -345,6 +368,9 @@ pub static ARCANIST_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
             0.905625,
         )]))
         .with_spammable()
+        .with_stacks(vec![StackMechanic::new("Crux")
+            .consuming()
+            .with_damage_per_stack(0.33)])
         .with_bonuses(vec![BonusData::new(
             "Abyssal Ink",
             BonusSource::Skill,
@@ -383,14 +409,12 @@ pub static ARCANIST_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
             Resource::Ultimate,
         )
         .with_skill_id(40189867)
-        .with_damage(SkillDamage::new().with_dots(vec![DotDamage::new(
-            10.0,
-            DamageFlags::magic_aoe(),
-            0.055222,
-            0.57983,
-        )
-        .with_interval(1.0)
-        .with_increase_per_tick(0.05)])),
+        .with_damage(SkillDamage::new().with_dots(vec![
+            DotDamage::new(10.0, DamageFlags::magic_aoe(), 0.055222, 0.57983).with_interval(1.0),
+        ]))
+        .with_stacks(vec![StackMechanic::new("The Languid Eye")
+            .with_gain(StackGain::DotTick, 1)
+            .with_damage_per_stack(0.05)]),
         SkillData::new(
             "The Tide King's Gaze",
             "The Unblinking Eye",
@@ -446,7 +470,11 @@ pub static ARCANIST_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
             0.03099,
             0.325395,
         )]))
-        .with_spammable(),
+        .with_spammable()
+        .with_stacks(vec![StackMechanic::new("Crux")
+            .with_gain(StackGain::Cast, 1)
+            .with_max_stacks(3)
+            .with_decay(30.0)]),
         SkillData::new(
             "Cruxweaver Armor",
             "Fatewoven Armor",
@@ -502,7 +530,8 @@ pub static ARCANIST_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
             SkillLineName::SoldierOfApocrypha,
             Resource::Magicka,
         )
-        .with_skill_id(40183241),
+        .with_skill_id(40183241)
+        .with_stacks(vec![StackMechanic::new("Crux").consuming()]),
         SkillData::new(
             "Rune of Eldritch Horror",
             "Rune of Eldritch Horror",
@@ -550,7 +579,8 @@ pub static ARCANIST_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
             SkillLineName::SoldierOfApocrypha,
             Resource::Magicka,
         )
-        .with_skill_id(40185894),
+        .with_skill_id(40185894)
+        .with_stacks(vec![StackMechanic::new("Crux").consuming()]),
         SkillData::new(
             "Runic Defense",
             "Runic Defense",
@@ -572,7 +602,11 @@ pub static ARCANIST_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
             0.05165,
             0.542325,
         )]))
-        .with_spammable(),
+        .with_spammable()
+        .with_stacks(vec![StackMechanic::new("Crux")
+            .with_gain(StackGain::Cast, 1)
+            .with_max_stacks(3)
+            .with_decay(30.0)]),
         SkillData::new(
             "Runic Jolt",
             "Runic Jolt",
@@ -586,7 +620,11 @@ pub static ARCANIST_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
             0.05,
             0.525,
         )]))
-        .with_spammable(),
+        .with_spammable()
+        .with_stacks(vec![StackMechanic::new("Crux")
+            .with_gain(StackGain::Cast, 1)
+            .with_max_stacks(3)
+            .with_decay(30.0)]),
         SkillData::new(
             "Runic Sunder",
             "Runic Jolt",
@@ -601,6 +639,10 @@ pub static ARCANIST_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
             0.542325,
         )]))
         .with_spammable()
+        .with_stacks(vec![StackMechanic::new("Crux")
+            .with_gain(StackGain::Cast, 1)
+            .with_max_stacks(3)
+            .with_decay(30.0)])
         .with_bonuses(vec![BonusData::new(
             "Runic Sunder Debuff",
             BonusSource::Skill,
@@ -627,7 +669,8 @@ pub static ARCANIST_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
             SkillLineName::SoldierOfApocrypha,
             Resource::Magicka,
         )
-        .with_skill_id(40185901),
+        .with_skill_id(40185901)
+        .with_stacks(vec![StackMechanic::new("Crux").consuming()]),
         SkillData::new(
             "Unbreakable Fate",
             "Fatewoven Armor",
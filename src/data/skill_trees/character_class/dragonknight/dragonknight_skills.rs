@@ -2,7 +2,9 @@
 // Manual overrides (bonuses, execute, etc.) stored in generator script.
 use crate::data::bonuses::{EMPOWER, MAJOR_BREACH, MAJOR_BRUTALITY, MAJOR_SORCERY};
 use crate::domain::{
-    SkillTree, DamageFlags, DotDamage, HitDamage, Resource, SkillDamage, SkillData, SkillLineName,
+    BonusData, BonusSource, BonusTarget, BonusTrigger, BonusValue, SkillTree, DamageFlags,
+    DotDamage, HitDamage, Resource, SkillDamage, SkillData, SkillLineName, StackGain,
+    StackMechanic,
 };
 use once_cell::sync::Lazy;
 
@@ -220,7 +222,23 @@ pub static DRAGONKNIGHT_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
             0.1033,
             1.08465,
         )]))
-        .with_spammable(),
+        .with_spammable()
+        .with_stacks(vec![StackMechanic::new("Seething Fury")
+            .with_gain(StackGain::SkillLineCast(SkillLineName::ArdentFlame), 1)
+            .with_max_stacks(3)
+            .with_decay(15.0)
+            .consuming()
+            .with_damage_per_stack(0.2)
+            .with_bonuses_per_stack(vec![BonusData::new(
+                "Seething Fury",
+                BonusSource::Skill,
+                BonusTrigger::Passive,
+                BonusValue::new(
+                    "Seething Fury",
+                    BonusTarget::WeaponAndSpellDamageFlat,
+                    100.0,
+                ),
+            )])]),
         SkillData::new(
             "Noxious Breath",
             "Fiery Breath",
@@ -348,9 +366,11 @@ pub static DRAGONKNIGHT_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
                     0.015495,
                     0.162697,
                 )
-                .with_interval(2.0)
-                .with_increase_per_tick(0.12)]),
-        ),
+                .with_interval(2.0)]),
+        )
+        .with_stacks(vec![StackMechanic::new("Venomous Claw")
+            .with_gain(StackGain::DotTick, 1)
+            .with_damage_per_stack(0.12)]),
         SkillData::new(
             "Burning Talons",
             "Dark Talons",
@@ -5,7 +5,8 @@ use crate::data::bonuses::{
 };
 use crate::domain::{
     BonusData, BonusSource, BonusTarget, BonusTrigger, BonusValue, SkillTree, DamageFlags,
-    DotDamage, HitDamage, Resource, SkillDamage, SkillData, SkillLineName,
+    DotDamage, DotSnapshot, HitDamage, Resource, SkillDamage, SkillData, SkillLineName, StackGain,
+    StackMechanic,
 };
 use once_cell::sync::Lazy;
 
@@ -226,6 +227,8 @@ pub static NECROMANCER_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
                 )
                 .with_interval(1.0)]),
         )
+        .with_stacks(vec![StackMechanic::new("Corpse").consuming()])
+        .with_dot_snapshot(DotSnapshot::empty())
         .with_bonuses(vec![BonusData::new(
             "Avid Boneyard",
             BonusSource::Skill,
@@ -247,7 +250,10 @@ pub static NECROMANCER_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
         .with_skill_id(40117690)
         .with_damage(SkillDamage::new().with_hits(vec![
             HitDamage::new(DamageFlags::disease_aoe(), 0.12, 1.26).with_delay(2.5),
-        ])),
+        ]))
+        .with_stacks(vec![StackMechanic::new("Corpse")
+            .with_gain(StackGain::Cast, 1)
+            .with_decay(20.0)]),
         SkillData::new(
             "Boneyard",
             "Boneyard",
@@ -271,6 +277,8 @@ pub static NECROMANCER_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
                 )
                 .with_interval(1.0)]),
         )
+        .with_stacks(vec![StackMechanic::new("Corpse").consuming()])
+        .with_dot_snapshot(DotSnapshot::empty())
         .with_bonuses(vec![BonusData::new(
             "Boneyard",
             BonusSource::Skill,
@@ -487,6 +495,9 @@ pub static NECROMANCER_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
             0.02066,
             0.21693,
         )]))
+        .with_stacks(vec![StackMechanic::new("Corpse")
+            .with_gain(StackGain::Cast, 1)
+            .with_decay(20.0)])
         .with_bonuses(vec![BonusData::new(
             "Skeletal Arcanist",
             BonusSource::Skill,
@@ -512,8 +523,15 @@ pub static NECROMANCER_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
             0.02066,
             0.21693,
         )
-        .with_interval(2.0)
-        .with_increase_per_tick(0.15)]))
+        .with_interval(2.0)]))
+        .with_stacks(vec![
+            StackMechanic::new("Skeletal Archer")
+                .with_gain(StackGain::DotTick, 1)
+                .with_damage_per_stack(0.15),
+            StackMechanic::new("Corpse")
+                .with_gain(StackGain::Cast, 1)
+                .with_decay(20.0),
+        ])
         .with_bonuses(vec![BonusData::new(
             "Skeletal Archer",
             BonusSource::Skill,
@@ -538,6 +556,9 @@ pub static NECROMANCER_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
             0.02,
             0.21,
         )]))
+        .with_stacks(vec![StackMechanic::new("Corpse")
+            .with_gain(StackGain::Cast, 1)
+            .with_decay(20.0)])
         .with_bonuses(vec![BonusData::new(
             "Skeletal Mage",
             BonusSource::Skill,
@@ -568,6 +589,8 @@ pub static NECROMANCER_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
                 )
                 .with_interval(1.0)]),
         )
+        .with_stacks(vec![StackMechanic::new("Corpse").consuming()])
+        .with_dot_snapshot(DotSnapshot::empty())
         .with_bonuses(vec![
             BonusData::new(
                 "Unnerving Boneyard",
@@ -7,6 +7,7 @@ use crate::data::bonuses::{
 use crate::domain::{
     BonusData, BonusSource, BonusTarget, BonusTrigger, BonusValue, SkillTree, DamageFlags,
     DotDamage, ExecuteScaling, HitDamage, Resource, SkillDamage, SkillData, SkillLineName,
+    StackGain, StackMechanic,
 };
 use once_cell::sync::Lazy;
 
@@ -92,7 +93,10 @@ pub static NIGHTBLADE_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
             0.18,
             1.89,
         )]))
-        .with_proc_light_attacks(4)
+        .with_stacks(vec![StackMechanic::new("Grim Focus")
+            .with_gain(StackGain::LightAttack, 1)
+            .with_required_stacks(4)
+            .consuming()])
         .with_bonuses(vec![
             MAJOR_PROPHECY
                 .clone()
@@ -208,7 +212,10 @@ pub static NIGHTBLADE_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
             0.204534,
             2.14761,
         )]))
-        .with_proc_light_attacks(4)
+        .with_stacks(vec![StackMechanic::new("Merciless Resolve")
+            .with_gain(StackGain::LightAttack, 1)
+            .with_required_stacks(4)
+            .consuming()])
         .with_bonuses(vec![
             MAJOR_PROPHECY
                 .clone()
@@ -251,7 +258,10 @@ pub static NIGHTBLADE_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
             0.18594,
             1.95237,
         )]))
-        .with_proc_light_attacks(4)
+        .with_stacks(vec![StackMechanic::new("Relentless Focus")
+            .with_gain(StackGain::LightAttack, 1)
+            .with_required_stacks(4)
+            .consuming()])
         .with_bonuses(vec![
             MAJOR_PROPHECY
                 .clone()
@@ -3,7 +3,8 @@
 use crate::data::bonuses::{MAJOR_BRUTALITY, MAJOR_PROPHECY, MAJOR_SAVAGERY, MAJOR_SORCERY};
 use crate::domain::{
     BonusData, BonusSource, BonusTarget, BonusTrigger, BonusValue, SkillTree, DamageFlags,
-    DotDamage, HitDamage, Resource, SkillDamage, SkillData, SkillLineName,
+    DotDamage, DotSnapshot, HitDamage, Resource, SkillDamage, SkillData, SkillLineName, StackGain,
+    StackMechanic,
 };
 use once_cell::sync::Lazy;
 
@@ -583,14 +584,12 @@ pub static SORCERER_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
             Resource::Stamina,
         )
         .with_skill_id(30244)
-        .with_damage(SkillDamage::new().with_dots(vec![DotDamage::new(
-            20.0,
-            DamageFlags::physical_aoe(),
-            0.018782,
-            0.19721,
-        )
-        .with_interval(2.0)
-        .with_increase_per_tick(0.12)])),
+        .with_damage(SkillDamage::new().with_dots(vec![
+            DotDamage::new(20.0, DamageFlags::physical_aoe(), 0.018782, 0.19721).with_interval(2.0),
+        ]))
+        .with_stacks(vec![StackMechanic::new("Hurricane")
+            .with_gain(StackGain::DotTick, 1)
+            .with_damage_per_stack(0.12)]),
         SkillData::new(
             "Lightning Flood",
             "Lightning Splash",
@@ -613,7 +612,8 @@ pub static SORCERER_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
                     0.188006,
                 )
                 .with_interval(1.0)]),
-        ),
+        )
+        .with_dot_snapshot(DotSnapshot::empty()),
         SkillData::new(
             "Lightning Form",
             "Lightning Form",
@@ -650,7 +650,8 @@ pub static SORCERER_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
                     0.14,
                 )
                 .with_interval(1.0)]),
-        ),
+        )
+        .with_dot_snapshot(DotSnapshot::empty()),
         SkillData::new(
             "Liquid Lightning",
             "Lightning Splash",
@@ -673,7 +674,8 @@ pub static SORCERER_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
                     0.14462,
                 )
                 .with_interval(1.0)]),
-        ),
+        )
+        .with_dot_snapshot(DotSnapshot::empty()),
         SkillData::new(
             "Mages' Fury",
             "Mages' Fury",
@@ -3,6 +3,7 @@
 use crate::data::bonuses::{EMPOWER, MAJOR_BREACH, MAJOR_BRUTALITY, MAJOR_SORCERY};
 use crate::domain::{
     SkillTree, DamageFlags, DotDamage, HitDamage, Resource, SkillDamage, SkillData, SkillLineName,
+    StackGain, StackMechanic,
 };
 use once_cell::sync::Lazy;
 
@@ -48,6 +49,11 @@ pub static TEMPLAR_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
             0.415783,
         )]))
         .with_channel_time(0.8)
+        .with_stacks(vec![StackMechanic::new("Jabs")
+            .with_gain(StackGain::Cast, 1)
+            .with_max_stacks(3)
+            .with_decay(4.0)
+            .with_damage_per_stack(0.05)])
         .with_bonuses(vec![
             MAJOR_BRUTALITY.clone().with_duration(10.0),
             MAJOR_SORCERY.clone().with_duration(10.0),
@@ -204,7 +210,12 @@ pub static TEMPLAR_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
             0.038333,
             0.4025,
         )]))
-        .with_channel_time(0.8),
+        .with_channel_time(0.8)
+        .with_stacks(vec![StackMechanic::new("Jabs")
+            .with_gain(StackGain::Cast, 1)
+            .with_max_stacks(3)
+            .with_decay(4.0)
+            .with_damage_per_stack(0.05)]),
         SkillData::new(
             "Puncturing Sweep",
             "Puncturing Strikes",
@@ -218,7 +229,12 @@ pub static TEMPLAR_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
             0.039598,
             0.415783,
         )]))
-        .with_channel_time(0.8),
+        .with_channel_time(0.8)
+        .with_stacks(vec![StackMechanic::new("Jabs")
+            .with_gain(StackGain::Cast, 1)
+            .with_max_stacks(3)
+            .with_decay(4.0)
+            .with_damage_per_stack(0.05)]),
         SkillData::new(
             "Radial Sweep",
             "Radial Sweep",
@@ -738,14 +754,12 @@ pub static TEMPLAR_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
             Resource::Magicka,
         )
         .with_skill_id(27275)
-        .with_damage(SkillDamage::new().with_dots(vec![DotDamage::new(
-            20.0,
-            DamageFlags::magic_aoe(),
-            0.018782,
-            0.19721,
-        )
-        .with_interval(2.0)
-        .with_increase_per_tick(0.12)])),
+        .with_damage(SkillDamage::new().with_dots(vec![
+            DotDamage::new(20.0, DamageFlags::magic_aoe(), 0.018782, 0.19721).with_interval(2.0),
+        ]))
+        .with_stacks(vec![StackMechanic::new("Ritual of Retribution")
+            .with_gain(StackGain::DotTick, 1)
+            .with_damage_per_stack(0.12)]),
         SkillData::new(
             "Rune Focus",
             "Rune Focus",
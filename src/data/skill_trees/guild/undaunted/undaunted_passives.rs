@@ -7,7 +7,7 @@ use crate::domain::{
 use once_cell::sync::Lazy;
 
 /// Returns Undaunted Mettle bonuses based on number of distinct armor weights worn.
-/// Each armor type grants +2% MaxMagicka and +2% MaxStamina.
+/// Each armor type grants +2% MaxMagicka, +2% MaxStamina and +2% MaxHealth.
 /// Standard 5/1/1 builds use 3 armor types → +6% each.
 pub fn undaunted_mettle_bonuses(armor_types: u8) -> Vec<BonusData> {
     if armor_types == 0 {
@@ -27,6 +27,12 @@ pub fn undaunted_mettle_bonuses(armor_types: u8) -> Vec<BonusData> {
             BonusTrigger::Passive,
             BonusValue::new("Max Stamina", BonusTarget::MaxStamina, pct),
         ),
+        BonusData::new(
+            "Undaunted Mettle",
+            BonusSource::Passive,
+            BonusTrigger::Passive,
+            BonusValue::new("Max Health", BonusTarget::MaxHealth, pct),
+        ),
     ]
 }
 
@@ -41,7 +41,7 @@ pub static BOW_PASSIVES: Lazy<Vec<PassiveData>> = Lazy::new(|| {
             vec![BonusData::new(
                 "Long Shots",
                 BonusSource::Passive,
-                BonusTrigger::BowEquipped,
+                BonusTrigger::AtRange,
                 BonusValue::new("Long Shots (Damage)", BonusTarget::Damage, 0.05),
             )
             .with_alternative(BonusValue::new(
@@ -2,8 +2,8 @@
 // Manual overrides (bonuses, execute, etc.) stored in generator script.
 use crate::data::bonuses::unique::{MAJOR_BREACH, MAJOR_BRUTALITY, MAJOR_SORCERY};
 use crate::domain::{
-    SkillTree, DamageFlags, DotDamage, ExecuteScaling, HitDamage, Resource, SkillDamage, SkillData,
-    SkillLineName,
+    SkillTree, DamageFlags, DotDamage, DotSnapshot, ExecuteScaling, HitDamage, Resource,
+    SkillDamage, SkillData, SkillLineName,
 };
 use once_cell::sync::Lazy;
 
@@ -47,7 +47,8 @@ pub static BOW_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
             0.208896,
         )
         .with_delay(2.0)
-        .with_interval(1.0)])),
+        .with_interval(1.0)]))
+        .with_dot_snapshot(DotSnapshot::empty()),
         SkillData::new(
             "Arrow Spray",
             "Arrow Spray",
@@ -123,7 +124,8 @@ pub static BOW_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
             0.160689,
         )
         .with_delay(2.0)
-        .with_interval(1.0)])),
+        .with_interval(1.0)]))
+        .with_dot_snapshot(DotSnapshot::empty()),
         SkillData::new(
             "Fiery Vault",
             "Fiery Vault",
@@ -335,6 +337,7 @@ pub static BOW_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
             0.155556,
         )
         .with_delay(2.0)
-        .with_interval(1.0)])),
+        .with_interval(1.0)]))
+        .with_dot_snapshot(DotSnapshot::empty()),
     ]
 });
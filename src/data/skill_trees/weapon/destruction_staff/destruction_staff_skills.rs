@@ -2,7 +2,8 @@
 // Manual overrides (bonuses, execute, etc.) stored in generator script.
 use crate::data::bonuses::unique::MAJOR_BREACH;
 use crate::domain::{
-    SkillTree, DamageFlags, DotDamage, HitDamage, Resource, SkillDamage, SkillData, SkillLineName,
+    SkillTree, DamageFlags, DotDamage, DotSnapshot, HitDamage, Resource, SkillDamage, SkillData,
+    SkillLineName,
 };
 use once_cell::sync::Lazy;
 
@@ -106,7 +107,8 @@ pub static DESTRUCTION_STAFF_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
             DamageFlags::MAGIC | DamageFlags::AOE,
             0.012521,
             0.131473,
-        ).with_interval(1.0)])),
+        ).with_interval(1.0)]))
+        .with_dot_snapshot(DotSnapshot::empty()),
         SkillData::new(
             "Elemental Drain",
             "Weakness to Elements",
@@ -277,7 +279,8 @@ pub static DESTRUCTION_STAFF_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
                     0.542325,
                 )
                 .with_delay(8.0)]),
-        ),
+        )
+        .with_dot_snapshot(DotSnapshot::empty()),
         SkillData::new(
             "Wall of Elements",
             "Wall of Elements",
@@ -291,7 +294,8 @@ pub static DESTRUCTION_STAFF_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
             DamageFlags::MAGIC | DamageFlags::AOE,
             0.012121,
             0.127273,
-        ).with_interval(1.0)])),
+        ).with_interval(1.0)]))
+        .with_dot_snapshot(DotSnapshot::empty()),
         SkillData::new(
             "Weakness to Elements",
             "Weakness to Elements",
@@ -2,10 +2,60 @@
 // Manual overrides (bonuses) stored in generator script.
 use crate::domain::{
     BonusData, BonusSource, BonusTarget, BonusTrigger, BonusValue, SkillTree, PassiveData,
-    SkillLineName,
+    SkillLineName, WeaponType,
 };
 use once_cell::sync::Lazy;
 
+/// Returns Twin Blade and Blunt bonuses for the one-handers held in each hand.
+/// Each axe grants +6% Critical Damage, each mace +1487 Penetration, each sword
+/// +129 Weapon and Spell Damage and each dagger +657 Critical Rating.
+pub fn twin_blade_and_blunt_bonuses(main_hand: WeaponType, off_hand: WeaponType) -> Vec<BonusData> {
+    [("Main Hand", main_hand), ("Off Hand", off_hand)]
+        .into_iter()
+        .filter_map(|(hand, weapon)| {
+            let (kind, target, value) = match weapon {
+                WeaponType::DualWieldAxe => ("Axe", BonusTarget::CriticalDamage, 0.06),
+                WeaponType::DualWieldMace => {
+                    ("Mace", BonusTarget::PhysicalAndSpellPenetration, 1487.0)
+                }
+                WeaponType::DualWieldSword => {
+                    ("Sword", BonusTarget::WeaponAndSpellDamageFlat, 129.0)
+                }
+                WeaponType::DualWieldDagger => ("Dagger", BonusTarget::CriticalRating, 657.0),
+                _ => return None,
+            };
+            let name = format!("Twin Blade and Blunt ({}, {})", kind, hand);
+            Some(BonusData::new(
+                name.clone(),
+                BonusSource::Passive,
+                BonusTrigger::DualWieldEquipped,
+                BonusValue::new(name, target, value),
+            ))
+        })
+        .collect()
+}
+
+/// Replaces the best-of Twin Blade and Blunt passive with the bonuses of the
+/// equipped pair. Passives without the dual wield line are returned unchanged.
+pub fn with_twin_blade_and_blunt_hands(
+    passives: &[BonusData],
+    main_hand: WeaponType,
+    off_hand: WeaponType,
+) -> Vec<BonusData> {
+    let is_generic =
+        |b: &BonusData| b.name.starts_with("Twin Blade and Blunt") && b.has_alternative();
+    if !passives.iter().any(is_generic) {
+        return passives.to_vec();
+    }
+    let mut bonuses: Vec<BonusData> = passives
+        .iter()
+        .filter(|b| !is_generic(b))
+        .cloned()
+        .collect();
+    bonuses.extend(twin_blade_and_blunt_bonuses(main_hand, off_hand));
+    bonuses
+}
+
 pub static DUAL_WIELD_PASSIVES: Lazy<Vec<PassiveData>> = Lazy::new(|| {
     vec![
         PassiveData::new(
@@ -3,7 +3,7 @@
 use crate::data::bonuses::unique::{EMPOWER, MAJOR_BERSERK, MAJOR_BRUTALITY, MAJOR_SORCERY};
 use crate::domain::{
     SkillTree, DamageFlags, DotDamage, ExecuteScaling, HitDamage, Resource, SkillDamage, SkillData,
-    SkillLineName,
+    SkillLineName, StackGain, StackMechanic,
 };
 use once_cell::sync::Lazy;
 
@@ -70,7 +70,12 @@ pub static TWO_HANDED_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
                     1.3478,
                 )
                 .with_interval(2.0)]),
-        ),
+        )
+        .with_stacks(vec![StackMechanic::new("Carve Bleed")
+            .with_gain(StackGain::Cast, 1)
+            .with_max_stacks(3)
+            .with_decay(12.0)
+            .with_damage_per_stack(0.1)]),
         SkillData::new(
             "Cleave",
             "Cleave",
@@ -242,7 +247,10 @@ pub static TWO_HANDED_SKILLS: Lazy<Vec<SkillData>> = Lazy::new(|| {
                     0.14462,
                 )
                 .with_interval(1.0)]),
-        ),
+        )
+        .with_stacks(vec![
+            StackMechanic::new("Carve Bleed").with_damage_per_stack(0.1)
+        ]),
         SkillData::new(
             "Uppercut",
             "Uppercut",
@@ -1,4 +1,4 @@
-use super::{formulas, BonusSource, BonusTrigger, SkillLineName, WeaponType};
+use super::{formulas, BonusSource, BonusTrigger, CombatMode, SkillLineName, WeaponType};
 use crate::domain::{BonusValue, CharacterStats};
 use serde::{Deserialize, Serialize};
 
@@ -40,6 +40,8 @@ pub struct BonusData {
     pub duration: Option<f64>,
     pub execute_threshold: Option<f64>,
     pub skill_line_filter: Option<SkillLineName>,
+    /// Only applies in this combat mode (e.g. set bonuses that only work against players)
+    pub combat_mode: Option<CombatMode>,
 
     value: Vec<BonusValue>,
 }
@@ -61,6 +63,7 @@ impl BonusData {
             duration: None,
             execute_threshold: None,
             skill_line_filter: None,
+            combat_mode: None,
         }
     }
 
@@ -94,6 +97,16 @@ impl BonusData {
         self
     }
 
+    pub fn with_combat_mode(mut self, mode: CombatMode) -> Self {
+        self.combat_mode = Some(mode);
+        self
+    }
+
+    /// Whether this bonus is active in `mode` (unscoped bonuses always are).
+    pub fn applies_in(&self, mode: CombatMode) -> bool {
+        self.combat_mode.is_none_or(|m| m == mode)
+    }
+
     pub fn with_alternative(mut self, value: BonusValue) -> Self {
         self.value.push(value);
         self
@@ -103,6 +116,38 @@ impl BonusData {
         self.value.len() > 1
     }
 
+    /// Copy with flat stat values multiplied by `scale` (gear level scaling).
+    /// Percentage values don't depend on item level and are kept as-is.
+    pub fn scaled(&self, scale: f64) -> BonusData {
+        let mut scaled = self.clone();
+        for v in scaled.value.iter_mut().filter(|v| v.target.is_flat_stat()) {
+            v.value *= scale;
+        }
+        scaled
+    }
+
+    /// Copy active for `uptime` (0.0 - 1.0) of the fight, folded into an always-on
+    /// bonus with every value multiplied by the uptime.
+    pub fn weighted(&self, uptime: f64) -> BonusData {
+        let mut weighted = self.clone().with_trigger(BonusTrigger::Passive);
+        for v in weighted.value.iter_mut() {
+            v.value *= uptime;
+        }
+        weighted
+    }
+
+    /// One bonus per value: the primary value first, then each alternative.
+    pub fn split_alternatives(&self) -> Vec<BonusData> {
+        self.value
+            .iter()
+            .map(|v| BonusData {
+                name: v.name.clone(),
+                value: vec![v.clone()],
+                ..self.clone()
+            })
+            .collect()
+    }
+
     pub fn resolve(&self, ctx: &ResolveContext) -> BonusValue {
         self.resolve_ref(ctx).clone()
     }
@@ -17,10 +17,14 @@ pub enum BonusTarget {
     DurationSkillLineMultiplier,
     EnemyDamageTaken,
     EnemyResistanceReduction,
+    EnemyWeaponAndSpellDamageReduction,
     FlameDamage,
     FrostDamage,
+    HealthRecovery,
     HeavyAttackDamage,
     LightAttackDamage,
+    MaxHealth,
+    MaxHealthFlat,
     MaxMagicka,
     MaxMagickaFlat,
     MaxStamina,
@@ -43,6 +47,27 @@ pub enum BonusTarget {
     WeaponDamageFlat,
 }
 
+impl BonusTarget {
+    /// Flat character stat (rating, penetration, flat damage or resource) whose
+    /// magnitude on gear scales with item level.
+    pub fn is_flat_stat(&self) -> bool {
+        matches!(
+            self,
+            BonusTarget::CriticalRating
+                | BonusTarget::SpellCriticalRating
+                | BonusTarget::WeaponCriticalRating
+                | BonusTarget::MaxMagickaFlat
+                | BonusTarget::MaxStaminaFlat
+                | BonusTarget::MaxHealthFlat
+                | BonusTarget::HealthRecovery
+                | BonusTarget::PhysicalAndSpellPenetration
+                | BonusTarget::SpellDamageFlat
+                | BonusTarget::WeaponDamageFlat
+                | BonusTarget::WeaponAndSpellDamageFlat
+        )
+    }
+}
+
 impl fmt::Display for BonusTarget {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let s = match self {
@@ -59,10 +84,14 @@ impl fmt::Display for BonusTarget {
             BonusTarget::DurationSkillLineMultiplier => "Duration (Mult)",
             BonusTarget::EnemyDamageTaken => "Enemy Damage Taken",
             BonusTarget::EnemyResistanceReduction => "Enemy Resistance Reduction",
+            BonusTarget::EnemyWeaponAndSpellDamageReduction => "Enemy Wpn & Spell Damage Reduction",
             BonusTarget::FlameDamage => "Flame Damage",
             BonusTarget::FrostDamage => "Frost Damage",
+            BonusTarget::HealthRecovery => "Health Recovery",
             BonusTarget::HeavyAttackDamage => "Heavy Attack Damage",
             BonusTarget::LightAttackDamage => "Light Attack Damage",
+            BonusTarget::MaxHealth => "Max Health",
+            BonusTarget::MaxHealthFlat => "Max Health (Flat)",
             BonusTarget::MaxMagicka => "Max Magicka",
             BonusTarget::MaxMagickaFlat => "Max Magicka (Flat)",
             BonusTarget::MaxStamina => "Max Stamina",
@@ -4,6 +4,8 @@ use serde::{Deserialize, Serialize};
 #[serde(rename_all = "kebab-case")]
 pub enum BonusTrigger {
     AbilitySlotted,
+    /// Applies beyond melee range; an alternative value, if any, applies within it
+    AtRange,
     AbilitySlottedCount,
     ArcanistCrux,
     BowEquipped,
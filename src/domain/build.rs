@@ -1,6 +1,7 @@
 use super::{
-    BonusData, BonusSource, BonusTarget, BonusTrigger, CharacterStats, SkillTree, DamageFlags,
-    ResolveContext, ResolvedBonus, SkillData, SkillLineName,
+    BonusData, BonusSource, BonusTarget, BonusTrigger, CharacterStats, CombatMode, SkillTree,
+    DamageFlags, PositionalProfile, PowerType, ResolveContext, ResolvedBonus, Resource, SkillData,
+    SkillLineName,
 };
 use crate::infrastructure::{format, table};
 use smallvec::SmallVec;
@@ -120,16 +121,87 @@ pub struct CachedPassiveContext {
 /// Pre-computed evaluation context: values constant across skills for a given
 /// (skill combo, bonus set) pair. Used by both the direct and cached paths.
 pub(crate) struct EvalContext {
+    /// Armor damage factor, including Battle Spirit in PvP.
     pub armor_factor: f64,
-    pub crit_mult: f64,
     /// Multiplier for EnemyDamageTaken from this context's bonuses.
     pub enemy_damage_taken: f64,
     pub max_stat: f64,
-    pub max_power: f64,
+    pub max_health: f64,
+    pub weapon: PowerPair,
+    pub spell: PowerPair,
+    /// Pair used by magicka, stamina and other (health/ultimate) skills.
+    pub resource_pairs: [PowerType; 3],
     pub lookup: ModifierLookup,
     pub filtered: SmallVec<[ResolvedBonus; 4]>,
 }
 
+/// Damage stat and critical multiplier of one weapon/spell pair.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PowerPair {
+    pub power: f64,
+    pub crit_mult: f64,
+}
+
+impl PowerPair {
+    pub(crate) fn from_stats(stats: &CharacterStats, power_type: PowerType) -> Self {
+        Self {
+            power: stats.power_for(power_type),
+            crit_mult: super::formulas::critical_multiplier(
+                stats.critical_chance_for(power_type),
+                stats.effective_critical_damage(),
+            ),
+        }
+    }
+}
+
+impl EvalContext {
+    fn new(
+        effective_stats: &CharacterStats,
+        enemy_damage_taken: f64,
+        lookup: ModifierLookup,
+        filtered: SmallVec<[ResolvedBonus; 4]>,
+    ) -> Self {
+        Self {
+            armor_factor: effective_stats.target_damage_factor(),
+            enemy_damage_taken,
+            max_stat: effective_stats.max_stat(),
+            max_health: effective_stats.max_health,
+            weapon: PowerPair::from_stats(effective_stats, PowerType::Weapon),
+            spell: PowerPair::from_stats(effective_stats, PowerType::Spell),
+            resource_pairs: [
+                effective_stats.power_type_for(Resource::Magicka),
+                effective_stats.power_type_for(Resource::Stamina),
+                effective_stats.power_type_for(Resource::Ultimate),
+            ],
+            lookup,
+            filtered,
+        }
+    }
+
+    /// Resource pool a skill with the given resource scales with.
+    #[inline(always)]
+    pub fn stat_for(&self, resource: Resource) -> f64 {
+        match resource {
+            Resource::Health => self.max_health,
+            _ => self.max_stat,
+        }
+    }
+
+    /// Pair a skill with the given resource scales with.
+    #[inline(always)]
+    pub fn pair_for(&self, resource: Resource) -> PowerPair {
+        let power_type = match resource {
+            Resource::Magicka => self.resource_pairs[0],
+            Resource::Stamina => self.resource_pairs[1],
+            Resource::Health | Resource::Ultimate => self.resource_pairs[2],
+        };
+        match power_type {
+            PowerType::Weapon => self.weapon,
+            PowerType::Spell => self.spell,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Build {
     skills: Vec<&'static SkillData>,
@@ -191,10 +263,16 @@ impl Build {
             if !extra_names.is_empty() && extra_names.contains(bonus.name.as_str()) {
                 continue; // suppressed by extra bonus with the same name
             }
-            if bonus.has_alternative() {
-                alt_bonuses.push(bonus.clone());
-            } else {
-                simple_bonuses.push(bonus.clone());
+            if !bonus.applies_in(character_stats.combat_mode) {
+                continue; // PvP-only or PvE-only bonus outside its mode
+            }
+            // Flanking and range bonuses weighted by positional uptime
+            for bonus in PositionalProfile::apply_opt(character_stats.position.as_ref(), bonus) {
+                if bonus.has_alternative() {
+                    alt_bonuses.push(bonus);
+                } else {
+                    simple_bonuses.push(bonus);
+                }
             }
         }
         for bonus in extra_bonuses.iter().cloned() {
@@ -205,6 +283,8 @@ impl Build {
             }
         }
 
+        let mut character_stats = character_stats;
+        character_stats.health_scaling = Self::health_scaling(&skills);
         let intermediate_stats =
             Self::apply_stat_bonuses_to_stats(&simple_bonuses, &character_stats, &skills);
 
@@ -245,6 +325,34 @@ impl Build {
         }
     }
 
+    /// Re-create this build without the given bonuses.
+    /// Alternative bonuses keep the choice made with the removed bonuses active.
+    pub fn without_bonuses(&self, removed: &[BonusData]) -> Self {
+        let bonuses: Vec<BonusData> = self
+            .resolved_bonuses
+            .iter()
+            .filter(|b| !removed.contains(b))
+            .cloned()
+            .collect();
+        let mut build = Self::new_with_extra(
+            self.skills.clone(),
+            &bonuses,
+            &[],
+            &[],
+            self.set_names.clone(),
+            self.character_stats.clone(),
+            &self.extra_bonuses,
+        );
+        build.cp_bonuses = self.cp_bonuses.clone();
+        build.passive_bonuses = self
+            .passive_bonuses
+            .iter()
+            .filter(|b| !removed.contains(b))
+            .cloned()
+            .collect();
+        build
+    }
+
     fn apply_stat_bonuses_to_stats(
         bonuses: &[BonusData],
         base_stats: &CharacterStats,
@@ -276,6 +384,20 @@ impl Build {
         stats
     }
 
+    /// Share of the skills' max stat coefficients on health-scaling skills.
+    fn health_scaling(skills: &[&SkillData]) -> f64 {
+        let total: f64 = skills.iter().map(|s| s.stat_coefficient()).sum();
+        if total <= 0.0 {
+            return 0.0;
+        }
+        let health: f64 = skills
+            .iter()
+            .filter(|s| s.resource == Resource::Health)
+            .map(|s| s.stat_coefficient())
+            .sum();
+        health / total
+    }
+
     fn bonus_multiplier(bonus: &BonusData, skills: &[&SkillData]) -> f64 {
         match bonus.trigger {
             BonusTrigger::AbilitySlottedCount => match bonus.skill_line_filter {
@@ -304,14 +426,23 @@ impl Build {
             BonusTarget::MaxStaminaFlat => {
                 stats.max_stamina += value;
             }
+            BonusTarget::MaxHealthFlat => {
+                stats.max_health += value;
+            }
+            BonusTarget::HealthRecovery => {
+                stats.health_recovery += value;
+            }
             BonusTarget::CriticalDamage => {
                 stats.critical_damage += value;
             }
             BonusTarget::CriticalRating => {
                 stats.critical_rating += value;
             }
-            BonusTarget::WeaponCriticalRating | BonusTarget::SpellCriticalRating => {
-                stats.critical_rating += value;
+            BonusTarget::WeaponCriticalRating => {
+                stats.weapon_critical_rating += value;
+            }
+            BonusTarget::SpellCriticalRating => {
+                stats.spell_critical_rating += value;
             }
             BonusTarget::PhysicalAndSpellPenetration => {
                 stats.penetration += value;
@@ -331,6 +462,9 @@ impl Build {
             BonusTarget::MaxStamina => {
                 stats.max_stamina *= 1.0 + value;
             }
+            BonusTarget::MaxHealth => {
+                stats.max_health *= 1.0 + value;
+            }
             BonusTarget::WeaponDamage => {
                 stats.weapon_damage *= 1.0 + value;
             }
@@ -350,6 +484,7 @@ impl Build {
             target,
             BonusTarget::MaxMagicka
                 | BonusTarget::MaxStamina
+                | BonusTarget::MaxHealth
                 | BonusTarget::WeaponDamage
                 | BonusTarget::SpellDamage
                 | BonusTarget::WeaponAndSpellDamageMultiplier
@@ -389,6 +524,7 @@ impl Build {
         let default_ctx = ResolveContext::default();
 
         let mut intermediate_stats = character_stats.clone();
+        intermediate_stats.health_scaling = Self::health_scaling(skills);
         let mut effective_stats = character_stats.clone();
         let mut resolved: SmallVec<[ResolvedBonus; 24]> = SmallVec::new();
         let mut deferred_intermediate: SmallVec<[(BonusTarget, f64); 8]> = SmallVec::new();
@@ -458,15 +594,6 @@ impl Build {
         Self::apply_deferred_pct(&mut effective_stats, &deferred_effective);
         effective_stats.clamp_caps();
 
-        let armor_factor = super::formulas::armor_damage_factor(
-            effective_stats.target_armor,
-            effective_stats.penetration,
-        );
-        let crit_mult = super::formulas::critical_multiplier(
-            effective_stats.critical_chance(),
-            effective_stats.critical_damage,
-        );
-
         let lookup = ModifierLookup::new(&resolved);
         let filtered: SmallVec<[ResolvedBonus; 4]> = resolved
             .iter()
@@ -484,29 +611,21 @@ impl Build {
             })
             .collect();
 
-        let max_stat = effective_stats.max_stat();
-        let max_power = effective_stats.max_power();
-
         let enemy_damage_taken = lookup.enemy_damage_taken();
 
-        EvalContext {
-            armor_factor,
-            crit_mult,
-            enemy_damage_taken,
-            max_stat,
-            max_power,
-            lookup,
-            filtered,
-        }
+        EvalContext::new(&effective_stats, enemy_damage_taken, lookup, filtered)
     }
 
-    /// Compute raw damage for a single skill (before armor_factor * crit_mult).
+    /// Compute damage for a single skill, including the critical multiplier of
+    /// the pair it scales with (before armor_factor).
     /// Execute-threshold hits are included weighted by their threshold (proportion
     /// of fight time they are active). Skill-level execute average multiplier is
     /// applied to the total.
     #[inline]
     pub(crate) fn single_skill_damage(skill: &SkillData, ctx: &EvalContext) -> f64 {
         let skill_line = skill.skill_line;
+        let pair = ctx.pair_for(skill.resource);
+        let max_stat = ctx.stat_for(skill.resource);
         let mut skill_damage = 0.0;
 
         if let Some(damage) = &skill.damage {
@@ -514,7 +633,7 @@ impl Build {
                 for hit in hits {
                     let modifier = ctx.lookup.modifier_for(hit.flags)
                         + Self::filtered_modifier(&ctx.filtered, skill_line, hit.flags);
-                    let hit_value = hit.effective_value(ctx.max_stat, ctx.max_power);
+                    let hit_value = hit.effective_value(max_stat, pair.power);
                     let hit_dmg = hit_value * (1.0 + modifier);
                     if let Some(threshold) = hit.execute_threshold {
                         skill_damage += hit_dmg * threshold;
@@ -528,15 +647,14 @@ impl Build {
                 for dot in dots {
                     let modifier = ctx.lookup.modifier_for(dot.flags)
                         + Self::filtered_modifier(&ctx.filtered, skill_line, dot.flags);
-                    let dot_value = dot.effective_value(ctx.max_stat, ctx.max_power);
+                    let dot_value = dot.effective_value(max_stat, pair.power);
 
                     let interval = dot.interval.unwrap_or(dot.duration);
                     let ticks = (dot.duration / interval).floor() as i32;
-                    let increase_per_tick = dot.increase_per_tick.unwrap_or(0.0);
                     let flat_increase_per_tick = dot.flat_increase_per_tick.unwrap_or(0.0);
 
                     for i in 0..ticks {
-                        let pct_mult = 1.0 + (i as f64) * increase_per_tick;
+                        let pct_mult = skill.dot_tick_multiplier(i as u32);
                         let flat_inc = (i as f64) * flat_increase_per_tick;
                         let tick_damage = dot_value * pct_mult + flat_inc;
 
@@ -554,7 +672,7 @@ impl Build {
             skill_damage *= execute.average_multiplier();
         }
 
-        skill_damage
+        skill_damage * pair.crit_mult
     }
 
     /// Fast damage computation without caching. Used when there is only 1 CP combo.
@@ -582,7 +700,7 @@ impl Build {
         for skill in skills {
             total += Self::single_skill_damage(skill, &ctx);
         }
-        total * ctx.armor_factor * ctx.crit_mult * (1.0 + ctx.enemy_damage_taken)
+        total * ctx.armor_factor * (1.0 + ctx.enemy_damage_taken)
     }
 
     /// Sum modifier values from filtered bonuses (those with skill_line_filter)
@@ -614,6 +732,7 @@ impl Build {
     ) -> CachedPassiveContext {
         let default_ctx = ResolveContext::default();
         let mut base_stats = character_stats.clone();
+        base_stats.health_scaling = Self::health_scaling(skills);
         let mut deferred: SmallVec<[(BonusTarget, f64); 8]> = SmallVec::new();
 
         // Build passive resolved list and apply stat bonuses
@@ -779,15 +898,6 @@ impl Build {
         Self::apply_deferred_pct(&mut effective_stats, &deferred_effective);
         effective_stats.clamp_caps();
 
-        let armor_factor = super::formulas::armor_damage_factor(
-            effective_stats.target_armor,
-            effective_stats.penetration,
-        );
-        let crit_mult = super::formulas::critical_multiplier(
-            effective_stats.critical_chance(),
-            effective_stats.critical_damage,
-        );
-
         let lookup = ModifierLookup::new(&cp_resolved);
         let filtered: SmallVec<[ResolvedBonus; 4]> = cp_resolved
             .iter()
@@ -806,21 +916,12 @@ impl Build {
             .collect();
 
         let enemy_damage_taken = lookup.enemy_damage_taken();
-        let max_stat = effective_stats.max_stat();
-        let max_power = effective_stats.max_power();
 
-        EvalContext {
-            armor_factor,
-            crit_mult,
-            enemy_damage_taken,
-            max_stat,
-            max_power,
-            lookup,
-            filtered,
-        }
+        EvalContext::new(&effective_stats, enemy_damage_taken, lookup, filtered)
     }
 
-    /// Compute raw damage for a single skill using cached passive mods + CP eval context.
+    /// Compute damage for a single skill using cached passive mods + CP eval context,
+    /// including the critical multiplier of the pair it scales with.
     /// Execute-threshold hits are included weighted by their threshold.
     /// Skill-level execute average multiplier is applied to the total.
     #[inline]
@@ -831,6 +932,8 @@ impl Build {
         cp_ctx: &EvalContext,
     ) -> f64 {
         let skill_line = skill.skill_line;
+        let pair = cp_ctx.pair_for(skill.resource);
+        let max_stat = cp_ctx.stat_for(skill.resource);
         let mut skill_damage = 0.0;
 
         if let Some(damage) = &skill.damage {
@@ -839,7 +942,7 @@ impl Build {
                     let cp_modifier = cp_ctx.lookup.modifier_for(hit.flags)
                         + Self::filtered_modifier(&cp_ctx.filtered, skill_line, hit.flags);
                     let total_modifier = passive_ctx.hit_mods[skill_idx][hit_idx] + cp_modifier;
-                    let hit_value = hit.effective_value(cp_ctx.max_stat, cp_ctx.max_power);
+                    let hit_value = hit.effective_value(max_stat, pair.power);
                     let hit_dmg = hit_value * (1.0 + total_modifier);
                     if let Some(threshold) = hit.execute_threshold {
                         skill_damage += hit_dmg * threshold;
@@ -854,15 +957,14 @@ impl Build {
                     let cp_modifier = cp_ctx.lookup.modifier_for(dot.flags)
                         + Self::filtered_modifier(&cp_ctx.filtered, skill_line, dot.flags);
                     let total_modifier = passive_ctx.dot_mods[skill_idx][dot_idx] + cp_modifier;
-                    let dot_value = dot.effective_value(cp_ctx.max_stat, cp_ctx.max_power);
+                    let dot_value = dot.effective_value(max_stat, pair.power);
 
                     let interval = dot.interval.unwrap_or(dot.duration);
                     let ticks = (dot.duration / interval).floor() as i32;
-                    let increase_per_tick = dot.increase_per_tick.unwrap_or(0.0);
                     let flat_increase_per_tick = dot.flat_increase_per_tick.unwrap_or(0.0);
 
                     for i in 0..ticks {
-                        let pct_mult = 1.0 + (i as f64) * increase_per_tick;
+                        let pct_mult = skill.dot_tick_multiplier(i as u32);
                         let flat_inc = (i as f64) * flat_increase_per_tick;
                         let tick_damage = dot_value * pct_mult + flat_inc;
 
@@ -880,7 +982,7 @@ impl Build {
             skill_damage *= execute.average_multiplier();
         }
 
-        skill_damage
+        skill_damage * pair.crit_mult
     }
 
     /// Fast damage computation using cached passive context.
@@ -905,7 +1007,7 @@ impl Build {
             total += Self::single_skill_damage_cached(skill, i, passive_ctx, &cp_ctx);
         }
         let enemy_damage_taken = passive_ctx.enemy_damage_taken + cp_ctx.enemy_damage_taken;
-        total * cp_ctx.armor_factor * cp_ctx.crit_mult * (1.0 + enemy_damage_taken)
+        total * cp_ctx.armor_factor * (1.0 + enemy_damage_taken)
     }
 
     /// Build passive modifier lookup (constant within a work unit).
@@ -1081,6 +1183,14 @@ impl Build {
                 let (b, e) = fmt_stat(base.max_stamina, eff.max_stamina);
                 ("Max Stamina", b, e)
             },
+            {
+                let (b, e) = fmt_stat(base.max_health, eff.max_health);
+                ("Max Health", b, e)
+            },
+            {
+                let (b, e) = fmt_stat(base.health_recovery, eff.health_recovery);
+                ("Health Recovery", b, e)
+            },
             {
                 let (b, e) = fmt_stat(base.weapon_damage, eff.weapon_damage);
                 ("Weapon Damage", b, e)
@@ -1090,8 +1200,12 @@ impl Build {
                 ("Spell Damage", b, e)
             },
             {
-                let (b, e) = fmt_pct(base.critical_chance(), eff.critical_chance());
-                ("Critical Chance", b, e)
+                let (b, e) = fmt_pct(base.weapon_critical_chance(), eff.weapon_critical_chance());
+                ("Weapon Critical", b, e)
+            },
+            {
+                let (b, e) = fmt_pct(base.spell_critical_chance(), eff.spell_critical_chance());
+                ("Spell Critical", b, e)
             },
             {
                 let (b, e) = fmt_crit_dmg(base.critical_damage, eff.critical_damage);
@@ -1101,6 +1215,10 @@ impl Build {
                 let (b, e) = fmt_stat(base.penetration, eff.penetration);
                 ("Penetration", b, e)
             },
+            {
+                let (b, e) = fmt_stat(base.penetration_overcap(), eff.penetration_overcap());
+                ("Pen. Overcap", b, e)
+            },
             {
                 let (b, e) = fmt_stat(base.target_armor, eff.target_armor);
                 ("Target Armor", b, e)
@@ -1204,7 +1322,10 @@ impl Build {
                 tooltip_stats.target_armor = 0.0;
                 tooltip_stats.penetration = 0.0;
                 tooltip_stats.critical_rating = 0.0;
+                tooltip_stats.weapon_critical_rating = 0.0;
+                tooltip_stats.spell_critical_rating = 0.0;
                 tooltip_stats.critical_damage = 1.0;
+                tooltip_stats.combat_mode = CombatMode::Pve;
                 let tooltip =
                     skill.calculate_damage_per_cast(&passive_bonuses, &tooltip_stats, None);
                 let effective = skill.calculate_damage_per_cast(
@@ -1272,6 +1393,7 @@ impl Build {
                 | BonusTarget::ShockDamage
                 | BonusTarget::PhysicalDamage
                 | BonusTarget::EnemyDamageTaken
+                | BonusTarget::EnemyWeaponAndSpellDamageReduction
                 | BonusTarget::StatusEffectChance
                 | BonusTarget::StatusEffectDamage
                 | BonusTarget::ChilledStatusEffectChance
@@ -1282,6 +1404,7 @@ impl Build {
                 | BonusTarget::WeaponAndSpellDamageMultiplier
                 | BonusTarget::MaxMagicka
                 | BonusTarget::MaxStamina
+                | BonusTarget::MaxHealth
                 | BonusTarget::WeaponDamage
                 | BonusTarget::SpellDamage
                 | BonusTarget::DurationSkillLineMultiplier => {
@@ -1,10 +1,16 @@
 use serde::{Deserialize, Serialize};
 
-use super::character_stats::ATTRIBUTE_POINTS_BONUS;
+use super::character_stats::{ATTRIBUTE_POINT_HEALTH, ATTRIBUTE_POINT_RESOURCE};
 use super::class_name::SkillTree;
-use super::equipment::{ArmorDistribution, ArmorTrait, AttributeChoice, JewelryTrait, WeaponTrait};
+use super::enemy::Enemy;
+use super::equipment::{
+    gear_level_scale, ArmorDistribution, ArmorGlyph, ArmorTrait, AttributeChoice, GearQuality,
+    JewelryGlyph, JewelryTrait, WeaponTrait, MAX_GEAR_LEVEL,
+};
 use super::food::Food;
 use super::mundus::MundusStone;
+use super::poison::Poison;
+use super::position::PositionalProfile;
 use super::weapon_type::WeaponType;
 use super::weapon_enchant::WeaponEnchant;
 use super::CharacterStats;
@@ -21,6 +27,14 @@ pub struct BuildMetadata {
     pub buffed_stats: Option<CharacterStats>,
 }
 
+fn default_gear_level() -> u16 {
+    MAX_GEAR_LEVEL
+}
+
+fn is_max_gear_level(level: &u16) -> bool {
+    *level == MAX_GEAR_LEVEL
+}
+
 fn default_armor_distribution() -> ArmorDistribution {
     ArmorDistribution {
         light: 1,
@@ -51,6 +65,14 @@ pub struct BuildConfig {
     pub bar1_weapon: Option<WeaponType>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bar2_weapon: Option<WeaponType>,
+    /// Off-hand one-hander of a dual wield bar (None = same type as the main hand)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bar1_off_hand: Option<WeaponType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bar2_off_hand: Option<WeaponType>,
+    /// One-bar (Oakensoul) build: all skills on bar 1, no bar swaps
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub single_bar: bool,
 
     // Gear traits (partial Vec = only pinned slots; free slots optimized)
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -59,17 +81,57 @@ pub struct BuildConfig {
     pub jewelry_traits: Vec<JewelryTrait>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub weapon_traits: Vec<WeaponTrait>,
+    /// Off-hand traits of dual wield bars (bar1, bar2); unset = no trait
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub off_hand_traits: Vec<WeaponTrait>,
+
+    // Glyphs (partial Vec = only pinned slots; free slots optimized)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub armor_glyphs: Vec<ArmorGlyph>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub jewelry_glyphs: Vec<JewelryGlyph>,
 
     // Enchants
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub bar1_enchant: Option<WeaponEnchant>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub bar2_enchant: Option<WeaponEnchant>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bar1_off_hand_enchant: Option<WeaponEnchant>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bar2_off_hand_enchant: Option<WeaponEnchant>,
+
+    // Poisons
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bar1_poison: Option<Poison>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bar2_poison: Option<Poison>,
+
+    // Quality per slot (partial Vec = only listed slots; the rest are Legendary)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub armor_quality: Vec<GearQuality>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub jewelry_quality: Vec<GearQuality>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub weapon_quality: Vec<GearQuality>,
+    /// Champion item level of all pieces (0 = level 50 gear, 160 = CP160)
+    #[serde(
+        default = "default_gear_level",
+        skip_serializing_if = "is_max_gear_level"
+    )]
+    pub gear_level: u16,
 
     // Armor
     #[serde(default = "default_armor_distribution")]
     pub armor: ArmorDistribution,
 
+    // Target (None = trial dummy, or a bare target dummy without trial buffs)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enemy: Option<Enemy>,
+    /// Flanking and melee uptime (None = positional conditions always met)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<PositionalProfile>,
+
     // Buffs
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mundus: Option<MundusStone>,
@@ -96,12 +158,28 @@ impl Default for BuildConfig {
             attributes: None,
             bar1_weapon: None,
             bar2_weapon: None,
+            bar1_off_hand: None,
+            bar2_off_hand: None,
+            single_bar: false,
             armor_traits: Vec::new(),
             jewelry_traits: Vec::new(),
             weapon_traits: Vec::new(),
+            off_hand_traits: Vec::new(),
+            armor_glyphs: Vec::new(),
+            jewelry_glyphs: Vec::new(),
             bar1_enchant: None,
             bar2_enchant: None,
+            bar1_off_hand_enchant: None,
+            bar2_off_hand_enchant: None,
+            bar1_poison: None,
+            bar2_poison: None,
+            armor_quality: Vec::new(),
+            jewelry_quality: Vec::new(),
+            weapon_quality: Vec::new(),
+            gear_level: MAX_GEAR_LEVEL,
             armor: default_armor_distribution(),
+            enemy: None,
+            position: None,
             mundus: None,
             food: None,
             potion: None,
@@ -118,10 +196,24 @@ const ONE_HANDED_BASE_DAMAGE: f64 = 1_335.0;
 // Armor enchantment values (CP160 gold glyphs)
 const LARGE_ARMOR_ENCHANT: f64 = 868.0; // chest, head, legs
 const SMALL_ARMOR_ENCHANT: f64 = 351.0; // shoulders, hands, waist, feet
-const TOTAL_ARMOR_ENCHANT: f64 = LARGE_ARMOR_ENCHANT * 3.0 + SMALL_ARMOR_ENCHANT * 4.0; // 4,008
 
-// Jewelry enchantment values (CP160 gold, Glyph of Increase Physical Harm)
+// Per-slot glyph value; armor slots are ordered chest, head, legs, shoulders, hands, waist, feet
+const ARMOR_SLOT_ENCHANT: [f64; 7] = [
+    LARGE_ARMOR_ENCHANT,
+    LARGE_ARMOR_ENCHANT,
+    LARGE_ARMOR_ENCHANT,
+    SMALL_ARMOR_ENCHANT,
+    SMALL_ARMOR_ENCHANT,
+    SMALL_ARMOR_ENCHANT,
+    SMALL_ARMOR_ENCHANT,
+]; // 4,008 total
+
+// Health glyphs restore 10% more than magicka/stamina glyphs of the same slot
+const ARMOR_HEALTH_ENCHANT_MULTIPLIER: f64 = 1.10;
+
+// Jewelry enchantment values (CP160 gold, Glyph of Increase Physical/Magical Harm)
 const JEWELRY_ENCHANT_DAMAGE: f64 = 174.0; // per piece
+const JEWELRY_ENCHANT_HEALTH_RECOVERY: f64 = 174.0; // per piece
 
 // Trait values (legendary quality)
 const ARMOR_INFUSED_ENCHANT_BONUS: f64 = 0.12; // +12% enchant per piece
@@ -134,18 +226,65 @@ const WEAPON_NIRNHONED_BONUS: f64 = 0.15; // +15% of weapon base as W/SD
 const WEAPON_PRECISE_CRIT_RATING: f64 = 1_117.0;
 const WEAPON_SHARPENED_PENETRATION: f64 = 3_276.0;
 
+/// Stat scale of a slot at the given quality and item level.
+fn slot_scale(qualities: &[GearQuality], slot: usize, level: u16) -> f64 {
+    qualities.get(slot).copied().unwrap_or_default().scale() * gear_level_scale(level)
+}
+
 impl BuildConfig {
+    /// Main and off hand of each dual wield bar (bar1, bar2) among the given bar weapons.
+    /// An unset off hand holds the same type as the main hand.
+    pub fn dual_wield_hands(
+        &self,
+        bar1_weapon: Option<WeaponType>,
+        bar2_weapon: Option<WeaponType>,
+    ) -> [Option<(WeaponType, WeaponType)>; 2] {
+        [
+            (bar1_weapon, self.bar1_off_hand),
+            (bar2_weapon, self.bar2_off_hand),
+        ]
+        .map(|(main, off)| {
+            main.filter(|w| w.is_dual_wield())
+                .map(|main| (main, off.unwrap_or(main)))
+        })
+    }
+
+    /// Hands of the first dual wield bar, the pair Twin Blade and Blunt is scored with.
+    pub fn first_dual_wield_hands(
+        &self,
+        bar1_weapon: Option<WeaponType>,
+        bar2_weapon: Option<WeaponType>,
+    ) -> Option<(WeaponType, WeaponType)> {
+        self.dual_wield_hands(bar1_weapon, bar2_weapon)
+            .into_iter()
+            .flatten()
+            .next()
+    }
+
+    /// Scale applied to flat set bonus stats. Set bonuses scale with item level only.
+    pub fn set_bonus_scale(&self) -> f64 {
+        gear_level_scale(self.gear_level)
+    }
+
     /// Compute character stats from gear configuration.
     /// Uses `bar1_weapon` for base damage calculation.
+    /// Base damage, glyphs and traits scale with each slot's quality and the gear level.
     pub fn compute_stats(&self) -> CharacterStats {
         let mut stats = CharacterStats::default();
+        if let Some(enemy) = &self.enemy {
+            enemy.apply_to(&mut stats);
+        }
+        stats.position = self.position;
+        let armor_scale = |slot: usize| slot_scale(&self.armor_quality, slot, self.gear_level);
+        let jewelry_scale = |slot: usize| slot_scale(&self.jewelry_quality, slot, self.gear_level);
+        let weapon_scale = slot_scale(&self.weapon_quality, 0, self.gear_level);
 
         // 1. Attribute points
-        match self.attributes {
-            Some(AttributeChoice::Magicka) => stats.max_magicka += ATTRIBUTE_POINTS_BONUS,
-            Some(AttributeChoice::Stamina) => stats.max_stamina += ATTRIBUTE_POINTS_BONUS,
-            Some(AttributeChoice::None) | None => {}
-        }
+        let (magicka_points, stamina_points, health_points) =
+            self.attributes.unwrap_or_default().points();
+        stats.max_magicka += magicka_points as f64 * ATTRIBUTE_POINT_RESOURCE;
+        stats.max_stamina += stamina_points as f64 * ATTRIBUTE_POINT_RESOURCE;
+        stats.max_health += health_points as f64 * ATTRIBUTE_POINT_HEALTH;
 
         // 2. Weapon base damage (replaces default 1,000)
         if let Some(weapon) = self.bar1_weapon {
@@ -153,7 +292,7 @@ impl BuildConfig {
                 TWO_HANDED_BASE_DAMAGE
             } else {
                 ONE_HANDED_BASE_DAMAGE
-            };
+            } * weapon_scale;
 
             if weapon.is_destruction_staff() {
                 stats.spell_damage = base;
@@ -162,96 +301,120 @@ impl BuildConfig {
             }
 
             // 3. Weapon trait bonus (bar1 weapon trait = index 0, default Nirnhoned)
-            match self
+            // A dual wield bar also applies its off-hand trait (default: no trait)
+            let main_trait = self
                 .weapon_traits
                 .first()
                 .copied()
-                .unwrap_or(WeaponTrait::Nirnhoned)
-            {
-                WeaponTrait::Nirnhoned => {
-                    let bonus = base * WEAPON_NIRNHONED_BONUS;
-                    if weapon.is_destruction_staff() {
-                        stats.spell_damage += bonus;
-                    } else {
-                        stats.weapon_damage += bonus;
+                .unwrap_or(WeaponTrait::Nirnhoned);
+            let mut hand_traits = vec![main_trait];
+            if weapon.is_dual_wield() {
+                hand_traits.extend(self.off_hand_traits.first().copied());
+            }
+            for weapon_trait in hand_traits {
+                match weapon_trait {
+                    WeaponTrait::Nirnhoned => {
+                        let bonus = base * WEAPON_NIRNHONED_BONUS;
+                        if weapon.is_destruction_staff() {
+                            stats.spell_damage += bonus;
+                        } else {
+                            stats.weapon_damage += bonus;
+                        }
                     }
+                    WeaponTrait::Precise => {
+                        stats.critical_rating += WEAPON_PRECISE_CRIT_RATING * weapon_scale;
+                    }
+                    WeaponTrait::Sharpened => {
+                        stats.penetration += WEAPON_SHARPENED_PENETRATION * weapon_scale;
+                    }
+                    _ => {} // Other traits not modeled for DPS
                 }
-                WeaponTrait::Precise => {
-                    stats.critical_rating += WEAPON_PRECISE_CRIT_RATING;
-                }
-                WeaponTrait::Sharpened => {
-                    stats.penetration += WEAPON_SHARPENED_PENETRATION;
-                }
-                _ => {} // Other traits not modeled for DPS
             }
         }
 
-        // 4. Armor enchantments (7 pieces, all stamina or magicka)
-        // Unspecified slots default to Divines (no infused bonus)
-        let infused_armor_count = self
-            .armor_traits
-            .iter()
-            .filter(|t| **t == ArmorTrait::Infused)
-            .count() as f64;
-        let non_infused_count = 7.0 - infused_armor_count;
-        let armor_enchant_total = {
-            let base_per_piece = TOTAL_ARMOR_ENCHANT / 7.0;
-            non_infused_count * base_per_piece
-                + infused_armor_count * base_per_piece * (1.0 + ARMOR_INFUSED_ENCHANT_BONUS)
-        };
-
-        // Apply enchant to the primary resource
-        if self.attributes == Some(AttributeChoice::Magicka)
+        // 4. Armor enchantments (7 pieces, per-slot glyph)
+        // Unspecified slots default to Divines (no infused bonus) and a primary resource glyph
+        let default_armor_glyph = if magicka_points > stamina_points
             || self.bar1_weapon.map_or(false, |w| w.is_destruction_staff())
         {
-            stats.max_magicka += armor_enchant_total;
+            ArmorGlyph::Magicka
         } else {
-            stats.max_stamina += armor_enchant_total;
+            ArmorGlyph::Stamina
+        };
+        for (slot, base) in ARMOR_SLOT_ENCHANT.iter().enumerate() {
+            let enchant = match self.armor_traits.get(slot) {
+                Some(ArmorTrait::Infused) => base * (1.0 + ARMOR_INFUSED_ENCHANT_BONUS),
+                _ => *base,
+            } * armor_scale(slot);
+            match self
+                .armor_glyphs
+                .get(slot)
+                .copied()
+                .unwrap_or(default_armor_glyph)
+            {
+                ArmorGlyph::Magicka => stats.max_magicka += enchant,
+                ArmorGlyph::Stamina => stats.max_stamina += enchant,
+                ArmorGlyph::Health => stats.max_health += enchant * ARMOR_HEALTH_ENCHANT_MULTIPLIER,
+            }
         }
 
-        // 5. Jewelry enchantments (3 pieces, weapon+spell damage)
-        let mut jewelry_damage_total = 0.0;
-        for jt in &self.jewelry_traits {
-            let enchant = match jt {
-                JewelryTrait::Infused => {
-                    JEWELRY_ENCHANT_DAMAGE * (1.0 + JEWELRY_INFUSED_ENCHANT_BONUS)
+        // 5. Jewelry enchantments (per-slot glyph, paired with the slot's trait)
+        // Unspecified glyphs keep the weapon+spell damage default
+        for (slot, jt) in self.jewelry_traits.iter().enumerate() {
+            let enchant_scale = match jt {
+                JewelryTrait::Infused => 1.0 + JEWELRY_INFUSED_ENCHANT_BONUS,
+                _ => 1.0,
+            } * jewelry_scale(slot);
+            match self.jewelry_glyphs.get(slot) {
+                None => {
+                    stats.weapon_damage += JEWELRY_ENCHANT_DAMAGE * enchant_scale;
+                    stats.spell_damage += JEWELRY_ENCHANT_DAMAGE * enchant_scale;
                 }
-                _ => JEWELRY_ENCHANT_DAMAGE,
-            };
-            jewelry_damage_total += enchant;
+                Some(JewelryGlyph::PhysicalHarm) => {
+                    stats.weapon_damage += JEWELRY_ENCHANT_DAMAGE * enchant_scale
+                }
+                Some(JewelryGlyph::MagicalHarm) => {
+                    stats.spell_damage += JEWELRY_ENCHANT_DAMAGE * enchant_scale
+                }
+                Some(JewelryGlyph::HealthRecovery) => {
+                    stats.health_recovery += JEWELRY_ENCHANT_HEALTH_RECOVERY * enchant_scale
+                }
+                Some(_) => {} // Magicka/stamina recovery not modeled for DPS
+            }
         }
-        stats.weapon_damage += jewelry_damage_total;
-        stats.spell_damage += jewelry_damage_total;
 
         // 6. Jewelry trait bonuses (per-piece)
-        for jt in &self.jewelry_traits {
+        for (slot, jt) in self.jewelry_traits.iter().enumerate() {
+            let scale = jewelry_scale(slot);
             match jt {
                 JewelryTrait::Bloodthirsty => {
                     let avg_per_piece = BLOODTHIRSTY_THRESHOLD * (BLOODTHIRSTY_MAX_PER_PIECE / 2.0);
-                    stats.weapon_damage += avg_per_piece;
-                    stats.spell_damage += avg_per_piece;
+                    stats.weapon_damage += avg_per_piece * scale;
+                    stats.spell_damage += avg_per_piece * scale;
                 }
                 JewelryTrait::Robust => {
-                    stats.max_stamina += JEWELRY_ROBUST_STAMINA;
+                    stats.max_stamina += JEWELRY_ROBUST_STAMINA * scale;
                 }
                 JewelryTrait::Arcane => {
-                    stats.max_magicka += JEWELRY_ARCANE_MAGICKA;
+                    stats.max_magicka += JEWELRY_ARCANE_MAGICKA * scale;
                 }
                 _ => {}
             }
         }
 
         // 7. Mundus stone (amplified by Divines)
-        // Unspecified armor slots default to Divines for mundus calculation
+        // Unspecified armor slots default to Divines for mundus calculation;
+        // each Divines piece counts in proportion to its quality and level
         if let Some(mundus) = &self.mundus {
-            let explicit_divines = self
-                .armor_traits
-                .iter()
-                .filter(|t| **t == ArmorTrait::Divines)
-                .count();
-            let unspecified_slots = 7_usize.saturating_sub(self.armor_traits.len());
-            let divines_count = (explicit_divines + unspecified_slots) as u8;
-            mundus.apply(&mut stats, divines_count);
+            let divines: f64 = (0..7)
+                .filter(|&slot| {
+                    self.armor_traits
+                        .get(slot)
+                        .is_none_or(|t| *t == ArmorTrait::Divines)
+                })
+                .map(armor_scale)
+                .sum();
+            mundus.apply(&mut stats, divines);
         }
 
         // 8. Racial passives
@@ -267,3 +430,109 @@ impl BuildConfig {
         stats
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-6
+    }
+
+    #[test]
+    fn test_partial_armor_glyphs_fill_unset_slots_with_primary_resource() {
+        let config = BuildConfig {
+            bar1_weapon: Some(WeaponType::TwoHandedSword),
+            ..BuildConfig::default()
+        };
+        let base = config.compute_stats();
+        let pinned = BuildConfig {
+            armor_glyphs: vec![ArmorGlyph::Health, ArmorGlyph::Magicka],
+            ..config.clone()
+        }
+        .compute_stats();
+
+        // Chest and head are pinned; the other five slots keep the stamina default
+        assert!(close(
+            pinned.max_stamina,
+            base.max_stamina - 2.0 * LARGE_ARMOR_ENCHANT
+        ));
+        assert!(close(
+            pinned.max_magicka,
+            base.max_magicka + LARGE_ARMOR_ENCHANT
+        ));
+        assert!(close(
+            pinned.max_health,
+            base.max_health + LARGE_ARMOR_ENCHANT * ARMOR_HEALTH_ENCHANT_MULTIPLIER
+        ));
+    }
+
+    #[test]
+    fn test_infused_amplifies_only_its_own_armor_slot() {
+        let config = BuildConfig {
+            bar1_weapon: Some(WeaponType::TwoHandedSword),
+            armor_glyphs: vec![ArmorGlyph::Health, ArmorGlyph::Health],
+            ..BuildConfig::default()
+        };
+        let base = config.compute_stats();
+        let infused = BuildConfig {
+            armor_traits: vec![ArmorTrait::Infused],
+            ..config.clone()
+        }
+        .compute_stats();
+
+        assert!(close(
+            infused.max_health,
+            base.max_health
+                + LARGE_ARMOR_ENCHANT
+                    * ARMOR_INFUSED_ENCHANT_BONUS
+                    * ARMOR_HEALTH_ENCHANT_MULTIPLIER
+        ));
+        assert!(close(infused.max_stamina, base.max_stamina));
+    }
+
+    #[test]
+    fn test_each_hand_applies_its_own_weapon_trait() {
+        let precise = BuildConfig {
+            bar1_weapon: Some(WeaponType::DualWieldDagger),
+            weapon_traits: vec![WeaponTrait::Precise],
+            ..BuildConfig::default()
+        };
+        let unset = BuildConfig {
+            weapon_traits: vec![WeaponTrait::Charged],
+            ..precise.clone()
+        }
+        .compute_stats();
+
+        // The off hand has no trait by default, so Precise is counted once
+        let main_only = precise.compute_stats();
+        assert!(close(
+            main_only.critical_rating,
+            unset.critical_rating + WEAPON_PRECISE_CRIT_RATING
+        ));
+        assert!(close(main_only.penetration, unset.penetration));
+
+        let sharpened_off_hand = BuildConfig {
+            off_hand_traits: vec![WeaponTrait::Sharpened],
+            ..precise.clone()
+        }
+        .compute_stats();
+        assert!(close(
+            sharpened_off_hand.critical_rating,
+            main_only.critical_rating
+        ));
+        assert!(close(
+            sharpened_off_hand.penetration,
+            unset.penetration + WEAPON_SHARPENED_PENETRATION
+        ));
+
+        // A two-hander has no off hand
+        let two_handed = BuildConfig {
+            bar1_weapon: Some(WeaponType::TwoHandedSword),
+            off_hand_traits: vec![WeaponTrait::Sharpened],
+            ..precise
+        }
+        .compute_stats();
+        assert!(close(two_handed.penetration, unset.penetration));
+    }
+}
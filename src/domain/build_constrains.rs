@@ -5,6 +5,13 @@ pub const BUILD_CONSTRAINTS: BuildConstraints = BuildConstraints {
     weapon_skill_line_count: 2,
 };
 
+/// One-bar (Oakensoul) builds: a single bar of 5 skills and one weapon.
+pub const SINGLE_BAR_CONSTRAINTS: BuildConstraints = BuildConstraints {
+    skill_count: 5,
+    weapon_skill_line_count: 1,
+    ..BUILD_CONSTRAINTS
+};
+
 #[derive(Debug, Clone, Copy)]
 pub struct BuildConstraints {
     pub skill_count: usize,
@@ -12,3 +19,13 @@ pub struct BuildConstraints {
     pub class_skill_line_count: usize,
     pub weapon_skill_line_count: usize,
 }
+
+impl BuildConstraints {
+    pub fn for_bars(single_bar: bool) -> BuildConstraints {
+        if single_bar {
+            SINGLE_BAR_CONSTRAINTS
+        } else {
+            BUILD_CONSTRAINTS
+        }
+    }
+}
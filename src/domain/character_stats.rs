@@ -1,10 +1,19 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+use super::combat_mode::CombatMode;
+use super::enemy::ArmorDebuff;
 use super::formulas;
+use super::position::PositionalProfile;
+use super::power_type::PowerType;
+use super::resource::Resource;
 use crate::infrastructure::{format, table};
 
-pub const ATTRIBUTE_POINTS_BONUS: f64 = 111.0 * 64.0;
+/// Max magicka or stamina gained per attribute point.
+pub const ATTRIBUTE_POINT_RESOURCE: f64 = 111.0;
+/// Max health gained per attribute point.
+pub const ATTRIBUTE_POINT_HEALTH: f64 = 122.0;
+pub const ATTRIBUTE_POINTS_BONUS: f64 = ATTRIBUTE_POINT_RESOURCE * 64.0;
 pub const MAX_CRITICAL_CHANCE: f64 = 1.0;
 pub const MAX_CRITICAL_DAMAGE: f64 = 2.25;
 
@@ -12,12 +21,42 @@ pub const MAX_CRITICAL_DAMAGE: f64 = 2.25;
 pub struct CharacterStats {
     pub max_magicka: f64,
     pub max_stamina: f64,
+    /// Only feeds damage of health-scaling skills (`Resource::Health`)
+    #[serde(default = "default_max_health")]
+    pub max_health: f64,
+    #[serde(default = "default_health_recovery")]
+    pub health_recovery: f64,
+    /// Share of the skills' max stat coefficients that scale with max health
+    /// (0.0-1.0), which weights max health when comparing bonuses
+    #[serde(default)]
+    pub health_scaling: f64,
     pub weapon_damage: f64,
     pub spell_damage: f64,
+    /// Critical rating shared by weapon and spell critical
     pub critical_rating: f64,
+    /// Rating that only applies to weapon critical (e.g. Savagery)
+    #[serde(default)]
+    pub weapon_critical_rating: f64,
+    /// Rating that only applies to spell critical (e.g. Prophecy)
+    #[serde(default)]
+    pub spell_critical_rating: f64,
     pub critical_damage: f64,
     pub penetration: f64,
     pub target_armor: f64,
+    /// Enemy critical resistance, reduces the bonus of critical hits
+    #[serde(default)]
+    pub target_critical_resistance: f64,
+    /// Enemy armor debuffs that are only up for part of the fight, weighted
+    /// by uptime in the damage factor rather than in `penetration`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub target_armor_debuffs: Vec<ArmorDebuff>,
+    /// PvP applies Battle Spirit to all damage dealt
+    #[serde(default)]
+    pub combat_mode: CombatMode,
+    /// Flanking and melee uptime that positional bonuses are weighted by
+    /// (None = positional conditions always met)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<PositionalProfile>,
 }
 
 impl Default for CharacterStats {
@@ -25,16 +64,33 @@ impl Default for CharacterStats {
         Self {
             max_magicka: 12_000.0,
             max_stamina: 12_000.0,
+            max_health: 16_000.0,
+            health_recovery: 309.0,
+            health_scaling: 0.0,
             weapon_damage: 1_000.0,
             spell_damage: 1_000.0,
             critical_rating: 0.0,
+            weapon_critical_rating: 0.0,
+            spell_critical_rating: 0.0,
             critical_damage: 1.50,
             penetration: 0.0,
             target_armor: 18_200.0,
+            target_critical_resistance: 0.0,
+            target_armor_debuffs: Vec::new(),
+            combat_mode: CombatMode::Pve,
+            position: None,
         }
     }
 }
 
+fn default_max_health() -> f64 {
+    CharacterStats::default().max_health
+}
+
+fn default_health_recovery() -> f64 {
+    CharacterStats::default().health_recovery
+}
+
 impl CharacterStats {
     pub fn new(
         max_magicka: f64,
@@ -49,12 +105,21 @@ impl CharacterStats {
         Self {
             max_magicka,
             max_stamina,
+            max_health: default_max_health(),
+            health_recovery: default_health_recovery(),
+            health_scaling: 0.0,
             weapon_damage,
             spell_damage,
             critical_rating,
+            weapon_critical_rating: 0.0,
+            spell_critical_rating: 0.0,
             critical_damage,
             penetration,
             target_armor,
+            target_critical_resistance: 0.0,
+            target_armor_debuffs: Vec::new(),
+            combat_mode: CombatMode::Pve,
+            position: None,
         }
     }
 
@@ -68,6 +133,16 @@ impl CharacterStats {
         self
     }
 
+    pub fn with_max_health(mut self, value: f64) -> Self {
+        self.max_health = value;
+        self
+    }
+
+    pub fn with_health_recovery(mut self, value: f64) -> Self {
+        self.health_recovery = value;
+        self
+    }
+
     pub fn with_weapon_damage(mut self, value: f64) -> Self {
         self.weapon_damage = value;
         self
@@ -83,6 +158,16 @@ impl CharacterStats {
         self
     }
 
+    pub fn with_weapon_critical_rating(mut self, value: f64) -> Self {
+        self.weapon_critical_rating = value;
+        self
+    }
+
+    pub fn with_spell_critical_rating(mut self, value: f64) -> Self {
+        self.spell_critical_rating = value;
+        self
+    }
+
     pub fn with_critical_damage(mut self, value: f64) -> Self {
         self.critical_damage = value;
         self
@@ -98,16 +183,128 @@ impl CharacterStats {
         self
     }
 
+    pub fn with_target_critical_resistance(mut self, value: f64) -> Self {
+        self.target_critical_resistance = value;
+        self
+    }
+
+    pub fn with_combat_mode(mut self, mode: CombatMode) -> Self {
+        self.combat_mode = mode;
+        self
+    }
+
     pub fn max_stat(&self) -> f64 {
         self.max_magicka.max(self.max_stamina)
     }
 
+    /// Resource pool a skill's stat coefficient scales with: max health for
+    /// health-scaling skills, the higher of magicka and stamina otherwise.
+    pub fn stat_for(&self, resource: Resource) -> f64 {
+        match resource {
+            Resource::Health => self.max_health,
+            _ => self.max_stat(),
+        }
+    }
+
     pub fn max_power(&self) -> f64 {
         self.weapon_damage.max(self.spell_damage)
     }
 
+    pub fn weapon_critical_rating_total(&self) -> f64 {
+        self.critical_rating + self.weapon_critical_rating
+    }
+
+    pub fn spell_critical_rating_total(&self) -> f64 {
+        self.critical_rating + self.spell_critical_rating
+    }
+
+    pub fn weapon_critical_chance(&self) -> f64 {
+        formulas::crit_rating_to_chance(self.weapon_critical_rating_total())
+    }
+
+    pub fn spell_critical_chance(&self) -> f64 {
+        formulas::crit_rating_to_chance(self.spell_critical_rating_total())
+    }
+
+    pub fn power_for(&self, power_type: PowerType) -> f64 {
+        match power_type {
+            PowerType::Weapon => self.weapon_damage,
+            PowerType::Spell => self.spell_damage,
+        }
+    }
+
+    pub fn critical_rating_for(&self, power_type: PowerType) -> f64 {
+        match power_type {
+            PowerType::Weapon => self.weapon_critical_rating_total(),
+            PowerType::Spell => self.spell_critical_rating_total(),
+        }
+    }
+
+    pub fn critical_chance_for(&self, power_type: PowerType) -> f64 {
+        formulas::crit_rating_to_chance(self.critical_rating_for(power_type))
+    }
+
+    /// Pair used by damage that is not tied to a skill (procs, enchants, light
+    /// attacks): the higher damage stat, ties broken by the higher critical.
+    pub fn best_power_type(&self) -> PowerType {
+        let spell_crit_higher =
+            self.spell_critical_rating_total() > self.weapon_critical_rating_total();
+        if self.spell_damage > self.weapon_damage
+            || (self.spell_damage == self.weapon_damage && spell_crit_higher)
+        {
+            PowerType::Spell
+        } else {
+            PowerType::Weapon
+        }
+    }
+
+    /// Pair a skill scales with. Skills use the higher damage stat; on a tie
+    /// they keep their resource's native pair.
+    pub fn power_type_for(&self, resource: Resource) -> PowerType {
+        if self.weapon_damage != self.spell_damage {
+            return self.best_power_type();
+        }
+        PowerType::for_resource(resource).unwrap_or_else(|| self.best_power_type())
+    }
+
+    /// Critical chance of the best pair (see `best_power_type`).
     pub fn critical_chance(&self) -> f64 {
-        formulas::crit_rating_to_chance(self.critical_rating)
+        self.critical_chance_for(self.best_power_type())
+    }
+
+    /// Critical damage multiplier after the enemy's critical resistance.
+    pub fn effective_critical_damage(&self) -> f64 {
+        formulas::critical_damage_after_resistance(
+            self.critical_damage,
+            self.target_critical_resistance,
+        )
+    }
+
+    /// Fraction of damage that reaches the target after its armor and, in
+    /// PvP, Battle Spirit.
+    pub fn target_damage_factor(&self) -> f64 {
+        self.armor_damage_factor(self.penetration)
+            * formulas::battle_spirit_multiplier(self.combat_mode)
+    }
+
+    /// Armor damage factor at `penetration`, averaged over the uptime of the
+    /// target's part-time armor debuffs.
+    pub fn armor_damage_factor(&self, penetration: f64) -> f64 {
+        if self.target_armor_debuffs.is_empty() {
+            return formulas::armor_damage_factor(self.target_armor, penetration);
+        }
+        formulas::uptime_weighted_armor_factor(
+            self.target_armor,
+            penetration,
+            self.target_armor_debuffs
+                .iter()
+                .map(|d| (d.value, d.uptime)),
+        )
+    }
+
+    /// Penetration beyond what it takes to strip the target's armor.
+    pub fn penetration_overcap(&self) -> f64 {
+        formulas::penetration_overcap(self.target_armor, self.penetration)
     }
 
     pub fn clamp_caps(&mut self) {
@@ -121,16 +318,36 @@ impl fmt::Display for CharacterStats {
         let fmt_pct = |val: f64| format!("{:.2}%", val * 100.0);
         let fmt_crit_dmg = |val: f64| format!("{:.2}%", (val - 1.0) * 100.0);
 
-        let data: Vec<Vec<String>> = vec![
+        let mut data: Vec<Vec<String>> = vec![
             vec!["Max Magicka".into(), fmt_stat(self.max_magicka)],
             vec!["Max Stamina".into(), fmt_stat(self.max_stamina)],
+            vec!["Max Health".into(), fmt_stat(self.max_health)],
+            vec!["Health Recovery".into(), fmt_stat(self.health_recovery)],
             vec!["Weapon Damage".into(), fmt_stat(self.weapon_damage)],
             vec!["Spell Damage".into(), fmt_stat(self.spell_damage)],
-            vec!["Critical Chance".into(), fmt_pct(self.critical_chance())],
+            vec![
+                "Weapon Critical".into(),
+                fmt_pct(self.weapon_critical_chance()),
+            ],
+            vec![
+                "Spell Critical".into(),
+                fmt_pct(self.spell_critical_chance()),
+            ],
             vec!["Critical Damage".into(), fmt_crit_dmg(self.critical_damage)],
             vec!["Penetration".into(), fmt_stat(self.penetration)],
+            vec!["Pen. Overcap".into(), fmt_stat(self.penetration_overcap())],
             vec!["Target Armor".into(), fmt_stat(self.target_armor)],
+            vec![
+                "Target Crit Resist".into(),
+                fmt_stat(self.target_critical_resistance),
+            ],
         ];
+        if self.combat_mode.is_pvp() {
+            data.push(vec!["Combat Mode".into(), self.combat_mode.to_string()]);
+        }
+        if let Some(position) = self.position {
+            data.push(vec!["Position".into(), position.to_string()]);
+        }
 
         write!(
             f,
@@ -166,6 +383,16 @@ mod tests {
         assert_eq!(stats.max_stat(), 50_000.0);
     }
 
+    #[test]
+    fn test_stat_for_health_skill_uses_max_health() {
+        let stats = CharacterStats::default()
+            .with_max_magicka(40_000.0)
+            .with_max_health(30_000.0);
+        assert_eq!(stats.stat_for(Resource::Health), 30_000.0);
+        assert_eq!(stats.stat_for(Resource::Stamina), 40_000.0);
+        assert_eq!(stats.stat_for(Resource::Ultimate), 40_000.0);
+    }
+
     #[test]
     fn test_max_power_weapon_higher() {
         let stats = CharacterStats::default().with_weapon_damage(6_000.0);
@@ -209,6 +436,32 @@ mod tests {
         assert!((stats.critical_chance() - 0.10).abs() < 0.0001);
     }
 
+    #[test]
+    fn test_weapon_and_spell_critical_are_separate() {
+        let stats = CharacterStats::default()
+            .with_critical_rating(3000.0)
+            .with_weapon_critical_rating(2629.0);
+        assert!((stats.spell_critical_chance() - 0.237).abs() < 0.001);
+        assert!(stats.weapon_critical_chance() > stats.spell_critical_chance());
+    }
+
+    #[test]
+    fn test_power_type_follows_higher_damage() {
+        let stats = CharacterStats::default()
+            .with_spell_damage(6_000.0)
+            .with_weapon_critical_rating(2629.0);
+        assert_eq!(stats.best_power_type(), PowerType::Spell);
+        assert_eq!(stats.power_type_for(Resource::Stamina), PowerType::Spell);
+    }
+
+    #[test]
+    fn test_power_type_tie_uses_resource() {
+        let stats = CharacterStats::default().with_spell_critical_rating(2629.0);
+        assert_eq!(stats.power_type_for(Resource::Stamina), PowerType::Weapon);
+        assert_eq!(stats.power_type_for(Resource::Magicka), PowerType::Spell);
+        assert_eq!(stats.power_type_for(Resource::Ultimate), PowerType::Spell);
+    }
+
     #[test]
     fn test_clamp_caps_above_cap() {
         let mut stats = CharacterStats::default().with_critical_damage(3.0);
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Damage taken reduction from Battle Spirit, applied to all damage against
+/// players in Cyrodiil, Imperial City and battlegrounds.
+pub const BATTLE_SPIRIT_DAMAGE_REDUCTION: f64 = 0.50;
+
+/// Whether damage is dealt to monsters (PvE) or to players (PvP).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CombatMode {
+    #[default]
+    Pve,
+    Pvp,
+}
+
+impl CombatMode {
+    /// Multiplier on damage dealt from Battle Spirit (1.0 in PvE).
+    pub fn damage_multiplier(&self) -> f64 {
+        match self {
+            CombatMode::Pve => 1.0,
+            CombatMode::Pvp => 1.0 - BATTLE_SPIRIT_DAMAGE_REDUCTION,
+        }
+    }
+
+    pub fn is_pvp(&self) -> bool {
+        *self == CombatMode::Pvp
+    }
+
+    pub fn parse(s: &str) -> Result<CombatMode, String> {
+        match s.to_lowercase().as_str() {
+            "pve" => Ok(CombatMode::Pve),
+            "pvp" => Ok(CombatMode::Pvp),
+            _ => Err(format!("Unknown combat mode '{}'. Valid: pve, pvp", s)),
+        }
+    }
+}
+
+impl fmt::Display for CombatMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CombatMode::Pve => write!(f, "PvE"),
+            CombatMode::Pvp => write!(f, "PvP"),
+        }
+    }
+}
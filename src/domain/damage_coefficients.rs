@@ -6,13 +6,14 @@ use serde::{Deserialize, Serialize};
 /// base_damage = coef_a * max_stat + coef_b * max_power
 ///
 /// Where:
-/// - max_stat = max(magicka, stamina)
+/// - max_stat = max(magicka, stamina), or max health for health-scaling skills
+///   (`Resource::Health`)
 /// - max_power = max(weapon_damage, spell_damage)
 ///
 /// Coefficients can be found on UESP for each skill.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct DamageCoefficients {
-    /// Coefficient for max_stat (magicka/stamina)
+    /// Coefficient for max_stat (magicka/stamina, or health)
     pub coef_a: f64,
     /// Coefficient for max_power (weapon/spell damage)
     pub coef_b: f64,
@@ -27,7 +28,8 @@ impl DamageCoefficients {
     /// Calculate base damage from character stats
     ///
     /// # Arguments
-    /// * `max_stat` - The higher of max_magicka and max_stamina
+    /// * `max_stat` - The higher of max_magicka and max_stamina (max_health for
+    ///   health-scaling skills, see `CharacterStats::stat_for`)
     /// * `max_power` - The higher of weapon_damage and spell_damage
     pub fn calculate_base_damage(&self, max_stat: f64, max_power: f64) -> f64 {
         self.coef_a * max_stat + self.coef_b * max_power
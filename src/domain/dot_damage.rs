@@ -12,9 +12,6 @@ pub struct DotDamage {
     /// Defaults to duration if not specified (total damage over duration)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub interval: Option<f64>,
-    /// Percentage increase per tick (e.g., 0.12 for 12%)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub increase_per_tick: Option<f64>,
     /// Flat increase per tick
     #[serde(skip_serializing_if = "Option::is_none")]
     pub flat_increase_per_tick: Option<f64>,
@@ -31,7 +28,6 @@ impl DotDamage {
             coefficients: DamageCoefficients::new(coef_a, coef_b),
             delay: None,
             interval: None,
-            increase_per_tick: None,
             flat_increase_per_tick: None,
             ignores_modifier: None,
         }
@@ -42,11 +38,6 @@ impl DotDamage {
         self
     }
 
-    pub fn with_increase_per_tick(mut self, increase: f64) -> Self {
-        self.increase_per_tick = Some(increase);
-        self
-    }
-
     pub fn with_flat_increase_per_tick(mut self, increase: f64) -> Self {
         self.flat_increase_per_tick = Some(increase);
         self
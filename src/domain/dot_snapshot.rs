@@ -0,0 +1,118 @@
+use bitflags::bitflags;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+bitflags! {
+    /// Parts of a DoT locked in at cast time. Parts left out are re-read on every tick.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+    pub struct DotSnapshot: u8 {
+        /// Weapon/spell damage and max stat (the tick's base value)
+        const POWER        = 0b0000_0001;
+        /// Damage done buffs and passives
+        const DAMAGE_DONE  = 0b0000_0010;
+        /// Enemy damage taken debuffs
+        const DAMAGE_TAKEN = 0b0000_0100;
+        /// Enemy armor after penetration
+        const ARMOR        = 0b0000_1000;
+        /// Critical chance and damage
+        const CRITICAL     = 0b0001_0000;
+    }
+}
+
+const PART_NAMES: [(DotSnapshot, &str); 5] = [
+    (DotSnapshot::POWER, "power"),
+    (DotSnapshot::DAMAGE_DONE, "done"),
+    (DotSnapshot::DAMAGE_TAKEN, "taken"),
+    (DotSnapshot::ARMOR, "armor"),
+    (DotSnapshot::CRITICAL, "crit"),
+];
+
+impl DotSnapshot {
+    /// Character stats snapshot; buffs, debuffs and armor update per tick.
+    pub fn stats() -> Self {
+        Self::POWER | Self::CRITICAL
+    }
+
+    /// Parse a preset (snapshot, dynamic, stats) or '+'-separated parts
+    /// (power, done, taken, armor, crit) that snapshot.
+    pub fn parse(s: &str) -> Result<DotSnapshot, String> {
+        match s.to_lowercase().as_str() {
+            "snapshot" => return Ok(DotSnapshot::all()),
+            "dynamic" => return Ok(DotSnapshot::empty()),
+            "stats" => return Ok(DotSnapshot::stats()),
+            _ => {}
+        }
+        s.split('+').try_fold(DotSnapshot::empty(), |parts, name| {
+            let name = name.trim().to_lowercase();
+            PART_NAMES
+                .iter()
+                .find(|(_, n)| *n == name)
+                .map(|(part, _)| parts | *part)
+                .ok_or_else(|| {
+                    format!(
+                        "Unknown DoT snapshot '{}'. Valid: snapshot, dynamic, stats or parts \
+                         joined with '+' (power, done, taken, armor, crit)",
+                        s
+                    )
+                })
+        })
+    }
+}
+
+impl Default for DotSnapshot {
+    fn default() -> Self {
+        DotSnapshot::all()
+    }
+}
+
+impl fmt::Display for DotSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_all() {
+            return write!(f, "snapshot");
+        }
+        if self.is_empty() {
+            return write!(f, "dynamic");
+        }
+        let names: Vec<&str> = PART_NAMES
+            .iter()
+            .filter(|(part, _)| self.contains(*part))
+            .map(|(_, name)| *name)
+            .collect();
+        write!(f, "{}", names.join("+"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_presets() {
+        assert_eq!(DotSnapshot::parse("snapshot"), Ok(DotSnapshot::all()));
+        assert_eq!(DotSnapshot::parse("Dynamic"), Ok(DotSnapshot::empty()));
+        assert_eq!(
+            DotSnapshot::parse("stats"),
+            Ok(DotSnapshot::POWER | DotSnapshot::CRITICAL)
+        );
+    }
+
+    #[test]
+    fn test_parse_parts() {
+        assert_eq!(
+            DotSnapshot::parse("power+done"),
+            Ok(DotSnapshot::POWER | DotSnapshot::DAMAGE_DONE)
+        );
+        assert!(DotSnapshot::parse("power+speed").is_err());
+    }
+
+    #[test]
+    fn test_display_round_trips() {
+        for parts in [
+            DotSnapshot::all(),
+            DotSnapshot::empty(),
+            DotSnapshot::ARMOR | DotSnapshot::DAMAGE_TAKEN,
+        ] {
+            assert_eq!(DotSnapshot::parse(&parts.to_string()), Ok(parts));
+        }
+    }
+}
@@ -0,0 +1,284 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use super::simulation::parse_seconds;
+use super::Enemy;
+
+/// When an encounter phase begins.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PhaseStart {
+    /// Seconds after the pull
+    Time(f64),
+    /// Boss health fraction (0.0 - 1.0) the phase starts at
+    HealthPct(f64),
+}
+
+/// What happens during an encounter phase.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub enum PhaseKind {
+    /// Boss cannot be damaged (flying, shielded). Nothing is cast and damage to it is lost.
+    Immune,
+    /// Forced movement to a mechanic. Nothing is cast, running DoTs keep hitting.
+    Movement,
+    /// Bar swapping is blocked by a mechanic. The rotation continues on the current bar.
+    BarLocked,
+    /// Adds that take area damage alongside the boss until their health runs out
+    Adds {
+        name: String,
+        count: u32,
+        /// Health of each add left for this player to burn (its share of the
+        /// group's damage)
+        hp: f64,
+    },
+}
+
+/// A scripted part of an encounter: adds spawning, immune or movement windows.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EncounterPhase {
+    #[serde(flatten)]
+    pub kind: PhaseKind,
+    pub start: PhaseStart,
+    /// Seconds the phase lasts (adds: until the rest of the group has killed
+    /// them, if this player's area damage has not)
+    pub duration: f64,
+    /// Repeat interval in seconds for time-started phases
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub every: Option<f64>,
+    /// DoTs on the boss expire when the phase starts (e.g. it teleports away)
+    /// instead of ticking through it
+    #[serde(default)]
+    pub clears_dots: bool,
+}
+
+impl EncounterPhase {
+    /// Parse a downtime window `KIND@START+DURATION[/EVERY][:clear-dots]`.
+    /// Kinds are `immune`, `movement` and `bar-locked`; START is a time or a
+    /// boss health percentage (e.g. `movement@45s+4s/50s`, `immune@50%+20s`).
+    pub fn parse(s: &str) -> Result<EncounterPhase, String> {
+        let trimmed = s.trim().to_lowercase();
+        let invalid = || {
+            format!(
+                "Unknown downtime '{}'. Expected KIND@START+DURATION[/EVERY][:clear-dots] \
+                 (e.g. movement@45s+4s/50s, immune@50%+20s)",
+                s
+            )
+        };
+        let (spec, clears_dots) = match trimmed.strip_suffix(":clear-dots") {
+            Some(spec) => (spec, true),
+            None => (trimmed.as_str(), false),
+        };
+        let (kind, timing) = spec.split_once('@').ok_or_else(invalid)?;
+        let kind = match kind {
+            "immune" => PhaseKind::Immune,
+            "movement" => PhaseKind::Movement,
+            "bar-locked" => PhaseKind::BarLocked,
+            _ => {
+                return Err(format!(
+                    "Unknown downtime kind '{}'. Valid: immune, movement, bar-locked",
+                    kind
+                ))
+            }
+        };
+        let (start, rest) = timing.split_once('+').ok_or_else(invalid)?;
+        let (duration, every) = match rest.split_once('/') {
+            Some((duration, every)) => (duration, Some(parse_seconds(every)?)),
+            None => (rest, None),
+        };
+        let start = match start.strip_suffix('%') {
+            Some(pct) => PhaseStart::HealthPct(pct.parse::<f64>().map_err(|_| invalid())? / 100.0),
+            None => PhaseStart::Time(parse_seconds(start)?),
+        };
+        let phase = EncounterPhase {
+            kind,
+            start,
+            duration: parse_seconds(duration)?,
+            every,
+            clears_dots,
+        };
+        phase.validate()?;
+        Ok(phase)
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.duration <= 0.0 {
+            return Err("phase durations must be positive".to_string());
+        }
+        if let PhaseKind::Adds { hp, .. } = self.kind {
+            if hp <= 0.0 || !hp.is_finite() {
+                return Err("adds must have positive HP".to_string());
+            }
+        }
+        if self.every.is_some_and(|every| every <= self.duration) {
+            return Err("a phase must repeat less often than it lasts".to_string());
+        }
+        if let PhaseStart::HealthPct(pct) = self.start {
+            if !(0.0..=1.0).contains(&pct) {
+                return Err("health phases start between 0% and 100%".to_string());
+            }
+            if self.every.is_some() {
+                return Err("only time-started phases can repeat".to_string());
+            }
+        }
+        Ok(())
+    }
+
+    /// Immune and movement phases stop the rotation (bar-locked phases do not).
+    pub fn is_downtime(&self) -> bool {
+        matches!(self.kind, PhaseKind::Immune | PhaseKind::Movement)
+    }
+
+    /// Timeline description of the phase starting.
+    pub fn description(&self) -> String {
+        let description = match &self.kind {
+            PhaseKind::Immune => format!("Boss immune for {}s", self.duration),
+            PhaseKind::Movement => format!("Movement for {}s", self.duration),
+            PhaseKind::BarLocked => format!("Bar locked for {}s", self.duration),
+            PhaseKind::Adds { name, count, .. } => format!("{}x {} spawn", count, name),
+        };
+        if self.clears_dots {
+            format!("{} (DoTs expire)", description)
+        } else {
+            description
+        }
+    }
+}
+
+/// Boss behaviour once it drops below a health threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ExecutePhase {
+    /// Boss health fraction (0.0 - 1.0) the execute phase starts at
+    pub health_pct: f64,
+    /// Extra damage taken by the boss during the execute phase (e.g. 0.1 for 10%)
+    #[serde(default)]
+    pub damage_taken: f64,
+}
+
+/// A scripted boss fight played out by the fight simulator.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Encounter {
+    /// Name used to select the encounter (e.g. "rockgrove-oax")
+    pub id: String,
+    pub name: String,
+    /// Boss health
+    pub hp: f64,
+    /// Boss resistances and the armor debuffs the group keeps on it
+    pub enemy: Enemy,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub phases: Vec<EncounterPhase>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub execute: Option<ExecutePhase>,
+}
+
+impl Encounter {
+    pub fn from_json(json: &str) -> Result<Encounter, String> {
+        let encounter: Encounter =
+            serde_json::from_str(json).map_err(|e| format!("Invalid encounter: {}", e))?;
+        encounter.validate()?;
+        Ok(encounter)
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.hp <= 0.0 || !self.hp.is_finite() {
+            return Err(format!("Encounter '{}' must have positive HP", self.id));
+        }
+        for phase in &self.phases {
+            phase
+                .validate()
+                .map_err(|e| format!("Encounter '{}': {}", self.id, e))?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Encounter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_json_phases() {
+        let encounter = Encounter::from_json(
+            r#"{
+                "id": "test-boss",
+                "name": "Test Boss",
+                "hp": 5000000,
+                "enemy": { "name": "Test Boss", "armor": 18200 },
+                "phases": [
+                    { "type": "adds", "name": "Imp", "count": 3, "hp": 200000, "start": { "time": 10 }, "duration": 8, "every": 30 },
+                    { "type": "immune", "start": { "health-pct": 0.5 }, "duration": 12 }
+                ],
+                "execute": { "health_pct": 0.2, "damage_taken": 0.1 }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(encounter.phases.len(), 2);
+        assert_eq!(
+            encounter.phases[0].kind,
+            PhaseKind::Adds {
+                name: "Imp".to_string(),
+                count: 3,
+                hp: 200_000.0
+            }
+        );
+        assert!(!encounter.phases[0].is_downtime());
+        assert_eq!(encounter.phases[1].start, PhaseStart::HealthPct(0.5));
+        assert!(encounter.phases[1].is_downtime());
+        assert_eq!(encounter.execute.unwrap().damage_taken, 0.1);
+    }
+
+    #[test]
+    fn test_builtin_encounters_load() {
+        let oax = Encounter::parse("rockgrove-oax").unwrap();
+        assert_eq!(oax.name, "Oaxiltso (Rockgrove)");
+        assert!(!oax.phases.is_empty());
+        assert!(Encounter::parse("sunspire-lokkestiiz").is_ok());
+        assert!(Encounter::parse("unknown-boss").is_err());
+    }
+
+    #[test]
+    fn test_from_json_rejects_repeating_health_phase() {
+        let result = Encounter::from_json(
+            r#"{
+                "id": "test-boss",
+                "name": "Test Boss",
+                "hp": 5000000,
+                "enemy": { "name": "Test Boss", "armor": 18200 },
+                "phases": [
+                    { "type": "movement", "start": { "health-pct": 0.5 }, "duration": 3, "every": 20 }
+                ]
+            }"#,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_downtime() {
+        let movement = EncounterPhase::parse("movement@45s+4s/50s").unwrap();
+        assert_eq!(movement.kind, PhaseKind::Movement);
+        assert_eq!(movement.start, PhaseStart::Time(45.0));
+        assert_eq!(movement.duration, 4.0);
+        assert_eq!(movement.every, Some(50.0));
+        assert!(!movement.clears_dots);
+
+        let immune = EncounterPhase::parse("immune@50%+20s:clear-dots").unwrap();
+        assert_eq!(immune.start, PhaseStart::HealthPct(0.5));
+        assert!(immune.clears_dots);
+        assert!(immune.is_downtime());
+
+        let locked = EncounterPhase::parse("bar-locked@1m+8").unwrap();
+        assert_eq!(locked.start, PhaseStart::Time(60.0));
+        assert!(!locked.is_downtime());
+
+        assert!(EncounterPhase::parse("nap@10s+5s").is_err());
+        assert!(EncounterPhase::parse("movement@10s").is_err());
+        assert!(EncounterPhase::parse("movement@10s+5s/4s").is_err());
+        assert!(EncounterPhase::parse("immune@50%+20s/60s").is_err());
+    }
+}
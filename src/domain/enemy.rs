@@ -0,0 +1,213 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use super::{
+    BonusData, BonusSource, BonusTarget, BonusTrigger, BonusValue, CharacterStats, CombatMode,
+};
+
+/// Base Physical and Spell Resistance of trial bosses and dummies at CP160.
+pub const DEFAULT_ENEMY_ARMOR: f64 = 18_200.0;
+/// Resistances of a typical Cyrodiil damage dealer (medium armor, Resolve, Armor Master).
+pub const PVP_PLAYER_ARMOR: f64 = 26_000.0;
+/// Critical resistance of a typical Cyrodiil damage dealer (Impenetrable gear, CP).
+pub const PVP_PLAYER_CRITICAL_RESISTANCE: f64 = 2_640.0;
+/// Resistances of a heavy armor PvP tank, at the 33,000 soft cap.
+pub const PVP_TANK_ARMOR: f64 = 33_000.0;
+pub const PVP_TANK_CRITICAL_RESISTANCE: f64 = 4_620.0;
+
+/// A debuff that strips resistance from the enemy for part of the fight.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArmorDebuff {
+    pub name: String,
+    /// Resistance removed while active
+    pub value: f64,
+    /// Fraction of the fight the debuff is up (0.0 - 1.0)
+    #[serde(default = "full_uptime")]
+    pub uptime: f64,
+}
+
+fn full_uptime() -> f64 {
+    1.0
+}
+
+impl ArmorDebuff {
+    pub fn new(name: &str, value: f64, uptime: f64) -> Self {
+        Self {
+            name: name.to_string(),
+            value,
+            uptime: uptime.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Up for the whole fight, so it can be folded into penetration.
+    pub fn is_permanent(&self) -> bool {
+        self.uptime >= 1.0
+    }
+}
+
+/// The target being damaged: its resistances and the armor debuffs applied to it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Enemy {
+    pub name: String,
+    /// Physical and Spell Resistance before debuffs and penetration
+    pub armor: f64,
+    /// Reduces the bonus damage of critical hits
+    #[serde(default)]
+    pub critical_resistance: f64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub armor_debuffs: Vec<ArmorDebuff>,
+    /// Players are hit in PvP, under Battle Spirit
+    #[serde(default)]
+    pub combat_mode: CombatMode,
+}
+
+impl Enemy {
+    /// 21M trial dummy: the group's Major/Minor Breach, Crusher and Alkosh stay up permanently.
+    pub fn trial_dummy() -> Self {
+        Self {
+            name: "Trial Dummy".to_string(),
+            armor: DEFAULT_ENEMY_ARMOR,
+            critical_resistance: 0.0,
+            armor_debuffs: vec![
+                ArmorDebuff::new("Major Breach", 5948.0, 1.0),
+                ArmorDebuff::new("Minor Breach", 2974.0, 1.0),
+                ArmorDebuff::new("Infused Crusher", 2108.0, 1.0),
+                ArmorDebuff::new("Roar of Alkosh", 6000.0, 1.0),
+            ],
+            combat_mode: CombatMode::Pve,
+        }
+    }
+
+    /// Solo target dummy with nothing debuffing it.
+    pub fn target_dummy() -> Self {
+        Self {
+            name: "Target Dummy".to_string(),
+            armor: DEFAULT_ENEMY_ARMOR,
+            critical_resistance: 0.0,
+            armor_debuffs: Vec::new(),
+            combat_mode: CombatMode::Pve,
+        }
+    }
+
+    /// Cyrodiil damage dealer: player resistances and critical resistance, no
+    /// group debuffs, Battle Spirit applies.
+    pub fn pvp_player() -> Self {
+        Self {
+            name: "PvP Player".to_string(),
+            armor: PVP_PLAYER_ARMOR,
+            critical_resistance: PVP_PLAYER_CRITICAL_RESISTANCE,
+            armor_debuffs: Vec::new(),
+            combat_mode: CombatMode::Pvp,
+        }
+    }
+
+    /// Heavy armor PvP tank at the resistance soft cap.
+    pub fn pvp_tank() -> Self {
+        Self {
+            name: "PvP Tank".to_string(),
+            armor: PVP_TANK_ARMOR,
+            critical_resistance: PVP_TANK_CRITICAL_RESISTANCE,
+            armor_debuffs: Vec::new(),
+            combat_mode: CombatMode::Pvp,
+        }
+    }
+
+    /// Enemy used when none is configured: the trial dummy when trial buffs
+    /// are enabled, otherwise a bare target dummy.
+    pub fn default_for(trial: bool) -> Self {
+        if trial {
+            Self::trial_dummy()
+        } else {
+            Self::target_dummy()
+        }
+    }
+
+    pub fn with_armor(mut self, value: f64) -> Self {
+        self.armor = value;
+        self
+    }
+
+    pub fn with_critical_resistance(mut self, value: f64) -> Self {
+        self.critical_resistance = value;
+        self
+    }
+
+    pub fn with_armor_debuff(mut self, debuff: ArmorDebuff) -> Self {
+        self.armor_debuffs.push(debuff);
+        self
+    }
+
+    pub fn with_combat_mode(mut self, mode: CombatMode) -> Self {
+        self.combat_mode = mode;
+        self
+    }
+
+    pub fn is_pvp(&self) -> bool {
+        self.combat_mode.is_pvp()
+    }
+
+    /// Armor debuffs as enemy resistance reduction bonuses. They share names
+    /// with the skills and sets that apply them so a build providing the same
+    /// debuff does not count it twice. Debuffs with partial uptime carry no
+    /// resistance here: `apply_to` weights them into the damage factor instead.
+    pub fn armor_debuff_bonuses(&self) -> Vec<BonusData> {
+        self.armor_debuffs
+            .iter()
+            .map(|d| {
+                BonusData::new(
+                    &d.name,
+                    BonusSource::Buff,
+                    BonusTrigger::Passive,
+                    BonusValue::new(
+                        &d.name,
+                        BonusTarget::EnemyResistanceReduction,
+                        if d.is_permanent() { d.value } else { 0.0 },
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    pub fn armor_debuff_names(&self) -> impl Iterator<Item = String> + '_ {
+        self.armor_debuffs.iter().map(|d| d.name.clone())
+    }
+
+    /// Set the target stats (armor, critical resistance, part-time armor
+    /// debuffs, combat mode) of `stats` to this enemy's.
+    pub fn apply_to(&self, stats: &mut CharacterStats) {
+        stats.target_armor = self.armor;
+        stats.target_critical_resistance = self.critical_resistance;
+        stats.target_armor_debuffs = self
+            .armor_debuffs
+            .iter()
+            .filter(|d| !d.is_permanent())
+            .cloned()
+            .collect();
+        stats.combat_mode = self.combat_mode;
+    }
+
+    pub fn parse(s: &str) -> Result<Enemy, String> {
+        match s.to_lowercase().replace(' ', "-").as_str() {
+            "trial-dummy" | "trial" => Ok(Enemy::trial_dummy()),
+            "target-dummy" | "dummy" => Ok(Enemy::target_dummy()),
+            "pvp-player" | "player" => Ok(Enemy::pvp_player()),
+            "pvp-tank" => Ok(Enemy::pvp_tank()),
+            _ => Err(format!(
+                "Unknown enemy '{}'. Valid: trial-dummy, target-dummy, pvp-player, pvp-tank",
+                s
+            )),
+        }
+    }
+}
+
+impl Default for Enemy {
+    fn default() -> Self {
+        Self::trial_dummy()
+    }
+}
+
+impl fmt::Display for Enemy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
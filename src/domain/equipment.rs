@@ -248,6 +248,86 @@ impl fmt::Display for JewelryTrait {
     }
 }
 
+/// Glyph slotted in an armor piece.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ArmorGlyph {
+    Magicka,
+    Stamina,
+    Health,
+}
+
+/// DPS-relevant armor glyphs for gear optimization.
+pub const DPS_ARMOR_GLYPHS: &[ArmorGlyph] = &[ArmorGlyph::Magicka, ArmorGlyph::Stamina];
+
+impl ArmorGlyph {
+    pub fn parse(s: &str) -> Result<ArmorGlyph, String> {
+        match s.to_lowercase().as_str() {
+            "magicka" | "mag" => Ok(ArmorGlyph::Magicka),
+            "stamina" | "stam" => Ok(ArmorGlyph::Stamina),
+            "health" => Ok(ArmorGlyph::Health),
+            _ => Err(format!(
+                "Unknown armor glyph '{}'. Valid: magicka, stamina, health",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ArmorGlyph {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArmorGlyph::Magicka => write!(f, "Magicka"),
+            ArmorGlyph::Stamina => write!(f, "Stamina"),
+            ArmorGlyph::Health => write!(f, "Health"),
+        }
+    }
+}
+
+/// Glyph slotted in a jewelry piece.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum JewelryGlyph {
+    /// Increase Physical Harm: adds Weapon Damage
+    PhysicalHarm,
+    /// Increase Magical Harm: adds Spell Damage
+    MagicalHarm,
+    MagickaRecovery,
+    StaminaRecovery,
+    HealthRecovery,
+}
+
+/// DPS-relevant jewelry glyphs for gear optimization.
+pub const DPS_JEWELRY_GLYPHS: &[JewelryGlyph] =
+    &[JewelryGlyph::PhysicalHarm, JewelryGlyph::MagicalHarm];
+
+impl JewelryGlyph {
+    pub fn parse(s: &str) -> Result<JewelryGlyph, String> {
+        match s.to_lowercase().as_str() {
+            "physical-harm" | "weapon-damage" => Ok(JewelryGlyph::PhysicalHarm),
+            "magical-harm" | "spell-damage" => Ok(JewelryGlyph::MagicalHarm),
+            "magicka-recovery" | "magicka-regen" => Ok(JewelryGlyph::MagickaRecovery),
+            "stamina-recovery" | "stamina-regen" => Ok(JewelryGlyph::StaminaRecovery),
+            "health-recovery" | "health-regen" => Ok(JewelryGlyph::HealthRecovery),
+            _ => Err(format!(
+                "Unknown jewelry glyph '{}'. Valid: physical-harm, magical-harm, \
+                 magicka-recovery, stamina-recovery, health-recovery",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for JewelryGlyph {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JewelryGlyph::PhysicalHarm => write!(f, "Physical Harm"),
+            JewelryGlyph::MagicalHarm => write!(f, "Magical Harm"),
+            JewelryGlyph::MagickaRecovery => write!(f, "Magicka Recovery"),
+            JewelryGlyph::StaminaRecovery => write!(f, "Stamina Recovery"),
+            JewelryGlyph::HealthRecovery => write!(f, "Health Recovery"),
+        }
+    }
+}
+
 /// Weapon trait applied to the equipped weapon(s).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum WeaponTrait {
@@ -306,6 +386,12 @@ impl fmt::Display for WeaponTrait {
     }
 }
 
+/// Attribute points available at max level.
+pub const ATTRIBUTE_POINTS: u8 = 64;
+
+/// Granularity of the attribute split search and the health cost report.
+pub const ATTRIBUTE_SPLIT_STEP: u8 = 8;
+
 /// Attribute point allocation choice.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 pub enum AttributeChoice {
@@ -313,6 +399,12 @@ pub enum AttributeChoice {
     None,
     Magicka,
     Stamina,
+    /// Arbitrary split of the attribute points (sum ≤ 64)
+    Split {
+        magicka: u8,
+        stamina: u8,
+        health: u8,
+    },
 }
 
 /// DPS-relevant attribute choices for gear optimization.
@@ -322,12 +414,286 @@ pub const DPS_ATTRIBUTES: &[AttributeChoice] = &[
     AttributeChoice::Stamina,
 ];
 
+impl AttributeChoice {
+    /// Build a choice from a point split, collapsing all-in allocations to
+    /// `Magicka`/`Stamina`/`None`.
+    pub fn from_points(magicka: u8, stamina: u8, health: u8) -> AttributeChoice {
+        match (magicka, stamina, health) {
+            (0, 0, 0) => AttributeChoice::None,
+            (ATTRIBUTE_POINTS, 0, 0) => AttributeChoice::Magicka,
+            (0, ATTRIBUTE_POINTS, 0) => AttributeChoice::Stamina,
+            _ => AttributeChoice::Split {
+                magicka,
+                stamina,
+                health,
+            },
+        }
+    }
+
+    /// Points spent as (magicka, stamina, health).
+    pub fn points(&self) -> (u8, u8, u8) {
+        match *self {
+            AttributeChoice::None => (0, 0, 0),
+            AttributeChoice::Magicka => (ATTRIBUTE_POINTS, 0, 0),
+            AttributeChoice::Stamina => (0, ATTRIBUTE_POINTS, 0),
+            AttributeChoice::Split {
+                magicka,
+                stamina,
+                health,
+            } => (magicka, stamina, health),
+        }
+    }
+
+    /// All magicka/stamina/health splits of the attribute points in `ATTRIBUTE_SPLIT_STEP` increments.
+    pub fn splits() -> Vec<AttributeChoice> {
+        let mut splits = Vec::new();
+        for magicka in (0..=ATTRIBUTE_POINTS).step_by(ATTRIBUTE_SPLIT_STEP as usize) {
+            for stamina in (0..=ATTRIBUTE_POINTS - magicka).step_by(ATTRIBUTE_SPLIT_STEP as usize) {
+                let health = ATTRIBUTE_POINTS - magicka - stamina;
+                splits.push(AttributeChoice::from_points(magicka, stamina, health));
+            }
+        }
+        splits
+    }
+
+    /// Move `points` into health, taken from the larger of magicka and stamina first.
+    pub fn with_health_points(&self, points: u8) -> AttributeChoice {
+        let (mut magicka, mut stamina, mut health) = self.points();
+        for _ in 0..points {
+            if magicka == 0 && stamina == 0 {
+                break;
+            }
+            if magicka > stamina {
+                magicka -= 1;
+            } else {
+                stamina -= 1;
+            }
+            health += 1;
+        }
+        AttributeChoice::from_points(magicka, stamina, health)
+    }
+
+    /// Parse "none", "magicka", "stamina" or a magicka/stamina/health split like
+    /// "40/24/0" (the displayed "40M/24S/0H" form is accepted too).
+    pub fn parse(s: &str) -> Result<AttributeChoice, String> {
+        let s = s.trim().to_lowercase();
+        match s.as_str() {
+            "none" => return Ok(AttributeChoice::None),
+            "magicka" => return Ok(AttributeChoice::Magicka),
+            "stamina" => return Ok(AttributeChoice::Stamina),
+            _ => {}
+        }
+        let parts: Vec<&str> = s.split('/').collect();
+        if parts.len() != 3 {
+            return Err(format!(
+                "Invalid attributes '{}'. Valid: none, magicka, stamina or magicka/stamina/health points (e.g. 40/24/0)",
+                s
+            ));
+        }
+        let mut points = [0u8; 3];
+        for (i, (name, suffix)) in [("magicka", 'm'), ("stamina", 's'), ("health", 'h')]
+            .iter()
+            .enumerate()
+        {
+            let part = parts[i].trim();
+            points[i] = part
+                .strip_suffix(*suffix)
+                .unwrap_or(part)
+                .parse()
+                .map_err(|_| format!("Invalid {} attribute points: '{}'", name, part))?;
+        }
+        let sum: u32 = points.iter().map(|&p| p as u32).sum();
+        if sum > ATTRIBUTE_POINTS as u32 {
+            return Err(format!(
+                "Attribute point total must be ≤ {}, got {} ({}/{}/{})",
+                ATTRIBUTE_POINTS, sum, points[0], points[1], points[2]
+            ));
+        }
+        Ok(AttributeChoice::from_points(
+            points[0], points[1], points[2],
+        ))
+    }
+}
+
 impl fmt::Display for AttributeChoice {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             AttributeChoice::None => write!(f, "None"),
             AttributeChoice::Magicka => write!(f, "Magicka"),
             AttributeChoice::Stamina => write!(f, "Stamina"),
+            AttributeChoice::Split {
+                magicka,
+                stamina,
+                health,
+            } => write!(f, "{}M/{}S/{}H", magicka, stamina, health),
+        }
+    }
+}
+
+/// Item quality of a gear piece. Stat values in `BuildConfig` are tuned for Legendary.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Serialize, Deserialize,
+)]
+pub enum GearQuality {
+    /// White
+    Normal,
+    /// Green
+    Fine,
+    /// Blue
+    Superior,
+    /// Purple
+    Epic,
+    /// Gold
+    #[default]
+    Legendary,
+}
+
+impl GearQuality {
+    /// Approximate multiplier on quality-dependent stats (base weapon damage,
+    /// glyphs and traits) relative to Legendary.
+    pub fn scale(&self) -> f64 {
+        match self {
+            GearQuality::Normal => 0.80,
+            GearQuality::Fine => 0.85,
+            GearQuality::Superior => 0.90,
+            GearQuality::Epic => 0.95,
+            GearQuality::Legendary => 1.0,
+        }
+    }
+
+    pub fn parse(s: &str) -> Result<GearQuality, String> {
+        match s.to_lowercase().as_str() {
+            "normal" | "white" => Ok(GearQuality::Normal),
+            "fine" | "green" => Ok(GearQuality::Fine),
+            "superior" | "blue" => Ok(GearQuality::Superior),
+            "epic" | "purple" => Ok(GearQuality::Epic),
+            "legendary" | "gold" => Ok(GearQuality::Legendary),
+            _ => Err(format!(
+                "Unknown gear quality '{}'. Valid: normal, fine, superior, epic, legendary",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for GearQuality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GearQuality::Normal => write!(f, "Normal"),
+            GearQuality::Fine => write!(f, "Fine"),
+            GearQuality::Superior => write!(f, "Superior"),
+            GearQuality::Epic => write!(f, "Epic"),
+            GearQuality::Legendary => write!(f, "Legendary"),
+        }
+    }
+}
+
+/// Highest item level (CP160).
+pub const MAX_GEAR_LEVEL: u16 = 160;
+
+/// Stat scale of level 50 (CP0) gear relative to CP160.
+const LEVEL_50_SCALE: f64 = 0.80;
+
+/// Multiplier on level-dependent stats (everything quality scales, plus set
+/// bonuses) for gear at the given champion item level (0 = level 50, max 160).
+/// Interpolates linearly between the level 50 and CP160 values.
+pub fn gear_level_scale(level: u16) -> f64 {
+    let progress = level.min(MAX_GEAR_LEVEL) as f64 / MAX_GEAR_LEVEL as f64;
+    LEVEL_50_SCALE + (1.0 - LEVEL_50_SCALE) * progress
+}
+
+/// Parse an item level like "160", "cp160" or "cp 150" (0 = level 50 gear).
+pub fn parse_gear_level(s: &str) -> Result<u16, String> {
+    let digits = s.trim().to_lowercase().replace("cp", "");
+    match digits.trim().parse::<u16>() {
+        Ok(level) if level <= MAX_GEAR_LEVEL && level % 10 == 0 => Ok(level),
+        _ => Err(format!(
+            "Invalid gear level '{}'. Valid: 0 (level 50) to {} in steps of 10",
+            s, MAX_GEAR_LEVEL
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attribute_choice_parse_round_trips() {
+        for choice in [
+            AttributeChoice::None,
+            AttributeChoice::Magicka,
+            AttributeChoice::Stamina,
+            AttributeChoice::from_points(40, 16, 8),
+        ] {
+            assert_eq!(AttributeChoice::parse(&choice.to_string()), Ok(choice));
+        }
+        assert_eq!(
+            AttributeChoice::parse("40/24/0"),
+            Ok(AttributeChoice::from_points(40, 24, 0))
+        );
+    }
+
+    #[test]
+    fn test_attribute_choice_parse_rejects_invalid_totals() {
+        assert!(AttributeChoice::parse("40/24/8").is_err());
+        assert!(AttributeChoice::parse("40/24").is_err());
+        assert!(AttributeChoice::parse("40/x/0").is_err());
+        // Unspent points are allowed
+        assert!(AttributeChoice::parse("32/0/0").is_ok());
+    }
+
+    #[test]
+    fn test_attribute_choice_from_points_collapses_all_in() {
+        assert_eq!(AttributeChoice::from_points(0, 0, 0), AttributeChoice::None);
+        assert_eq!(
+            AttributeChoice::from_points(ATTRIBUTE_POINTS, 0, 0),
+            AttributeChoice::Magicka
+        );
+        assert_eq!(
+            AttributeChoice::from_points(0, ATTRIBUTE_POINTS, 0),
+            AttributeChoice::Stamina
+        );
+        assert_eq!(
+            AttributeChoice::from_points(0, 0, ATTRIBUTE_POINTS).points(),
+            (0, 0, ATTRIBUTE_POINTS)
+        );
+    }
+
+    #[test]
+    fn test_with_health_points_takes_from_larger_pool() {
+        assert_eq!(
+            AttributeChoice::Stamina.with_health_points(8).points(),
+            (0, 56, 8)
+        );
+        assert_eq!(
+            AttributeChoice::from_points(30, 34, 0)
+                .with_health_points(8)
+                .points(),
+            (28, 28, 8)
+        );
+        // Never moves more points than magicka and stamina hold
+        assert_eq!(
+            AttributeChoice::from_points(4, 0, 60)
+                .with_health_points(8)
+                .points(),
+            (0, 0, ATTRIBUTE_POINTS)
+        );
+    }
+
+    #[test]
+    fn test_splits_cover_every_step_combination() {
+        let splits = AttributeChoice::splits();
+        // One split per magicka/stamina step pair that fits in the points
+        let steps = (ATTRIBUTE_POINTS / ATTRIBUTE_SPLIT_STEP) as usize + 1;
+        assert_eq!(splits.len(), steps * (steps + 1) / 2);
+        for split in &splits {
+            let (magicka, stamina, health) = split.points();
+            assert_eq!(magicka + stamina + health, ATTRIBUTE_POINTS);
+            assert_eq!(magicka % ATTRIBUTE_SPLIT_STEP, 0);
+            assert_eq!(stamina % ATTRIBUTE_SPLIT_STEP, 0);
         }
+        assert!(splits.contains(&AttributeChoice::Magicka));
+        assert!(splits.contains(&AttributeChoice::Stamina));
     }
 }
@@ -30,7 +30,7 @@ impl Food {
             Food::BewitchedSugarSkulls => {
                 stats.max_stamina += 4_250.0;
                 stats.max_magicka += 4_250.0;
-                // +4,620 health not modeled
+                stats.max_health += 4_620.0;
             }
         }
     }
@@ -4,7 +4,7 @@
 //! between different ESO attribute representations (ratings to percentages, etc.)
 
 use super::character_stats::{MAX_CRITICAL_CHANCE, MAX_CRITICAL_DAMAGE};
-use super::{BonusTarget, CharacterStats};
+use super::{BonusTarget, CharacterStats, CombatMode};
 
 // ==================== CONSTANTS ====================
 
@@ -21,6 +21,9 @@ pub const BASE_CRIT_CHANCE: f64 = 0.10;
 /// Used in armor mitigation calculations at CP160
 pub const ARMOR_LEVEL_CONSTANT: f64 = 3_300.0;
 
+/// Critical resistance per 1% of critical damage bonus removed
+pub const CRITICAL_RESISTANCE_PER_PERCENT: f64 = 660.0;
+
 // ==================== CRITICAL ====================
 
 /// Converts critical rating to total critical chance (includes base 10%)
@@ -48,6 +51,12 @@ pub fn effective_armor(armor: f64, penetration: f64) -> f64 {
     (armor - penetration).max(0.0)
 }
 
+/// Penetration beyond the target's armor. Armor cannot drop below 0, so
+/// this part adds nothing to `armor_damage_factor`.
+pub fn penetration_overcap(armor: f64, penetration: f64) -> f64 {
+    (penetration - armor).max(0.0)
+}
+
 // ==================== RESOURCE TO DAMAGE ====================
 
 /// Convert stamina/magicka resource to equivalent weapon/spell damage bonus.
@@ -69,6 +78,13 @@ pub fn critical_multiplier(crit_chance: f64, crit_damage: f64) -> f64 {
     1.0 + (crit_chance * (crit_damage - 1.0))
 }
 
+/// Critical damage multiplier left after the target's critical resistance.
+/// Formula: 1 + max(0, (crit_damage - 1) - resistance / 660 / 100)
+pub fn critical_damage_after_resistance(crit_damage: f64, critical_resistance: f64) -> f64 {
+    let reduction = critical_resistance / CRITICAL_RESISTANCE_PER_PERCENT / 100.0;
+    1.0 + (crit_damage - 1.0 - reduction).max(0.0)
+}
+
 // ==================== ARMOR DAMAGE FACTOR ====================
 
 /// Calculate damage factor after armor mitigation.
@@ -77,11 +93,51 @@ pub fn critical_multiplier(crit_chance: f64, crit_damage: f64) -> f64 {
 /// Formula: 1 - mitigation = 1 - (effective_armor / (effective_armor + 3300))
 ///
 /// Where effective_armor = max(0, target_armor - penetration)
+/// Penetration past the target's armor is wasted, see `penetration_overcap`.
 pub fn armor_damage_factor(target_armor: f64, penetration: f64) -> f64 {
     let eff_armor = effective_armor(target_armor, penetration);
     1.0 - armor_to_mitigation(eff_armor)
 }
 
+/// Armor damage factor averaged over debuffs that strip resistance for only
+/// part of the fight, given as `(value, uptime)` pairs. The factor is not
+/// linear in armor, so each combination of active debuffs is weighted by its
+/// uptime rather than averaging the resistance removed.
+pub fn uptime_weighted_armor_factor(
+    target_armor: f64,
+    penetration: f64,
+    debuffs: impl IntoIterator<Item = (f64, f64)>,
+) -> f64 {
+    // (resistance removed, share of the fight) for each combination
+    let mut combinations = vec![(0.0, 1.0)];
+    for (value, uptime) in debuffs {
+        combinations = combinations
+            .into_iter()
+            .flat_map(|(removed, weight)| {
+                [
+                    (removed + value, weight * uptime),
+                    (removed, weight * (1.0 - uptime)),
+                ]
+            })
+            .collect();
+    }
+    combinations
+        .into_iter()
+        .map(|(removed, weight)| weight * armor_damage_factor(target_armor, penetration + removed))
+        .sum()
+}
+
+/// Damage multiplier from Battle Spirit: halves damage against players in PvP.
+pub fn battle_spirit_multiplier(mode: CombatMode) -> f64 {
+    mode.damage_multiplier()
+}
+
+/// Fraction of damage that reaches the target: armor damage factor times
+/// Battle Spirit in PvP.
+pub fn target_damage_factor(target_armor: f64, penetration: f64, mode: CombatMode) -> f64 {
+    armor_damage_factor(target_armor, penetration) * battle_spirit_multiplier(mode)
+}
+
 // ==================== FINAL DAMAGE CALCULATION ====================
 
 /// Calculate final damage combining all factors.
@@ -89,6 +145,9 @@ pub fn armor_damage_factor(target_armor: f64, penetration: f64) -> f64 {
 /// ESO post-U35 damage formula uses two separate multiplicative modifier layers:
 ///   final = base * (1 + damage_done_sum) * (1 + damage_taken_sum) * armor_factor * crit_mult
 ///
+/// In PvP the result is further reduced by Battle Spirit, and the target's
+/// critical resistance lowers the critical damage bonus.
+///
 /// # Arguments
 /// * `base_damage` - Raw damage from skill (coefficient-calculated)
 /// * `damage_done_sum` - Sum of damage-done modifiers (Damage, DirectDamage, FlameDamage, etc.)
@@ -97,6 +156,9 @@ pub fn armor_damage_factor(target_armor: f64, penetration: f64) -> f64 {
 /// * `penetration` - Character's armor penetration
 /// * `crit_chance` - Critical strike chance (0.0 - 1.0)
 /// * `crit_damage` - Critical damage multiplier (e.g., 1.75 for 75% bonus)
+/// * `critical_resistance` - Target's critical resistance
+/// * `mode` - PvE or PvP (Battle Spirit)
+#[allow(clippy::too_many_arguments)]
 pub fn calculate_final_damage(
     base_damage: f64,
     damage_done_sum: f64,
@@ -105,11 +167,16 @@ pub fn calculate_final_damage(
     penetration: f64,
     crit_chance: f64,
     crit_damage: f64,
+    critical_resistance: f64,
+    mode: CombatMode,
 ) -> f64 {
     let modified_damage = base_damage * (1.0 + damage_done_sum) * (1.0 + damage_taken_sum);
-    let armor_factor = armor_damage_factor(target_armor, penetration);
-    let crit_mult = critical_multiplier(crit_chance, crit_damage);
-    modified_damage * armor_factor * crit_mult
+    let target_factor = target_damage_factor(target_armor, penetration, mode);
+    let crit_mult = critical_multiplier(
+        crit_chance,
+        critical_damage_after_resistance(crit_damage, critical_resistance),
+    );
+    modified_damage * target_factor * crit_mult
 }
 
 // ==================== EFFECTIVE DAMAGE CONTRIBUTION ====================
@@ -136,22 +203,34 @@ pub fn effective_damage_contribution(
         | BonusTarget::PhysicalDamage
         | BonusTarget::EnemyDamageTaken => value,
 
-        // Crit rating → crit chance → scales with crit damage bonus (capped at 100%)
-        BonusTarget::CriticalRating => {
-            let current_chance = stats.critical_chance();
-            let new_chance =
-                crit_rating_to_chance(stats.critical_rating + value).min(MAX_CRITICAL_CHANCE);
-            let marginal_chance = new_chance - current_chance;
+        // Crit rating → crit chance → scales with crit damage bonus (capped at 100%).
+        // Damage uses the best weapon/spell pair, so weapon or spell only rating
+        // counts when it feeds (or becomes) that pair.
+        BonusTarget::CriticalRating
+        | BonusTarget::WeaponCriticalRating
+        | BonusTarget::SpellCriticalRating => {
+            let mut modified = stats.clone();
+            match target {
+                BonusTarget::WeaponCriticalRating => modified.weapon_critical_rating += value,
+                BonusTarget::SpellCriticalRating => modified.spell_critical_rating += value,
+                _ => modified.critical_rating += value,
+            }
+            let new_chance = modified.critical_chance().min(MAX_CRITICAL_CHANCE);
+            let marginal_chance = new_chance - stats.critical_chance();
             if marginal_chance <= 0.0 {
                 return 0.0;
             }
-            marginal_chance * (stats.critical_damage - 1.0)
+            marginal_chance * (stats.effective_critical_damage() - 1.0)
         }
 
-        // Crit damage → scales with crit chance (capped at 125% bonus / 2.25 total)
+        // Crit damage → scales with crit chance (capped at 125% bonus / 2.25 total).
+        // Against critical resistance only the part above the resistance counts.
         BonusTarget::CriticalDamage => {
-            let clamped_value =
-                (stats.critical_damage + value).min(MAX_CRITICAL_DAMAGE) - stats.critical_damage;
+            let new_crit_damage = critical_damage_after_resistance(
+                (stats.critical_damage + value).min(MAX_CRITICAL_DAMAGE),
+                stats.target_critical_resistance,
+            );
+            let clamped_value = new_crit_damage - stats.effective_critical_damage();
             if clamped_value <= 0.0 {
                 return 0.0;
             }
@@ -159,15 +238,27 @@ pub fn effective_damage_contribution(
         }
 
         // Flat weapon/spell damage → relative increase to base power
-        BonusTarget::WeaponAndSpellDamageFlat
-        | BonusTarget::WeaponDamageFlat
-        | BonusTarget::SpellDamageFlat => {
+        BonusTarget::WeaponAndSpellDamageFlat => {
             let base = stats.max_power();
             if base <= 0.0 {
                 return 0.0;
             }
             value / base
         }
+        BonusTarget::WeaponDamageFlat => {
+            let base = stats.max_power();
+            if base <= 0.0 {
+                return 0.0;
+            }
+            (stats.weapon_damage + value).max(stats.spell_damage) / base - 1.0
+        }
+        BonusTarget::SpellDamageFlat => {
+            let base = stats.max_power();
+            if base <= 0.0 {
+                return 0.0;
+            }
+            (stats.spell_damage + value).max(stats.weapon_damage) / base - 1.0
+        }
 
         // Flat max resource → convert to damage equivalent via resource_to_damage_bonus
         BonusTarget::MaxMagickaFlat | BonusTarget::MaxStaminaFlat => {
@@ -178,26 +269,27 @@ pub fn effective_damage_contribution(
             resource_to_damage_bonus(value) / base
         }
 
-        // Penetration → relative improvement in armor damage factor
-        BonusTarget::PhysicalAndSpellPenetration | BonusTarget::EnemyResistanceReduction => {
-            let old_factor = armor_damage_factor(stats.target_armor, stats.penetration);
-            if old_factor <= 0.0 {
+        // Flat max health → only feeds health-scaling skills, weighted by their
+        // share of the build's max stat coefficients
+        BonusTarget::MaxHealthFlat => {
+            let base = stats.max_power();
+            if base <= 0.0 {
                 return 0.0;
             }
-            let new_factor = armor_damage_factor(stats.target_armor, stats.penetration + value);
-            (new_factor - old_factor) / old_factor
+            stats.health_scaling * resource_to_damage_bonus(value) / base
         }
 
-        // Weapon/Spell crit rating - same as generic CriticalRating
-        BonusTarget::WeaponCriticalRating | BonusTarget::SpellCriticalRating => {
-            let current_chance = stats.critical_chance();
-            let new_chance =
-                crit_rating_to_chance(stats.critical_rating + value).min(MAX_CRITICAL_CHANCE);
-            let marginal_chance = new_chance - current_chance;
-            if marginal_chance <= 0.0 {
+        // Health recovery doesn't feed skill damage
+        BonusTarget::HealthRecovery => 0.0,
+
+        // Penetration → relative improvement in armor damage factor
+        BonusTarget::PhysicalAndSpellPenetration | BonusTarget::EnemyResistanceReduction => {
+            let old_factor = stats.armor_damage_factor(stats.penetration);
+            if old_factor <= 0.0 {
                 return 0.0;
             }
-            marginal_chance * (stats.critical_damage - 1.0)
+            let new_factor = stats.armor_damage_factor(stats.penetration + value);
+            (new_factor - old_factor) / old_factor
         }
 
         // Percentage max resource → resource-to-damage scaled
@@ -215,21 +307,29 @@ pub fn effective_damage_contribution(
             }
             resource_to_damage_bonus(stats.max_stamina * value) / base
         }
+        BonusTarget::MaxHealth => {
+            let base = stats.max_power();
+            if base <= 0.0 {
+                return 0.0;
+            }
+            stats.health_scaling * resource_to_damage_bonus(stats.max_health * value) / base
+        }
 
         // Percentage weapon/spell damage → relative power increase
+        // Only the part that lifts the higher of the two damage stats counts
         BonusTarget::WeaponDamage => {
             let base = stats.max_power();
             if base <= 0.0 {
                 return 0.0;
             }
-            (stats.weapon_damage * value) / base
+            (stats.weapon_damage * (1.0 + value)).max(stats.spell_damage) / base - 1.0
         }
         BonusTarget::SpellDamage => {
             let base = stats.max_power();
             if base <= 0.0 {
                 return 0.0;
             }
-            (stats.spell_damage * value) / base
+            (stats.spell_damage * (1.0 + value)).max(stats.weapon_damage) / base - 1.0
         }
         BonusTarget::WeaponAndSpellDamageMultiplier => {
             let base = stats.max_power();
@@ -247,6 +347,7 @@ pub fn effective_damage_contribution(
 #[cfg(test)]
 mod tests {
     use crate::domain::character_stats::{MAX_CRITICAL_CHANCE, MAX_CRITICAL_DAMAGE};
+    use crate::domain::{ArmorDebuff, Enemy};
 
     use super::*;
 
@@ -492,12 +593,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_partial_uptime_armor_debuff_weights_damage_factor() {
+        // Alkosh up half the fight: half the damage lands at 12000 penetration
+        // and half at 6000, not all of it at the averaged 9000
+        let mut stats = CharacterStats::default().with_penetration(6000.0);
+        Enemy::target_dummy()
+            .with_armor_debuff(ArmorDebuff::new("Roar of Alkosh", 6000.0, 0.5))
+            .apply_to(&mut stats);
+        let expected = 0.5 * armor_damage_factor(18200.0, 12000.0)
+            + 0.5 * armor_damage_factor(18200.0, 6000.0);
+        assert!((stats.target_damage_factor() - expected).abs() < 1e-12);
+        assert!(stats.target_damage_factor() > armor_damage_factor(18200.0, 9000.0));
+    }
+
     // ==================== FINAL DAMAGE CALCULATION TESTS ====================
 
     #[test]
     fn test_calculate_final_damage_basic() {
         // Simple case: 1000 base, no modifiers, no armor, no crit
-        let damage = calculate_final_damage(1000.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0);
+        let damage =
+            calculate_final_damage(1000.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, CombatMode::Pve);
         assert!(
             (damage - 1000.0).abs() < 0.01,
             "Expected 1000 damage, got {}",
@@ -508,7 +624,8 @@ mod tests {
     #[test]
     fn test_calculate_final_damage_with_modifiers() {
         // 1000 base, 15% damage done, no damage taken, no armor, no crit
-        let damage = calculate_final_damage(1000.0, 0.15, 0.0, 0.0, 0.0, 0.0, 1.0);
+        let damage =
+            calculate_final_damage(1000.0, 0.15, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, CombatMode::Pve);
         assert!(
             (damage - 1150.0).abs() < 0.01,
             "Expected 1150 damage with 15% modifier, got {}",
@@ -521,7 +638,8 @@ mod tests {
         // 1000 base, 20% damage done, 30% damage taken
         // Correct: 1000 * 1.2 * 1.3 = 1560 (multiplicative)
         // Wrong (old): 1000 * 1.5 = 1500 (additive)
-        let damage = calculate_final_damage(1000.0, 0.20, 0.30, 0.0, 0.0, 0.0, 1.0);
+        let damage =
+            calculate_final_damage(1000.0, 0.20, 0.30, 0.0, 0.0, 0.0, 1.0, 0.0, CombatMode::Pve);
         let expected = 1000.0 * 1.2 * 1.3;
         assert!(
             (damage - expected).abs() < 0.01,
@@ -540,7 +658,17 @@ mod tests {
         // armor_factor = 1.0 (full penetration)
         // crit_mult = 1 + (0.6 * 0.75) = 1.45
         // final = 12000 * 1.0 * 1.45 = 17400
-        let damage = calculate_final_damage(10000.0, 0.20, 0.0, 18200.0, 18200.0, 0.60, 1.75);
+        let damage = calculate_final_damage(
+            10000.0,
+            0.20,
+            0.0,
+            18200.0,
+            18200.0,
+            0.60,
+            1.75,
+            0.0,
+            CombatMode::Pve,
+        );
         let expected = 10000.0 * 1.2 * 1.45;
         assert!(
             (damage - expected).abs() < 0.01,
@@ -550,6 +678,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_calculate_final_damage_pvp() {
+        // Same as above against a player: 1320 critical resistance removes 2%
+        // of the critical damage bonus and Battle Spirit halves the result.
+        // crit_mult = 1 + (0.6 * 0.73) = 1.438
+        let damage = calculate_final_damage(
+            10000.0,
+            0.20,
+            0.0,
+            18200.0,
+            18200.0,
+            0.60,
+            1.75,
+            1320.0,
+            CombatMode::Pvp,
+        );
+        let expected = 10000.0 * 1.2 * 1.438 * 0.5;
+        assert!(
+            (damage - expected).abs() < 0.01,
+            "Expected {} damage, got {}",
+            expected,
+            damage
+        );
+    }
+
     // ==================== EFFECTIVE DAMAGE CONTRIBUTION TESTS ====================
 
     fn test_stats() -> CharacterStats {
@@ -602,6 +755,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_edc_crit_damage_under_critical_resistance() {
+        // 59400 resistance removes 90% of the bonus: the first 15% added is lost
+        let stats = test_stats().with_target_critical_resistance(59400.0);
+        let result = effective_damage_contribution(BonusTarget::CriticalDamage, 0.10, &stats);
+        assert_eq!(result, 0.0);
+        let result = effective_damage_contribution(BonusTarget::CriticalDamage, 0.21, &stats);
+        let expected = 0.06 * 0.60;
+        assert!(
+            (result - expected).abs() < 0.0001,
+            "Expected {}, got {}",
+            expected,
+            result
+        );
+    }
+
     #[test]
     fn test_edc_flat_damage() {
         let stats = test_stats();
@@ -617,6 +786,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_edc_max_health_weighted_by_health_scaling() {
+        let mut stats = test_stats();
+        assert_eq!(
+            effective_damage_contribution(BonusTarget::MaxHealthFlat, 1000.0, &stats),
+            0.0
+        );
+
+        // Half the build's stat coefficients scale with health
+        stats.health_scaling = 0.5;
+        let result = effective_damage_contribution(BonusTarget::MaxHealthFlat, 1000.0, &stats);
+        let expected = 0.5 * resource_to_damage_bonus(1000.0) / 6000.0;
+        assert!(
+            (result - expected).abs() < 0.0001,
+            "Expected {}, got {}",
+            expected,
+            result
+        );
+        assert_eq!(
+            effective_damage_contribution(BonusTarget::HealthRecovery, 500.0, &stats),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_edc_penetration_past_cap_is_zero() {
+        let stats = test_stats().with_penetration(18200.0);
+        assert_eq!(
+            penetration_overcap(stats.target_armor, stats.penetration),
+            0.0
+        );
+        let result =
+            effective_damage_contribution(BonusTarget::PhysicalAndSpellPenetration, 1487.0, &stats);
+        assert_eq!(result, 0.0);
+        assert_eq!(penetration_overcap(18200.0, 19687.0), 1487.0);
+    }
+
+    #[test]
+    fn test_critical_damage_after_resistance() {
+        // 1320 resistance removes 2% of the bonus
+        assert!((critical_damage_after_resistance(1.75, 1320.0) - 1.73).abs() < 1e-9);
+        // Never below a normal hit
+        assert_eq!(critical_damage_after_resistance(1.10, 66_000.0), 1.0);
+    }
+
     #[test]
     fn test_edc_penetration() {
         let stats = test_stats();
@@ -690,4 +904,15 @@ mod tests {
             result
         );
     }
+
+    #[test]
+    fn test_edc_off_pair_crit_rating_returns_zero() {
+        // Weapon damage is higher, so Prophecy-style spell crit adds nothing
+        let stats = test_stats().with_weapon_damage(6500.0);
+        let spell = effective_damage_contribution(BonusTarget::SpellCriticalRating, 2629.0, &stats);
+        let weapon =
+            effective_damage_contribution(BonusTarget::WeaponCriticalRating, 2629.0, &stats);
+        assert!(spell.abs() < 0.0001, "Expected 0.0, got {}", spell);
+        assert!(weapon > 0.0);
+    }
 }
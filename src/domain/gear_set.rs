@@ -119,6 +119,15 @@ impl SetData {
             .collect()
     }
 
+    /// Returns owned cumulative bonuses at the given piece count with flat stats
+    /// scaled for the item level (see `gear_level_scale`).
+    pub fn scaled_bonuses_at(&self, piece_count: u8, scale: f64) -> Vec<BonusData> {
+        self.bonuses_at(piece_count)
+            .into_iter()
+            .map(|b| b.scaled(scale))
+            .collect()
+    }
+
     /// Returns cumulative proc effects at the given piece count.
     pub fn proc_effects_at(&self, piece_count: u8) -> Vec<&SetProcEffect> {
         self.thresholds
@@ -161,7 +170,9 @@ impl fmt::Display for SetData {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::{BonusSource, BonusTarget, BonusTrigger, BonusValue};
+    use crate::domain::{
+        BonusSource, BonusTarget, BonusTrigger, BonusValue, CharacterStats, ResolveContext,
+    };
 
     fn test_set() -> SetData {
         SetData::new("Test Set", SetType::Normal)
@@ -251,4 +262,13 @@ mod tests {
         assert!(names.contains(&"Test 3pc"));
         assert!(names.contains(&"Test 4pc"));
     }
+
+    #[test]
+    fn test_scaled_bonuses_at_scales_flat_stats() {
+        let set = test_set();
+        let ctx = ResolveContext::new(CharacterStats::default());
+        let scaled = set.scaled_bonuses_at(2, 0.8);
+        assert_eq!(scaled.len(), 1);
+        assert!((scaled[0].resolve(&ctx).value - 1096.0 * 0.8).abs() < 0.01);
+    }
 }
@@ -0,0 +1,177 @@
+use serde::{Deserialize, Serialize};
+
+use super::{BuildConfig, SetData, SetType, WeaponType};
+
+/// Smallest and largest DPS roster the group optimizer accepts.
+pub const MIN_GROUP_SIZE: usize = 4;
+pub const MAX_GROUP_SIZE: usize = 8;
+
+/// What a player has to wear to provide a group duty.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DutyProvider {
+    /// Any of these sets (the first is pinned when the duty is assigned)
+    Sets(&'static [&'static str]),
+    /// A weapon on either bar
+    Weapon(WeaponType),
+}
+
+/// A unique group buff or debuff that one damage dealer has to provide.
+/// The trial dummy assumes these are always up; in a group they cost someone a slot.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GroupDuty {
+    pub name: &'static str,
+    pub provider: DutyProvider,
+}
+
+impl GroupDuty {
+    /// Whether this set provides the duty (Perfected variants included).
+    pub fn is_provided_by_set(&self, set_name: &str) -> bool {
+        match self.provider {
+            DutyProvider::Sets(sets) => {
+                let base_name = set_name.strip_prefix("Perfected ").unwrap_or(set_name);
+                sets.contains(&base_name)
+            }
+            DutyProvider::Weapon(_) => false,
+        }
+    }
+
+    /// Whether a build already provides the duty through its pinned sets or weapons.
+    pub fn is_provided_by(&self, config: &BuildConfig) -> bool {
+        match self.provider {
+            DutyProvider::Sets(_) => config.sets.iter().any(|s| self.is_provided_by_set(s)),
+            DutyProvider::Weapon(weapon) => {
+                config.bar1_weapon == Some(weapon) || config.bar2_weapon == Some(weapon)
+            }
+        }
+    }
+
+    /// Pin the duty on a build. Returns None when the build has no room left for it.
+    pub fn assign_to(&self, config: &BuildConfig) -> Option<BuildConfig> {
+        let mut config = config.clone();
+        match self.provider {
+            DutyProvider::Sets(sets) => {
+                let set = SetData::parse(sets[0]).ok()?;
+                let pinned_normals = config
+                    .sets
+                    .iter()
+                    .filter_map(|name| SetData::parse(name).ok())
+                    .filter(|s| matches!(s.set_type, SetType::Normal | SetType::Arena))
+                    .count();
+                if pinned_normals >= 2 {
+                    return None;
+                }
+                config.sets.push(set.name.clone());
+            }
+            DutyProvider::Weapon(weapon) => {
+                if config.bar1_weapon.is_none() {
+                    config.bar1_weapon = Some(weapon);
+                } else if config.bar2_weapon.is_none() && !config.single_bar {
+                    config.bar2_weapon = Some(weapon);
+                } else {
+                    return None;
+                }
+            }
+        }
+        Some(config)
+    }
+}
+
+/// One damage dealer of a group: a name plus the usual build pins
+/// (classes, race, sets, skills...).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupMember {
+    pub name: String,
+    #[serde(flatten)]
+    pub config: BuildConfig,
+}
+
+/// A DPS roster optimized together.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupConfig {
+    pub players: Vec<GroupMember>,
+}
+
+impl GroupConfig {
+    pub fn from_json(json: &str) -> Result<GroupConfig, String> {
+        let group: GroupConfig =
+            serde_json::from_str(json).map_err(|e| format!("Invalid group file: {}", e))?;
+        group.validate()?;
+        Ok(group)
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if !(MIN_GROUP_SIZE..=MAX_GROUP_SIZE).contains(&self.players.len()) {
+            return Err(format!(
+                "A group needs {}-{} players, got {}",
+                MIN_GROUP_SIZE,
+                MAX_GROUP_SIZE,
+                self.players.len()
+            ));
+        }
+        for (idx, player) in self.players.iter().enumerate() {
+            if self.players[..idx].iter().any(|p| p.name == player.name) {
+                return Err(format!("Duplicate player name '{}'", player.name));
+            }
+            for name in &player.config.sets {
+                SetData::parse(name).map_err(|e| format!("Player '{}': {}", player.name, e))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::Race;
+
+    const ALKOSH: GroupDuty = GroupDuty {
+        name: "Roar of Alkosh",
+        provider: DutyProvider::Sets(&["Roar of Alkosh"]),
+    };
+
+    #[test]
+    fn test_from_json_group() {
+        let group = GroupConfig::from_json(
+            r#"{
+                "players": [
+                    { "name": "Arc", "classes": ["arcanist"] },
+                    { "name": "Nb", "classes": ["nightblade"], "race": "Khajiit" },
+                    { "name": "Dk", "sets": ["Roar of Alkosh"] },
+                    { "name": "Cro" }
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(group.players.len(), 4);
+        assert_eq!(group.players[1].config.race, Some(Race::Khajiit));
+        assert!(ALKOSH.is_provided_by(&group.players[2].config));
+        assert!(!ALKOSH.is_provided_by(&group.players[3].config));
+    }
+
+    #[test]
+    fn test_from_json_rejects_group_size() {
+        let result = GroupConfig::from_json(r#"{ "players": [ { "name": "Solo" } ] }"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_assign_duty() {
+        let config = BuildConfig::default();
+        let assigned = ALKOSH.assign_to(&config).unwrap();
+        assert!(ALKOSH.is_provided_by(&assigned));
+
+        let full = BuildConfig {
+            sets: vec!["Deadly Strike".to_string(), "Kinras's Wrath".to_string()],
+            ..BuildConfig::default()
+        };
+        assert!(ALKOSH.assign_to(&full).is_none());
+
+        let brittle = GroupDuty {
+            name: "Minor Brittle",
+            provider: DutyProvider::Weapon(WeaponType::IceStaff),
+        };
+        let assigned = brittle.assign_to(&config).unwrap();
+        assert_eq!(assigned.bar1_weapon, Some(WeaponType::IceStaff));
+    }
+}
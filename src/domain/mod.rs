@@ -8,19 +8,27 @@ pub mod build_config;
 pub mod build_constrains;
 pub mod character_stats;
 pub mod class_name;
+pub mod combat_mode;
 pub mod damage_coefficients;
 pub mod damage_flags;
 pub mod dot_damage;
+pub mod dot_snapshot;
+pub mod encounter;
+pub mod enemy;
 pub mod equipment;
 pub mod execute;
 pub mod food;
 pub mod formulas;
 pub mod gear_set;
+pub mod group;
 pub mod hit_damage;
 pub mod light_attack;
 pub mod mundus;
 pub mod passive;
+pub mod poison;
+pub mod position;
 pub mod potion;
+pub mod power_type;
 pub mod race;
 pub mod resource;
 pub mod set_proc;
@@ -29,7 +37,9 @@ pub mod skill;
 pub mod skill_damage;
 pub mod skill_line_name;
 pub mod skill_mechanic;
+pub mod stack_mechanic;
 pub mod status_effect;
+pub mod synergy;
 pub mod weapon_choice;
 pub mod weapon_enchant;
 pub mod weapon_type;
@@ -41,34 +51,52 @@ pub use bonus_trigger::BonusTrigger;
 pub use bonus_value::{BonusValue, ResolvedBonus};
 pub use build::{Build, CachedPassiveContext};
 pub use build_config::{BuildConfig, BuildMetadata};
-pub use build_constrains::BUILD_CONSTRAINTS;
+pub use build_constrains::{BuildConstraints, BUILD_CONSTRAINTS, SINGLE_BAR_CONSTRAINTS};
 pub use character_stats::{CharacterStats, ATTRIBUTE_POINTS_BONUS};
 pub use class_name::SkillTree;
+pub use combat_mode::{CombatMode, BATTLE_SPIRIT_DAMAGE_REDUCTION};
 pub use damage_coefficients::DamageCoefficients;
 pub use damage_flags::DamageFlags;
 pub use dot_damage::DotDamage;
+pub use dot_snapshot::DotSnapshot;
+pub use encounter::{Encounter, EncounterPhase, ExecutePhase, PhaseKind, PhaseStart};
+pub use enemy::{ArmorDebuff, Enemy, DEFAULT_ENEMY_ARMOR};
 pub use equipment::{
-    ArmorDistribution, ArmorTrait, ArmorWeight, AttributeChoice, JewelryTrait, WeaponTrait,
-    DPS_ARMOR_TRAITS, DPS_ATTRIBUTES, DPS_JEWELRY_TRAITS, DPS_WEAPON_TRAITS,
+    gear_level_scale, parse_gear_level, ArmorDistribution, ArmorGlyph, ArmorTrait, ArmorWeight,
+    AttributeChoice, GearQuality, JewelryGlyph, JewelryTrait, WeaponTrait, ATTRIBUTE_POINTS,
+    ATTRIBUTE_SPLIT_STEP, DPS_ARMOR_GLYPHS, DPS_ARMOR_TRAITS, DPS_ATTRIBUTES, DPS_JEWELRY_GLYPHS,
+    DPS_JEWELRY_TRAITS, DPS_WEAPON_TRAITS, MAX_GEAR_LEVEL,
 };
 pub use execute::{ExecuteData, ExecuteScaling};
 pub use food::{Food, DPS_FOODS};
 pub use gear_set::{SetBonusThreshold, SetData, SetType};
+pub use group::{
+    DutyProvider, GroupConfig, GroupDuty, GroupMember, MAX_GROUP_SIZE, MIN_GROUP_SIZE,
+};
 pub use hit_damage::HitDamage;
 pub use light_attack::LightAttackData;
 pub use mundus::{MundusStone, DPS_MUNDUS_STONES};
 pub use passive::PassiveData;
-pub use potion::Potion;
+pub use poison::{Poison, DPS_POISONS};
+pub use position::PositionalProfile;
+pub use potion::{Potion, DPS_POTIONS};
+pub use power_type::PowerType;
 pub use race::{Race, DPS_RACES};
 pub use resource::Resource;
 pub use set_proc::{SetProcAction, SetProcEffect, SetProcTrigger};
 pub use simulation::{
-    ActiveBar, ActiveBuff, ActiveEffect, BuffUptime, SimulationResult, SkillBreakdown,
+    ActiveBar, ActiveBuff, ActiveEffect, BuffUptime, DpsSample, FightPhase, FightTarget,
+    MonteCarloSummary, Opener, ResourceSustain, SimulationResult, SkillBreakdown, TimelineEvent,
 };
 pub use skill::SkillData;
 pub use skill_damage::SkillDamage;
 pub use skill_line_name::SkillLineName;
 pub use skill_mechanic::SkillMechanic;
+pub use stack_mechanic::{StackGain, StackMechanic};
+pub use synergy::{
+    Synergy, SynergyAvailability, SynergyPassive, SynergyProfile, SYNERGY_COOLDOWN,
+    UNDAUNTED_COMMAND_RESTORE_PCT,
+};
 pub use weapon_choice::WeaponChoice;
-pub use weapon_enchant::WeaponEnchant;
-pub use weapon_type::WeaponType;
+pub use weapon_enchant::{WeaponEnchant, DPS_WEAPON_ENCHANTS};
+pub use weapon_type::{WeaponType, DUAL_WIELD_WEAPONS};
@@ -32,9 +32,10 @@ pub const DPS_MUNDUS_STONES: &[MundusStone] = &[
 
 impl MundusStone {
     /// Apply mundus stone bonus to stats, amplified by Divines trait.
-    /// `divines_count` is the number of armor pieces with the Divines trait (0-7).
-    pub fn apply(&self, stats: &mut CharacterStats, divines_count: u8) {
-        let amp = 1.0 + 0.075 * divines_count as f64;
+    /// `divines` is the number of armor pieces with the Divines trait (0-7),
+    /// weighted by each piece's quality and level scale.
+    pub fn apply(&self, stats: &mut CharacterStats, divines: f64) {
+        let amp = 1.0 + 0.075 * divines;
         match self {
             MundusStone::Thief => stats.critical_rating += 1_333.0 * amp,
             MundusStone::Shadow => stats.critical_damage += 0.11 * amp,
@@ -43,9 +44,7 @@ impl MundusStone {
             MundusStone::Lover => stats.penetration += 2_744.0 * amp,
             MundusStone::Tower => stats.max_stamina += 2_023.0 * amp,
             MundusStone::Mage => stats.max_magicka += 2_023.0 * amp,
-            MundusStone::Lord => {
-                // +2,225 max health - not modeled (no max_health field)
-            }
+            MundusStone::Lord => stats.max_health += 2_225.0 * amp,
             MundusStone::Steed => {
                 // +movement speed - not modeled
             }
@@ -0,0 +1,117 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use super::{BonusData, BonusSource, BonusTarget, BonusTrigger, BonusValue, DamageFlags};
+
+/// Weapon poisons applied to a bar's weapon for DPS calculations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Poison {
+    /// Damage Health Poison: deals poison damage on proc
+    DamageHealth,
+    /// Drain Health Poison: smaller hit that heals the wielder and grants Minor Force
+    DrainHealth,
+    /// Crown Lethal Poison: deals poison damage and applies Minor Vulnerability
+    CrownLethal,
+}
+
+/// All poisons for gear optimization.
+pub const DPS_POISONS: &[Poison] = &[
+    Poison::DamageHealth,
+    Poison::DrainHealth,
+    Poison::CrownLethal,
+];
+
+/// Internal cooldown between poison procs (seconds).
+const POISON_COOLDOWN: f64 = 10.0;
+
+/// Duration of the buff/debuff a poison applies (seconds).
+const POISON_EFFECT_DURATION: f64 = 10.0;
+
+/// Chance for an off-cooldown poison to proc on a light attack.
+const POISON_PROC_CHANCE: f64 = 1.0;
+
+impl Poison {
+    /// Base proc damage of a CP160 crafted poison.
+    pub fn base_damage(&self) -> f64 {
+        match self {
+            Poison::DamageHealth => 2_400.0,
+            Poison::DrainHealth => 1_600.0,
+            Poison::CrownLethal => 1_200.0,
+        }
+    }
+
+    /// Internal cooldown between procs (seconds).
+    pub fn cooldown(&self) -> f64 {
+        POISON_COOLDOWN
+    }
+
+    /// Chance (0.0-1.0) to proc on each light attack while off cooldown.
+    pub fn proc_chance(&self) -> f64 {
+        POISON_PROC_CHANCE
+    }
+
+    /// DamageFlags for the poison proc's damage.
+    pub fn damage_flags(&self) -> DamageFlags {
+        DamageFlags::POISON | DamageFlags::DIRECT | DamageFlags::SINGLE_TARGET
+    }
+
+    /// Buffs (on the wielder) or debuffs (on the enemy) applied on proc.
+    /// The static optimizer phases scale these by `uptime()`; the fight
+    /// simulator applies them for `effect_duration()` on every proc.
+    pub fn bonuses(&self) -> Vec<BonusData> {
+        match self {
+            Poison::DamageHealth => Vec::new(),
+            // Minor Force: +10% Critical Damage
+            Poison::DrainHealth => vec![BonusData::new(
+                "Minor Force",
+                BonusSource::Buff,
+                BonusTrigger::Passive,
+                BonusValue::new("Minor Force", BonusTarget::CriticalDamage, 0.10),
+            )],
+            // Minor Vulnerability: +5% Enemy Damage Taken
+            Poison::CrownLethal => vec![BonusData::new(
+                "Minor Vulnerability",
+                BonusSource::Buff,
+                BonusTrigger::Passive,
+                BonusValue::new("Minor Vulnerability", BonusTarget::EnemyDamageTaken, 0.05),
+            )],
+        }
+    }
+
+    pub fn effect_duration(&self) -> f64 {
+        POISON_EFFECT_DURATION
+    }
+
+    /// Fraction of the fight the poison's buff/debuff is expected to be active.
+    pub fn uptime(&self) -> f64 {
+        (self.effect_duration() / self.cooldown()).min(1.0)
+    }
+
+    /// Rough DPS estimate of the proc damage, used to rank poisons without simulating.
+    pub fn estimated_dps(&self) -> f64 {
+        let modifier_estimate = 2.5;
+        self.base_damage() * self.proc_chance() / self.cooldown() * modifier_estimate
+    }
+
+    pub fn parse(s: &str) -> Result<Poison, String> {
+        match s.to_lowercase().replace(' ', "-").as_str() {
+            "damage-health" | "damage" => Ok(Poison::DamageHealth),
+            "drain-health" | "drain" => Ok(Poison::DrainHealth),
+            "crown-lethal" | "crown" => Ok(Poison::CrownLethal),
+            _ => Err(format!(
+                "Unknown poison '{}'. Valid: damage-health, drain-health, crown-lethal",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Poison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Poison::DamageHealth => write!(f, "Damage Health"),
+            Poison::DrainHealth => write!(f, "Drain Health"),
+            Poison::CrownLethal => write!(f, "Crown Lethal"),
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use super::{BonusData, BonusTrigger};
+
+/// Where a player stands relative to the target over a fight. Without a profile,
+/// flanking bonuses are assumed always on and range-dependent bonuses pick their
+/// best value.
+///
+/// Only bonuses are weighted by position. Skills carry no range data, so
+/// ranged-only abilities (e.g. bow skills) still hit in full from melee range.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PositionalProfile {
+    /// Fraction (0.0 - 1.0) of the fight spent flanking the target
+    pub flanking: f64,
+    /// Fraction (0.0 - 1.0) of the fight spent in melee range of the target
+    pub melee: f64,
+}
+
+impl PositionalProfile {
+    /// Fraction of the fight spent beyond melee range.
+    pub fn ranged(&self) -> f64 {
+        1.0 - self.melee
+    }
+
+    /// The bonus weighted by positional uptime. Flanking bonuses scale by the
+    /// flanking share; at-range bonuses split into their ranged value and their
+    /// melee alternative. Other bonuses are returned unchanged.
+    pub fn apply(&self, bonus: &BonusData) -> Vec<BonusData> {
+        match bonus.trigger {
+            BonusTrigger::Flanking => vec![bonus.weighted(self.flanking)],
+            BonusTrigger::AtRange => bonus
+                .split_alternatives()
+                .iter()
+                .enumerate()
+                .map(|(idx, part)| {
+                    let uptime = if idx == 0 { self.ranged() } else { self.melee };
+                    part.weighted(uptime)
+                })
+                .collect(),
+            _ => vec![bonus.clone()],
+        }
+    }
+
+    /// `bonus` as seen from `position` (unchanged without a profile).
+    pub fn apply_opt(position: Option<&PositionalProfile>, bonus: &BonusData) -> Vec<BonusData> {
+        match position {
+            Some(position) => position.apply(bonus),
+            None => vec![bonus.clone()],
+        }
+    }
+
+    /// Parse `FLANKING%,MELEE%` (e.g. `60%,100%` or `0,30`).
+    pub fn parse(s: &str) -> Result<PositionalProfile, String> {
+        let invalid = || {
+            format!(
+                "Invalid position '{}'. Expected FLANKING%,MELEE% (e.g. 60%,100%)",
+                s
+            )
+        };
+        let (flanking, melee) = s.split_once(',').ok_or_else(invalid)?;
+        let pct = |v: &str| -> Result<f64, String> {
+            let pct: f64 = v
+                .trim()
+                .trim_end_matches('%')
+                .parse()
+                .map_err(|_| invalid())?;
+            if !(0.0..=100.0).contains(&pct) {
+                return Err(format!("Position percentages must be 0-100, got {}", pct));
+            }
+            Ok(pct / 100.0)
+        };
+        Ok(PositionalProfile {
+            flanking: pct(flanking)?,
+            melee: pct(melee)?,
+        })
+    }
+}
+
+impl fmt::Display for PositionalProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:.0}% flanking, {:.0}% melee",
+            self.flanking * 100.0,
+            self.melee * 100.0
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{BonusSource, BonusTarget, BonusValue, ResolveContext};
+
+    #[test]
+    fn test_parse_position() {
+        let position = PositionalProfile::parse("60%,100%").unwrap();
+        assert_eq!(position.flanking, 0.6);
+        assert_eq!(position.melee, 1.0);
+        assert!(PositionalProfile::parse("60").is_err());
+        assert!(PositionalProfile::parse("150,0").is_err());
+    }
+
+    #[test]
+    fn test_apply_weights_positional_bonuses() {
+        let position = PositionalProfile {
+            flanking: 0.5,
+            melee: 0.25,
+        };
+        let ctx = ResolveContext::default();
+
+        let flanking = BonusData::new(
+            "Master Assassin",
+            BonusSource::Passive,
+            BonusTrigger::Flanking,
+            BonusValue::new("Master Assassin", BonusTarget::CriticalRating, 1448.0),
+        );
+        let applied = position.apply(&flanking);
+        assert_eq!(applied.len(), 1);
+        assert_eq!(applied[0].resolve(&ctx).value, 724.0);
+        // Weighted bonuses are plain passives, so applying again changes nothing
+        assert_eq!(position.apply(&applied[0]), applied);
+
+        let long_shots = BonusData::new(
+            "Long Shots",
+            BonusSource::Passive,
+            BonusTrigger::AtRange,
+            BonusValue::new("Long Shots (Damage)", BonusTarget::Damage, 0.08),
+        )
+        .with_alternative(BonusValue::new(
+            "Long Shots (Crit Rating)",
+            BonusTarget::CriticalRating,
+            1314.0,
+        ));
+        let applied = position.apply(&long_shots);
+        assert_eq!(applied.len(), 2);
+        assert!((applied[0].resolve(&ctx).value - 0.06).abs() < 1e-9);
+        assert_eq!(applied[1].resolve(&ctx).value, 328.5);
+    }
+}
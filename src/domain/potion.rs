@@ -9,10 +9,27 @@ pub enum Potion {
     SpellPower,
 }
 
+/// All potions for gear optimization.
+pub const DPS_POTIONS: &[Potion] = &[Potion::WeaponPower, Potion::SpellPower];
+
+/// Buff duration of a gold CP160 crafted potion in seconds
+pub const POTION_DURATION: f64 = 47.6;
+
+/// Shared potion cooldown in seconds
+pub const POTION_COOLDOWN: f64 = 45.0;
+
 impl Potion {
-    /// Returns passive bonuses for this potion type.
-    /// Potions have ~100% uptime on trial dummy (47.6s duration, 45s cooldown),
-    /// so they are modeled as flat passive bonuses.
+    /// Display name of the potion.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Potion::WeaponPower => "Weapon Power",
+            Potion::SpellPower => "Spell Power",
+        }
+    }
+
+    /// Returns the buffs granted by this potion as passive bonuses.
+    /// The static optimizer phases treat these as always active; the fight
+    /// simulator drinks the potion on cooldown and applies them for `duration()`.
     pub fn bonuses(&self) -> Vec<BonusData> {
         match self {
             Potion::WeaponPower => vec![
@@ -58,6 +75,14 @@ impl Potion {
         }
     }
 
+    pub fn duration(&self) -> f64 {
+        POTION_DURATION
+    }
+
+    pub fn cooldown(&self) -> f64 {
+        POTION_COOLDOWN
+    }
+
     pub fn parse(s: &str) -> Result<Potion, String> {
         match s.to_lowercase().replace(' ', "-").as_str() {
             "weapon-power" | "weapon" => Ok(Potion::WeaponPower),
@@ -72,9 +97,6 @@ impl Potion {
 
 impl fmt::Display for Potion {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Potion::WeaponPower => write!(f, "Weapon Power"),
-            Potion::SpellPower => write!(f, "Spell Power"),
-        }
+        write!(f, "{}", self.name())
     }
 }
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use super::resource::Resource;
+
+/// The damage/crit pair a hit scales with: weapon damage + weapon critical,
+/// or spell damage + spell critical.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PowerType {
+    Weapon,
+    Spell,
+}
+
+impl PowerType {
+    /// Native pair of a skill's resource. Health and ultimate skills have no
+    /// native pair.
+    pub fn for_resource(resource: Resource) -> Option<PowerType> {
+        match resource {
+            Resource::Magicka => Some(PowerType::Spell),
+            Resource::Stamina => Some(PowerType::Weapon),
+            Resource::Health | Resource::Ultimate => None,
+        }
+    }
+}
+
+impl fmt::Display for PowerType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PowerType::Weapon => write!(f, "Weapon"),
+            PowerType::Spell => write!(f, "Spell"),
+        }
+    }
+}
@@ -28,7 +28,7 @@ impl Race {
             }
             Race::Argonian => {
                 stats.max_magicka += 1_000.0;
-                // +1,000 health not modeled (no max_health field)
+                stats.max_health += 1_000.0;
             }
             Race::Bosmer => {
                 stats.max_stamina += 2_000.0;
@@ -45,22 +45,22 @@ impl Race {
             }
             Race::Imperial => {
                 stats.max_stamina += 2_000.0;
-                // +2,000 health not modeled
+                stats.max_health += 2_000.0;
             }
             Race::Khajiit => {
                 stats.max_stamina += 915.0;
                 stats.max_magicka += 915.0;
                 stats.critical_damage += 0.12;
-                // +915 health not modeled
+                stats.max_health += 915.0;
             }
             Race::Nord => {
                 stats.max_stamina += 1_500.0;
-                // +1,000 health not modeled
+                stats.max_health += 1_000.0;
             }
             Race::Orc => {
                 stats.max_stamina += 1_000.0;
                 stats.weapon_damage += 258.0;
-                // +1,000 health not modeled
+                stats.max_health += 1_000.0;
             }
             Race::Redguard => {
                 stats.max_stamina += 2_000.0;
@@ -1,11 +1,25 @@
+use super::simulation::HEAVY_ATTACK_INTERVAL;
 use super::BonusTarget;
 use super::DamageFlags;
+use super::Resource;
+use super::SkillLineName;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SetProcTrigger {
     OnLightAttack,
     OnDirectDamage,
     OnDealDamage,
+    /// Swapping bars. The proc charges while not swapping: every swap restarts its cooldown
+    OnBarSwap,
+    /// Fires at the expected critical strike rate of light attacks and skill hits
+    OnCriticalHit,
+    OnDotTick,
+    /// Fully charged heavy attacks, woven every `HEAVY_ATTACK_INTERVAL` while such a set is worn
+    OnHeavyAttack,
+    /// Casting any ability from the given skill line
+    OnSkillLineCast(SkillLineName),
+    /// Applying a status effect (weapon enchants' Burning, Chilled, ...)
+    OnStatusEffectApplied,
 }
 
 #[derive(Debug, Clone)]
@@ -49,6 +63,41 @@ pub enum SetProcAction {
         max_value: f64,
         threshold_pct: Option<f64>,
     },
+    /// Zaan-style tether: damage per tick grows by ramp_per_tick (fraction of base) each tick
+    RampingBeam {
+        base_damage_per_tick: f64,
+        ramp_per_tick: f64,
+        tick_interval: f64,
+        duration: f64,
+        flags: DamageFlags,
+    },
+    /// Sheer Venom-style: hit + optional DoT dealing up to max_bonus more damage,
+    /// scaling linearly as enemy health drops below threshold_pct (0.0-1.0)
+    ExecuteScalingDamage {
+        hit_damage: f64,
+        dot_total_damage: f64,
+        dot_duration: f64,
+        flags: DamageFlags,
+        max_bonus: f64,
+        threshold_pct: f64,
+    },
+    /// Night Mother's Gaze-style: applies a debuff to the enemy (e.g. Major Breach)
+    EnemyDebuff {
+        debuff_name: String,
+        target: BonusTarget,
+        value: f64,
+        duration: f64,
+    },
+    /// Morkuldin-style: summons an entity that attacks every attack_interval for its duration
+    SummonedEntity {
+        damage_per_attack: f64,
+        attack_interval: f64,
+        duration: f64,
+        delay: f64,
+        flags: DamageFlags,
+    },
+    /// Baron Zaudrus/Vivec's Duality-style: restores a resource (tracked as sustain, no damage)
+    RestoreResource { resource: Resource, amount: f64 },
 }
 
 #[derive(Debug, Clone)]
@@ -57,9 +106,28 @@ pub struct SetProcEffect {
     pub trigger: SetProcTrigger,
     pub action: SetProcAction,
     pub cooldown: f64,
+    /// Chance (0.0-1.0) to proc on each trigger while off cooldown
+    pub proc_chance: f64,
 }
 
+/// Assumed time between trigger opportunities when estimating proc rates
+const ESTIMATED_TRIGGER_INTERVAL: f64 = 1.0;
+
 impl SetProcEffect {
+    /// Assumed seconds between trigger opportunities (heavy attacks are woven far less often).
+    fn trigger_interval(&self) -> f64 {
+        match self.trigger {
+            SetProcTrigger::OnHeavyAttack => HEAVY_ATTACK_INTERVAL,
+            _ => ESTIMATED_TRIGGER_INTERVAL,
+        }
+    }
+
+    /// Expected seconds between procs: the cooldown plus the triggers spent failing the proc roll.
+    pub fn expected_interval(&self) -> f64 {
+        let trigger_interval = self.trigger_interval();
+        self.cooldown.max(trigger_interval) + trigger_interval * (1.0 / self.proc_chance - 1.0)
+    }
+
     /// Rough DPS estimate for set optimizer scoring (before modifiers).
     pub fn estimated_dps(&self) -> f64 {
         let modifier_estimate = 2.5;
@@ -70,25 +138,26 @@ impl SetProcEffect {
                 max_stacks,
                 ..
             } => {
-                damage_per_stack_per_tick * (*max_stacks as f64) / tick_interval * modifier_estimate
+                damage_per_stack_per_tick * (*max_stacks as f64) / tick_interval
+                    * modifier_estimate
+                    * self.proc_chance
             }
             SetProcAction::DamageProc {
                 hit_damage,
                 dot_total_damage,
                 ..
             } => {
-                let cd = if self.cooldown > 0.0 {
-                    self.cooldown
-                } else {
-                    1.0
-                };
+                let cd = self.expected_interval();
                 (hit_damage + dot_total_damage) / cd * modifier_estimate
             }
             SetProcAction::StackingBuff {
-                at_max_buff_value, ..
+                at_max_buff_value,
+                at_max_buff_duration,
+                ..
             } => {
-                // Estimated base DPS × buff % × uptime
-                80_000.0 * at_max_buff_value * 0.8
+                // Estimated base DPS × buff % × uptime (rare triggers can't keep it up)
+                let uptime = (at_max_buff_duration / self.trigger_interval()).min(0.8);
+                80_000.0 * at_max_buff_value * uptime * self.proc_chance
             }
             SetProcAction::FlatLightAttackBonus { value } => value * modifier_estimate,
             SetProcAction::ResourceScalingBuff {
@@ -108,6 +177,94 @@ impl SetProcEffect {
                 };
                 80_000.0 * max_value * uptime
             }
+            SetProcAction::RampingBeam {
+                base_damage_per_tick,
+                ramp_per_tick,
+                tick_interval,
+                duration,
+                ..
+            } => {
+                let ticks = (duration / tick_interval).floor();
+                let total =
+                    base_damage_per_tick * (ticks + ramp_per_tick * ticks * (ticks - 1.0) / 2.0);
+                total / self.expected_interval().max(*duration) * modifier_estimate
+            }
+            SetProcAction::ExecuteScalingDamage {
+                hit_damage,
+                dot_total_damage,
+                max_bonus,
+                threshold_pct,
+                ..
+            } => {
+                // Health falls uniformly over the fight: below threshold for threshold_pct
+                // of it, at half the max bonus on average
+                let avg_mult = 1.0 + threshold_pct * max_bonus / 2.0;
+                let cd = self.expected_interval();
+                (hit_damage + dot_total_damage) * avg_mult / cd * modifier_estimate
+            }
+            SetProcAction::EnemyDebuff {
+                target,
+                value,
+                duration,
+                ..
+            } => {
+                let uptime = (duration / self.expected_interval()).min(1.0);
+                // Roughly 1% more damage per 660 resistance removed
+                let damage_pct = match target {
+                    BonusTarget::EnemyResistanceReduction => value / 66_000.0,
+                    _ => *value,
+                };
+                80_000.0 * damage_pct * uptime
+            }
+            SetProcAction::SummonedEntity {
+                damage_per_attack,
+                attack_interval,
+                duration,
+                ..
+            } => {
+                let attacks = (duration / attack_interval).floor();
+                damage_per_attack * attacks / self.expected_interval().max(*duration)
+                    * modifier_estimate
+            }
+            SetProcAction::RestoreResource { .. } => 0.0,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn debuff(cooldown: f64, proc_chance: f64) -> SetProcEffect {
+        SetProcEffect {
+            name: "Debuff".to_string(),
+            trigger: SetProcTrigger::OnLightAttack,
+            action: SetProcAction::EnemyDebuff {
+                debuff_name: "Major Vulnerability".to_string(),
+                target: BonusTarget::EnemyDamageTaken,
+                value: 0.10,
+                duration: 10.0,
+            },
+            cooldown,
+            proc_chance,
+        }
+    }
+
+    #[test]
+    fn test_enemy_debuff_uptime_from_cooldown_and_duration() {
+        // Reapplied as it expires: always up
+        assert_eq!(debuff(10.0, 1.0).estimated_dps(), 8_000.0);
+        // Up for 10 of every 20 seconds
+        assert_eq!(debuff(20.0, 1.0).estimated_dps(), 4_000.0);
+        // A 50% chance adds one failed trigger per proc
+        assert_eq!(debuff(19.0, 0.5).estimated_dps(), 4_000.0);
+    }
+
+    #[test]
+    fn test_heavy_attack_procs_estimated_at_heavy_attack_cadence() {
+        let mut proc = debuff(0.0, 1.0);
+        proc.trigger = SetProcTrigger::OnHeavyAttack;
+        assert_eq!(proc.expected_interval(), HEAVY_ATTACK_INTERVAL);
+        assert!(proc.estimated_dps() > 0.0);
+    }
+}
@@ -3,6 +3,10 @@ use std::fmt;
 use super::BonusTarget;
 use super::DamageCoefficients;
 use super::DamageFlags;
+use super::DotSnapshot;
+use super::Resource;
+use super::SkillLineName;
+use super::StackMechanic;
 use crate::infrastructure::{format, table};
 
 /// Global cooldown in seconds (1 GCD per action)
@@ -11,9 +15,173 @@ pub const GCD: f64 = 1.0;
 /// Bar swap animation delay in seconds
 pub const BAR_SWAP_DELAY: f64 = 0.3;
 
+/// Seconds between the heavy attacks woven into the rotation while a heavy attack set is worn
+pub const HEAVY_ATTACK_INTERVAL: f64 = 10.0;
+
+/// Seconds to fully charge a heavy attack
+pub const HEAVY_ATTACK_CAST_TIME: f64 = 1.5;
+
+/// Fully charged heavy attack damage relative to a light attack of the same weapon
+pub const HEAVY_ATTACK_DAMAGE_MULT: f64 = 3.0;
+
 /// Trial dummy HP (21 million)
 pub const TRIAL_DUMMY_HP: f64 = 21_000_000.0;
 
+/// Fight lengths (seconds) at which cumulative DPS is sampled for the DPS curve
+pub const DPS_CURVE_CHECKPOINTS: &[f64] = &[
+    10.0, 20.0, 30.0, 45.0, 60.0, 90.0, 120.0, 180.0, 240.0, 300.0, 420.0, 600.0,
+];
+
+/// What ends a fight simulation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FightTarget {
+    /// Fight until a target with this much HP is dead
+    TargetHp(f64),
+    /// Fight for a fixed number of seconds. Enemy health is assumed to fall
+    /// linearly so execute ranges are reached at the same point of the fight.
+    Duration(f64),
+}
+
+impl Default for FightTarget {
+    fn default() -> Self {
+        FightTarget::TargetHp(TRIAL_DUMMY_HP)
+    }
+}
+
+impl FightTarget {
+    /// Parse a target HP: dummy presets (`3M`, `6M`, `21M`), `k`/`M` suffixed
+    /// values or a plain number.
+    pub fn parse_target_hp(s: &str) -> Result<FightTarget, String> {
+        let lower = s.trim().to_lowercase();
+        let (number, multiplier) = if let Some(n) = lower.strip_suffix('m') {
+            (n, 1_000_000.0)
+        } else if let Some(n) = lower.strip_suffix('k') {
+            (n, 1_000.0)
+        } else {
+            (lower.as_str(), 1.0)
+        };
+        let value: f64 = number.replace(['_', ','], "").parse().map_err(|_| {
+            format!(
+                "Unknown target HP '{}'. Valid: 3M, 6M, 21M or a number (e.g. 4500000, 750k)",
+                s
+            )
+        })?;
+        let hp = value * multiplier;
+        if hp <= 0.0 || !hp.is_finite() {
+            return Err(format!("Target HP must be positive, got '{}'", s));
+        }
+        Ok(FightTarget::TargetHp(hp))
+    }
+
+    /// Parse a fight duration such as `90s`, `90`, `2m` or `1m30s`.
+    pub fn parse_duration(s: &str) -> Result<FightTarget, String> {
+        let duration = parse_seconds(s)?;
+        if duration <= 0.0 {
+            return Err(format!("Fight duration must be positive, got '{}'", s));
+        }
+        Ok(FightTarget::Duration(duration))
+    }
+}
+
+/// Parse a time such as `90s`, `90`, `2m` or `1m30s` into seconds.
+pub fn parse_seconds(s: &str) -> Result<f64, String> {
+    let lower = s.trim().to_lowercase();
+    let invalid = || {
+        format!(
+            "Unknown duration '{}'. Valid: seconds (90, 90s), minutes (2m) or both (1m30s)",
+            s
+        )
+    };
+    let (minutes, seconds) = match lower.split_once('m') {
+        Some((m, rest)) => (m, rest.strip_suffix('s').unwrap_or(rest)),
+        None => ("", lower.strip_suffix('s').unwrap_or(&lower)),
+    };
+    let minutes: f64 = if minutes.is_empty() {
+        0.0
+    } else {
+        minutes.parse().map_err(|_| invalid())?
+    };
+    let seconds: f64 = if seconds.is_empty() {
+        0.0
+    } else {
+        seconds.parse().map_err(|_| invalid())?
+    };
+    let total = minutes * 60.0 + seconds;
+    if total < 0.0 || !total.is_finite() {
+        return Err(invalid());
+    }
+    Ok(total)
+}
+
+impl fmt::Display for FightTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FightTarget::TargetHp(hp) if *hp == TRIAL_DUMMY_HP => {
+                write!(f, "21M HP Trial Dummy")
+            }
+            FightTarget::TargetHp(hp) if hp % 1_000_000.0 == 0.0 => {
+                write!(f, "{}M HP Target", hp / 1_000_000.0)
+            }
+            FightTarget::TargetHp(hp) => {
+                write!(f, "{} HP Target", format::format_number(*hp as u64))
+            }
+            FightTarget::Duration(d) => write!(f, "Fixed {}s Fight", d),
+        }
+    }
+}
+
+/// Pre-pull buffing sequence run before the damage clock starts.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Opener {
+    /// Start cold at the pull
+    #[default]
+    None,
+    /// Pre-cast every slotted skill with a timed Cast buff, longest buff first
+    Auto,
+    /// Pre-cast these skills (by name) in order
+    Custom(Vec<String>),
+}
+
+impl Opener {
+    /// Parse `none`, `auto` or a comma-separated list of skill names.
+    pub fn parse(s: &str) -> Result<Opener, String> {
+        match s.trim().to_lowercase().as_str() {
+            "" | "none" => Ok(Opener::None),
+            "auto" => Ok(Opener::Auto),
+            _ => Ok(Opener::Custom(
+                s.split(',').map(|name| name.trim().to_string()).collect(),
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Opener {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Opener::None => write!(f, "None"),
+            Opener::Auto => write!(f, "Auto"),
+            Opener::Custom(skills) => write!(f, "{}", skills.join(", ")),
+        }
+    }
+}
+
+/// Which part of the encounter a timeline event belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FightPhase {
+    /// Pre-buffing before the pull (negative timestamps)
+    Opener,
+    Fight,
+}
+
+/// A single action recorded during a fight simulation.
+#[derive(Debug, Clone)]
+pub struct TimelineEvent {
+    /// Seconds relative to the pull (negative during the opener)
+    pub time: f64,
+    pub phase: FightPhase,
+    pub description: String,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ActiveBar {
     Bar1,
@@ -41,17 +209,26 @@ pub struct ActiveEffect {
     pub base_value: f64,
     pub flags: DamageFlags,
     pub coefficients: DamageCoefficients,
-    pub increase_per_tick: f64,
+    /// Damage multiplier from the caster's stacks held when the DoT was applied
+    pub stack_multiplier: f64,
+    /// Counters this DoT builds on every tick (ramping DoTs)
+    pub tick_stacks: Vec<StackMechanic>,
     pub flat_increase_per_tick: f64,
     pub ignores_modifier: bool,
-    /// Damage-done modifier sum snapshotted at cast time (DoT ticks use this, not current buffs)
-    pub snapshotted_done_modifier: f64,
-    /// EnemyDamageTaken modifier sum snapshotted at cast time (separate multiplicative layer)
-    pub snapshotted_taken_modifier: f64,
-    /// Armor damage factor snapshotted at cast time
-    pub snapshotted_armor_factor: f64,
-    /// Critical multiplier snapshotted at cast time
-    pub snapshotted_crit_mult: f64,
+    /// Parts kept from cast time; the others below are refreshed before every tick
+    pub snapshot: DotSnapshot,
+    /// Stat pool the base value scales with (None = fixed damage, e.g. set procs)
+    pub resource: Option<Resource>,
+    /// Skill line for skill-line damage passives when re-reading the done modifier
+    pub skill_line: Option<SkillLineName>,
+    /// Damage-done modifier sum
+    pub done_modifier: f64,
+    /// EnemyDamageTaken modifier sum (separate multiplicative layer)
+    pub taken_modifier: f64,
+    /// Armor damage factor
+    pub armor_factor: f64,
+    /// Critical multiplier
+    pub crit_mult: f64,
 }
 
 /// Tracks an active buff granted by a skill.
@@ -77,17 +254,79 @@ pub struct BuffUptime {
     pub external: bool,
 }
 
+/// Resource restored by procs (e.g. absorb enchants) during a fight simulation.
+#[derive(Debug, Clone, Copy)]
+pub struct ResourceSustain {
+    pub resource: Resource,
+    pub restored: f64,
+}
+
+/// Cumulative DPS at a point in the fight.
+#[derive(Debug, Clone, Copy)]
+pub struct DpsSample {
+    pub time: f64,
+    pub dps: f64,
+}
+
+/// DPS spread across Monte Carlo runs with rolled procs.
+#[derive(Debug, Clone, Copy)]
+pub struct MonteCarloSummary {
+    pub runs: usize,
+    pub mean_dps: f64,
+    pub std_dev: f64,
+    pub min_dps: f64,
+    pub max_dps: f64,
+}
+
 /// Results of a fight simulation.
 #[derive(Debug, Clone)]
 pub struct SimulationResult {
     pub total_damage: f64,
     pub fight_duration: f64,
     pub dps: f64,
+    /// Seconds until the target died (None for fixed-duration fights)
+    pub time_to_kill: Option<f64>,
+    /// Cumulative DPS at each of `DPS_CURVE_CHECKPOINTS` reached, plus the final fight length
+    pub dps_curve: Vec<DpsSample>,
+    /// Seconds spent pre-buffing before the pull (not part of fight_duration)
+    pub opener_duration: f64,
+    /// Recorded actions (empty unless timeline recording is enabled)
+    pub timeline: Vec<TimelineEvent>,
+    /// DPS spread when this is the representative run of a Monte Carlo simulation
+    pub monte_carlo: Option<MonteCarloSummary>,
     pub skill_breakdown: Vec<SkillBreakdown>,
     pub la_damage: f64,
     pub la_count: u32,
     pub bar_swap_count: u32,
     pub buff_uptimes: Vec<BuffUptime>,
+    pub resources_restored: Vec<ResourceSustain>,
+    /// Area damage dealt to encounter adds (not part of total_damage)
+    pub add_damage: f64,
+    /// Damage dealt by activated synergies (part of total_damage)
+    pub synergy_damage: f64,
+    /// Seconds the rotation was stopped by immune and movement phases
+    pub downtime: f64,
+}
+
+impl SimulationResult {
+    /// DPS over the time the rotation was running, leaving out downtime.
+    pub fn uptime_dps(&self) -> f64 {
+        let uptime = self.fight_duration - self.downtime;
+        if uptime > 0.0 {
+            self.total_damage / uptime
+        } else {
+            0.0
+        }
+    }
+
+    /// Fraction (0.0 - 1.0) of the fight the rotation was running.
+    pub fn uptime_pct(&self) -> f64 {
+        if self.fight_duration > 0.0 {
+            1.0 - self.downtime / self.fight_duration
+        } else {
+            1.0
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -213,6 +452,167 @@ impl fmt::Display for SimulationResult {
             write!(f, "\n{}", uptime_table)?;
         }
 
+        // Sustain table
+        if !self.resources_restored.is_empty() {
+            let sustain_data: Vec<Vec<String>> = self
+                .resources_restored
+                .iter()
+                .map(|r| {
+                    let per_second = if self.fight_duration > 0.0 {
+                        r.restored / self.fight_duration
+                    } else {
+                        0.0
+                    };
+                    vec![
+                        r.resource.to_string(),
+                        format::format_number(r.restored as u64),
+                        std::format!("{:.0}", per_second),
+                    ]
+                })
+                .collect();
+            let sustain_table = table::table(
+                &sustain_data,
+                table::TableOptions {
+                    title: Some("Sustain".to_string()),
+                    columns: vec![
+                        table::ColumnDefinition::new("Resource", 12),
+                        table::ColumnDefinition::new("Restored", 12).align_right(),
+                        table::ColumnDefinition::new("Per Sec", 8).align_right(),
+                    ],
+                    footer: None,
+                },
+            );
+            write!(f, "\n{}", sustain_table)?;
+        }
+
+        // DPS by fight length table
+        if !self.dps_curve.is_empty() {
+            let curve_data: Vec<Vec<String>> = self
+                .dps_curve
+                .iter()
+                .map(|s| {
+                    vec![
+                        std::format!("{:.0}s", s.time),
+                        format::format_number(s.dps as u64),
+                    ]
+                })
+                .collect();
+            let curve_table = table::table(
+                &curve_data,
+                table::TableOptions {
+                    title: Some("DPS by Fight Length".to_string()),
+                    columns: vec![
+                        table::ColumnDefinition::new("Time", 8).align_right(),
+                        table::ColumnDefinition::new("DPS", 10).align_right(),
+                    ],
+                    footer: None,
+                },
+            );
+            write!(f, "\n{}", curve_table)?;
+        }
+
+        // Timeline tables (opener reported separately from the fight)
+        for (phase, title) in [
+            (FightPhase::Opener, "Opener"),
+            (FightPhase::Fight, "Fight Timeline"),
+        ] {
+            let events: Vec<Vec<String>> = self
+                .timeline
+                .iter()
+                .filter(|e| e.phase == phase)
+                .map(|e| vec![std::format!("{:.2}s", e.time), e.description.clone()])
+                .collect();
+            if events.is_empty() {
+                continue;
+            }
+            // Widen for DoT tick modifiers, within reason
+            let event_width = events
+                .iter()
+                .map(|e| e[1].len())
+                .max()
+                .unwrap_or(0)
+                .clamp(44, 96);
+            let timeline_table = table::table(
+                &events,
+                table::TableOptions {
+                    title: Some(title.to_string()),
+                    columns: vec![
+                        table::ColumnDefinition::new("Time", 9).align_right(),
+                        table::ColumnDefinition::new("Event", event_width),
+                    ],
+                    footer: None,
+                },
+            );
+            write!(f, "\n{}", timeline_table)?;
+        }
+
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_target_hp_presets() {
+        assert_eq!(
+            FightTarget::parse_target_hp("3M"),
+            Ok(FightTarget::TargetHp(3_000_000.0))
+        );
+        assert_eq!(
+            FightTarget::parse_target_hp("21m"),
+            Ok(FightTarget::TargetHp(TRIAL_DUMMY_HP))
+        );
+        assert_eq!(
+            FightTarget::parse_target_hp("750k"),
+            Ok(FightTarget::TargetHp(750_000.0))
+        );
+        assert_eq!(
+            FightTarget::parse_target_hp("4500000"),
+            Ok(FightTarget::TargetHp(4_500_000.0))
+        );
+    }
+
+    #[test]
+    fn test_parse_target_hp_invalid() {
+        assert!(FightTarget::parse_target_hp("lots").is_err());
+        assert!(FightTarget::parse_target_hp("0").is_err());
+        assert!(FightTarget::parse_target_hp("-3M").is_err());
+    }
+
+    #[test]
+    fn test_parse_opener() {
+        assert_eq!(Opener::parse("none"), Ok(Opener::None));
+        assert_eq!(Opener::parse("Auto"), Ok(Opener::Auto));
+        assert_eq!(
+            Opener::parse("Barbed Trap, Scalding Rune"),
+            Ok(Opener::Custom(vec![
+                "Barbed Trap".to_string(),
+                "Scalding Rune".to_string()
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(
+            FightTarget::parse_duration("90s"),
+            Ok(FightTarget::Duration(90.0))
+        );
+        assert_eq!(
+            FightTarget::parse_duration("90"),
+            Ok(FightTarget::Duration(90.0))
+        );
+        assert_eq!(
+            FightTarget::parse_duration("2m"),
+            Ok(FightTarget::Duration(120.0))
+        );
+        assert_eq!(
+            FightTarget::parse_duration("1m30s"),
+            Ok(FightTarget::Duration(90.0))
+        );
+        assert!(FightTarget::parse_duration("soon").is_err());
+        assert!(FightTarget::parse_duration("0s").is_err());
+    }
+}
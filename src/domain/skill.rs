@@ -1,7 +1,7 @@
 use super::{
-    formulas, BonusData, BonusTarget, CharacterStats, SkillTree, DamageFlags, ExecuteData,
-    ExecuteScaling, ResolveContext, ResolvedBonus, Resource, SkillDamage, SkillLineName,
-    SkillMechanic,
+    formulas, BonusData, BonusTarget, CharacterStats, DamageFlags, DotSnapshot, ExecuteData,
+    ExecuteScaling, PowerType, ResolveContext, ResolvedBonus, Resource, SkillDamage, SkillLineName,
+    SkillMechanic, SkillTree, StackMechanic,
 };
 use serde::{Deserialize, Serialize};
 
@@ -27,8 +27,11 @@ pub struct SkillData {
     pub spammable: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cooldown: Option<f64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stacks: Vec<StackMechanic>,
+    /// DoT parts locked in at cast time (None = the simulator's default)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub proc_light_attacks: Option<u32>,
+    pub dot_snapshot: Option<DotSnapshot>,
 }
 
 // Builder
@@ -53,7 +56,8 @@ impl SkillData {
             bonuses: None,
             spammable: false,
             cooldown: None,
-            proc_light_attacks: None,
+            stacks: Vec::new(),
+            dot_snapshot: None,
         }
     }
 
@@ -97,21 +101,65 @@ impl SkillData {
         self
     }
 
-    pub fn with_proc_light_attacks(mut self, count: u32) -> Self {
-        self.proc_light_attacks = Some(count);
+    pub fn with_stacks(mut self, stacks: Vec<StackMechanic>) -> Self {
+        self.stacks = stacks;
+        self
+    }
+
+    pub fn with_dot_snapshot(mut self, snapshot: DotSnapshot) -> Self {
+        self.dot_snapshot = Some(snapshot);
         self
     }
 }
 
 impl SkillData {
+    /// Max stat coefficient of one cast, hits plus every DoT tick.
+    pub fn stat_coefficient(&self) -> f64 {
+        let Some(damage) = &self.damage else {
+            return 0.0;
+        };
+        let hits: f64 = damage
+            .hits
+            .iter()
+            .flatten()
+            .map(|hit| hit.coefficients.coef_a)
+            .sum();
+        let dots: f64 = damage
+            .dots
+            .iter()
+            .flatten()
+            .map(|dot| {
+                let interval = dot.interval.unwrap_or(dot.duration);
+                dot.coefficients.coef_a * (dot.duration / interval).floor()
+            })
+            .sum();
+        hits + dots
+    }
+
+    /// Damage/crit pair this skill scales with under the given stats.
+    pub fn power_type(&self, stats: &CharacterStats) -> PowerType {
+        stats.power_type_for(self.resource)
+    }
+
+    /// Damage multiplier of the `tick`th tick (from 0) of this skill's DoTs from
+    /// counters that ramp per tick.
+    pub fn dot_tick_multiplier(&self, tick: u32) -> f64 {
+        self.stacks
+            .iter()
+            .filter(|m| m.ramps_per_tick())
+            .map(|m| m.tick_multiplier(tick))
+            .product()
+    }
+
     pub fn calculate_damage_per_cast(
         &self,
         bonuses: &[BonusData],
         stats: &CharacterStats,
         enemy_health: Option<f64>,
     ) -> f64 {
-        let max_stat = stats.max_stat();
-        let max_power = stats.max_power();
+        let power_type = self.power_type(stats);
+        let max_stat = stats.stat_for(self.resource);
+        let max_power = stats.power_for(power_type);
 
         let mut total_damage_per_cast = 0.0;
 
@@ -194,11 +242,10 @@ impl SkillData {
 
                     let interval = dot.interval.unwrap_or(dot.duration);
                     let ticks = (dot.duration / interval).floor() as i32;
-                    let increase_per_tick = dot.increase_per_tick.unwrap_or(0.0);
                     let flat_increase_per_tick = dot.flat_increase_per_tick.unwrap_or(0.0);
 
                     for i in 0..ticks {
-                        let percentage_multiplier = 1.0 + (i as f64) * increase_per_tick;
+                        let percentage_multiplier = self.dot_tick_multiplier(i as u32);
                         let flat_increase = (i as f64) * flat_increase_per_tick;
                         let tick_damage = dot_value * percentage_multiplier + flat_increase;
 
@@ -220,17 +267,20 @@ impl SkillData {
             }
         }
 
-        let armor_factor = formulas::armor_damage_factor(stats.target_armor, stats.penetration);
-        let crit_mult =
-            formulas::critical_multiplier(stats.critical_chance(), stats.critical_damage);
+        let armor_factor = stats.target_damage_factor();
+        let crit_mult = formulas::critical_multiplier(
+            stats.critical_chance_for(power_type),
+            stats.effective_critical_damage(),
+        );
 
         total_damage_per_cast * armor_factor * crit_mult * (1.0 + enemy_damage_taken)
     }
 
     /// Fast damage calculation using pre-resolved lightweight bonuses.
     /// Avoids BonusValue String cloning and BonusData overhead.
-    /// `armor_factor` and `crit_mult` are precomputed from effective_stats
-    /// and shared across all skills in a build evaluation.
+    /// `armor_factor` is precomputed from effective_stats and shared across all
+    /// skills in a build evaluation; `crit_mult` must match this skill's
+    /// `power_type`.
     pub fn calculate_damage_per_cast_fast(
         &self,
         bonuses: &[ResolvedBonus],
@@ -239,8 +289,8 @@ impl SkillData {
         armor_factor: f64,
         crit_mult: f64,
     ) -> f64 {
-        let max_stat = stats.max_stat();
-        let max_power = stats.max_power();
+        let max_stat = stats.stat_for(self.resource);
+        let max_power = stats.power_for(self.power_type(stats));
 
         let mut total_damage_per_cast = 0.0;
 
@@ -315,11 +365,10 @@ impl SkillData {
 
                     let interval = dot.interval.unwrap_or(dot.duration);
                     let ticks = (dot.duration / interval).floor() as i32;
-                    let increase_per_tick = dot.increase_per_tick.unwrap_or(0.0);
                     let flat_increase_per_tick = dot.flat_increase_per_tick.unwrap_or(0.0);
 
                     for i in 0..ticks {
-                        let percentage_multiplier = 1.0 + (i as f64) * increase_per_tick;
+                        let percentage_multiplier = self.dot_tick_multiplier(i as u32);
                         let flat_increase = (i as f64) * flat_increase_per_tick;
                         let tick_damage = dot_value * percentage_multiplier + flat_increase;
 
@@ -426,6 +475,14 @@ impl SkillData {
             lines.push(format!("  Channel Time:    {}s", channel_time));
         }
 
+        for stack in &self.stacks {
+            lines.push(format!("  Stacks:          {}", stack));
+        }
+
+        if let Some(snapshot) = self.dot_snapshot {
+            lines.push(format!("  DoT Snapshot:    {}", snapshot));
+        }
+
         lines
     }
 
@@ -433,7 +490,7 @@ impl SkillData {
         let mut lines = vec!["  Damage".to_string(), format!("  {}", "-".repeat(56))];
 
         let stats = CharacterStats::default();
-        let max_stat = stats.max_stat();
+        let max_stat = stats.stat_for(self.resource);
         let max_power = stats.max_power();
 
         if let Some(damage) = &self.damage {
@@ -471,10 +528,6 @@ impl SkillData {
                             .interval
                             .map(|i| format!(" every {}s", i))
                             .unwrap_or_default();
-                        let increase = dot
-                            .increase_per_tick
-                            .map(|i| format!(" (+{:.0}%/tick)", i * 100.0))
-                            .unwrap_or_default();
                         let flat_increase = dot
                             .flat_increase_per_tick
                             .map(|f| format!(" (+{}/tick)", f))
@@ -482,12 +535,11 @@ impl SkillData {
                         let flags_str = format!(" [{}]", dot.flags);
                         let value = dot.effective_value(max_stat, max_power);
                         lines.push(format!(
-                            "    {}. {:.0}{} for {}s{}{}{}",
+                            "    {}. {:.0}{} for {}s{}{}",
                             j + 1,
                             value,
                             interval,
                             dot.duration,
-                            increase,
                             flat_increase,
                             flags_str
                         ));
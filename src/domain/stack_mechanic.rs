@@ -0,0 +1,263 @@
+use super::{BonusData, SkillLineName};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Event that adds stacks to a counter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum StackGain {
+    /// Casting the skill that declares the mechanic
+    Cast,
+    /// Every light attack while the skill is slotted
+    LightAttack,
+    /// Casting a different skill from this skill line while the skill is slotted
+    SkillLineCast(SkillLineName),
+    /// Every tick of the declaring skill's DoTs (ramping DoTs). These stacks belong
+    /// to the DoT and start over when it is reapplied
+    DotTick,
+}
+
+impl fmt::Display for StackGain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StackGain::Cast => write!(f, "on cast"),
+            StackGain::LightAttack => write!(f, "per light attack"),
+            StackGain::SkillLineCast(line) => write!(f, "per {} cast", line),
+            StackGain::DotTick => write!(f, "per DoT tick"),
+        }
+    }
+}
+
+/// A stack counter a skill builds, requires or consumes (Crux, Grim Focus,
+/// Seething Fury...). Skills declaring the same `name` share one counter.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StackMechanic {
+    pub name: String,
+    /// Event adding `stacks_per_gain` stacks
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gain: Option<StackGain>,
+    pub stacks_per_gain: u32,
+    /// Most stacks the counter holds (None = unlimited)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_stacks: Option<u32>,
+    /// Seconds stacks last after the last gain (None = until consumed)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decay: Option<f64>,
+    /// Stacks needed before the skill's hits fire
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_stacks: Option<u32>,
+    /// Casting the skill (once its requirement is met) consumes all stacks
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub consumes: bool,
+    /// Damage increase per stack held when the skill is cast (hits and DoTs)
+    #[serde(default)]
+    pub damage_per_stack: f64,
+    /// Seconds added to the skill's channel per stack held when the skill is cast
+    #[serde(default)]
+    pub duration_per_stack: f64,
+    /// Buffs held while the counter has stacks, worth their value once per stack
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bonuses_per_stack: Vec<BonusData>,
+}
+
+// Builder
+impl StackMechanic {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            gain: None,
+            stacks_per_gain: 1,
+            max_stacks: None,
+            decay: None,
+            required_stacks: None,
+            consumes: false,
+            damage_per_stack: 0.0,
+            duration_per_stack: 0.0,
+            bonuses_per_stack: Vec::new(),
+        }
+    }
+
+    pub fn with_gain(mut self, gain: StackGain, stacks: u32) -> Self {
+        self.gain = Some(gain);
+        self.stacks_per_gain = stacks;
+        self
+    }
+
+    pub fn with_max_stacks(mut self, max_stacks: u32) -> Self {
+        self.max_stacks = Some(max_stacks);
+        self
+    }
+
+    pub fn with_decay(mut self, decay: f64) -> Self {
+        self.decay = Some(decay);
+        self
+    }
+
+    pub fn with_required_stacks(mut self, stacks: u32) -> Self {
+        self.required_stacks = Some(stacks);
+        self
+    }
+
+    pub fn consuming(mut self) -> Self {
+        self.consumes = true;
+        self
+    }
+
+    pub fn with_damage_per_stack(mut self, damage: f64) -> Self {
+        self.damage_per_stack = damage;
+        self
+    }
+
+    pub fn with_duration_per_stack(mut self, duration: f64) -> Self {
+        self.duration_per_stack = duration;
+        self
+    }
+
+    pub fn with_bonuses_per_stack(mut self, bonuses: Vec<BonusData>) -> Self {
+        self.bonuses_per_stack = bonuses;
+        self
+    }
+}
+
+impl StackMechanic {
+    /// Stacks held after a gain event with `stacks` held.
+    pub fn gained(&self, stacks: u32) -> u32 {
+        let gained = stacks + self.stacks_per_gain;
+        self.max_stacks.map_or(gained, |max| gained.min(max))
+    }
+
+    /// Stacks held after `gains` gain events from none (a DoT's ramp after that many ticks).
+    pub fn stacks_after(&self, gains: u32) -> u32 {
+        let stacks = gains * self.stacks_per_gain;
+        self.max_stacks.map_or(stacks, |max| stacks.min(max))
+    }
+
+    /// Whether the skill's hits fire with `stacks` held.
+    pub fn is_ready(&self, stacks: u32) -> bool {
+        self.required_stacks
+            .is_none_or(|required| stacks >= required)
+    }
+
+    /// Damage multiplier when casting with `stacks` held.
+    pub fn damage_multiplier(&self, stacks: u32) -> f64 {
+        1.0 + self.damage_per_stack * stacks as f64
+    }
+
+    /// Seconds added to the skill's channel when casting with `stacks` held.
+    pub fn extra_duration(&self, stacks: u32) -> f64 {
+        self.duration_per_stack * stacks as f64
+    }
+
+    /// Whether casting the skill adds stacks (a generator).
+    pub fn gains_on_cast(&self) -> bool {
+        self.gain == Some(StackGain::Cast)
+    }
+
+    /// Whether each tick of the skill's DoTs adds stacks to that DoT.
+    pub fn ramps_per_tick(&self) -> bool {
+        self.gain == Some(StackGain::DotTick)
+    }
+
+    /// Damage multiplier of the `tick`th tick (from 0) of a DoT ramping with this counter.
+    pub fn tick_multiplier(&self, tick: u32) -> f64 {
+        self.damage_multiplier(self.stacks_after(tick))
+    }
+
+    /// Whether the skill consumes stacks for more damage or a longer channel.
+    pub fn is_spender(&self) -> bool {
+        self.consumes
+            && !self.ramps_per_tick()
+            && (self.damage_per_stack > 0.0 || self.duration_per_stack > 0.0)
+    }
+}
+
+impl fmt::Display for StackMechanic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(gain) = self.gain {
+            parts.push(format!("+{} {}", self.stacks_per_gain, gain));
+        }
+        if let Some(max) = self.max_stacks {
+            parts.push(format!("max {}", max));
+        }
+        if let Some(decay) = self.decay {
+            parts.push(format!("lasts {}s", decay));
+        }
+        if let Some(required) = self.required_stacks {
+            parts.push(format!("needs {}", required));
+        }
+        if self.consumes {
+            parts.push("consumes all".to_string());
+        }
+        if self.damage_per_stack > 0.0 {
+            parts.push(format!(
+                "+{}% damage per stack",
+                self.damage_per_stack * 100.0
+            ));
+        }
+        if self.duration_per_stack > 0.0 {
+            parts.push(format!("+{}s per stack", self.duration_per_stack));
+        }
+        for bonus in &self.bonuses_per_stack {
+            parts.push(format!("{} per stack", bonus.name));
+        }
+        write!(f, "{} ({})", self.name, parts.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gain_caps_at_max_stacks() {
+        let crux = StackMechanic::new("Crux")
+            .with_gain(StackGain::Cast, 1)
+            .with_max_stacks(3);
+        assert_eq!(crux.gained(0), 1);
+        assert_eq!(crux.gained(3), 3);
+        assert!(crux.gains_on_cast());
+        assert!(!crux.is_spender());
+
+        let unlimited = StackMechanic::new("Grim Focus").with_gain(StackGain::LightAttack, 1);
+        assert_eq!(unlimited.gained(10), 11);
+    }
+
+    #[test]
+    fn test_spend_scales_with_stacks() {
+        let spender = StackMechanic::new("Crux")
+            .consuming()
+            .with_damage_per_stack(0.33)
+            .with_duration_per_stack(0.3);
+        assert!(spender.is_spender());
+        assert_eq!(spender.damage_multiplier(0), 1.0);
+        assert!((spender.damage_multiplier(3) - 1.99).abs() < 1e-9);
+        assert!((spender.extra_duration(3) - 0.9).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_required_stacks() {
+        let grim_focus = StackMechanic::new("Grim Focus")
+            .with_gain(StackGain::LightAttack, 1)
+            .with_required_stacks(4)
+            .consuming();
+        assert!(!grim_focus.is_ready(3));
+        assert!(grim_focus.is_ready(4));
+        assert!(!grim_focus.is_spender());
+    }
+
+    #[test]
+    fn test_dot_tick_ramp() {
+        let ramp = StackMechanic::new("Hurricane")
+            .with_gain(StackGain::DotTick, 1)
+            .with_damage_per_stack(0.12);
+        assert!(ramp.ramps_per_tick());
+        assert!(!ramp.gains_on_cast());
+        assert_eq!(ramp.tick_multiplier(0), 1.0);
+        assert!((ramp.tick_multiplier(9) - 2.08).abs() < 1e-9);
+
+        let capped = ramp.with_max_stacks(5);
+        assert_eq!(capped.stacks_after(9), 5);
+        assert!((capped.tick_multiplier(9) - 1.6).abs() < 1e-9);
+    }
+}
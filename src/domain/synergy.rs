@@ -0,0 +1,284 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use super::{
+    BonusData, BonusSource, BonusTarget, BonusTrigger, BonusValue, DamageFlags, HitDamage,
+};
+
+/// Cooldown before the same synergy can be activated again, in seconds
+pub const SYNERGY_COOLDOWN: f64 = 20.0;
+
+/// Undaunted Command: activating a synergy restores 4% of Max Health, Magicka and Stamina.
+pub const UNDAUNTED_COMMAND_RESTORE_PCT: f64 = 0.04;
+
+/// Buff duration of the Necrotic passive in seconds
+const NECROTIC_DURATION: f64 = 10.0;
+
+/// A synergy offered by another group member's skill. Activating it is off the
+/// global cooldown and uses the activating player's stats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Synergy {
+    /// Orbs (Necrotic Orb)
+    Combustion,
+    /// Shards (Luminous Shards)
+    BlessedShards,
+    /// Blood Altar
+    BloodFunnel,
+    /// Boneyard
+    GraveRobber,
+    /// Summon Storm Atronach
+    ChargedLightning,
+}
+
+/// Buff duration of Charged Lightning's Major Berserk in seconds
+const CHARGED_LIGHTNING_DURATION: f64 = 10.0;
+
+impl Synergy {
+    /// Skill that offers the synergy.
+    pub fn source(&self) -> &'static str {
+        match self {
+            Synergy::Combustion => "Necrotic Orb",
+            Synergy::BlessedShards => "Luminous Shards",
+            Synergy::BloodFunnel => "Blood Altar",
+            Synergy::GraveRobber => "Boneyard",
+            Synergy::ChargedLightning => "Summon Storm Atronach",
+        }
+    }
+
+    /// Damage dealt on activation. Coefficients are approximations.
+    pub fn damage(&self) -> Option<HitDamage> {
+        match self {
+            Synergy::Combustion => Some(HitDamage::new(
+                DamageFlags::MAGIC | DamageFlags::AOE,
+                0.0333,
+                0.35,
+            )),
+            Synergy::GraveRobber => Some(HitDamage::new(
+                DamageFlags::FROST | DamageFlags::AOE,
+                0.0333,
+                0.35,
+            )),
+            Synergy::BlessedShards | Synergy::BloodFunnel | Synergy::ChargedLightning => None,
+        }
+    }
+
+    /// Magicka or Stamina (whichever is the build's primary resource) restored on activation.
+    pub fn resource_restore(&self) -> f64 {
+        match self {
+            Synergy::Combustion | Synergy::BlessedShards => 3960.0,
+            _ => 0.0,
+        }
+    }
+
+    /// Fraction of Max Health healed on activation.
+    pub fn heal_pct(&self) -> f64 {
+        match self {
+            Synergy::BloodFunnel => 0.33,
+            _ => 0.0,
+        }
+    }
+
+    /// Buffs granted on activation, lasting `buff_duration()`.
+    pub fn bonuses(&self) -> Vec<BonusData> {
+        match self {
+            // Major Berserk: +10% Damage Done
+            Synergy::ChargedLightning => vec![BonusData::new(
+                "Major Berserk",
+                BonusSource::Buff,
+                BonusTrigger::Passive,
+                BonusValue::new("Major Berserk", BonusTarget::Damage, 0.10),
+            )],
+            _ => vec![],
+        }
+    }
+
+    pub fn buff_duration(&self) -> f64 {
+        CHARGED_LIGHTNING_DURATION
+    }
+
+    pub fn cooldown(&self) -> f64 {
+        SYNERGY_COOLDOWN
+    }
+}
+
+impl fmt::Display for Synergy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Synergy::Combustion => write!(f, "Combustion"),
+            Synergy::BlessedShards => write!(f, "Blessed Shards"),
+            Synergy::BloodFunnel => write!(f, "Blood Funnel"),
+            Synergy::GraveRobber => write!(f, "Grave Robber"),
+            Synergy::ChargedLightning => write!(f, "Charged Lightning"),
+        }
+    }
+}
+
+/// A passive of the activating player that triggers on every synergy activation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SynergyPassive {
+    /// Restores a share of Max Health, Magicka and Stamina
+    UndauntedCommand,
+    /// Boosts damage done for a while after activating a synergy. The value is
+    /// an approximation.
+    Necrotic,
+}
+
+impl SynergyPassive {
+    /// Fraction of Max Health, Magicka and Stamina restored on activation.
+    pub fn restore_pct(&self) -> f64 {
+        match self {
+            SynergyPassive::UndauntedCommand => UNDAUNTED_COMMAND_RESTORE_PCT,
+            SynergyPassive::Necrotic => 0.0,
+        }
+    }
+
+    /// Buffs granted on activation, lasting `buff_duration()`.
+    pub fn bonuses(&self) -> Vec<BonusData> {
+        match self {
+            SynergyPassive::Necrotic => vec![BonusData::new(
+                "Necrotic",
+                BonusSource::Passive,
+                BonusTrigger::Passive,
+                BonusValue::new("Necrotic", BonusTarget::Damage, 0.05),
+            )],
+            SynergyPassive::UndauntedCommand => vec![],
+        }
+    }
+
+    pub fn buff_duration(&self) -> f64 {
+        NECROTIC_DURATION
+    }
+}
+
+impl fmt::Display for SynergyPassive {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SynergyPassive::UndauntedCommand => write!(f, "Undaunted Command"),
+            SynergyPassive::Necrotic => write!(f, "Necrotic"),
+        }
+    }
+}
+
+/// When the group offers a synergy: first at `first` seconds, then every `every` seconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SynergyAvailability {
+    pub synergy: Synergy,
+    pub first: f64,
+    pub every: f64,
+}
+
+impl SynergyAvailability {
+    const fn new(synergy: Synergy, first: f64, every: f64) -> Self {
+        Self {
+            synergy,
+            first,
+            every,
+        }
+    }
+
+    /// First offer at or after `time`.
+    pub fn next_offer(&self, time: f64) -> f64 {
+        if time <= self.first {
+            return self.first;
+        }
+        self.first + ((time - self.first) / self.every).ceil() * self.every
+    }
+}
+
+/// Which synergies the rest of the group offers and how often. Timings are
+/// approximations of typical tank and healer setups.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SynergyProfile {
+    /// Solo or parse dummy: no synergies
+    #[default]
+    None,
+    /// 4-player dungeon: one tank and one healer
+    Dungeon,
+    /// 12-player trial: two tanks, two healers and a summoned atronach
+    Trial,
+}
+
+const DUNGEON_SYNERGIES: &[SynergyAvailability] = &[
+    SynergyAvailability::new(Synergy::BlessedShards, 4.0, 20.0),
+    SynergyAvailability::new(Synergy::Combustion, 6.0, 20.0),
+];
+
+const TRIAL_SYNERGIES: &[SynergyAvailability] = &[
+    SynergyAvailability::new(Synergy::BlessedShards, 2.0, 10.0),
+    SynergyAvailability::new(Synergy::Combustion, 4.0, 10.0),
+    SynergyAvailability::new(Synergy::GraveRobber, 6.0, 12.0),
+    SynergyAvailability::new(Synergy::BloodFunnel, 8.0, 20.0),
+    SynergyAvailability::new(Synergy::ChargedLightning, 12.0, 60.0),
+];
+
+/// Synergy passives a player grouping for content is expected to have
+const GROUP_SYNERGY_PASSIVES: &[SynergyPassive] =
+    &[SynergyPassive::UndauntedCommand, SynergyPassive::Necrotic];
+
+impl SynergyProfile {
+    pub fn availability(&self) -> &'static [SynergyAvailability] {
+        match self {
+            SynergyProfile::None => &[],
+            SynergyProfile::Dungeon => DUNGEON_SYNERGIES,
+            SynergyProfile::Trial => TRIAL_SYNERGIES,
+        }
+    }
+
+    /// Passives that trigger on each activation (none when solo).
+    pub fn passives(&self) -> &'static [SynergyPassive] {
+        match self {
+            SynergyProfile::None => &[],
+            SynergyProfile::Dungeon | SynergyProfile::Trial => GROUP_SYNERGY_PASSIVES,
+        }
+    }
+
+    pub fn parse(s: &str) -> Result<SynergyProfile, String> {
+        match s.to_lowercase().as_str() {
+            "none" => Ok(SynergyProfile::None),
+            "dungeon" => Ok(SynergyProfile::Dungeon),
+            "trial" => Ok(SynergyProfile::Trial),
+            _ => Err(format!(
+                "Unknown synergy profile '{}'. Valid: none, dungeon, trial",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for SynergyProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SynergyProfile::None => write!(f, "None"),
+            SynergyProfile::Dungeon => write!(f, "Dungeon"),
+            SynergyProfile::Trial => write!(f, "Trial"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_offer() {
+        let orbs = SynergyAvailability::new(Synergy::Combustion, 4.0, 10.0);
+        assert_eq!(orbs.next_offer(0.0), 4.0);
+        assert_eq!(orbs.next_offer(4.0), 4.0);
+        assert_eq!(orbs.next_offer(24.0), 24.0);
+        assert_eq!(orbs.next_offer(24.5), 34.0);
+    }
+
+    #[test]
+    fn test_parse_synergy_profile() {
+        assert_eq!(
+            SynergyProfile::parse("Trial").unwrap(),
+            SynergyProfile::Trial
+        );
+        assert!(SynergyProfile::parse("none")
+            .unwrap()
+            .availability()
+            .is_empty());
+        assert!(SynergyProfile::parse("raid").is_err());
+    }
+}
@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-use super::DamageFlags;
+use super::{
+    BonusData, BonusSource, BonusTarget, BonusTrigger, BonusValue, DamageFlags, Resource,
+    WeaponTrait,
+};
 
 /// Weapon enchant (glyph) types for DPS calculations.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -14,32 +17,83 @@ pub enum WeaponEnchant {
     Shock,
     /// Glyph of Weapon Damage (Berserker): adds W/SD instead of proc damage
     Berserker,
+    /// Glyph of Frost: deals frost damage and applies Chilled on proc
+    Frost,
+    /// Glyph of Crushing: reduces the enemy's Physical and Spell Resistance on proc
+    Crusher,
+    /// Glyph of Weakening: reduces the enemy's Weapon and Spell Damage on proc
+    Weakening,
+    /// Glyph of Absorb Magicka: deals magic damage and restores Magicka on proc
+    AbsorbMagicka,
+    /// Glyph of Absorb Stamina: deals disease damage and restores Stamina on proc
+    AbsorbStamina,
 }
 
+/// All weapon enchants for gear optimization.
+pub const DPS_WEAPON_ENCHANTS: &[WeaponEnchant] = &[
+    WeaponEnchant::Flame,
+    WeaponEnchant::Poison,
+    WeaponEnchant::Shock,
+    WeaponEnchant::Berserker,
+    WeaponEnchant::Frost,
+    WeaponEnchant::Crusher,
+    WeaponEnchant::Weakening,
+    WeaponEnchant::AbsorbMagicka,
+    WeaponEnchant::AbsorbStamina,
+];
+
 /// Base damage for CP160 gold weapon enchant glyphs.
 /// These are flat damage values that proc on weapon attacks.
 const ENCHANT_BASE_DAMAGE: f64 = 1_747.0;
 
-/// Cooldown for weapon enchants:
-/// - Non-Infused: 4s (but we model Infused by default)
-/// - Infused front bar: ~2s effective (halved)
-/// - Infused back bar: ~1s effective (halved + proc on ability cast)
-const ENCHANT_COOLDOWN_INFUSED: f64 = 2.0;
+/// Absorb glyphs trade half of the proc damage for the resource they restore.
+const ABSORB_BASE_DAMAGE: f64 = 873.0;
+const ABSORB_RESOURCE_RESTORED: f64 = 1_000.0;
+
+/// Infused Crusher: Physical and Spell Resistance removed from the enemy.
+const CRUSHER_RESISTANCE_REDUCTION: f64 = 2_108.0;
+
+/// Weakening: fraction of the enemy's Weapon and Spell Damage removed.
+const WEAKENING_DAMAGE_REDUCTION: f64 = 0.10;
+
+/// Duration of the Crusher and Weakening debuffs (seconds).
+const ENCHANT_DEBUFF_DURATION: f64 = 5.0;
+
+/// Cooldown between weapon enchant procs (seconds).
+const ENCHANT_COOLDOWN: f64 = 4.0;
+
+/// The Infused weapon trait halves the enchant cooldown.
+const INFUSED_COOLDOWN_MULT: f64 = 0.5;
+
+/// Chance for an off-cooldown enchant to proc on a light attack. Glyphs no longer roll:
+/// the first weapon attack after the cooldown always procs.
+const ENCHANT_PROC_CHANCE: f64 = 1.0;
 
 impl WeaponEnchant {
     /// Base proc damage at CP160 gold quality.
     pub fn base_damage(&self) -> f64 {
         match self {
-            WeaponEnchant::Flame | WeaponEnchant::Poison | WeaponEnchant::Shock => {
-                ENCHANT_BASE_DAMAGE
-            }
+            WeaponEnchant::Flame
+            | WeaponEnchant::Poison
+            | WeaponEnchant::Shock
+            | WeaponEnchant::Frost => ENCHANT_BASE_DAMAGE,
+            WeaponEnchant::AbsorbMagicka | WeaponEnchant::AbsorbStamina => ABSORB_BASE_DAMAGE,
             WeaponEnchant::Berserker => 0.0, // No proc damage, adds W/SD instead
+            WeaponEnchant::Crusher | WeaponEnchant::Weakening => 0.0, // Debuff only
         }
     }
 
-    /// Internal cooldown between procs (seconds), assuming Infused trait.
-    pub fn cooldown(&self) -> f64 {
-        ENCHANT_COOLDOWN_INFUSED
+    /// Internal cooldown between procs (seconds) on a weapon with the given trait.
+    pub fn cooldown(&self, weapon_trait: Option<WeaponTrait>) -> f64 {
+        match weapon_trait {
+            Some(WeaponTrait::Infused) => ENCHANT_COOLDOWN * INFUSED_COOLDOWN_MULT,
+            _ => ENCHANT_COOLDOWN,
+        }
+    }
+
+    /// Chance (0.0-1.0) to proc on each light attack while off cooldown.
+    pub fn proc_chance(&self) -> f64 {
+        ENCHANT_PROC_CHANCE
     }
 
     /// DamageFlags for the enchant proc's damage type.
@@ -54,7 +108,18 @@ impl WeaponEnchant {
             WeaponEnchant::Shock => {
                 DamageFlags::SHOCK | DamageFlags::DIRECT | DamageFlags::SINGLE_TARGET
             }
-            WeaponEnchant::Berserker => DamageFlags::empty(),
+            WeaponEnchant::Frost => {
+                DamageFlags::FROST | DamageFlags::DIRECT | DamageFlags::SINGLE_TARGET
+            }
+            WeaponEnchant::AbsorbMagicka => {
+                DamageFlags::MAGIC | DamageFlags::DIRECT | DamageFlags::SINGLE_TARGET
+            }
+            WeaponEnchant::AbsorbStamina => {
+                DamageFlags::DISEASE | DamageFlags::DIRECT | DamageFlags::SINGLE_TARGET
+            }
+            WeaponEnchant::Berserker | WeaponEnchant::Crusher | WeaponEnchant::Weakening => {
+                DamageFlags::empty()
+            }
         }
     }
 
@@ -73,19 +138,91 @@ impl WeaponEnchant {
                 duration: 6.0,
                 flags: DamageFlags::PHYSICAL | DamageFlags::DOT | DamageFlags::SINGLE_TARGET,
             }),
+            WeaponEnchant::Frost => Some(EnchantStatusEffect {
+                name: "Chilled",
+                total_damage: 1_200.0,
+                duration: 4.0,
+                flags: DamageFlags::FROST | DamageFlags::DOT | DamageFlags::SINGLE_TARGET,
+            }),
             WeaponEnchant::Shock => None, // Concussed is a debuff, not damage
-            WeaponEnchant::Berserker => None,
+            WeaponEnchant::Berserker
+            | WeaponEnchant::Crusher
+            | WeaponEnchant::Weakening
+            | WeaponEnchant::AbsorbMagicka
+            | WeaponEnchant::AbsorbStamina => None,
+        }
+    }
+
+    /// Debuffs applied to the enemy on proc.
+    /// The static optimizer phases scale these by `uptime()`; the fight
+    /// simulator applies them for `effect_duration()` on every proc.
+    pub fn bonuses(&self) -> Vec<BonusData> {
+        match self {
+            // Same debuff the trial dummy provides, so it is suppressed there
+            WeaponEnchant::Crusher => vec![BonusData::new(
+                "Infused Crusher",
+                BonusSource::Buff,
+                BonusTrigger::Passive,
+                BonusValue::new(
+                    "Infused Crusher",
+                    BonusTarget::EnemyResistanceReduction,
+                    CRUSHER_RESISTANCE_REDUCTION,
+                ),
+            )],
+            // Group utility only: lowers the damage the enemy deals
+            WeaponEnchant::Weakening => vec![BonusData::new(
+                "Weakening",
+                BonusSource::Buff,
+                BonusTrigger::Passive,
+                BonusValue::new(
+                    "Weakening",
+                    BonusTarget::EnemyWeaponAndSpellDamageReduction,
+                    WEAKENING_DAMAGE_REDUCTION,
+                ),
+            )],
+            _ => Vec::new(),
+        }
+    }
+
+    pub fn effect_duration(&self) -> f64 {
+        ENCHANT_DEBUFF_DURATION
+    }
+
+    /// Fraction of the fight the enchant's debuff is expected to be active.
+    pub fn uptime(&self, weapon_trait: Option<WeaponTrait>) -> f64 {
+        (self.effect_duration() * self.proc_chance() / self.cooldown(weapon_trait)).min(1.0)
+    }
+
+    /// Resource restored to the wielder on proc (absorb glyphs only).
+    pub fn resource_restored(&self) -> Option<(Resource, f64)> {
+        match self {
+            WeaponEnchant::AbsorbMagicka => Some((Resource::Magicka, ABSORB_RESOURCE_RESTORED)),
+            WeaponEnchant::AbsorbStamina => Some((Resource::Stamina, ABSORB_RESOURCE_RESTORED)),
+            _ => None,
         }
     }
 
+    /// Rough DPS estimate of the proc and status damage, used to rank enchants without simulating.
+    pub fn estimated_dps(&self, weapon_trait: Option<WeaponTrait>) -> f64 {
+        let modifier_estimate = 2.5;
+        let status_damage = self.status_effect().map_or(0.0, |s| s.total_damage);
+        (self.base_damage() + status_damage) * self.proc_chance() / self.cooldown(weapon_trait)
+            * modifier_estimate
+    }
+
     pub fn parse(s: &str) -> Result<WeaponEnchant, String> {
-        match s.to_lowercase().as_str() {
+        match s.to_lowercase().replace(' ', "-").as_str() {
             "flame" | "fire" => Ok(WeaponEnchant::Flame),
             "poison" => Ok(WeaponEnchant::Poison),
             "shock" | "lightning" => Ok(WeaponEnchant::Shock),
             "berserker" | "weapon-damage" => Ok(WeaponEnchant::Berserker),
+            "frost" | "ice" => Ok(WeaponEnchant::Frost),
+            "crusher" | "crushing" => Ok(WeaponEnchant::Crusher),
+            "weakening" => Ok(WeaponEnchant::Weakening),
+            "absorb-magicka" => Ok(WeaponEnchant::AbsorbMagicka),
+            "absorb-stamina" => Ok(WeaponEnchant::AbsorbStamina),
             _ => Err(format!(
-                "Unknown weapon enchant '{}'. Valid: flame, poison, shock, berserker",
+                "Unknown weapon enchant '{}'. Valid: flame, poison, shock, berserker, frost, crusher, weakening, absorb-magicka, absorb-stamina",
                 s
             )),
         }
@@ -99,6 +236,11 @@ impl fmt::Display for WeaponEnchant {
             WeaponEnchant::Poison => write!(f, "Poison"),
             WeaponEnchant::Shock => write!(f, "Shock"),
             WeaponEnchant::Berserker => write!(f, "Berserker"),
+            WeaponEnchant::Frost => write!(f, "Frost"),
+            WeaponEnchant::Crusher => write!(f, "Crusher"),
+            WeaponEnchant::Weakening => write!(f, "Weakening"),
+            WeaponEnchant::AbsorbMagicka => write!(f, "Absorb Magicka"),
+            WeaponEnchant::AbsorbStamina => write!(f, "Absorb Stamina"),
         }
     }
 }
@@ -17,6 +17,14 @@ pub enum WeaponType {
     Bow,
 }
 
+/// One-handed weapons a dual wield bar can hold in either hand.
+pub const DUAL_WIELD_WEAPONS: &[WeaponType] = &[
+    WeaponType::DualWieldSword,
+    WeaponType::DualWieldAxe,
+    WeaponType::DualWieldMace,
+    WeaponType::DualWieldDagger,
+];
+
 impl WeaponType {
     pub fn is_two_handed(&self) -> bool {
         matches!(
@@ -62,6 +70,20 @@ impl WeaponType {
         }
     }
 
+    /// Parse the one-hander held in a dual wield off hand ("dagger" or "dual-wield-dagger").
+    pub fn parse_off_hand(s: &str) -> Result<WeaponType, String> {
+        match s.to_lowercase().replace(' ', "-").as_str() {
+            "sword" | "dual-wield-sword" => Ok(WeaponType::DualWieldSword),
+            "axe" | "dual-wield-axe" => Ok(WeaponType::DualWieldAxe),
+            "mace" | "dual-wield-mace" => Ok(WeaponType::DualWieldMace),
+            "dagger" | "dual-wield-dagger" => Ok(WeaponType::DualWieldDagger),
+            _ => Err(format!(
+                "Unknown off-hand weapon '{}'. Valid: sword, axe, mace, dagger",
+                s
+            )),
+        }
+    }
+
     pub fn parse(s: &str) -> Result<WeaponType, String> {
         match s.to_lowercase().replace(' ', "-").as_str() {
             "two-handed-sword" => Ok(WeaponType::TwoHandedSword),
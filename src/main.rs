@@ -16,7 +16,7 @@ enum Commands {
     /// Run a discrete-event fight simulation for a specific build configuration
     Simulate(SimulateArgs),
     /// Find the optimal build to maximize total damage per cast
-    Optimize(OptimizeArgs),
+    Optimize(Box<OptimizeArgs>),
     /// Show stat breakpoints: how much of one stat before another becomes the better investment
     Breakpoints(BreakpointsArgs),
 }
@@ -12,6 +12,13 @@ pub struct BarDistribution {
     pub bar2: WeaponBar,
 }
 
+impl BarDistribution {
+    /// One-bar builds keep every skill on bar 1 and leave bar 2 empty.
+    pub fn is_single_bar(&self) -> bool {
+        self.bar2.skills.is_empty()
+    }
+}
+
 /// Infer weapon types from the weapon skill lines present in the build.
 /// Returns (bar1_weapon, bar2_weapon).
 pub fn infer_weapons(skills: &[&'static SkillData]) -> Result<(WeaponType, WeaponType), String> {
@@ -159,6 +166,23 @@ pub fn generate_distributions(
         .collect()
 }
 
+/// The only distribution of a one-bar build: every skill on bar 1, bar 2 empty.
+pub fn generate_single_bar_distribution(
+    skills: &[&'static SkillData],
+    weapon: WeaponType,
+) -> Vec<BarDistribution> {
+    vec![BarDistribution {
+        bar1: WeaponBar {
+            weapon_type: weapon,
+            skills: skills.to_vec(),
+        },
+        bar2: WeaponBar {
+            weapon_type: weapon,
+            skills: Vec::new(),
+        },
+    }]
+}
+
 fn combinations(items: &[usize], k: usize) -> Vec<Vec<usize>> {
     if k == 0 {
         return vec![vec![]];
@@ -196,4 +220,16 @@ mod tests {
         assert_eq!(combos.len(), 1);
         assert!(combos[0].is_empty());
     }
+
+    #[test]
+    fn test_single_bar_distribution_leaves_bar2_empty() {
+        let skills: Vec<&'static SkillData> = ["Lotus Fan", "Merciless Resolve", "Dark Shade"]
+            .iter()
+            .map(|name| SkillData::parse(name).unwrap())
+            .collect();
+        let distributions = generate_single_bar_distribution(&skills, WeaponType::Bow);
+        assert_eq!(distributions.len(), 1);
+        assert_eq!(distributions[0].bar1.skills.len(), 3);
+        assert!(distributions[0].is_single_bar());
+    }
 }
@@ -4,27 +4,33 @@ use crate::domain::formulas::effective_damage_contribution;
 /// Stats we compare in the breakpoint grid.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BreakpointStat {
-    WeaponSpellDamage,
+    WeaponDamage,
+    SpellDamage,
     Resource,
-    CritRating,
+    WeaponCritRating,
+    SpellCritRating,
     CritDamage,
     Penetration,
 }
 
 impl BreakpointStat {
-    pub const ALL: [BreakpointStat; 5] = [
-        BreakpointStat::WeaponSpellDamage,
+    pub const ALL: [BreakpointStat; 7] = [
+        BreakpointStat::WeaponDamage,
+        BreakpointStat::SpellDamage,
         BreakpointStat::Resource,
-        BreakpointStat::CritRating,
+        BreakpointStat::WeaponCritRating,
+        BreakpointStat::SpellCritRating,
         BreakpointStat::CritDamage,
         BreakpointStat::Penetration,
     ];
 
     pub fn label(&self) -> &'static str {
         match self {
-            BreakpointStat::WeaponSpellDamage => "WD/SD",
+            BreakpointStat::WeaponDamage => "WD",
+            BreakpointStat::SpellDamage => "SD",
             BreakpointStat::Resource => "Resource",
-            BreakpointStat::CritRating => "CritRat",
+            BreakpointStat::WeaponCritRating => "WpnCrit",
+            BreakpointStat::SpellCritRating => "SplCrit",
             BreakpointStat::CritDamage => "CritDmg",
             BreakpointStat::Penetration => "Pen",
         }
@@ -33,7 +39,8 @@ impl BreakpointStat {
     /// The BonusTarget used for edc evaluation.
     fn bonus_target(&self, stats: &CharacterStats) -> BonusTarget {
         match self {
-            BreakpointStat::WeaponSpellDamage => BonusTarget::WeaponAndSpellDamageFlat,
+            BreakpointStat::WeaponDamage => BonusTarget::WeaponDamageFlat,
+            BreakpointStat::SpellDamage => BonusTarget::SpellDamageFlat,
             BreakpointStat::Resource => {
                 if stats.max_magicka >= stats.max_stamina {
                     BonusTarget::MaxMagickaFlat
@@ -41,7 +48,8 @@ impl BreakpointStat {
                     BonusTarget::MaxStaminaFlat
                 }
             }
-            BreakpointStat::CritRating => BonusTarget::CriticalRating,
+            BreakpointStat::WeaponCritRating => BonusTarget::WeaponCriticalRating,
+            BreakpointStat::SpellCritRating => BonusTarget::SpellCriticalRating,
             BreakpointStat::CritDamage => BonusTarget::CriticalDamage,
             BreakpointStat::Penetration => BonusTarget::PhysicalAndSpellPenetration,
         }
@@ -58,9 +66,14 @@ impl BreakpointStat {
     /// Maximum additional amount of this stat that can be added.
     fn max_delta(&self, stats: &CharacterStats) -> f64 {
         match self {
-            BreakpointStat::WeaponSpellDamage => 5000.0,
+            BreakpointStat::WeaponDamage | BreakpointStat::SpellDamage => 5000.0,
             BreakpointStat::Resource => 50000.0,
-            BreakpointStat::CritRating => (21_912.0 - stats.critical_rating).max(0.0),
+            BreakpointStat::WeaponCritRating => {
+                (21_912.0 - stats.weapon_critical_rating_total()).max(0.0)
+            }
+            BreakpointStat::SpellCritRating => {
+                (21_912.0 - stats.spell_critical_rating_total()).max(0.0)
+            }
             BreakpointStat::CritDamage => (2.25 - stats.critical_damage).max(0.0),
             BreakpointStat::Penetration => (stats.target_armor - stats.penetration).max(0.0),
         }
@@ -70,8 +83,10 @@ impl BreakpointStat {
     pub(crate) fn apply_delta(&self, stats: &CharacterStats, delta: f64) -> CharacterStats {
         let mut s = stats.clone();
         match self {
-            BreakpointStat::WeaponSpellDamage => {
+            BreakpointStat::WeaponDamage => {
                 s.weapon_damage += delta;
+            }
+            BreakpointStat::SpellDamage => {
                 s.spell_damage += delta;
             }
             BreakpointStat::Resource => {
@@ -81,8 +96,11 @@ impl BreakpointStat {
                     s.max_stamina += delta;
                 }
             }
-            BreakpointStat::CritRating => {
-                s.critical_rating += delta;
+            BreakpointStat::WeaponCritRating => {
+                s.weapon_critical_rating += delta;
+            }
+            BreakpointStat::SpellCritRating => {
+                s.spell_critical_rating += delta;
             }
             BreakpointStat::CritDamage => {
                 s.critical_damage += delta;
@@ -291,8 +309,8 @@ mod tests {
         // Same stat: edc_a == edc_b, so edc_a <= edc_b -> Some(0)
         let result = find_crossover(
             &stats,
-            BreakpointStat::CritRating,
-            BreakpointStat::CritRating,
+            BreakpointStat::WeaponCritRating,
+            BreakpointStat::WeaponCritRating,
             1000.0,
         );
         assert_eq!(result, Some(0.0));
@@ -304,18 +322,26 @@ mod tests {
         let result = find_crossover(
             &stats,
             BreakpointStat::Penetration,
-            BreakpointStat::WeaponSpellDamage,
+            BreakpointStat::WeaponDamage,
             BreakpointStat::Penetration.max_delta(&stats),
         );
         assert!(result.is_none());
     }
 
     #[test]
-    fn test_apply_delta_wd_sd() {
+    fn test_apply_delta_weapon_damage() {
         let stats = test_stats();
-        let modified = BreakpointStat::WeaponSpellDamage.apply_delta(&stats, 100.0);
+        let modified = BreakpointStat::WeaponDamage.apply_delta(&stats, 100.0);
         assert_eq!(modified.weapon_damage, 6100.0);
-        assert_eq!(modified.spell_damage, 6100.0);
+        assert_eq!(modified.spell_damage, 6000.0);
+    }
+
+    #[test]
+    fn test_apply_delta_spell_crit_rating() {
+        let stats = test_stats();
+        let modified = BreakpointStat::SpellCritRating.apply_delta(&stats, 1314.0);
+        assert_eq!(modified.spell_critical_rating, 1314.0);
+        assert_eq!(modified.weapon_critical_rating_total(), 10956.0);
     }
 
     #[test]
@@ -329,7 +355,7 @@ mod tests {
     #[test]
     fn test_grid_format_diagonal() {
         let grid = BreakpointGrid {
-            cells: vec![vec![None; 5]; 5],
+            cells: vec![vec![None; 7]; 7],
         };
         assert_eq!(grid.format_cell(0, 0), "-");
         assert_eq!(grid.format_cell(2, 2), "-");
@@ -338,34 +364,34 @@ mod tests {
     #[test]
     fn test_grid_format_na() {
         let grid = BreakpointGrid {
-            cells: vec![vec![None; 5]; 5],
+            cells: vec![vec![None; 7]; 7],
         };
         assert_eq!(grid.format_cell(0, 1), "N/A");
     }
 
     #[test]
     fn test_grid_format_crit_damage_row_uses_percent() {
-        let mut cells = vec![vec![None; 5]; 5];
-        cells[3][0] = Some(0.15);
+        let mut cells = vec![vec![None; 7]; 7];
+        cells[5][0] = Some(0.15);
         let grid = BreakpointGrid { cells };
-        assert_eq!(grid.format_cell(3, 0), "+15%");
+        assert_eq!(grid.format_cell(5, 0), "+15%");
     }
 
     #[test]
     fn test_grid_format_integer_row() {
-        let mut cells = vec![vec![None; 5]; 5];
+        let mut cells = vec![vec![None; 7]; 7];
         cells[0][2] = Some(450.0);
         let grid = BreakpointGrid { cells };
         assert_eq!(grid.format_cell(0, 2), "+450");
     }
 
     #[test]
-    fn test_run_produces_5x5_grid() {
+    fn test_run_produces_7x7_grid() {
         let stats = test_stats();
         let grid = BreakpointsPipeline::run(&stats);
-        assert_eq!(grid.cells.len(), 5);
+        assert_eq!(grid.cells.len(), 7);
         for row in &grid.cells {
-            assert_eq!(row.len(), 5);
+            assert_eq!(row.len(), 7);
         }
     }
 }
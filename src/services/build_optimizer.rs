@@ -1,8 +1,8 @@
 use crate::data::bonuses::CHAMPION_POINTS;
 use crate::data::skills::ALL_SKILLS;
 use crate::domain::{
-    BonusData, BonusTrigger, Build, CharacterStats, DamageFlags, ResolvedBonus, SkillData,
-    BUILD_CONSTRAINTS,
+    BonusData, BonusTrigger, Build, BuildConstraints, CharacterStats, DamageFlags,
+    PositionalProfile, ResolvedBonus, SkillData, BUILD_CONSTRAINTS,
 };
 use crate::domain::{ResolveContext, SkillLineName, SkillTree};
 use crate::infrastructure::{combinatorics, format, logger, table};
@@ -31,6 +31,8 @@ pub struct BuildOptimizerOptions {
     pub set_names: Vec<(String, u8)>,
     pub extra_bonuses: Vec<BonusData>,
     pub armor_passive_bonuses: Vec<BonusData>,
+    /// Search one-bar builds (5 skills, one weapon skill line)
+    pub single_bar: bool,
 }
 
 /// Three-way split of bonuses for the optimizer fast path:
@@ -41,6 +43,7 @@ type PreSplitBonuses = (Vec<ResolvedBonus>, Vec<BonusData>, Vec<BonusData>);
 
 pub struct BuildOptimizer {
     character_stats: CharacterStats,
+    constraints: BuildConstraints,
     required_class_names: Vec<SkillTree>,
     class_names: HashSet<SkillTree>,
     required_weapon_skill_lines: Vec<SkillLineName>,
@@ -85,6 +88,7 @@ impl BuildOptimizer {
         let mut required_weapon_skill_lines = options.required_weapon_skill_lines;
         let required_champion_points = options.required_champion_points;
         let pure = options.pure;
+        let constraints = BuildConstraints::for_bars(options.single_bar);
 
         // Auto-infer constraints from required skills
         let mut forced_morphs: Vec<String> = Vec::new();
@@ -165,7 +169,11 @@ impl BuildOptimizer {
             Self::generate_class_skill_line_combinations(pure, &required_class_names, verbose);
 
         let (weapon_skill_line_names, weapon_skill_line_combinations) =
-            Self::generate_weapon_skill_line_combinations(&required_weapon_skill_lines, verbose);
+            Self::generate_weapon_skill_line_combinations(
+                &required_weapon_skill_lines,
+                constraints.weapon_skill_line_count,
+                verbose,
+            );
 
         let guild_skill_lines = SkillLineName::GUILD.to_vec();
         let skill_line_combinations: Vec<Vec<SkillLineName>> = combinatorics::cartesian_product(
@@ -255,8 +263,12 @@ impl BuildOptimizer {
             .map(|b| b.name.clone())
             .collect();
 
-        let (mut passive_bonuses_list, mut passive_original) =
-            Self::generate_passive_bonuses(&skill_line_combinations, &extra_bonus_names, verbose);
+        let (mut passive_bonuses_list, mut passive_original) = Self::generate_passive_bonuses(
+            &skill_line_combinations,
+            &extra_bonus_names,
+            options.character_stats.position,
+            verbose,
+        );
 
         // Merge armor passives into every passive combination
         if !options.armor_passive_bonuses.is_empty() {
@@ -277,7 +289,15 @@ impl BuildOptimizer {
 
         // Merge fixed set bonuses into CP combo pre_resolved buckets
         // (set bonuses are always BonusTrigger::Passive → always pre_resolved)
-        let set_bonuses = options.set_bonuses;
+        let combat_mode = options.character_stats.combat_mode;
+        let set_bonuses: Vec<BonusData> = options
+            .set_bonuses
+            .into_iter()
+            .filter(|b| b.applies_in(combat_mode))
+            .flat_map(|b| {
+                PositionalProfile::apply_opt(options.character_stats.position.as_ref(), &b)
+            })
+            .collect();
         let set_names = options.set_names;
         if !set_bonuses.is_empty() {
             let (set_pre, set_ability, set_alt) = Self::three_way_split(set_bonuses.clone());
@@ -320,6 +340,7 @@ impl BuildOptimizer {
         }
 
         let total_possible_build_count = Self::calculate_total_build_count(
+            constraints.skill_count,
             &champion_point_combinations,
             &spammable_skills,
             &finisher_skills,
@@ -330,6 +351,7 @@ impl BuildOptimizer {
 
         let optimizer = Self {
             character_stats: options.character_stats,
+            constraints,
             required_class_names,
             class_names,
             required_weapon_skill_lines,
@@ -405,7 +427,11 @@ impl BuildOptimizer {
             .cloned()
             .collect();
 
-        let free_slots = BUILD_CONSTRAINTS.champion_point_count - required_champion_points.len();
+        let free_slots = BUILD_CONSTRAINTS.champion_point_count
+            - required_champion_points
+                .iter()
+                .filter(|cp| !cp.is_fitness_champion_point())
+                .count();
 
         let filtered: Vec<Vec<BonusData>> =
             combinatorics::generate_combinations(&free_pool, free_slots)
@@ -524,6 +550,7 @@ impl BuildOptimizer {
 
     fn generate_weapon_skill_line_combinations(
         required_weapon_skill_lines: &[SkillLineName],
+        weapon_skill_line_count: usize,
         verbose: bool,
     ) -> (HashSet<SkillLineName>, Vec<Vec<SkillLineName>>) {
         let mut weapon_skill_line_names: HashSet<SkillLineName> = HashSet::new();
@@ -533,8 +560,7 @@ impl BuildOptimizer {
             .filter(|w| !required_weapon_skill_lines.contains(w))
             .copied()
             .collect();
-        let free_slots =
-            BUILD_CONSTRAINTS.weapon_skill_line_count - required_weapon_skill_lines.len();
+        let free_slots = weapon_skill_line_count.saturating_sub(required_weapon_skill_lines.len());
 
         for &w in required_weapon_skill_lines {
             weapon_skill_line_names.insert(w);
@@ -765,6 +791,7 @@ impl BuildOptimizer {
     fn generate_passive_bonuses(
         skill_line_combinations: &[Vec<SkillLineName>],
         suppressed_names: &HashSet<String>,
+        position: Option<PositionalProfile>,
         verbose: bool,
     ) -> (Vec<PreSplitBonuses>, Vec<Vec<BonusData>>) {
         let all_used_skill_lines: HashSet<SkillLineName> =
@@ -783,6 +810,7 @@ impl BuildOptimizer {
                     .flat_map(|sl| passives_service.get_passives_by_skill_line(*sl))
                     .flat_map(|passive| passive.bonuses.iter().cloned())
                     .filter(|b| !suppressed_names.contains(&b.name))
+                    .flat_map(|b| PositionalProfile::apply_opt(position.as_ref(), &b))
                     .collect()
             })
             .collect();
@@ -803,6 +831,7 @@ impl BuildOptimizer {
     }
 
     fn calculate_total_build_count(
+        skill_count: usize,
         champion_point_combinations: &[PreSplitBonuses],
         spammable_skills: &[Vec<&'static SkillData>],
         finisher_skills: &[Vec<&'static SkillData>],
@@ -810,7 +839,7 @@ impl BuildOptimizer {
         required_non_spammable_count: usize,
         has_required_finisher: bool,
     ) -> u64 {
-        let base_free_slots = BUILD_CONSTRAINTS.skill_count - 1 - required_non_spammable_count;
+        let base_free_slots = skill_count - 1 - required_non_spammable_count;
         let skill_combinations_count: u64 = spammable_skills
             .iter()
             .zip(finisher_skills.iter())
@@ -964,7 +993,7 @@ impl BuildOptimizer {
                     };
 
                     let req_count = self.required_non_spammable.len();
-                    let non_spam_count = BUILD_CONSTRAINTS.skill_count - 1 - has_finisher as usize;
+                    let non_spam_count = self.constraints.skill_count - 1 - has_finisher as usize;
                     let free_slots = non_spam_count - req_count;
 
                     if self.champion_point_combinations.len() > 1 {
@@ -1030,9 +1059,8 @@ impl BuildOptimizer {
                                             );
                                         cp_raw_totals[cp_idx] += cp_skill_damages[cp_idx][i];
                                     }
-                                    let damage = cp_raw_totals[cp_idx]
-                                        * cp_ctxs[cp_idx].armor_factor
-                                        * cp_ctxs[cp_idx].crit_mult;
+                                    let damage =
+                                        cp_raw_totals[cp_idx] * cp_ctxs[cp_idx].armor_factor;
                                     track(damage, &combo, cp_idx);
                                 }
                             } else {
@@ -1065,7 +1093,7 @@ impl BuildOptimizer {
                                         raw += d;
                                     }
                                     cp_raw_totals[cp_idx] = raw;
-                                    let damage = raw * cp_ctx.armor_factor * cp_ctx.crit_mult;
+                                    let damage = raw * cp_ctx.armor_factor;
                                     track(damage, &combo, cp_idx);
                                     cp_ctxs.push(cp_ctx);
                                 }
@@ -1113,7 +1141,7 @@ impl BuildOptimizer {
                                         Build::single_skill_damage(combo[i], ctx);
                                     raw_total += per_skill_damages[i];
                                 }
-                                let damage = raw_total * ctx.armor_factor * ctx.crit_mult;
+                                let damage = raw_total * ctx.armor_factor;
                                 track(damage, &combo, 0);
                             } else {
                                 let ctx = Build::compute_eval_context(
@@ -1131,7 +1159,7 @@ impl BuildOptimizer {
                                     per_skill_damages[i] = Build::single_skill_damage(skill, &ctx);
                                     raw_total += per_skill_damages[i];
                                 }
-                                let damage = raw_total * ctx.armor_factor * ctx.crit_mult;
+                                let damage = raw_total * ctx.armor_factor;
                                 track(damage, &combo, 0);
                                 eval_ctx = Some(ctx);
                             }
@@ -1207,19 +1235,19 @@ impl BuildOptimizer {
         let config_data = vec![
             vec![
                 "Skills".to_string(),
-                BUILD_CONSTRAINTS.skill_count.to_string(),
+                self.constraints.skill_count.to_string(),
             ],
             vec![
                 "Champion Points".to_string(),
-                BUILD_CONSTRAINTS.champion_point_count.to_string(),
+                self.constraints.champion_point_count.to_string(),
             ],
             vec![
                 "Class Skill Lines".to_string(),
-                BUILD_CONSTRAINTS.class_skill_line_count.to_string(),
+                self.constraints.class_skill_line_count.to_string(),
             ],
             vec![
                 "Weapon Skill Lines".to_string(),
-                BUILD_CONSTRAINTS.weapon_skill_line_count.to_string(),
+                self.constraints.weapon_skill_line_count.to_string(),
             ],
             vec!["Workers".to_string(), self.parallelism.to_string()],
         ];
@@ -1,11 +1,22 @@
 use crate::data::light_attacks::light_attack_for_weapon;
-use crate::domain::simulation::{BAR_SWAP_DELAY, GCD, TRIAL_DUMMY_HP};
+use crate::domain::build::PowerPair;
+use crate::domain::simulation::{
+    BAR_SWAP_DELAY, DPS_CURVE_CHECKPOINTS, GCD, HEAVY_ATTACK_CAST_TIME, HEAVY_ATTACK_DAMAGE_MULT,
+    HEAVY_ATTACK_INTERVAL, TRIAL_DUMMY_HP,
+};
 use crate::domain::weapon_enchant::WeaponEnchant;
 use crate::domain::{
     ActiveBar, ActiveBuff, ActiveEffect, BonusData, BonusTarget, BonusTrigger, BuffUptime,
-    CharacterStats, DamageCoefficients, DamageFlags, ResolveContext, SetProcAction, SetProcEffect,
-    SetProcTrigger, SimulationResult, SkillBreakdown, SkillData, SkillLineName,
+    CharacterStats, DamageCoefficients, DamageFlags, DotSnapshot, DpsSample, Encounter,
+    EncounterPhase, ExecutePhase, FightPhase, FightTarget, MonteCarloSummary, Opener, PhaseKind,
+    PhaseStart, Poison, Potion, ResolveContext, Resource, ResourceSustain, SetProcAction,
+    SetProcEffect, SetProcTrigger, SimulationResult, SkillBreakdown, SkillData, SkillLineName,
+    StackGain, StackMechanic, Synergy, SynergyAvailability, SynergyPassive, SynergyProfile,
+    TimelineEvent, WeaponTrait, WeaponType,
 };
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rayon::prelude::*;
 use std::collections::{HashMap, HashSet};
 
 use super::bar_distribution::BarDistribution;
@@ -14,6 +25,8 @@ pub const DEFAULT_AVG_RESOURCE_PCT: f64 = 50.0;
 
 pub struct FightSimulator {
     pub target_hp: f64,
+    /// Fixed fight length in seconds (None = fight until target_hp is depleted)
+    pub max_duration: Option<f64>,
     pub effective_stats: CharacterStats,
     pub resolved_bonuses: Vec<BonusData>,
     pub armor_factor: f64,
@@ -24,10 +37,60 @@ pub struct FightSimulator {
     /// Weapon enchants for each bar (None = no enchant modeled)
     pub bar1_enchant: Option<WeaponEnchant>,
     pub bar2_enchant: Option<WeaponEnchant>,
+    /// Off-hand enchants of dual wield bars (None = no second enchant)
+    pub bar1_off_hand_enchant: Option<WeaponEnchant>,
+    pub bar2_off_hand_enchant: Option<WeaponEnchant>,
+    /// Weapon traits of each bar's main and off hand (Infused shortens enchant cooldowns)
+    pub bar1_weapon_trait: Option<WeaponTrait>,
+    pub bar2_weapon_trait: Option<WeaponTrait>,
+    pub bar1_off_hand_trait: Option<WeaponTrait>,
+    pub bar2_off_hand_trait: Option<WeaponTrait>,
+    /// Weapon poisons for each bar (None = no poison modeled)
+    pub bar1_poison: Option<Poison>,
+    pub bar2_poison: Option<Poison>,
+    /// Potion drunk on cooldown during the fight (None = no potion modeled)
+    pub potion: Option<Potion>,
     /// Set proc effects from equipped gear sets
     pub set_procs: Vec<SetProcEffect>,
     /// Average resource percentage (0-100) for resource-scaling set procs
     pub avg_resource_pct: f64,
+    /// Pre-pull buffing sequence run before the damage clock starts
+    pub opener: Opener,
+    /// Record every action into the result timeline
+    pub record_timeline: bool,
+    /// DoT parts locked in at cast time for skills without their own setting
+    pub dot_snapshot: DotSnapshot,
+    /// Scripted encounter phases: adds, immune, movement and bar-locked windows
+    pub encounter_phases: Vec<EncounterPhase>,
+    /// Boss behaviour below an execute health threshold
+    pub execute_phase: Option<ExecutePhase>,
+    /// Synergies the group offers, activated whenever available and off cooldown
+    pub synergies: &'static [SynergyAvailability],
+    /// Passives triggered by each synergy activation
+    pub synergy_passives: &'static [SynergyPassive],
+    /// Percentage stat multipliers already baked into effective_stats.
+    /// The potion's flat buffs are scaled by these so a drunk potion matches the
+    /// always-on potion bonus of the static optimizer.
+    static_multipliers: StatMultipliers,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct StatMultipliers {
+    weapon_damage: f64,
+    spell_damage: f64,
+    max_magicka: f64,
+    max_stamina: f64,
+    max_health: f64,
+}
+
+impl StatMultipliers {
+    const NONE: Self = Self {
+        weapon_damage: 1.0,
+        spell_damage: 1.0,
+        max_magicka: 1.0,
+        max_stamina: 1.0,
+        max_health: 1.0,
+    };
 }
 
 struct SimState {
@@ -37,39 +100,169 @@ struct SimState {
     active_effects: Vec<ActiveEffect>,
     active_buffs: Vec<ActiveBuff>,
     gcd_ready: f64,
-    // Proc tracking: skill name -> accumulated light attack count
-    proc_counters: HashMap<String, u32>,
+    // Skill stacks: counter name -> (stacks, time they decay)
+    stacks: HashMap<String, (u32, f64)>,
+    // Most stacks each counter holds, from the slotted skills' declarations
+    stack_caps: HashMap<String, u32>,
     // Tracking
     skill_damage: HashMap<String, (f64, u32)>,
     la_damage: f64,
     la_count: u32,
     bar_swap_count: u32,
+    // Time the next heavy attack is due (only woven for heavy attack set procs)
+    heavy_attack_ready: f64,
     // Buff uptime tracking: buff name -> total seconds active
     buff_uptimes: HashMap<String, f64>,
     // Weapon enchant cooldown: time when enchant can next proc
     enchant_ready: f64,
+    // Off-hand enchant cooldown of dual wield bars
+    off_hand_enchant_ready: f64,
+    // Potion cooldown: time when the next potion can be drunk
+    potion_ready: f64,
     // Weapon enchant damage tracking
     enchant_damage: f64,
     enchant_proc_count: u32,
+    // Weapon poison cooldown: time when the poison can next proc
+    poison_ready: f64,
+    // Weapon poison damage tracking: breakdown name -> (damage, procs)
+    poison_damage: HashMap<String, (f64, u32)>,
+    // Resources restored by procs (absorb enchants)
+    resources_restored: HashMap<Resource, f64>,
     // Set proc state
     set_proc_cooldowns: HashMap<String, f64>,
     set_proc_stacks: HashMap<String, (u32, f64)>,
     set_proc_damage: HashMap<String, (f64, u32)>,
     // Flat LA bonus from set procs (summed once at init)
     flat_la_bonus: f64,
+    // DPS-vs-fight-length samples
+    dps_curve: Vec<DpsSample>,
+    // Recorded actions (only when record_timeline is set)
+    timeline: Vec<TimelineEvent>,
+    next_curve_checkpoint: usize,
+    // Proc chance accrued per source towards its next proc (deterministic runs)
+    proc_chance_progress: HashMap<String, f64>,
+    // Random proc rolls (Monte Carlo runs only)
+    rng: Option<StdRng>,
+    // Next start of each encounter phase (None once it has played out)
+    phase_starts: Vec<Option<PhaseStart>>,
+    // Rotation stopped until this time (immune and movement phases)
+    downtime_until: f64,
+    // Boss takes no damage until this time
+    immune_until: f64,
+    // Bar swapping blocked until this time (bar-locked phases)
+    bar_locked_until: f64,
+    // Seconds the rotation was stopped by downtime phases
+    downtime: f64,
+    // Adds spawned by encounter phases
+    adds: Vec<AddGroup>,
+    // Area damage dealt to adds
+    add_damage: f64,
+    // Time each synergy can next be activated (next offer after its cooldown)
+    synergy_ready: Vec<f64>,
+    // Synergy damage tracking: synergy -> (damage, activations)
+    synergy_damage: HashMap<Synergy, (f64, u32)>,
+}
+
+/// Adds from one spawn. They take the same area damage, so they share a
+/// remaining health value and die together.
+struct AddGroup {
+    count: u32,
+    // Remaining health of each add
+    hp: f64,
+    // The rest of the group has killed them by then
+    until: f64,
+}
+
+impl AddGroup {
+    fn alive(&self, time: f64) -> bool {
+        self.hp > 0.0 && time < self.until
+    }
+}
+
+impl SimState {
+    /// Hit every alive add with `damage`. Adds die once their health is depleted.
+    fn damage_adds(&mut self, damage: f64) {
+        let time = self.time;
+        for group in self.adds.iter_mut().filter(|g| g.alive(time)) {
+            let landed = damage.min(group.hp);
+            group.hp -= landed;
+            self.add_damage += landed * group.count as f64;
+        }
+    }
+
+    /// Stacks held on a counter (none once they have decayed).
+    fn stacks(&self, name: &str) -> u32 {
+        match self.stacks.get(name) {
+            Some(&(stacks, decays_at)) if self.time < decays_at => stacks,
+            _ => 0,
+        }
+    }
+
+    /// Whether a capped counter holds as many stacks as it can.
+    fn stacks_full(&self, name: &str) -> bool {
+        self.stack_caps
+            .get(name)
+            .is_some_and(|&cap| self.stacks(name) >= cap)
+    }
+
+    /// Whether a capped counter still has room for more stacks.
+    fn stacks_below_cap(&self, name: &str) -> bool {
+        self.stack_caps
+            .get(name)
+            .is_some_and(|&cap| self.stacks(name) < cap)
+    }
+
+    /// Apply a gain event of `mechanic` and restart its decay timer.
+    fn gain_stacks(&mut self, mechanic: &StackMechanic) {
+        let stacks = mechanic.gained(self.stacks(&mechanic.name));
+        let decays_at = mechanic.decay.map_or(f64::INFINITY, |d| self.time + d);
+        self.stacks
+            .insert(mechanic.name.clone(), (stacks, decays_at));
+    }
+
+    /// Apply damage to the boss and return what landed (nothing while it is immune).
+    /// Area damage also hits every alive add.
+    fn deal_damage(&mut self, damage: f64, flags: DamageFlags) -> f64 {
+        if flags.contains(DamageFlags::AOE) {
+            self.damage_adds(damage);
+        }
+        if self.time < self.immune_until {
+            return 0.0;
+        }
+        self.remaining_hp -= damage;
+        damage
+    }
 }
 
 /// Pre-computed stats with active buffs applied.
+/// `max_power`, `crit_chance` and `crit_mult` belong to the best weapon/spell pair
+/// and are used by damage not tied to a skill (light attacks, enchants, procs).
 struct BuffedContext {
+    stats: CharacterStats,
     max_stat: f64,
     max_power: f64,
     armor_factor: f64,
+    crit_chance: f64,
     crit_mult: f64,
 }
 
+impl BuffedContext {
+    /// Resource pool a skill's stat coefficient scales with.
+    fn skill_stat(&self, skill: &SkillData) -> f64 {
+        self.stats.stat_for(skill.resource)
+    }
+
+    /// Damage stat and critical multiplier of the pair a skill scales with.
+    fn skill_pair(&self, skill: &SkillData) -> PowerPair {
+        PowerPair::from_stats(&self.stats, skill.power_type(&self.stats))
+    }
+}
+
 #[derive(Debug)]
 enum Action {
     CastSkill(usize),
+    LightAttack,
+    HeavyAttack,
     BarSwap,
 }
 
@@ -79,17 +272,36 @@ impl FightSimulator {
         resolved_bonuses: &[BonusData],
         suppressed_buff_names: HashSet<String>,
     ) -> Self {
-        let armor_factor = crate::domain::formulas::armor_damage_factor(
-            effective_stats.target_armor,
-            effective_stats.penetration,
-        );
+        let armor_factor = effective_stats.target_damage_factor();
         let crit_mult = crate::domain::formulas::critical_multiplier(
             effective_stats.critical_chance(),
-            effective_stats.critical_damage,
+            effective_stats.effective_critical_damage(),
         );
 
+        let ctx = ResolveContext::new(effective_stats.clone());
+        let mut static_multipliers = StatMultipliers::NONE;
+        for bonus in resolved_bonuses {
+            if bonus.trigger == BonusTrigger::AbilitySlottedCount {
+                continue;
+            }
+            let bv = bonus.resolve(&ctx);
+            match bv.target {
+                BonusTarget::WeaponDamage => static_multipliers.weapon_damage *= 1.0 + bv.value,
+                BonusTarget::SpellDamage => static_multipliers.spell_damage *= 1.0 + bv.value,
+                BonusTarget::WeaponAndSpellDamageMultiplier => {
+                    static_multipliers.weapon_damage *= 1.0 + bv.value;
+                    static_multipliers.spell_damage *= 1.0 + bv.value;
+                }
+                BonusTarget::MaxMagicka => static_multipliers.max_magicka *= 1.0 + bv.value,
+                BonusTarget::MaxStamina => static_multipliers.max_stamina *= 1.0 + bv.value,
+                BonusTarget::MaxHealth => static_multipliers.max_health *= 1.0 + bv.value,
+                _ => {}
+            }
+        }
+
         Self {
             target_hp: TRIAL_DUMMY_HP,
+            max_duration: None,
             effective_stats: effective_stats.clone(),
             resolved_bonuses: resolved_bonuses.to_vec(),
             armor_factor,
@@ -97,8 +309,25 @@ impl FightSimulator {
             suppressed_buff_names,
             bar1_enchant: None,
             bar2_enchant: None,
+            bar1_off_hand_enchant: None,
+            bar2_off_hand_enchant: None,
+            bar1_weapon_trait: None,
+            bar2_weapon_trait: None,
+            bar1_off_hand_trait: None,
+            bar2_off_hand_trait: None,
+            bar1_poison: None,
+            bar2_poison: None,
+            potion: None,
             set_procs: Vec::new(),
             avg_resource_pct: DEFAULT_AVG_RESOURCE_PCT,
+            opener: Opener::None,
+            record_timeline: false,
+            dot_snapshot: DotSnapshot::default(),
+            encounter_phases: Vec::new(),
+            execute_phase: None,
+            synergies: &[],
+            synergy_passives: &[],
+            static_multipliers,
         }
     }
 
@@ -107,6 +336,64 @@ impl FightSimulator {
         self
     }
 
+    pub fn with_potion(mut self, potion: Option<Potion>) -> Self {
+        self.potion = potion;
+        self
+    }
+
+    pub fn with_opener(mut self, opener: Opener) -> Self {
+        self.opener = opener;
+        self
+    }
+
+    pub fn with_timeline(mut self, record: bool) -> Self {
+        self.record_timeline = record;
+        self
+    }
+
+    pub fn with_dot_snapshot(mut self, snapshot: DotSnapshot) -> Self {
+        self.dot_snapshot = snapshot;
+        self
+    }
+
+    pub fn with_fight_target(mut self, target: FightTarget) -> Self {
+        match target {
+            FightTarget::TargetHp(hp) => {
+                self.target_hp = hp;
+                self.max_duration = None;
+            }
+            FightTarget::Duration(duration) => {
+                self.target_hp = TRIAL_DUMMY_HP;
+                self.max_duration = Some(duration);
+            }
+        }
+        self
+    }
+
+    /// Play out an encounter script: boss HP, phases and execute behaviour.
+    /// Replaces the fight target.
+    pub fn with_encounter(mut self, encounter: Option<&Encounter>) -> Self {
+        if let Some(encounter) = encounter {
+            self.target_hp = encounter.hp;
+            self.max_duration = None;
+            self.encounter_phases = encounter.phases.clone();
+            self.execute_phase = encounter.execute;
+        }
+        self
+    }
+
+    /// Add downtime windows on top of the encounter script (if any).
+    pub fn with_downtime(mut self, phases: Vec<EncounterPhase>) -> Self {
+        self.encounter_phases.extend(phases);
+        self
+    }
+
+    pub fn with_synergies(mut self, profile: SynergyProfile) -> Self {
+        self.synergies = profile.availability();
+        self.synergy_passives = profile.passives();
+        self
+    }
+
     pub fn with_avg_resource_pct(mut self, pct: f64) -> Self {
         self.avg_resource_pct = pct;
         self
@@ -122,9 +409,50 @@ impl FightSimulator {
         self
     }
 
-    /// Compute character stats with all AbilitySlotted buffs applied (self-buffed stats).
+    pub fn with_off_hand_enchants(
+        mut self,
+        bar1_enchant: Option<WeaponEnchant>,
+        bar2_enchant: Option<WeaponEnchant>,
+    ) -> Self {
+        self.bar1_off_hand_enchant = bar1_enchant;
+        self.bar2_off_hand_enchant = bar2_enchant;
+        self
+    }
+
+    pub fn with_weapon_traits(
+        mut self,
+        bar1_trait: Option<WeaponTrait>,
+        bar2_trait: Option<WeaponTrait>,
+    ) -> Self {
+        self.bar1_weapon_trait = bar1_trait;
+        self.bar2_weapon_trait = bar2_trait;
+        self
+    }
+
+    pub fn with_off_hand_traits(
+        mut self,
+        bar1_trait: Option<WeaponTrait>,
+        bar2_trait: Option<WeaponTrait>,
+    ) -> Self {
+        self.bar1_off_hand_trait = bar1_trait;
+        self.bar2_off_hand_trait = bar2_trait;
+        self
+    }
+
+    pub fn with_poisons(
+        mut self,
+        bar1_poison: Option<Poison>,
+        bar2_poison: Option<Poison>,
+    ) -> Self {
+        self.bar1_poison = bar1_poison;
+        self.bar2_poison = bar2_poison;
+        self
+    }
+
+    /// Compute character stats with all AbilitySlotted buffs and the potion applied (self-buffed stats).
     pub fn compute_buffed_stats(&self, distribution: &BarDistribution) -> CharacterStats {
         let mut buffs: Vec<ActiveBuff> = Vec::new();
+        self.apply_potion_buffs(&mut buffs);
 
         for skill in distribution
             .bar1
@@ -162,33 +490,46 @@ impl FightSimulator {
     fn apply_buffs_to_stats(&self, active_buffs: &[ActiveBuff]) -> CharacterStats {
         let mut stats = self.effective_stats.clone();
 
-        // Pass 1: flat stat buffs
+        // Pass 1: flat stat buffs (the potion's are scaled by the percentage bonuses
+        // baked into effective_stats)
         for buff in active_buffs {
+            let mult = if self.is_potion_buff(buff) {
+                self.static_multipliers
+            } else {
+                StatMultipliers::NONE
+            };
             match buff.target {
                 BonusTarget::WeaponAndSpellDamageFlat => {
-                    stats.weapon_damage += buff.value;
-                    stats.spell_damage += buff.value;
+                    stats.weapon_damage += buff.value * mult.weapon_damage;
+                    stats.spell_damage += buff.value * mult.spell_damage;
                 }
                 BonusTarget::WeaponDamageFlat => {
-                    stats.weapon_damage += buff.value;
+                    stats.weapon_damage += buff.value * mult.weapon_damage;
                 }
                 BonusTarget::SpellDamageFlat => {
-                    stats.spell_damage += buff.value;
+                    stats.spell_damage += buff.value * mult.spell_damage;
                 }
                 BonusTarget::MaxMagickaFlat => {
-                    stats.max_magicka += buff.value;
+                    stats.max_magicka += buff.value * mult.max_magicka;
                 }
                 BonusTarget::MaxStaminaFlat => {
-                    stats.max_stamina += buff.value;
+                    stats.max_stamina += buff.value * mult.max_stamina;
+                }
+                BonusTarget::MaxHealthFlat => {
+                    stats.max_health += buff.value * mult.max_health;
                 }
                 BonusTarget::CriticalDamage => {
                     stats.critical_damage += buff.value;
                 }
-                BonusTarget::CriticalRating
-                | BonusTarget::WeaponCriticalRating
-                | BonusTarget::SpellCriticalRating => {
+                BonusTarget::CriticalRating => {
                     stats.critical_rating += buff.value;
                 }
+                BonusTarget::WeaponCriticalRating => {
+                    stats.weapon_critical_rating += buff.value;
+                }
+                BonusTarget::SpellCriticalRating => {
+                    stats.spell_critical_rating += buff.value;
+                }
                 BonusTarget::PhysicalAndSpellPenetration
                 | BonusTarget::EnemyResistanceReduction => {
                     stats.penetration += buff.value;
@@ -218,17 +559,95 @@ impl FightSimulator {
         stats
     }
 
+    /// Simulate the fight with proc chances applied as expected values.
     pub fn simulate(&self, distribution: &BarDistribution) -> SimulationResult {
-        // Initialize proc counters for all proc skills on both bars
-        let mut proc_counters = HashMap::new();
+        self.run_fight(distribution, None)
+    }
+
+    /// Simulate the fight `runs` times, rolling proc chances and critical hit procs for real.
+    /// Seeds are derived from `seed` so results are reproducible. Returns the run closest to
+    /// the mean DPS with the spread across all runs attached.
+    pub fn simulate_monte_carlo(
+        &self,
+        distribution: &BarDistribution,
+        runs: usize,
+        seed: u64,
+    ) -> SimulationResult {
+        let mut results: Vec<SimulationResult> = (0..runs.max(1) as u64)
+            .into_par_iter()
+            .map(|i| {
+                let rng = StdRng::seed_from_u64(seed.wrapping_add(i));
+                self.run_fight(distribution, Some(rng))
+            })
+            .collect();
+
+        let n = results.len() as f64;
+        let mean_dps = results.iter().map(|r| r.dps).sum::<f64>() / n;
+        let std_dev = (results
+            .iter()
+            .map(|r| (r.dps - mean_dps).powi(2))
+            .sum::<f64>()
+            / n)
+            .sqrt();
+        let min_dps = results.iter().map(|r| r.dps).fold(f64::INFINITY, f64::min);
+        let max_dps = results
+            .iter()
+            .map(|r| r.dps)
+            .fold(f64::NEG_INFINITY, f64::max);
+
+        let closest = results
+            .iter()
+            .enumerate()
+            .min_by(|a, b| {
+                (a.1.dps - mean_dps)
+                    .abs()
+                    .partial_cmp(&(b.1.dps - mean_dps).abs())
+                    .unwrap()
+            })
+            .map(|(i, _)| i)
+            .unwrap_or(0);
+        let mut result = results.swap_remove(closest);
+        result.monte_carlo = Some(MonteCarloSummary {
+            runs: results.len() + 1,
+            mean_dps,
+            std_dev,
+            min_dps,
+            max_dps,
+        });
+        result
+    }
+
+    fn run_fight(&self, distribution: &BarDistribution, rng: Option<StdRng>) -> SimulationResult {
+        // Stack counters declared by skills on both bars. Light attack and skill line
+        // gains apply while the declaring skill is slotted: (skill name, mechanic)
+        let mut stack_caps = HashMap::new();
+        let mut passive_stack_gains: Vec<(&str, &StackMechanic)> = Vec::new();
+        let mut stack_bonuses: Vec<&StackMechanic> = Vec::new();
         for skill in distribution
             .bar1
             .skills
             .iter()
             .chain(distribution.bar2.skills.iter())
         {
-            if skill.proc_light_attacks.is_some() {
-                proc_counters.entry(skill.name.clone()).or_insert(0);
+            for mechanic in &skill.stacks {
+                if let Some(max) = mechanic.max_stacks {
+                    stack_caps.insert(mechanic.name.clone(), max);
+                }
+                if !mechanic.bonuses_per_stack.is_empty()
+                    && !stack_bonuses.iter().any(|m| m.name == mechanic.name)
+                {
+                    stack_bonuses.push(mechanic);
+                }
+                let passive = mechanic.gain.is_some()
+                    && !mechanic.gains_on_cast()
+                    && !mechanic.ramps_per_tick();
+                if passive
+                    && !passive_stack_gains
+                        .iter()
+                        .any(|(_, m)| m.name == mechanic.name && m.gain == mechanic.gain)
+                {
+                    passive_stack_gains.push((skill.name.as_str(), mechanic));
+                }
             }
         }
 
@@ -249,19 +668,44 @@ impl FightSimulator {
             active_effects: Vec::new(),
             active_buffs: Vec::new(),
             gcd_ready: 0.0,
-            proc_counters,
+            stacks: HashMap::new(),
+            stack_caps,
             skill_damage: HashMap::new(),
             la_damage: 0.0,
             la_count: 0,
             bar_swap_count: 0,
+            heavy_attack_ready: 0.0,
             buff_uptimes: HashMap::new(),
             enchant_ready: 0.0,
+            off_hand_enchant_ready: 0.0,
+            potion_ready: 0.0,
             enchant_damage: 0.0,
             enchant_proc_count: 0,
+            poison_ready: 0.0,
+            poison_damage: HashMap::new(),
+            resources_restored: HashMap::new(),
             set_proc_cooldowns: HashMap::new(),
             set_proc_stacks: HashMap::new(),
             set_proc_damage: HashMap::new(),
             flat_la_bonus,
+            dps_curve: Vec::new(),
+            next_curve_checkpoint: 0,
+            timeline: Vec::new(),
+            proc_chance_progress: HashMap::new(),
+            rng,
+            phase_starts: self
+                .encounter_phases
+                .iter()
+                .map(|p| Some(p.start))
+                .collect(),
+            downtime_until: 0.0,
+            immune_until: 0.0,
+            bar_locked_until: 0.0,
+            downtime: 0.0,
+            adds: Vec::new(),
+            add_damage: 0.0,
+            synergy_ready: self.synergies.iter().map(|s| s.first).collect(),
+            synergy_damage: HashMap::new(),
         };
 
         // Register permanent AbilitySlotted buffs from all skills on both bars
@@ -297,26 +741,82 @@ impl FightSimulator {
             }
         }
 
+        // Pre-pull opener ends exactly at t=0
+        let opener_duration = self.run_opener(&mut state, distribution);
+
         // Safety: prevent infinite loops
         let max_iterations = 1_000_000;
         let mut iterations = 0;
 
-        while state.remaining_hp > 0.0 && iterations < max_iterations {
+        while !self.fight_over(&state) && iterations < max_iterations {
             iterations += 1;
 
-            // Advance time to next GCD
-            let target_time = state.gcd_ready;
+            // Advance time to next GCD (never past the end of a fixed-duration fight
+            // or the start of a scripted encounter phase)
+            let mut target_time = match self.max_duration {
+                Some(duration) => state.gcd_ready.min(duration),
+                None => state.gcd_ready,
+            };
+            if let Some(next_phase) = self.next_phase_time(&state) {
+                target_time = target_time.min(next_phase);
+            }
             if state.time < target_time {
                 self.advance_time(&mut state, target_time);
             }
 
+            // Sample cumulative DPS for the fight-length curve
+            while let Some(&checkpoint) = DPS_CURVE_CHECKPOINTS.get(state.next_curve_checkpoint) {
+                if state.time < checkpoint {
+                    break;
+                }
+                state.dps_curve.push(DpsSample {
+                    time: checkpoint,
+                    dps: (self.target_hp - state.remaining_hp) / state.time,
+                });
+                state.next_curve_checkpoint += 1;
+            }
+
+            if self.max_duration.is_some_and(|d| state.time >= d) {
+                break;
+            }
+
+            self.start_encounter_phases(&mut state);
+
+            // Potions are off the GCD: drink whenever the cooldown is up
+            if let Some(potion) = self.potion {
+                if state.time >= state.potion_ready {
+                    self.apply_potion_buffs(&mut state.active_buffs);
+                    state.potion_ready = state.time + potion.cooldown();
+                    self.record_event(&mut state, format!("Drink {} Potion", potion));
+                }
+            }
+
+            // Nothing is cast during immune and movement phases
+            if state.time < state.downtime_until {
+                state.gcd_ready = state.gcd_ready.max(state.downtime_until);
+                continue;
+            }
+
+            // Synergies are off the GCD too
+            self.activate_synergies(&mut state);
+
+            // Stopped at a phase start between two actions
+            if state.time < state.gcd_ready {
+                continue;
+            }
+
             let current_skills = match state.active_bar {
                 ActiveBar::Bar1 => &distribution.bar1.skills,
                 ActiveBar::Bar2 => &distribution.bar2.skills,
             };
-            let other_skills = match state.active_bar {
-                ActiveBar::Bar1 => &distribution.bar2.skills,
-                ActiveBar::Bar2 => &distribution.bar1.skills,
+            // A bar-locked phase plays like a one-bar build
+            let other_skills: &[&'static SkillData] = if state.time < state.bar_locked_until {
+                &[]
+            } else {
+                match state.active_bar {
+                    ActiveBar::Bar1 => &distribution.bar2.skills,
+                    ActiveBar::Bar2 => &distribution.bar1.skills,
+                }
             };
 
             let action = self.decide_action(&state, current_skills, other_skills);
@@ -332,129 +832,46 @@ impl FightSimulator {
                     // Compute buffed context from current active buffs
                     let buffed = self.compute_buffed_context(&state.active_buffs);
 
-                    let health_pct = state.remaining_hp / self.target_hp;
-
-                    // 1. Light attack damage (uses current buffs)
-                    let la_data = light_attack_for_weapon(current_weapon);
-                    let (la_done_base, la_taken_base) =
-                        self.compute_modifier_for_flags(la_data.flags, None, health_pct);
-                    let (la_done_buff, la_taken_buff) =
-                        self.compute_buff_modifier_for_flags(la_data.flags, &state.active_buffs);
-                    let mut la_dmg = la_data.calculate_damage(
-                        la_done_base + la_done_buff,
-                        la_taken_base + la_taken_buff,
-                        buffed.max_stat,
-                        buffed.max_power,
-                        buffed.armor_factor,
-                        buffed.crit_mult,
-                    );
-                    // Add flat LA bonus from set procs (applied with same modifiers)
-                    if state.flat_la_bonus > 0.0 {
-                        la_dmg += state.flat_la_bonus
-                            * (1.0 + la_done_base + la_done_buff)
-                            * (1.0 + la_taken_base + la_taken_buff)
-                            * buffed.armor_factor
-                            * buffed.crit_mult;
-                    }
-                    state.remaining_hp -= la_dmg;
-                    state.la_damage += la_dmg;
-                    state.la_count += 1;
-
-                    // Increment all proc counters on every light attack
-                    for counter in state.proc_counters.values_mut() {
-                        *counter += 1;
-                    }
-
-                    // 1b. Weapon enchant proc (triggered by light attack)
-                    if state.time >= state.enchant_ready {
-                        let enchant = match state.active_bar {
-                            ActiveBar::Bar1 => self.bar1_enchant,
-                            ActiveBar::Bar2 => self.bar2_enchant,
-                        };
-                        if let Some(enchant) = enchant {
-                            let base_dmg = enchant.base_damage();
-                            if base_dmg > 0.0 {
-                                let flags = enchant.damage_flags();
-                                let (done_base, taken_base) =
-                                    self.compute_modifier_for_flags(flags, None, health_pct);
-                                let (done_buff, taken_buff) = self
-                                    .compute_buff_modifier_for_flags(flags, &state.active_buffs);
-                                let enchant_dmg = base_dmg
-                                    * (1.0 + done_base + done_buff)
-                                    * (1.0 + taken_base + taken_buff)
-                                    * buffed.armor_factor
-                                    * buffed.crit_mult;
-                                state.remaining_hp -= enchant_dmg;
-                                state.enchant_damage += enchant_dmg;
-                                state.enchant_proc_count += 1;
-                            }
-
-                            // Register status effect as active DoT
-                            if let Some(status) = enchant.status_effect() {
-                                let tick_value = status.total_damage;
-                                let (done_base, taken_base) =
-                                    self.compute_modifier_for_flags(status.flags, None, health_pct);
-                                let (done_buff, taken_buff) = self.compute_buff_modifier_for_flags(
-                                    status.flags,
-                                    &state.active_buffs,
-                                );
-
-                                // Remove existing status effect of same type
-                                state
-                                    .active_effects
-                                    .retain(|e| e.source_skill_name != status.name);
-
-                                state.active_effects.push(ActiveEffect {
-                                    source_skill_name: status.name.to_string(),
-                                    remaining_duration: status.duration,
-                                    next_tick_in: status.duration,
-                                    tick_interval: status.duration,
-                                    tick_count: 0,
-                                    total_ticks: 1,
-                                    base_value: tick_value,
-                                    flags: status.flags,
-                                    coefficients: crate::domain::DamageCoefficients::new(0.0, 0.0),
-                                    increase_per_tick: 0.0,
-                                    flat_increase_per_tick: 0.0,
-                                    ignores_modifier: false,
-                                    snapshotted_done_modifier: done_base + done_buff,
-                                    snapshotted_taken_modifier: taken_base + taken_buff,
-                                    snapshotted_armor_factor: buffed.armor_factor,
-                                    snapshotted_crit_mult: buffed.crit_mult,
-                                });
-                            }
-
-                            state.enchant_ready = state.time + enchant.cooldown();
-                        }
-                    }
+                    let health_pct = self.health_pct(&state);
 
-                    // 1c. Set proc triggers: OnLightAttack
-                    self.process_set_procs(
-                        SetProcTrigger::OnLightAttack,
-                        &buffed,
+                    // 1. Light attack weave: damage, enchant/poison procs and light attack procs
+                    self.weave_light_attack(
                         &mut state,
+                        current_weapon,
+                        &buffed,
                         health_pct,
+                        &passive_stack_gains,
+                        &stack_bonuses,
                     );
 
-                    // 2. Skill hit damage (instant portion), gated by proc requirement
-                    let hit_dmg = if let Some(threshold) = skill.proc_light_attacks {
-                        let counter = state.proc_counters.get(&skill.name).copied().unwrap_or(0);
-                        if counter >= threshold {
-                            let dmg = self.calc_skill_hits(
-                                skill,
-                                &buffed,
-                                &state.active_buffs,
-                                health_pct,
-                            );
-                            state.proc_counters.insert(skill.name.clone(), 0);
-                            dmg
-                        } else {
-                            0.0
-                        }
-                    } else {
+                    // 1d. Stacks held: hits wait for required stacks, damage scales per stack,
+                    // and hits also scale with the channel length (longer channels keep ticking)
+                    let held: Vec<u32> =
+                        skill.stacks.iter().map(|m| state.stacks(&m.name)).collect();
+                    let stacks_ready = skill
+                        .stacks
+                        .iter()
+                        .zip(&held)
+                        .all(|(m, &stacks)| m.is_ready(stacks));
+                    let channel = skill.channel_time.unwrap_or(GCD);
+                    let mut cast_time = channel;
+                    let mut stack_multiplier = 1.0;
+                    for (mechanic, &stacks) in skill.stacks.iter().zip(&held) {
+                        cast_time += mechanic.extra_duration(stacks);
+                        stack_multiplier *= mechanic.damage_multiplier(stacks);
+                    }
+                    let dot_stack_multiplier = stack_multiplier;
+                    stack_multiplier *= cast_time / channel;
+
+                    // 2. Skill hit damage (instant portion), gated by stack requirements
+                    let (single_dmg, area_dmg) = if stacks_ready {
                         self.calc_skill_hits(skill, &buffed, &state.active_buffs, health_pct)
+                    } else {
+                        (0.0, 0.0)
                     };
-                    state.remaining_hp -= hit_dmg;
+                    let hit_dmg = state
+                        .deal_damage(single_dmg * stack_multiplier, DamageFlags::empty())
+                        + state.deal_damage(area_dmg * stack_multiplier, DamageFlags::AOE);
 
                     let entry = state
                         .skill_damage
@@ -463,7 +880,7 @@ impl FightSimulator {
                     entry.0 += hit_dmg;
                     entry.1 += 1;
 
-                    // 2b. Set proc triggers: OnDirectDamage (after skill hit)
+                    // 2b. Set proc triggers: OnDirectDamage and OnCriticalHit (after skill hit)
                     if hit_dmg > 0.0 {
                         self.process_set_procs(
                             SetProcTrigger::OnDirectDamage,
@@ -471,65 +888,17 @@ impl FightSimulator {
                             &mut state,
                             health_pct,
                         );
+                        self.process_critical_hit(&buffed, &mut state, health_pct);
                     }
 
                     // 3. Register/refresh DoTs as active effects (snapshot at cast time)
-                    if let Some(damage) = &skill.damage {
-                        if let Some(dots) = &damage.dots {
-                            for dot in dots {
-                                let base_value =
-                                    dot.effective_value(buffed.max_stat, buffed.max_power);
-                                let interval = dot.interval.unwrap_or(dot.duration);
-                                let total_ticks = (dot.duration / interval).floor() as i32;
-                                let delay = dot.delay.unwrap_or(0.0);
-
-                                // Snapshot modifier at cast time (includes execute bonuses if currently in range)
-                                let (snapshotted_done, snapshotted_taken) =
-                                    if dot.ignores_modifier.unwrap_or(false) {
-                                        (0.0, 0.0)
-                                    } else {
-                                        let (done_base, taken_base) = self
-                                            .compute_modifier_for_flags(
-                                                dot.flags,
-                                                Some(skill.skill_line),
-                                                health_pct,
-                                            );
-                                        let (done_buff, taken_buff) = self
-                                            .compute_buff_modifier_for_flags(
-                                                dot.flags,
-                                                &state.active_buffs,
-                                            );
-                                        (done_base + done_buff, taken_base + taken_buff)
-                                    };
-
-                                // Remove existing effect from same skill
-                                state.active_effects.retain(|e| {
-                                    e.source_skill_name != skill.name || e.flags != dot.flags
-                                });
-
-                                state.active_effects.push(ActiveEffect {
-                                    source_skill_name: skill.name.clone(),
-                                    remaining_duration: dot.duration + delay,
-                                    next_tick_in: interval + delay,
-                                    tick_interval: interval,
-                                    tick_count: 0,
-                                    total_ticks,
-                                    base_value,
-                                    flags: dot.flags,
-                                    coefficients: dot.coefficients,
-                                    increase_per_tick: dot.increase_per_tick.unwrap_or(0.0),
-                                    flat_increase_per_tick: dot
-                                        .flat_increase_per_tick
-                                        .unwrap_or(0.0),
-                                    ignores_modifier: dot.ignores_modifier.unwrap_or(false),
-                                    snapshotted_done_modifier: snapshotted_done,
-                                    snapshotted_taken_modifier: snapshotted_taken,
-                                    snapshotted_armor_factor: buffed.armor_factor,
-                                    snapshotted_crit_mult: buffed.crit_mult,
-                                });
-                            }
-                        }
-                    }
+                    self.register_skill_dots(
+                        &mut state,
+                        skill,
+                        &buffed,
+                        health_pct,
+                        dot_stack_multiplier,
+                    );
 
                     // 3b. Set proc triggers: OnDealDamage (after all damage)
                     self.process_set_procs(
@@ -539,17 +908,155 @@ impl FightSimulator {
                         health_pct,
                     );
 
+                    // 3c. Set proc triggers: OnSkillLineCast
+                    self.process_set_procs(
+                        SetProcTrigger::OnSkillLineCast(skill.skill_line),
+                        &buffed,
+                        &mut state,
+                        health_pct,
+                    );
+
                     // 4. Register/refresh Cast buffs from skill bonuses
                     self.register_cast_buffs(&mut state, skill);
 
+                    // 4b. Consume spent stacks, then gain stacks from this cast
+                    let line_gains: Vec<&StackMechanic> = passive_stack_gains
+                        .iter()
+                        .filter(|(owner, m)| {
+                            *owner != skill.name
+                                && m.gain == Some(StackGain::SkillLineCast(skill.skill_line))
+                        })
+                        .map(|(_, m)| *m)
+                        .collect();
+                    let mut before: Vec<(&str, u32)> = Vec::new();
+                    for mechanic in skill.stacks.iter().chain(line_gains.iter().copied()) {
+                        if !before.iter().any(|(name, _)| *name == mechanic.name) {
+                            before.push((&mechanic.name, state.stacks(&mechanic.name)));
+                        }
+                    }
+                    for mechanic in &skill.stacks {
+                        if mechanic.consumes && stacks_ready {
+                            state.stacks.remove(&mechanic.name);
+                        }
+                        if mechanic.gains_on_cast() {
+                            state.gain_stacks(mechanic);
+                        }
+                    }
+                    for mechanic in line_gains {
+                        state.gain_stacks(mechanic);
+                    }
+                    self.sync_stack_bonuses(&mut state, &stack_bonuses);
+                    let mut description = format!("Cast {}", skill.name);
+                    for (name, stacks) in before {
+                        let after = state.stacks(name);
+                        if after != stacks {
+                            description =
+                                format!("{} ({} {} -> {})", description, name, stacks, after);
+                        }
+                    }
+                    self.record_event(&mut state, description);
+
                     // 5. Advance GCD
-                    let cast_time = skill.channel_time.unwrap_or(GCD);
                     state.gcd_ready = state.time + cast_time;
                 }
+                Action::LightAttack => {
+                    let current_weapon = match state.active_bar {
+                        ActiveBar::Bar1 => distribution.bar1.weapon_type,
+                        ActiveBar::Bar2 => distribution.bar2.weapon_type,
+                    };
+                    let buffed = self.compute_buffed_context(&state.active_buffs);
+                    let health_pct = self.health_pct(&state);
+                    self.weave_light_attack(
+                        &mut state,
+                        current_weapon,
+                        &buffed,
+                        health_pct,
+                        &passive_stack_gains,
+                        &stack_bonuses,
+                    );
+                    self.process_set_procs(
+                        SetProcTrigger::OnDealDamage,
+                        &buffed,
+                        &mut state,
+                        health_pct,
+                    );
+                    self.record_event(&mut state, "Light Attack".to_string());
+
+                    state.gcd_ready = state.time + GCD;
+                }
+                Action::HeavyAttack => {
+                    let current_weapon = match state.active_bar {
+                        ActiveBar::Bar1 => distribution.bar1.weapon_type,
+                        ActiveBar::Bar2 => distribution.bar2.weapon_type,
+                    };
+                    let buffed = self.compute_buffed_context(&state.active_buffs);
+                    let health_pct = self.health_pct(&state);
+
+                    // Scaled from the weapon's light attack, without light attack bonuses
+                    let la_data = light_attack_for_weapon(current_weapon);
+                    let flags = la_data.flags.difference(DamageFlags::LIGHT_ATTACK);
+                    let (done_base, taken_base) =
+                        self.compute_modifier_for_flags(flags, None, health_pct);
+                    let (done_buff, taken_buff) =
+                        self.compute_buff_modifier_for_flags(flags, &state.active_buffs);
+                    let ha_dmg = la_data.calculate_damage(
+                        done_base + done_buff,
+                        taken_base + taken_buff,
+                        buffed.max_stat,
+                        buffed.max_power,
+                        buffed.armor_factor,
+                        buffed.crit_mult,
+                    ) * HEAVY_ATTACK_DAMAGE_MULT;
+                    let ha_dmg = state.deal_damage(ha_dmg, flags);
+                    let entry = state
+                        .skill_damage
+                        .entry("Heavy Attack".to_string())
+                        .or_insert((0.0, 0));
+                    entry.0 += ha_dmg;
+                    entry.1 += 1;
+
+                    for trigger in [
+                        SetProcTrigger::OnHeavyAttack,
+                        SetProcTrigger::OnDirectDamage,
+                    ] {
+                        self.process_set_procs(trigger, &buffed, &mut state, health_pct);
+                    }
+                    self.process_critical_hit(&buffed, &mut state, health_pct);
+                    self.process_set_procs(
+                        SetProcTrigger::OnDealDamage,
+                        &buffed,
+                        &mut state,
+                        health_pct,
+                    );
+                    self.record_event(&mut state, "Heavy Attack".to_string());
+
+                    state.gcd_ready = state.time + HEAVY_ATTACK_CAST_TIME;
+                    state.heavy_attack_ready = state.time + HEAVY_ATTACK_INTERVAL;
+                }
                 Action::BarSwap => {
                     state.active_bar = state.active_bar.opposite();
                     state.gcd_ready = state.time + BAR_SWAP_DELAY;
                     state.bar_swap_count += 1;
+                    let description = Self::bar_swap_description(state.active_bar);
+                    self.record_event(&mut state, description);
+
+                    // Set proc triggers: OnBarSwap
+                    if self.has_set_proc(SetProcTrigger::OnBarSwap) {
+                        let buffed = self.compute_buffed_context(&state.active_buffs);
+                        let health_pct = self.health_pct(&state);
+                        self.process_set_procs(
+                            SetProcTrigger::OnBarSwap,
+                            &buffed,
+                            &mut state,
+                            health_pct,
+                        );
+                        // Bar swap procs charge while not swapping: restart them on every swap
+                        for proc in &self.set_procs {
+                            if proc.trigger == SetProcTrigger::OnBarSwap {
+                                self.start_set_proc_cooldown(proc, &mut state);
+                            }
+                        }
+                    }
                 }
             }
         }
@@ -575,6 +1082,17 @@ impl FightSimulator {
             });
         }
 
+        // Add weapon poison damage entries
+        for (name, (damage, count)) in &state.poison_damage {
+            if *damage > 0.0 {
+                skill_breakdown.push(SkillBreakdown {
+                    skill_name: name.clone(),
+                    damage: *damage,
+                    cast_count: *count,
+                });
+            }
+        }
+
         // Add set proc damage entries
         for (name, (damage, count)) in &state.set_proc_damage {
             if *damage > 0.0 {
@@ -586,9 +1104,41 @@ impl FightSimulator {
             }
         }
 
+        // Add synergy damage entries
+        for (synergy, (damage, count)) in &state.synergy_damage {
+            if *damage > 0.0 {
+                skill_breakdown.push(SkillBreakdown {
+                    skill_name: format!("{} (Synergy)", synergy),
+                    damage: *damage,
+                    cast_count: *count,
+                });
+            }
+        }
+
         skill_breakdown.sort_by(|a, b| b.damage.partial_cmp(&a.damage).unwrap());
 
-        let total_damage = self.target_hp - state.remaining_hp.max(0.0);
+        // Fixed-duration fights keep dealing damage past target_hp; only HP fights cap overkill
+        let (total_damage, time_to_kill) = if self.max_duration.is_some() {
+            (self.target_hp - state.remaining_hp, None)
+        } else {
+            let killed = state.remaining_hp <= 0.0;
+            (
+                self.target_hp - state.remaining_hp.max(0.0),
+                killed.then_some(fight_duration),
+            )
+        };
+
+        // Close the curve with the final fight length
+        if state
+            .dps_curve
+            .last()
+            .is_none_or(|s| s.time < fight_duration)
+        {
+            state.dps_curve.push(DpsSample {
+                time: fight_duration,
+                dps: total_damage / fight_duration,
+            });
+        }
 
         let mut buff_uptimes: Vec<BuffUptime> = state
             .buff_uptimes
@@ -611,15 +1161,101 @@ impl FightSimulator {
 
         buff_uptimes.sort_by(|a, b| a.name.cmp(&b.name));
 
+        let mut resources_restored: Vec<ResourceSustain> = state
+            .resources_restored
+            .into_iter()
+            .map(|(resource, restored)| ResourceSustain { resource, restored })
+            .collect();
+        resources_restored.sort_by_key(|r| r.resource.to_string());
+
         SimulationResult {
             total_damage,
             fight_duration,
             dps: total_damage / fight_duration,
+            time_to_kill,
+            dps_curve: state.dps_curve,
+            opener_duration,
+            timeline: state.timeline,
+            monte_carlo: None,
             skill_breakdown,
             la_damage: state.la_damage,
             la_count: state.la_count,
             bar_swap_count: state.bar_swap_count,
             buff_uptimes,
+            resources_restored,
+            add_damage: state.add_damage,
+            synergy_damage: state
+                .synergy_damage
+                .values()
+                .map(|(damage, _)| damage)
+                .sum(),
+            downtime: state.downtime,
+        }
+    }
+
+    /// Earliest upcoming start of a time-started encounter phase.
+    fn next_phase_time(&self, state: &SimState) -> Option<f64> {
+        state
+            .phase_starts
+            .iter()
+            .filter_map(|start| match start {
+                Some(PhaseStart::Time(t)) if *t > state.time => Some(*t),
+                _ => None,
+            })
+            .min_by(|a, b| a.partial_cmp(b).unwrap())
+    }
+
+    /// Start every encounter phase that is due: by time, or by boss health.
+    fn start_encounter_phases(&self, state: &mut SimState) {
+        for (idx, phase) in self.encounter_phases.iter().enumerate() {
+            let due = match state.phase_starts[idx] {
+                Some(PhaseStart::Time(t)) => state.time >= t,
+                Some(PhaseStart::HealthPct(pct)) => self.health_pct(state) <= pct,
+                None => false,
+            };
+            if !due {
+                continue;
+            }
+            state.phase_starts[idx] = match (state.phase_starts[idx], phase.every) {
+                (Some(PhaseStart::Time(t)), Some(every)) => Some(PhaseStart::Time(t + every)),
+                _ => None,
+            };
+
+            let end = state.time + phase.duration;
+            match &phase.kind {
+                PhaseKind::Immune => {
+                    state.immune_until = state.immune_until.max(end);
+                    state.downtime_until = state.downtime_until.max(end);
+                }
+                PhaseKind::Movement => {
+                    state.downtime_until = state.downtime_until.max(end);
+                }
+                PhaseKind::BarLocked => state.bar_locked_until = state.bar_locked_until.max(end),
+                PhaseKind::Adds { count, hp, .. } => state.adds.push(AddGroup {
+                    count: *count,
+                    hp: *hp,
+                    until: end,
+                }),
+            }
+            if phase.clears_dots {
+                state.active_effects.clear();
+            }
+            self.record_event(state, phase.description());
+        }
+    }
+
+    fn fight_over(&self, state: &SimState) -> bool {
+        match self.max_duration {
+            Some(duration) => state.time >= duration,
+            None => state.remaining_hp <= 0.0,
+        }
+    }
+
+    /// Enemy health fraction (0.0-1.0). Fixed-duration fights drain linearly over time.
+    fn health_pct(&self, state: &SimState) -> f64 {
+        match self.max_duration {
+            Some(duration) => (1.0 - state.time / duration).clamp(0.0, 1.0),
+            None => state.remaining_hp / self.target_hp,
         }
     }
 
@@ -627,18 +1263,21 @@ impl FightSimulator {
     fn compute_buffed_context(&self, active_buffs: &[ActiveBuff]) -> BuffedContext {
         let stats = self.apply_buffs_to_stats(active_buffs);
 
-        let armor_factor =
-            crate::domain::formulas::armor_damage_factor(stats.target_armor, stats.penetration);
+        let armor_factor = stats.target_damage_factor();
+        let best = stats.best_power_type();
+        let crit_chance = stats.critical_chance_for(best);
         let crit_mult = crate::domain::formulas::critical_multiplier(
-            stats.critical_chance(),
-            stats.critical_damage,
+            crit_chance,
+            stats.effective_critical_damage(),
         );
 
         BuffedContext {
             max_stat: stats.max_stat(),
-            max_power: stats.max_power(),
+            max_power: stats.power_for(best),
             armor_factor,
+            crit_chance,
             crit_mult,
+            stats,
         }
     }
 
@@ -695,6 +1334,381 @@ impl FightSimulator {
         }
     }
 
+    /// Whether a buff was granted by drinking the potion.
+    fn is_potion_buff(&self, buff: &ActiveBuff) -> bool {
+        self.potion.is_some_and(|potion| {
+            buff.source_skill_name
+                .strip_suffix(" Potion")
+                .is_some_and(|name| name == potion.name())
+        })
+    }
+
+    /// Apply/refresh the potion's buffs for its full duration.
+    /// Permanent buffs of the same name (e.g. from a slotted skill) are left untouched.
+    fn apply_potion_buffs(&self, active_buffs: &mut Vec<ActiveBuff>) {
+        let potion = match self.potion {
+            Some(p) => p,
+            None => return,
+        };
+        let ctx = ResolveContext::new(self.effective_stats.clone());
+        for bonus in potion.bonuses() {
+            let bv = bonus.resolve(&ctx);
+            if self.suppressed_buff_names.contains(&bv.name) {
+                continue;
+            }
+            if let Some(existing) = active_buffs.iter_mut().find(|b| b.name == bv.name) {
+                if existing.remaining_duration.is_some() {
+                    existing.remaining_duration = Some(potion.duration());
+                }
+            } else {
+                active_buffs.push(ActiveBuff {
+                    name: bv.name,
+                    source_skill_name: format!("{} Potion", potion.name()),
+                    remaining_duration: Some(potion.duration()),
+                    target: bv.target,
+                    value: bv.value,
+                });
+            }
+        }
+    }
+
+    /// Activate every synergy the group currently offers that is off cooldown,
+    /// triggering the profile's synergy passives each time.
+    fn activate_synergies(&self, state: &mut SimState) {
+        for (idx, availability) in self.synergies.iter().enumerate() {
+            if state.time < state.synergy_ready[idx] {
+                continue;
+            }
+            let synergy = availability.synergy;
+            let buffed = self.compute_buffed_context(&state.active_buffs);
+
+            if let Some(hit) = synergy.damage() {
+                let health_pct = self.health_pct(state);
+                let (done_base, taken_base) =
+                    self.compute_modifier_for_flags(hit.flags, None, health_pct);
+                let (done_buff, taken_buff) =
+                    self.compute_buff_modifier_for_flags(hit.flags, &state.active_buffs);
+                let dmg = hit.effective_value(buffed.max_stat, buffed.max_power)
+                    * (1.0 + done_base + done_buff)
+                    * (1.0 + taken_base + taken_buff)
+                    * buffed.armor_factor
+                    * buffed.crit_mult;
+                let dmg = state.deal_damage(dmg, hit.flags);
+                let entry = state.synergy_damage.entry(synergy).or_insert((0.0, 0));
+                entry.0 += dmg;
+                entry.1 += 1;
+            }
+
+            let stats = &buffed.stats;
+            let primary = if stats.max_magicka >= stats.max_stamina {
+                Resource::Magicka
+            } else {
+                Resource::Stamina
+            };
+            let passive_restore_pct: f64 =
+                self.synergy_passives.iter().map(|p| p.restore_pct()).sum();
+            let restored = [
+                (primary, synergy.resource_restore()),
+                (Resource::Health, stats.max_health * synergy.heal_pct()),
+                (Resource::Health, stats.max_health * passive_restore_pct),
+                (Resource::Magicka, stats.max_magicka * passive_restore_pct),
+                (Resource::Stamina, stats.max_stamina * passive_restore_pct),
+            ];
+            for (resource, amount) in restored {
+                if amount > 0.0 {
+                    *state.resources_restored.entry(resource).or_insert(0.0) += amount;
+                }
+            }
+
+            self.apply_proc_effects(
+                synergy.bonuses(),
+                synergy.buff_duration(),
+                format!("{} Synergy", synergy),
+                &mut state.active_buffs,
+            );
+            for passive in self.synergy_passives {
+                self.apply_proc_effects(
+                    passive.bonuses(),
+                    passive.buff_duration(),
+                    passive.to_string(),
+                    &mut state.active_buffs,
+                );
+            }
+
+            state.synergy_ready[idx] = availability.next_offer(state.time + synergy.cooldown());
+            self.record_event(state, format!("Activate {} synergy", synergy));
+        }
+    }
+
+    /// Apply/refresh the buffs and debuffs of a poison, enchant proc or synergy for their duration.
+    fn apply_proc_effects(
+        &self,
+        bonuses: Vec<BonusData>,
+        duration: f64,
+        source: String,
+        active_buffs: &mut Vec<ActiveBuff>,
+    ) {
+        let ctx = ResolveContext::new(self.effective_stats.clone());
+        for bonus in bonuses {
+            let bv = bonus.resolve(&ctx);
+            if self.suppressed_buff_names.contains(&bv.name) {
+                continue;
+            }
+            if let Some(existing) = active_buffs.iter_mut().find(|b| b.name == bv.name) {
+                if existing.remaining_duration.is_some() {
+                    existing.remaining_duration = Some(duration);
+                }
+            } else {
+                active_buffs.push(ActiveBuff {
+                    name: bv.name,
+                    source_skill_name: source.clone(),
+                    remaining_duration: Some(duration),
+                    target: bv.target,
+                    value: bv.value,
+                });
+            }
+        }
+    }
+
+    /// Keep per-stack buffs in line with the stacks held: each is worth its value once
+    /// per stack and lasts until the counter decays or is consumed.
+    fn sync_stack_bonuses(&self, state: &mut SimState, mechanics: &[&StackMechanic]) {
+        if mechanics.is_empty() {
+            return;
+        }
+        let ctx = ResolveContext::new(self.effective_stats.clone());
+        for mechanic in mechanics {
+            let stacks = state.stacks(&mechanic.name);
+            let remaining = state
+                .stacks
+                .get(&mechanic.name)
+                .map(|&(_, decays_at)| decays_at - state.time)
+                .filter(|remaining| remaining.is_finite());
+            for bonus in &mechanic.bonuses_per_stack {
+                let bv = bonus.resolve(&ctx);
+                state.active_buffs.retain(|b| b.name != bv.name);
+                if stacks == 0 || self.suppressed_buff_names.contains(&bv.name) {
+                    continue;
+                }
+                state.active_buffs.push(ActiveBuff {
+                    name: bv.name,
+                    source_skill_name: mechanic.name.clone(),
+                    remaining_duration: remaining,
+                    target: bv.target,
+                    value: bv.value * stacks as f64,
+                });
+            }
+        }
+    }
+
+    /// Append an action to the timeline when recording is enabled.
+    fn record_event(&self, state: &mut SimState, description: String) {
+        if !self.record_timeline {
+            return;
+        }
+        let phase = if state.time < 0.0 {
+            FightPhase::Opener
+        } else {
+            FightPhase::Fight
+        };
+        state.timeline.push(TimelineEvent {
+            time: state.time,
+            phase,
+            description,
+        });
+    }
+
+    fn bar_swap_description(bar: ActiveBar) -> String {
+        match bar {
+            ActiveBar::Bar1 => "Swap to Bar 1".to_string(),
+            ActiveBar::Bar2 => "Swap to Bar 2".to_string(),
+        }
+    }
+
+    /// Skills to pre-cast before the pull, with the bar they are slotted on.
+    fn opener_sequence(
+        &self,
+        distribution: &BarDistribution,
+    ) -> Vec<(ActiveBar, &'static SkillData)> {
+        let slotted: Vec<(ActiveBar, &'static SkillData)> = distribution
+            .bar1
+            .skills
+            .iter()
+            .map(|s| (ActiveBar::Bar1, *s))
+            .chain(
+                distribution
+                    .bar2
+                    .skills
+                    .iter()
+                    .map(|s| (ActiveBar::Bar2, *s)),
+            )
+            .collect();
+
+        match &self.opener {
+            Opener::None => Vec::new(),
+            Opener::Custom(names) => names
+                .iter()
+                .filter_map(|name| {
+                    slotted
+                        .iter()
+                        .find(|(_, s)| s.name.eq_ignore_ascii_case(name))
+                        .copied()
+                })
+                .collect(),
+            Opener::Auto => {
+                let ctx = ResolveContext::new(self.effective_stats.clone());
+                let mut candidates: Vec<(ActiveBar, &'static SkillData, f64)> = slotted
+                    .into_iter()
+                    .filter_map(|(bar, skill)| {
+                        let longest_buff = skill
+                            .bonuses
+                            .as_ref()?
+                            .iter()
+                            .filter(|b| b.trigger == BonusTrigger::Cast)
+                            .filter(|b| !self.suppressed_buff_names.contains(&b.resolve(&ctx).name))
+                            .filter_map(|b| b.duration)
+                            .fold(0.0, f64::max);
+                        (longest_buff > 0.0).then_some((bar, skill, longest_buff))
+                    })
+                    .collect();
+                // Bar 2 first so the opener ends on the starting bar; longest buff first within a bar
+                candidates.sort_by(|a, b| {
+                    (a.0 == ActiveBar::Bar1)
+                        .cmp(&(b.0 == ActiveBar::Bar1))
+                        .then(b.2.partial_cmp(&a.2).unwrap())
+                });
+                candidates
+                    .into_iter()
+                    .map(|(bar, skill, _)| (bar, skill))
+                    .collect()
+            }
+        }
+    }
+
+    /// Run the opener so its last action finishes at the pull (t=0).
+    /// Buffs and DoTs are applied, but nothing damages the target before t=0.
+    /// Returns the opener duration in seconds.
+    fn run_opener(&self, state: &mut SimState, distribution: &BarDistribution) -> f64 {
+        let sequence = self.opener_sequence(distribution);
+        if sequence.is_empty() {
+            return 0.0;
+        }
+
+        // Plan the opener length up front so it can start at a negative time
+        let mut duration = 0.0;
+        let mut bar = state.active_bar;
+        for (skill_bar, skill) in &sequence {
+            if *skill_bar != bar {
+                duration += BAR_SWAP_DELAY;
+                bar = *skill_bar;
+            }
+            duration += skill.channel_time.unwrap_or(GCD);
+        }
+        state.time = -duration;
+        state.gcd_ready = state.time;
+
+        // Pre-potion at the start of the opener
+        if let Some(potion) = self.potion {
+            self.apply_potion_buffs(&mut state.active_buffs);
+            state.potion_ready = state.time + potion.cooldown();
+            self.record_event(state, format!("Drink {} Potion", potion));
+        }
+
+        for (skill_bar, skill) in sequence {
+            if state.time < state.gcd_ready {
+                self.advance_time(state, state.gcd_ready);
+            }
+            if state.active_bar != skill_bar {
+                state.active_bar = skill_bar;
+                self.record_event(state, Self::bar_swap_description(skill_bar));
+                self.advance_time(state, state.time + BAR_SWAP_DELAY);
+            }
+
+            let buffed = self.compute_buffed_context(&state.active_buffs);
+            let health_pct = self.health_pct(state);
+            self.register_skill_dots(state, skill, &buffed, health_pct, 1.0);
+            self.register_cast_buffs(state, skill);
+            self.record_event(state, format!("Cast {}", skill.name));
+            state.gcd_ready = state.time + skill.channel_time.unwrap_or(GCD);
+        }
+
+        self.advance_time(state, 0.0);
+        state.time = 0.0;
+        state.gcd_ready = 0.0;
+        duration
+    }
+
+    /// Register/refresh a skill's DoTs as active effects, snapshotting modifiers at cast time.
+    /// `stack_multiplier` is the damage multiplier of the stacks held when casting.
+    fn register_skill_dots(
+        &self,
+        state: &mut SimState,
+        skill: &SkillData,
+        buffed: &BuffedContext,
+        health_pct: f64,
+        stack_multiplier: f64,
+    ) {
+        let pair = buffed.skill_pair(skill);
+        if let Some(damage) = &skill.damage {
+            if let Some(dots) = &damage.dots {
+                for dot in dots {
+                    let base_value = dot.effective_value(buffed.skill_stat(skill), pair.power);
+                    let interval = dot.interval.unwrap_or(dot.duration);
+                    let total_ticks = (dot.duration / interval).floor() as i32;
+                    let delay = dot.delay.unwrap_or(0.0);
+
+                    // Snapshot modifier at cast time (includes execute bonuses if currently in range)
+                    let (snapshotted_done, snapshotted_taken) =
+                        if dot.ignores_modifier.unwrap_or(false) {
+                            (0.0, 0.0)
+                        } else {
+                            let (done_base, taken_base) = self.compute_modifier_for_flags(
+                                dot.flags,
+                                Some(skill.skill_line),
+                                health_pct,
+                            );
+                            let (done_buff, taken_buff) = self
+                                .compute_buff_modifier_for_flags(dot.flags, &state.active_buffs);
+                            (done_base + done_buff, taken_base + taken_buff)
+                        };
+
+                    // Remove existing effect from same skill
+                    state
+                        .active_effects
+                        .retain(|e| e.source_skill_name != skill.name || e.flags != dot.flags);
+
+                    state.active_effects.push(ActiveEffect {
+                        source_skill_name: skill.name.clone(),
+                        remaining_duration: dot.duration + delay,
+                        next_tick_in: interval + delay,
+                        tick_interval: interval,
+                        tick_count: 0,
+                        total_ticks,
+                        base_value,
+                        flags: dot.flags,
+                        coefficients: dot.coefficients,
+                        stack_multiplier,
+                        tick_stacks: skill
+                            .stacks
+                            .iter()
+                            .filter(|m| m.ramps_per_tick())
+                            .cloned()
+                            .collect(),
+                        flat_increase_per_tick: dot.flat_increase_per_tick.unwrap_or(0.0),
+                        ignores_modifier: dot.ignores_modifier.unwrap_or(false),
+                        snapshot: skill.dot_snapshot.unwrap_or(self.dot_snapshot),
+                        resource: Some(skill.resource),
+                        skill_line: Some(skill.skill_line),
+                        done_modifier: snapshotted_done,
+                        taken_modifier: snapshotted_taken,
+                        armor_factor: buffed.armor_factor,
+                        crit_mult: pair.crit_mult,
+                    });
+                }
+            }
+        }
+    }
+
     /// Register/refresh buffs from Cast-triggered bonuses when a skill is cast.
     fn register_cast_buffs(&self, state: &mut SimState, skill: &SkillData) {
         if let Some(bonuses) = &skill.bonuses {
@@ -736,12 +1750,20 @@ impl FightSimulator {
             return;
         }
 
-        // Accumulate buff uptimes before expiring
+        // Accumulate downtime (the opener before the pull is not counted)
+        let downtime_start = state.time.max(0.0);
+        let downtime_end = target_time.min(state.downtime_until);
+        if downtime_end > downtime_start {
+            state.downtime += downtime_end - downtime_start;
+        }
+
+        // Accumulate buff uptimes before expiring (opener time before the pull is not counted)
         for buff in &state.active_buffs {
-            let active_time = match buff.remaining_duration {
-                None => dt,                           // permanent - active full dt
-                Some(remaining) => dt.min(remaining), // may expire partway
+            let active_until = match buff.remaining_duration {
+                None => target_time,                               // permanent - active full dt
+                Some(remaining) => state.time + dt.min(remaining), // may expire partway
             };
+            let active_time = (active_until.max(0.0) - state.time.max(0.0)).max(0.0);
             *state.buff_uptimes.entry(buff.name.clone()).or_insert(0.0) += active_time;
         }
 
@@ -756,32 +1778,69 @@ impl FightSimulator {
             }
         });
 
-        // Tick all active DoT effects (using snapshotted values)
+        // Tick all active DoT effects. Parts that do not snapshot follow the buffs active now
+        let dynamic = state
+            .active_effects
+            .iter()
+            .any(|e| e.snapshot != DotSnapshot::all())
+            .then(|| {
+                (
+                    self.compute_buffed_context(&state.active_buffs),
+                    self.health_pct(state),
+                )
+            });
         let mut effects_to_remove = Vec::new();
+        let mut dot_ticks = 0;
+        let mut tick_events = Vec::new();
+        let immune = state.time < state.immune_until;
+        let mut add_hits = Vec::new();
         for (idx, effect) in state.active_effects.iter_mut().enumerate() {
             effect.remaining_duration -= dt;
             effect.next_tick_in -= dt;
+            if let Some((buffed, health_pct)) = &dynamic {
+                self.refresh_dynamic_dot(effect, buffed, &state.active_buffs, *health_pct);
+            }
 
             // Process any ticks that occurred during this time window
             while effect.next_tick_in <= 0.0 && effect.tick_count < effect.total_ticks {
-                let pct_mult = 1.0 + (effect.tick_count as f64) * effect.increase_per_tick;
+                // Ticks before the pull (pre-placed opener DoTs) hit nothing
+                if target_time + effect.next_tick_in < 0.0 {
+                    effect.tick_count += 1;
+                    effect.next_tick_in += effect.tick_interval;
+                    continue;
+                }
+
+                let pct_mult: f64 = effect
+                    .tick_stacks
+                    .iter()
+                    .map(|m| m.tick_multiplier(effect.tick_count as u32))
+                    .product();
                 let flat_inc = (effect.tick_count as f64) * effect.flat_increase_per_tick;
-                let tick_damage = effect.base_value * pct_mult + flat_inc;
+                let tick_damage = effect.base_value * effect.stack_multiplier * pct_mult + flat_inc;
 
                 let final_damage = if effect.ignores_modifier {
                     tick_damage
-                        * (1.0 + effect.snapshotted_taken_modifier)
-                        * effect.snapshotted_armor_factor
-                        * effect.snapshotted_crit_mult
+                        * (1.0 + effect.taken_modifier)
+                        * effect.armor_factor
+                        * effect.crit_mult
                 } else {
                     tick_damage
-                        * (1.0 + effect.snapshotted_done_modifier)
-                        * (1.0 + effect.snapshotted_taken_modifier)
-                        * effect.snapshotted_armor_factor
-                        * effect.snapshotted_crit_mult
+                        * (1.0 + effect.done_modifier)
+                        * (1.0 + effect.taken_modifier)
+                        * effect.armor_factor
+                        * effect.crit_mult
                 };
-
+                if effect.flags.contains(DamageFlags::AOE) {
+                    add_hits.push(final_damage);
+                }
+                let final_damage = if immune { 0.0 } else { final_damage };
                 state.remaining_hp -= final_damage;
+                if self.record_timeline {
+                    tick_events.push((
+                        target_time + effect.next_tick_in,
+                        Self::dot_tick_description(effect, tick_damage, final_damage),
+                    ));
+                }
 
                 // Track DoT damage under the source skill
                 let entry = state
@@ -789,6 +1848,7 @@ impl FightSimulator {
                     .entry(effect.source_skill_name.clone())
                     .or_insert((0.0, 0));
                 entry.0 += final_damage;
+                dot_ticks += 1;
 
                 effect.tick_count += 1;
                 effect.next_tick_in += effect.tick_interval;
@@ -803,8 +1863,119 @@ impl FightSimulator {
         for idx in effects_to_remove.into_iter().rev() {
             state.active_effects.remove(idx);
         }
+        for damage in add_hits {
+            state.damage_adds(damage);
+        }
 
         state.time = target_time;
+
+        // Record ticks in time order across effects
+        tick_events.sort_by(|a, b| a.0.total_cmp(&b.0));
+        for (time, description) in tick_events {
+            state.timeline.push(TimelineEvent {
+                time,
+                phase: FightPhase::Fight,
+                description,
+            });
+        }
+
+        // Set proc triggers: OnDotTick (once per damaging tick in this window)
+        if dot_ticks > 0 && self.has_set_proc(SetProcTrigger::OnDotTick) {
+            let buffed = self.compute_buffed_context(&state.active_buffs);
+            let health_pct = self.health_pct(state);
+            for _ in 0..dot_ticks {
+                self.process_set_procs(SetProcTrigger::OnDotTick, &buffed, state, health_pct);
+            }
+        }
+    }
+
+    /// Re-read the parts of a DoT that do not snapshot from current stats and buffs.
+    fn refresh_dynamic_dot(
+        &self,
+        effect: &mut ActiveEffect,
+        buffed: &BuffedContext,
+        active_buffs: &[ActiveBuff],
+        health_pct: f64,
+    ) {
+        let snapshot = effect.snapshot;
+        let crit_mult = match effect.resource {
+            Some(resource) => {
+                let pair =
+                    PowerPair::from_stats(&buffed.stats, buffed.stats.power_type_for(resource));
+                if !snapshot.contains(DotSnapshot::POWER) {
+                    effect.base_value = effect
+                        .coefficients
+                        .calculate_base_damage(buffed.stats.stat_for(resource), pair.power);
+                }
+                pair.crit_mult
+            }
+            None => buffed.crit_mult,
+        };
+        if !snapshot.contains(DotSnapshot::CRITICAL) {
+            effect.crit_mult = crit_mult;
+        }
+        if !snapshot.contains(DotSnapshot::ARMOR) {
+            effect.armor_factor = buffed.armor_factor;
+        }
+        if effect.ignores_modifier
+            || snapshot.contains(DotSnapshot::DAMAGE_DONE | DotSnapshot::DAMAGE_TAKEN)
+        {
+            return;
+        }
+        let (done_base, taken_base) =
+            self.compute_modifier_for_flags(effect.flags, effect.skill_line, health_pct);
+        let (done_buff, taken_buff) =
+            self.compute_buff_modifier_for_flags(effect.flags, active_buffs);
+        if !snapshot.contains(DotSnapshot::DAMAGE_DONE) {
+            effect.done_modifier = done_base + done_buff;
+        }
+        if !snapshot.contains(DotSnapshot::DAMAGE_TAKEN) {
+            effect.taken_modifier = taken_base + taken_buff;
+        }
+    }
+
+    /// Timeline line for a DoT tick with the modifiers it used (* = snapshotted at cast).
+    fn dot_tick_description(effect: &ActiveEffect, tick_damage: f64, final_damage: f64) -> String {
+        let mark = |part: DotSnapshot| {
+            if effect.snapshot.contains(part) {
+                "*"
+            } else {
+                ""
+            }
+        };
+        let mut parts = vec![format!(
+            "base {:.0}{}",
+            tick_damage,
+            mark(DotSnapshot::POWER)
+        )];
+        if !effect.ignores_modifier {
+            parts.push(format!(
+                "done {:+.0}%{}",
+                effect.done_modifier * 100.0,
+                mark(DotSnapshot::DAMAGE_DONE)
+            ));
+        }
+        parts.push(format!(
+            "taken {:+.0}%{}",
+            effect.taken_modifier * 100.0,
+            mark(DotSnapshot::DAMAGE_TAKEN)
+        ));
+        parts.push(format!(
+            "armor {:.2}{}",
+            effect.armor_factor,
+            mark(DotSnapshot::ARMOR)
+        ));
+        parts.push(format!(
+            "crit {:.2}{}",
+            effect.crit_mult,
+            mark(DotSnapshot::CRITICAL)
+        ));
+        format!(
+            "Tick {} {:.0}: {}",
+            effect.source_skill_name,
+            final_damage,
+            parts.join(", ")
+        )
     }
 
     fn decide_action(
@@ -813,7 +1984,7 @@ impl FightSimulator {
         current_skills: &[&'static SkillData],
         other_skills: &[&'static SkillData],
     ) -> Action {
-        let health_pct = state.remaining_hp / self.target_hp;
+        let health_pct = self.health_pct(state);
 
         // Priority 1: Current bar expired DoTs/buffs - recast
         if let Some(idx) = self.find_expired_dot_skill(state, current_skills) {
@@ -850,6 +2021,26 @@ impl FightSimulator {
             return Action::BarSwap;
         }
 
+        // Priority 7a: Heavy attack in place of a filler when one is due
+        if self.has_set_proc(SetProcTrigger::OnHeavyAttack)
+            && state.time >= state.heavy_attack_ready
+        {
+            return Action::HeavyAttack;
+        }
+
+        // Priority 8: Stack filler - generate below the cap, spend at the cap when it pays off
+        if let Some(idx) = self.find_stack_filler(state, current_skills) {
+            return Action::CastSkill(idx);
+        }
+
+        // Priority 8': Better stack spender on the other bar - swap, unless this bar's
+        // own DoTs/buffs are about to need a recast
+        if self.other_bar_has_stack_spender(state, current_skills, other_skills)
+            && !self.other_bar_needs_attention(state, current_skills)
+        {
+            return Action::BarSwap;
+        }
+
         // Priority 8a: Non-execute spammable or channeled filler
         if let Some(idx) = current_skills
             .iter()
@@ -874,8 +2065,205 @@ impl FightSimulator {
             return Action::CastSkill(idx);
         }
 
-        // Fallback: bar swap
-        Action::BarSwap
+        // Fallback: bar swap (a one-bar build has nothing to swap to, keep casting;
+        // with no skills slotted at all, keep light attacking)
+        if other_skills.is_empty() {
+            if current_skills.is_empty() {
+                return Action::LightAttack;
+            }
+            return Action::CastSkill(0);
+        }
+        Action::BarSwap
+    }
+
+    /// Weave a light attack with the current weapon: its damage, the stacks it
+    /// builds, weapon enchant and poison procs and light attack set procs.
+    fn weave_light_attack(
+        &self,
+        state: &mut SimState,
+        current_weapon: WeaponType,
+        buffed: &BuffedContext,
+        health_pct: f64,
+        passive_stack_gains: &[(&str, &StackMechanic)],
+        stack_bonuses: &[&StackMechanic],
+    ) {
+        // Light attack damage (uses current buffs)
+        let la_data = light_attack_for_weapon(current_weapon);
+        let (la_done_base, la_taken_base) =
+            self.compute_modifier_for_flags(la_data.flags, None, health_pct);
+        let (la_done_buff, la_taken_buff) =
+            self.compute_buff_modifier_for_flags(la_data.flags, &state.active_buffs);
+        let mut la_dmg = la_data.calculate_damage(
+            la_done_base + la_done_buff,
+            la_taken_base + la_taken_buff,
+            buffed.max_stat,
+            buffed.max_power,
+            buffed.armor_factor,
+            buffed.crit_mult,
+        );
+        // Add flat LA bonus from set procs (applied with same modifiers)
+        if state.flat_la_bonus > 0.0 {
+            la_dmg += state.flat_la_bonus
+                * (1.0 + la_done_base + la_done_buff)
+                * (1.0 + la_taken_base + la_taken_buff)
+                * buffed.armor_factor
+                * buffed.crit_mult;
+        }
+        state.la_damage += state.deal_damage(la_dmg, la_data.flags);
+        state.la_count += 1;
+
+        // Light attacks build stacks for slotted skills (Grim Focus)
+        for (_, mechanic) in passive_stack_gains {
+            if mechanic.gain == Some(StackGain::LightAttack) {
+                state.gain_stacks(mechanic);
+            }
+        }
+        self.sync_stack_bonuses(state, stack_bonuses);
+
+        // Weapon enchant procs (triggered by light attack)
+        // A dual wield bar also procs its off-hand enchant on its own cooldown;
+        // a second copy of the main-hand enchant adds nothing
+        let (main_enchant, off_hand_enchant) = match state.active_bar {
+            ActiveBar::Bar1 => (self.bar1_enchant, self.bar1_off_hand_enchant),
+            ActiveBar::Bar2 => (self.bar2_enchant, self.bar2_off_hand_enchant),
+        };
+        let (main_trait, off_hand_trait) = match state.active_bar {
+            ActiveBar::Bar1 => (self.bar1_weapon_trait, self.bar1_off_hand_trait),
+            ActiveBar::Bar2 => (self.bar2_weapon_trait, self.bar2_off_hand_trait),
+        };
+        let off_hand_enchant = off_hand_enchant.filter(|e| Some(*e) != main_enchant);
+        let hands = [(main_enchant, false), (off_hand_enchant, true)];
+        for (enchant, off_hand) in hands
+            .into_iter()
+            .filter_map(|(e, off_hand)| e.map(|e| (e, off_hand)))
+        {
+            let ready = if off_hand {
+                state.off_hand_enchant_ready
+            } else {
+                state.enchant_ready
+            };
+            if state.time < ready
+                || !self.roll_proc_chance("Weapon Enchant", enchant.proc_chance(), state)
+            {
+                continue;
+            }
+            let base_dmg = enchant.base_damage();
+            if base_dmg > 0.0 {
+                let flags = enchant.damage_flags();
+                let (done_base, taken_base) =
+                    self.compute_modifier_for_flags(flags, None, health_pct);
+                let (done_buff, taken_buff) =
+                    self.compute_buff_modifier_for_flags(flags, &state.active_buffs);
+                let enchant_dmg = base_dmg
+                    * (1.0 + done_base + done_buff)
+                    * (1.0 + taken_base + taken_buff)
+                    * buffed.armor_factor
+                    * buffed.crit_mult;
+                state.enchant_damage += state.deal_damage(enchant_dmg, flags);
+                state.enchant_proc_count += 1;
+            }
+
+            // Register status effect as active DoT
+            if let Some(status) = enchant.status_effect() {
+                let tick_value = status.total_damage;
+                let (done_base, taken_base) =
+                    self.compute_modifier_for_flags(status.flags, None, health_pct);
+                let (done_buff, taken_buff) =
+                    self.compute_buff_modifier_for_flags(status.flags, &state.active_buffs);
+
+                // Remove existing status effect of same type
+                state
+                    .active_effects
+                    .retain(|e| e.source_skill_name != status.name);
+
+                state.active_effects.push(ActiveEffect {
+                    source_skill_name: status.name.to_string(),
+                    remaining_duration: status.duration,
+                    next_tick_in: status.duration,
+                    tick_interval: status.duration,
+                    tick_count: 0,
+                    total_ticks: 1,
+                    base_value: tick_value,
+                    flags: status.flags,
+                    coefficients: crate::domain::DamageCoefficients::new(0.0, 0.0),
+                    stack_multiplier: 1.0,
+                    tick_stacks: Vec::new(),
+                    flat_increase_per_tick: 0.0,
+                    ignores_modifier: false,
+                    snapshot: self.dot_snapshot,
+                    resource: None,
+                    skill_line: None,
+                    done_modifier: done_base + done_buff,
+                    taken_modifier: taken_base + taken_buff,
+                    armor_factor: buffed.armor_factor,
+                    crit_mult: buffed.crit_mult,
+                });
+
+                self.process_set_procs(
+                    SetProcTrigger::OnStatusEffectApplied,
+                    buffed,
+                    state,
+                    health_pct,
+                );
+            }
+
+            self.apply_proc_effects(
+                enchant.bonuses(),
+                enchant.effect_duration(),
+                format!("{} Enchant", enchant),
+                &mut state.active_buffs,
+            );
+            if let Some((resource, amount)) = enchant.resource_restored() {
+                *state.resources_restored.entry(resource).or_insert(0.0) += amount;
+            }
+
+            if off_hand {
+                state.off_hand_enchant_ready = state.time + enchant.cooldown(off_hand_trait);
+            } else {
+                state.enchant_ready = state.time + enchant.cooldown(main_trait);
+            }
+        }
+
+        // Weapon poison proc (triggered by light attack)
+        if state.time >= state.poison_ready {
+            let poison = match state.active_bar {
+                ActiveBar::Bar1 => self.bar1_poison,
+                ActiveBar::Bar2 => self.bar2_poison,
+            };
+            let poison =
+                poison.filter(|p| self.roll_proc_chance("Weapon Poison", p.proc_chance(), state));
+            if let Some(poison) = poison {
+                let flags = poison.damage_flags();
+                let (done_base, taken_base) =
+                    self.compute_modifier_for_flags(flags, None, health_pct);
+                let (done_buff, taken_buff) =
+                    self.compute_buff_modifier_for_flags(flags, &state.active_buffs);
+                let poison_dmg = poison.base_damage()
+                    * (1.0 + done_base + done_buff)
+                    * (1.0 + taken_base + taken_buff)
+                    * buffed.armor_factor
+                    * buffed.crit_mult;
+                let poison_dmg = state.deal_damage(poison_dmg, flags);
+                let entry = state
+                    .poison_damage
+                    .entry(format!("{} Poison", poison))
+                    .or_insert((0.0, 0));
+                entry.0 += poison_dmg;
+                entry.1 += 1;
+
+                self.apply_proc_effects(
+                    poison.bonuses(),
+                    poison.effect_duration(),
+                    format!("{} Poison", poison),
+                    &mut state.active_buffs,
+                );
+                state.poison_ready = state.time + poison.cooldown();
+            }
+        }
+
+        // Set proc triggers: OnLightAttack
+        self.process_set_procs(SetProcTrigger::OnLightAttack, buffed, state, health_pct);
+        self.process_critical_hit(buffed, state, health_pct);
     }
 
     /// Check if a skill has an active effect (DoT) or active buff sourced from it.
@@ -982,7 +2370,7 @@ impl FightSimulator {
             return false;
         }
         // Proc skills are handled by the proc priority system, not as DoTs/buffs
-        if skill.proc_light_attacks.is_some() {
+        if Self::requires_stacks(skill) {
             return false;
         }
         let has_dot = skill
@@ -997,19 +2385,26 @@ impl FightSimulator {
         has_dot || has_buff
     }
 
+    /// Whether a skill's hits wait for stacks (Grim Focus' spectral bow).
+    fn requires_stacks(skill: &SkillData) -> bool {
+        skill.stacks.iter().any(|m| m.required_stacks.is_some())
+    }
+
+    fn stacks_ready(state: &SimState, skill: &SkillData) -> bool {
+        skill
+            .stacks
+            .iter()
+            .all(|m| m.is_ready(state.stacks(&m.name)))
+    }
+
     fn find_ready_proc_skill(
         &self,
         state: &SimState,
         skills: &[&'static SkillData],
     ) -> Option<usize> {
-        skills.iter().position(|s| {
-            if let Some(threshold) = s.proc_light_attacks {
-                let counter = state.proc_counters.get(&s.name).copied().unwrap_or(0);
-                counter >= threshold
-            } else {
-                false
-            }
-        })
+        skills
+            .iter()
+            .position(|s| Self::requires_stacks(s) && Self::stacks_ready(state, s))
     }
 
     fn other_bar_has_ready_proc(
@@ -1017,23 +2412,75 @@ impl FightSimulator {
         state: &SimState,
         other_skills: &[&'static SkillData],
     ) -> bool {
-        other_skills.iter().any(|s| {
-            if let Some(threshold) = s.proc_light_attacks {
-                let counter = state.proc_counters.get(&s.name).copied().unwrap_or(0);
-                counter >= threshold
-            } else {
-                false
-            }
-        })
+        other_skills
+            .iter()
+            .any(|s| Self::requires_stacks(s) && Self::stacks_ready(state, s))
     }
 
     fn is_unready_proc(&self, state: &SimState, skill: &SkillData) -> bool {
-        if let Some(threshold) = skill.proc_light_attacks {
-            let counter = state.proc_counters.get(&skill.name).copied().unwrap_or(0);
-            counter < threshold
-        } else {
-            false
+        Self::requires_stacks(skill) && !Self::stacks_ready(state, skill)
+    }
+
+    /// Find a damaging generator whose counter is below its cap. Once every counter is
+    /// full, pick a spender instead when spending deals more damage per second.
+    /// DoT/buff generators are left to run out first (recasting would restart them).
+    fn find_stack_filler(&self, state: &SimState, skills: &[&'static SkillData]) -> Option<usize> {
+        let generator = |full: bool| {
+            skills.iter().position(|s| {
+                s.damage.is_some()
+                    && !(self.skill_has_dot_or_buff(s) && self.skill_has_active_presence(state, s))
+                    && s.stacks.iter().any(|m| {
+                        m.gains_on_cast()
+                            && if full {
+                                state.stacks_full(&m.name)
+                            } else {
+                                state.stacks_below_cap(&m.name)
+                            }
+                    })
+            })
+        };
+        if let Some(idx) = generator(false) {
+            return Some(idx);
         }
+        let capped = generator(true);
+        let capped_dps = capped.map_or(0.0, |idx| self.stack_cast_dps(state, skills[idx]));
+        self.find_stack_spender(state, skills)
+            .filter(|&idx| self.stack_cast_dps(state, skills[idx]) > capped_dps)
+            .or(capped)
+    }
+
+    /// Check if the other bar has a spender of a full counter worth swapping for.
+    fn other_bar_has_stack_spender(
+        &self,
+        state: &SimState,
+        current_skills: &[&'static SkillData],
+        other_skills: &[&'static SkillData],
+    ) -> bool {
+        let current_dps = self
+            .find_stack_filler(state, current_skills)
+            .map_or(0.0, |idx| self.stack_cast_dps(state, current_skills[idx]));
+        self.find_stack_spender(state, other_skills)
+            .is_some_and(|idx| self.stack_cast_dps(state, other_skills[idx]) > current_dps)
+    }
+
+    /// Find a damaging skill that spends a full counter.
+    fn find_stack_spender(&self, state: &SimState, skills: &[&'static SkillData]) -> Option<usize> {
+        skills.iter().position(|s| {
+            s.damage.is_some()
+                && s.stacks
+                    .iter()
+                    .any(|m| m.is_spender() && state.stacks_full(&m.name))
+        })
+    }
+
+    /// Expected damage per second of casting a skill with the stacks currently held.
+    fn stack_cast_dps(&self, state: &SimState, skill: &SkillData) -> f64 {
+        let multiplier: f64 = skill
+            .stacks
+            .iter()
+            .map(|m| m.damage_multiplier(state.stacks(&m.name)))
+            .product();
+        self.estimate_skill_dpc(skill) * multiplier / skill.channel_time.unwrap_or(GCD)
     }
 
     fn estimate_skill_dpc(&self, skill: &SkillData) -> f64 {
@@ -1071,14 +2518,17 @@ impl FightSimulator {
         })
     }
 
+    /// Damage of a skill's hits as (single target, area). Area hits also strike adds.
     fn calc_skill_hits(
         &self,
         skill: &SkillData,
         buffed: &BuffedContext,
         active_buffs: &[ActiveBuff],
         health_pct: f64,
-    ) -> f64 {
-        let mut total = 0.0;
+    ) -> (f64, f64) {
+        let mut single = 0.0;
+        let mut area = 0.0;
+        let pair = buffed.skill_pair(skill);
 
         if let Some(damage) = &skill.damage {
             if let Some(hits) = &damage.hits {
@@ -1095,21 +2545,70 @@ impl FightSimulator {
                     );
                     let (done_buff, taken_buff) =
                         self.compute_buff_modifier_for_flags(hit.flags, active_buffs);
-                    let base = hit.effective_value(buffed.max_stat, buffed.max_power);
+                    let base = hit.effective_value(buffed.skill_stat(skill), pair.power);
                     let mut dmg = base
                         * (1.0 + done_base + done_buff)
                         * (1.0 + taken_base + taken_buff)
                         * buffed.armor_factor
-                        * buffed.crit_mult;
+                        * pair.crit_mult;
                     if let Some(execute) = &skill.execute {
                         dmg *= execute.calculate_multiplier(health_pct);
                     }
-                    total += dmg;
+                    if hit.flags.contains(DamageFlags::AOE) {
+                        area += dmg;
+                    } else {
+                        single += dmg;
+                    }
+                }
+            }
+        }
+
+        (single, area)
+    }
+
+    fn has_set_proc(&self, trigger: SetProcTrigger) -> bool {
+        self.set_procs.iter().any(|p| p.trigger == trigger)
+    }
+
+    /// Whether a proc with the given chance fires. Deterministic runs use the expected value:
+    /// each attempt accrues the chance and the proc fires once a whole proc has built up.
+    /// Monte Carlo runs roll for it.
+    fn roll_proc_chance(&self, source: &str, chance: f64, state: &mut SimState) -> bool {
+        if chance >= 1.0 {
+            return true;
+        }
+        match state.rng.as_mut() {
+            Some(rng) => rng.random::<f64>() < chance,
+            None => {
+                let progress = state
+                    .proc_chance_progress
+                    .entry(source.to_string())
+                    .or_insert(0.0);
+                *progress += chance;
+                if *progress >= 1.0 {
+                    *progress -= 1.0;
+                    true
+                } else {
+                    false
                 }
             }
         }
+    }
+
+    /// Fire OnCriticalHit procs when a direct hit crits (at the expected crit rate unless
+    /// rolling for real).
+    fn process_critical_hit(&self, buffed: &BuffedContext, state: &mut SimState, health_pct: f64) {
+        if self.roll_proc_chance("Critical Hit", buffed.crit_chance, state) {
+            self.process_set_procs(SetProcTrigger::OnCriticalHit, buffed, state, health_pct);
+        }
+    }
 
-        total
+    fn start_set_proc_cooldown(&self, proc: &SetProcEffect, state: &mut SimState) {
+        if proc.cooldown > 0.0 {
+            state
+                .set_proc_cooldowns
+                .insert(proc.name.clone(), state.time + proc.cooldown);
+        }
     }
 
     /// Process all set procs that match the given trigger.
@@ -1138,6 +2637,9 @@ impl FightSimulator {
             if proc.cooldown > 0.0 && state.time < ready_time {
                 continue;
             }
+            if !self.roll_proc_chance(&proc.name, proc.proc_chance, state) {
+                continue;
+            }
 
             match &proc.action {
                 SetProcAction::DamageProc {
@@ -1157,7 +2659,7 @@ impl FightSimulator {
                         * (1.0 + taken_base + taken_buff)
                         * buffed.armor_factor
                         * buffed.crit_mult;
-                    state.remaining_hp -= dmg;
+                    let dmg = state.deal_damage(dmg, *hit_flags);
 
                     let entry = state
                         .set_proc_damage
@@ -1189,13 +2691,17 @@ impl FightSimulator {
                             base_value: *dot_total_damage,
                             flags: *dot_flags,
                             coefficients: DamageCoefficients::new(0.0, 0.0),
-                            increase_per_tick: 0.0,
+                            stack_multiplier: 1.0,
+                            tick_stacks: Vec::new(),
                             flat_increase_per_tick: 0.0,
                             ignores_modifier: false,
-                            snapshotted_done_modifier: dot_done_base + dot_done_buff,
-                            snapshotted_taken_modifier: dot_taken_base + dot_taken_buff,
-                            snapshotted_armor_factor: buffed.armor_factor,
-                            snapshotted_crit_mult: buffed.crit_mult,
+                            snapshot: self.dot_snapshot,
+                            resource: None,
+                            skill_line: None,
+                            done_modifier: dot_done_base + dot_done_buff,
+                            taken_modifier: dot_taken_base + dot_taken_buff,
+                            armor_factor: buffed.armor_factor,
+                            crit_mult: buffed.crit_mult,
                         });
                     }
 
@@ -1247,11 +2753,21 @@ impl FightSimulator {
                         existing.base_value = *damage_per_stack_per_tick * new_stacks as f64;
                         let new_total = (*stack_duration / *tick_interval).floor() as i32;
                         existing.total_ticks = existing.tick_count + new_total;
-                        // Re-snapshot modifiers on stack refresh
-                        existing.snapshotted_done_modifier = done_base + done_buff;
-                        existing.snapshotted_taken_modifier = taken_base + taken_buff;
-                        existing.snapshotted_armor_factor = buffed.armor_factor;
-                        existing.snapshotted_crit_mult = buffed.crit_mult;
+                        // A new stack re-applies the DoT: snapshotted parts lock in again,
+                        // the others keep following the buffs on every tick
+                        let snapshot = existing.snapshot;
+                        if snapshot.contains(DotSnapshot::DAMAGE_DONE) {
+                            existing.done_modifier = done_base + done_buff;
+                        }
+                        if snapshot.contains(DotSnapshot::DAMAGE_TAKEN) {
+                            existing.taken_modifier = taken_base + taken_buff;
+                        }
+                        if snapshot.contains(DotSnapshot::ARMOR) {
+                            existing.armor_factor = buffed.armor_factor;
+                        }
+                        if snapshot.contains(DotSnapshot::CRITICAL) {
+                            existing.crit_mult = buffed.crit_mult;
+                        }
                     } else {
                         let total_ticks = (*stack_duration / *tick_interval).floor() as i32;
                         state.active_effects.push(ActiveEffect {
@@ -1264,13 +2780,17 @@ impl FightSimulator {
                             base_value: *damage_per_stack_per_tick * new_stacks as f64,
                             flags: *flags,
                             coefficients: DamageCoefficients::new(0.0, 0.0),
-                            increase_per_tick: 0.0,
+                            stack_multiplier: 1.0,
+                            tick_stacks: Vec::new(),
                             flat_increase_per_tick: 0.0,
                             ignores_modifier: false,
-                            snapshotted_done_modifier: done_base + done_buff,
-                            snapshotted_taken_modifier: taken_base + taken_buff,
-                            snapshotted_armor_factor: buffed.armor_factor,
-                            snapshotted_crit_mult: buffed.crit_mult,
+                            snapshot: self.dot_snapshot,
+                            resource: None,
+                            skill_line: None,
+                            done_modifier: done_base + done_buff,
+                            taken_modifier: taken_base + taken_buff,
+                            armor_factor: buffed.armor_factor,
+                            crit_mult: buffed.crit_mult,
                         });
                     }
                 }
@@ -1346,6 +2866,184 @@ impl FightSimulator {
                         }
                     }
                 }
+                SetProcAction::RampingBeam {
+                    base_damage_per_tick,
+                    ramp_per_tick,
+                    tick_interval,
+                    duration,
+                    flags,
+                } => {
+                    let (done_base, taken_base) =
+                        self.compute_modifier_for_flags(*flags, None, health_pct);
+                    let (done_buff, taken_buff) =
+                        self.compute_buff_modifier_for_flags(*flags, &state.active_buffs);
+
+                    // The tether breaks whenever the target is out of melee range
+                    let tether_uptime = self.effective_stats.position.map_or(1.0, |p| p.melee);
+
+                    state
+                        .active_effects
+                        .retain(|e| e.source_skill_name != proc.name);
+                    state.active_effects.push(ActiveEffect {
+                        source_skill_name: proc.name.clone(),
+                        remaining_duration: *duration,
+                        next_tick_in: *tick_interval,
+                        tick_interval: *tick_interval,
+                        tick_count: 0,
+                        total_ticks: (*duration / *tick_interval).floor() as i32,
+                        base_value: *base_damage_per_tick * tether_uptime,
+                        flags: *flags,
+                        coefficients: DamageCoefficients::new(0.0, 0.0),
+                        stack_multiplier: 1.0,
+                        tick_stacks: vec![StackMechanic::new(proc.name.clone())
+                            .with_gain(StackGain::DotTick, 1)
+                            .with_damage_per_stack(*ramp_per_tick)],
+                        flat_increase_per_tick: 0.0,
+                        ignores_modifier: false,
+                        snapshot: self.dot_snapshot,
+                        resource: None,
+                        skill_line: None,
+                        done_modifier: done_base + done_buff,
+                        taken_modifier: taken_base + taken_buff,
+                        armor_factor: buffed.armor_factor,
+                        crit_mult: buffed.crit_mult,
+                    });
+                    self.start_set_proc_cooldown(proc, state);
+                }
+                SetProcAction::ExecuteScalingDamage {
+                    hit_damage,
+                    dot_total_damage,
+                    dot_duration,
+                    flags,
+                    max_bonus,
+                    threshold_pct,
+                } => {
+                    let execute_mult = if health_pct < *threshold_pct {
+                        1.0 + max_bonus * (1.0 - health_pct / threshold_pct)
+                    } else {
+                        1.0
+                    };
+                    let (done_base, taken_base) =
+                        self.compute_modifier_for_flags(*flags, None, health_pct);
+                    let (done_buff, taken_buff) =
+                        self.compute_buff_modifier_for_flags(*flags, &state.active_buffs);
+                    let modifier = execute_mult
+                        * (1.0 + done_base + done_buff)
+                        * (1.0 + taken_base + taken_buff)
+                        * buffed.armor_factor
+                        * buffed.crit_mult;
+
+                    if *hit_damage > 0.0 {
+                        let dmg = state.deal_damage(hit_damage * modifier, *flags);
+                        let entry = state
+                            .set_proc_damage
+                            .entry(proc.name.clone())
+                            .or_insert((0.0, 0));
+                        entry.0 += dmg;
+                        entry.1 += 1;
+                    }
+
+                    // DoT portion snapshots the execute bonus at application
+                    if *dot_total_damage > 0.0 && *dot_duration > 0.0 {
+                        let dot_source = format!("{} DoT", proc.name);
+                        state
+                            .active_effects
+                            .retain(|e| e.source_skill_name != dot_source);
+                        state.active_effects.push(ActiveEffect {
+                            source_skill_name: dot_source,
+                            remaining_duration: *dot_duration,
+                            next_tick_in: *dot_duration,
+                            tick_interval: *dot_duration,
+                            tick_count: 0,
+                            total_ticks: 1,
+                            base_value: dot_total_damage * execute_mult,
+                            flags: *flags,
+                            coefficients: DamageCoefficients::new(0.0, 0.0),
+                            stack_multiplier: 1.0,
+                            tick_stacks: Vec::new(),
+                            flat_increase_per_tick: 0.0,
+                            ignores_modifier: false,
+                            snapshot: self.dot_snapshot,
+                            resource: None,
+                            skill_line: None,
+                            done_modifier: done_base + done_buff,
+                            taken_modifier: taken_base + taken_buff,
+                            armor_factor: buffed.armor_factor,
+                            crit_mult: buffed.crit_mult,
+                        });
+                    }
+                    self.start_set_proc_cooldown(proc, state);
+                }
+                SetProcAction::EnemyDebuff {
+                    debuff_name,
+                    target,
+                    value,
+                    duration,
+                } => {
+                    // Skip debuffs already provided externally (e.g. trial dummy)
+                    if !self.suppressed_buff_names.contains(debuff_name) {
+                        if let Some(existing) = state
+                            .active_buffs
+                            .iter_mut()
+                            .find(|b| b.name == *debuff_name)
+                        {
+                            existing.remaining_duration = Some(*duration);
+                            existing.value = *value;
+                        } else {
+                            state.active_buffs.push(ActiveBuff {
+                                name: debuff_name.clone(),
+                                source_skill_name: proc.name.clone(),
+                                remaining_duration: Some(*duration),
+                                target: *target,
+                                value: *value,
+                            });
+                        }
+                    }
+                    self.start_set_proc_cooldown(proc, state);
+                }
+                SetProcAction::SummonedEntity {
+                    damage_per_attack,
+                    attack_interval,
+                    duration,
+                    delay,
+                    flags,
+                } => {
+                    let (done_base, taken_base) =
+                        self.compute_modifier_for_flags(*flags, None, health_pct);
+                    let (done_buff, taken_buff) =
+                        self.compute_buff_modifier_for_flags(*flags, &state.active_buffs);
+
+                    state
+                        .active_effects
+                        .retain(|e| e.source_skill_name != proc.name);
+                    state.active_effects.push(ActiveEffect {
+                        source_skill_name: proc.name.clone(),
+                        remaining_duration: *duration + *delay,
+                        next_tick_in: *attack_interval + *delay,
+                        tick_interval: *attack_interval,
+                        tick_count: 0,
+                        total_ticks: (*duration / *attack_interval).floor() as i32,
+                        base_value: *damage_per_attack,
+                        flags: *flags,
+                        coefficients: DamageCoefficients::new(0.0, 0.0),
+                        stack_multiplier: 1.0,
+                        tick_stacks: Vec::new(),
+                        flat_increase_per_tick: 0.0,
+                        ignores_modifier: false,
+                        snapshot: self.dot_snapshot,
+                        resource: None,
+                        skill_line: None,
+                        done_modifier: done_base + done_buff,
+                        taken_modifier: taken_base + taken_buff,
+                        armor_factor: buffed.armor_factor,
+                        crit_mult: buffed.crit_mult,
+                    });
+                    self.start_set_proc_cooldown(proc, state);
+                }
+                SetProcAction::RestoreResource { resource, amount } => {
+                    *state.resources_restored.entry(*resource).or_insert(0.0) += amount;
+                    self.start_set_proc_cooldown(proc, state);
+                }
                 SetProcAction::FlatLightAttackBonus { .. } => {
                     // Handled at init time via flat_la_bonus field
                 }
@@ -1384,6 +3082,700 @@ impl FightSimulator {
                 done += bv.value;
             }
         }
+        if let Some(execute) = &self.execute_phase {
+            if health_pct < execute.health_pct {
+                taken += execute.damage_taken;
+            }
+        }
         (done, taken)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::WeaponType;
+    use crate::services::bar_distribution::WeaponBar;
+
+    fn bar(weapon_type: WeaponType, names: &[&str]) -> WeaponBar {
+        WeaponBar {
+            weapon_type,
+            skills: names
+                .iter()
+                .map(|name| SkillData::parse(name).unwrap())
+                .collect(),
+        }
+    }
+
+    fn two_bar_distribution() -> BarDistribution {
+        BarDistribution {
+            bar1: bar(
+                WeaponType::DualWieldDagger,
+                &["Lotus Fan", "Merciless Resolve"],
+            ),
+            bar2: bar(WeaponType::Bow, &["Endless Hail", "Dark Shade"]),
+        }
+    }
+
+    fn simulator() -> FightSimulator {
+        FightSimulator::new(&CharacterStats::default(), &[], HashSet::new())
+            .with_fight_target(FightTarget::Duration(100.0))
+    }
+
+    fn light_attack_proc(name: &str, action: SetProcAction) -> SetProcEffect {
+        SetProcEffect {
+            name: name.to_string(),
+            trigger: SetProcTrigger::OnLightAttack,
+            action,
+            cooldown: 10.0,
+            proc_chance: 1.0,
+        }
+    }
+
+    fn damage_proc(name: &str, trigger: SetProcTrigger) -> SetProcEffect {
+        SetProcEffect {
+            name: name.to_string(),
+            trigger,
+            action: SetProcAction::DamageProc {
+                hit_damage: 1000.0,
+                hit_flags: DamageFlags::MAGIC | DamageFlags::SINGLE_TARGET | DamageFlags::DIRECT,
+                dot_total_damage: 0.0,
+                dot_duration: 0.0,
+                dot_flags: DamageFlags::empty(),
+            },
+            cooldown: 5.0,
+            proc_chance: 1.0,
+        }
+    }
+
+    fn breakdown_damage(result: &SimulationResult, name: &str) -> f64 {
+        result
+            .skill_breakdown
+            .iter()
+            .find(|b| b.skill_name == name)
+            .map_or(0.0, |b| b.damage)
+    }
+
+    fn event_times(result: &SimulationResult, prefix: &str) -> Vec<f64> {
+        result
+            .timeline
+            .iter()
+            .filter(|e| e.description.starts_with(prefix))
+            .map(|e| e.time)
+            .collect()
+    }
+
+    fn curve_times(result: &SimulationResult) -> Vec<f64> {
+        result.dps_curve.iter().map(|s| s.time).collect()
+    }
+
+    #[test]
+    fn test_duration_fight_stops_at_requested_time() {
+        let result = simulator().simulate(&two_bar_distribution());
+        assert_eq!(result.fight_duration, 100.0);
+        assert_eq!(result.time_to_kill, None);
+        assert!(result.total_damage > 0.0);
+        assert_eq!(
+            curve_times(&result),
+            vec![10.0, 20.0, 30.0, 45.0, 60.0, 90.0, 100.0]
+        );
+        let last = result.dps_curve.last().unwrap();
+        assert!((last.dps - result.dps).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_empty_bars_keep_light_attacking() {
+        let distribution = BarDistribution {
+            bar1: bar(WeaponType::DualWieldDagger, &[]),
+            bar2: bar(WeaponType::Bow, &[]),
+        };
+        let result = simulator().simulate(&distribution);
+        assert_eq!(result.la_count, 100);
+        assert!(result.la_damage > 0.0);
+        assert!((result.total_damage - result.la_damage).abs() < 1e-6);
+        assert_eq!(result.bar_swap_count, 0);
+    }
+
+    #[test]
+    fn test_target_hp_fight_runs_until_the_kill() {
+        let result = FightSimulator::new(&CharacterStats::default(), &[], HashSet::new())
+            .with_fight_target(FightTarget::TargetHp(200_000.0))
+            .simulate(&two_bar_distribution());
+        let time_to_kill = result.time_to_kill.expect("target killed");
+        assert_eq!(time_to_kill, result.fight_duration);
+        assert!((result.total_damage - 200_000.0).abs() < 1e-6);
+
+        let times = curve_times(&result);
+        assert!(times.len() > 2, "{:?}", times);
+        assert!(times.windows(2).all(|w| w[0] < w[1]), "{:?}", times);
+        assert_eq!(*times.last().unwrap(), time_to_kill);
+        assert!(times[..times.len() - 1]
+            .iter()
+            .all(|t| DPS_CURVE_CHECKPOINTS.contains(t)));
+    }
+
+    #[test]
+    fn test_potion_drunk_on_cooldown() {
+        let distribution = two_bar_distribution();
+        let result = simulator()
+            .with_potion(Some(Potion::WeaponPower))
+            .with_timeline(true)
+            .simulate(&distribution);
+
+        let cooldown = Potion::WeaponPower.cooldown();
+        let drinks = event_times(&result, "Drink");
+        assert_eq!(drinks.len(), 3);
+        for pair in drinks.windows(2) {
+            let gap = pair[1] - pair[0];
+            assert!(
+                (cooldown..cooldown + GCD).contains(&gap),
+                "potions {:.1}s apart",
+                gap
+            );
+        }
+
+        let without = simulator().simulate(&distribution);
+        assert!(result.dps > without.dps);
+    }
+
+    #[test]
+    fn test_only_potion_flat_buffs_scale_with_damage_multipliers() {
+        use crate::domain::{BonusSource, BonusValue};
+
+        let multiplier = BonusData::new(
+            "Test Damage",
+            BonusSource::Buff,
+            BonusTrigger::Passive,
+            BonusValue::new(
+                "Test Damage",
+                BonusTarget::WeaponAndSpellDamageMultiplier,
+                0.1,
+            ),
+        );
+        let sim = FightSimulator::new(&CharacterStats::default(), &[multiplier], HashSet::new())
+            .with_potion(Some(Potion::WeaponPower));
+        let flat_buff = |source: &str| ActiveBuff {
+            name: "Flat Damage".to_string(),
+            source_skill_name: source.to_string(),
+            remaining_duration: Some(10.0),
+            target: BonusTarget::WeaponAndSpellDamageFlat,
+            value: 100.0,
+        };
+        let base = sim.apply_buffs_to_stats(&[]);
+
+        let potion = sim.apply_buffs_to_stats(&[flat_buff("Weapon Power Potion")]);
+        assert!((potion.weapon_damage - base.weapon_damage - 110.0).abs() < 1e-9);
+        assert!((potion.spell_damage - base.spell_damage - 110.0).abs() < 1e-9);
+
+        let skill = sim.apply_buffs_to_stats(&[flat_buff("Lotus Fan")]);
+        assert!((skill.weapon_damage - base.weapon_damage - 100.0).abs() < 1e-9);
+        assert!((skill.spell_damage - base.spell_damage - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_set_proc_triggers_deal_damage() {
+        let distribution = two_bar_distribution();
+        let triggers = [
+            SetProcTrigger::OnLightAttack,
+            SetProcTrigger::OnDirectDamage,
+            SetProcTrigger::OnDealDamage,
+            SetProcTrigger::OnBarSwap,
+            SetProcTrigger::OnCriticalHit,
+            SetProcTrigger::OnDotTick,
+            SetProcTrigger::OnHeavyAttack,
+            SetProcTrigger::OnSkillLineCast(SkillLineName::Assassination),
+            SetProcTrigger::OnStatusEffectApplied,
+        ];
+        for trigger in triggers {
+            let name = format!("{:?} Proc", trigger);
+            let result = simulator()
+                .with_set_procs(vec![damage_proc(&name, trigger)])
+                .with_enchants(Some(WeaponEnchant::Flame), Some(WeaponEnchant::Flame))
+                .simulate(&distribution);
+            assert!(
+                breakdown_damage(&result, &name) > 0.0,
+                "{:?} never fired",
+                trigger
+            );
+        }
+    }
+
+    #[test]
+    fn test_heavy_attacks_woven_for_heavy_attack_sets() {
+        let distribution = two_bar_distribution();
+        let without = simulator().with_timeline(true).simulate(&distribution);
+        assert!(event_times(&without, "Heavy Attack").is_empty());
+
+        let heavy = damage_proc("Heavy", SetProcTrigger::OnHeavyAttack);
+        assert!(heavy.estimated_dps() > 0.0);
+        let result = simulator()
+            .with_set_procs(vec![heavy])
+            .with_timeline(true)
+            .simulate(&distribution);
+        let heavy_attacks = event_times(&result, "Heavy Attack");
+        assert!(heavy_attacks.len() >= 8);
+        for pair in heavy_attacks.windows(2) {
+            assert!(pair[1] - pair[0] >= HEAVY_ATTACK_INTERVAL);
+        }
+        assert!(breakdown_damage(&result, "Heavy Attack") > 0.0);
+    }
+
+    #[test]
+    fn test_bar_swap_procs_charge_while_not_swapping() {
+        let mut restore = damage_proc("Restore", SetProcTrigger::OnBarSwap);
+        restore.action = SetProcAction::RestoreResource {
+            resource: Resource::Magicka,
+            amount: 1000.0,
+        };
+        restore.cooldown = 15.0;
+        let result = simulator()
+            .with_set_procs(vec![restore])
+            .with_timeline(true)
+            .simulate(&two_bar_distribution());
+
+        // The first swap is charged; later ones only after 15 seconds without swapping
+        let swaps = event_times(&result, "Swap");
+        let charged = 1 + swaps.windows(2).filter(|w| w[1] - w[0] >= 15.0).count();
+        assert!(charged < swaps.len());
+        assert_eq!(result.resources_restored.len(), 1);
+        assert_eq!(
+            result.resources_restored[0].restored,
+            1000.0 * charged as f64
+        );
+    }
+
+    fn beam(ramp_per_tick: f64) -> SetProcEffect {
+        light_attack_proc(
+            "Beam",
+            SetProcAction::RampingBeam {
+                base_damage_per_tick: 100.0,
+                ramp_per_tick,
+                tick_interval: 1.0,
+                duration: 10.0,
+                flags: DamageFlags::FLAME | DamageFlags::SINGLE_TARGET | DamageFlags::DOT,
+            },
+        )
+    }
+
+    #[test]
+    fn test_ramping_beam_ticks_grow() {
+        let distribution = two_bar_distribution();
+        let flat = simulator()
+            .with_set_procs(vec![beam(0.0)])
+            .simulate(&distribution);
+        let ramping = simulator()
+            .with_set_procs(vec![beam(1.0)])
+            .simulate(&distribution);
+        // Ticks at 100% more per tick average 5.5x the flat tick over a full tether
+        // (the last tether is cut short by the end of the fight)
+        let ratio = breakdown_damage(&ramping, "Beam") / breakdown_damage(&flat, "Beam");
+        assert!((5.0..=5.5).contains(&ratio), "ramp ratio {:.2}", ratio);
+    }
+
+    #[test]
+    fn test_execute_scaling_proc_grows_as_health_drops() {
+        let execute = light_attack_proc(
+            "Execute",
+            SetProcAction::ExecuteScalingDamage {
+                hit_damage: 1000.0,
+                dot_total_damage: 0.0,
+                dot_duration: 0.0,
+                flags: DamageFlags::POISON | DamageFlags::SINGLE_TARGET | DamageFlags::DIRECT,
+                max_bonus: 1.0,
+                threshold_pct: 1.0,
+            },
+        );
+        let plain = damage_proc("Plain", SetProcTrigger::OnLightAttack);
+        let execute = SetProcEffect {
+            cooldown: plain.cooldown,
+            ..execute
+        };
+        // Health falls over the fixed-duration fight: half the max bonus on average
+        let result = simulator()
+            .with_set_procs(vec![execute, plain])
+            .simulate(&two_bar_distribution());
+        let ratio = breakdown_damage(&result, "Execute") / breakdown_damage(&result, "Plain");
+        assert!((1.4..1.6).contains(&ratio), "execute ratio {:.2}", ratio);
+    }
+
+    #[test]
+    fn test_enemy_debuff_proc_raises_damage_while_up() {
+        let debuff = light_attack_proc(
+            "Debuff",
+            SetProcAction::EnemyDebuff {
+                debuff_name: "Major Vulnerability".to_string(),
+                target: BonusTarget::EnemyDamageTaken,
+                value: 0.10,
+                duration: 5.0,
+            },
+        );
+        let distribution = two_bar_distribution();
+        let with = simulator()
+            .with_set_procs(vec![debuff])
+            .simulate(&distribution);
+        let without = simulator().simulate(&distribution);
+        assert!(with.dps > without.dps);
+
+        let uptime = with
+            .buff_uptimes
+            .iter()
+            .find(|b| b.name == "Major Vulnerability")
+            .unwrap()
+            .uptime;
+        assert!((0.45..0.55).contains(&uptime), "uptime {:.2}", uptime);
+    }
+
+    #[test]
+    fn test_summoned_entity_attacks_for_its_duration() {
+        let summon = light_attack_proc(
+            "Summon",
+            SetProcAction::SummonedEntity {
+                damage_per_attack: 500.0,
+                attack_interval: 1.0,
+                duration: 5.0,
+                delay: 1.0,
+                flags: DamageFlags::PHYSICAL | DamageFlags::SINGLE_TARGET | DamageFlags::DIRECT,
+            },
+        );
+        let result = simulator()
+            .with_set_procs(vec![summon])
+            .with_timeline(true)
+            .simulate(&two_bar_distribution());
+        // Ten summons in 100 seconds, five attacks each
+        assert_eq!(event_times(&result, "Tick Summon").len(), 50);
+        assert!(breakdown_damage(&result, "Summon") > 0.0);
+    }
+
+    #[test]
+    fn test_monte_carlo_summary_is_reproducible() {
+        let mut chance_proc = damage_proc("Chance", SetProcTrigger::OnLightAttack);
+        chance_proc.cooldown = 0.0;
+        chance_proc.proc_chance = 0.3;
+        let simulator = simulator().with_set_procs(vec![chance_proc]);
+        let distribution = two_bar_distribution();
+
+        let result = simulator.simulate_monte_carlo(&distribution, 20, 42);
+        let summary = result.monte_carlo.unwrap();
+        assert_eq!(summary.runs, 20);
+        assert!(summary.std_dev > 0.0);
+        assert!(summary.min_dps <= summary.mean_dps && summary.mean_dps <= summary.max_dps);
+        assert!((summary.min_dps..=summary.max_dps).contains(&result.dps));
+
+        let again = simulator
+            .simulate_monte_carlo(&distribution, 20, 42)
+            .monte_carlo
+            .unwrap();
+        assert_eq!(again.mean_dps, summary.mean_dps);
+        assert_eq!(again.std_dev, summary.std_dev);
+
+        // Rolled procs average out to the expected value of the deterministic run
+        let expected = simulator.simulate(&distribution).dps;
+        assert!((summary.mean_dps / expected - 1.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_infused_weapon_halves_enchant_cooldown() {
+        let distribution = two_bar_distribution();
+        let enchant_procs = |weapon_trait: Option<WeaponTrait>| {
+            simulator()
+                .with_enchants(Some(WeaponEnchant::Flame), Some(WeaponEnchant::Flame))
+                .with_weapon_traits(weapon_trait, weapon_trait)
+                .simulate(&distribution)
+                .skill_breakdown
+                .iter()
+                .find(|b| b.skill_name == "Weapon Enchant")
+                .unwrap()
+                .cast_count
+        };
+        let plain = enchant_procs(Some(WeaponTrait::Nirnhoned));
+        let infused = enchant_procs(Some(WeaponTrait::Infused));
+        assert!(plain <= 26, "{} procs on a 4 second cooldown", plain);
+        assert!(infused >= plain * 3 / 2, "{} vs {} procs", infused, plain);
+    }
+
+    #[test]
+    fn test_off_hand_enchant_and_trait_apply_to_their_own_hand() {
+        let distribution = two_bar_distribution();
+        let enchant_procs = |off_hand: Option<WeaponEnchant>, off_hand_trait| {
+            simulator()
+                .with_enchants(Some(WeaponEnchant::Flame), None)
+                .with_off_hand_enchants(off_hand, None)
+                .with_weapon_traits(Some(WeaponTrait::Nirnhoned), None)
+                .with_off_hand_traits(off_hand_trait, None)
+                .simulate(&distribution)
+                .skill_breakdown
+                .iter()
+                .find(|b| b.skill_name == "Weapon Enchant")
+                .unwrap()
+                .cast_count
+        };
+        let main_only = enchant_procs(None, None);
+
+        // An infused off hand doesn't shorten the main hand's cooldown
+        assert_eq!(enchant_procs(None, Some(WeaponTrait::Infused)), main_only);
+        // A second copy of the main-hand enchant adds nothing
+        assert_eq!(enchant_procs(Some(WeaponEnchant::Flame), None), main_only);
+        // A different off-hand enchant procs on its own cooldown
+        let both = enchant_procs(Some(WeaponEnchant::Poison), None);
+        assert!(both >= main_only * 3 / 2, "{} vs {} procs", both, main_only);
+    }
+
+    #[test]
+    fn test_enchant_debuffs_and_absorb_sustain() {
+        let distribution = two_bar_distribution();
+        let run = |enchant: Option<WeaponEnchant>| {
+            simulator()
+                .with_enchants(enchant, enchant)
+                .simulate(&distribution)
+        };
+        let unenchanted = run(None);
+
+        // Crusher deals no damage itself but lowers the enemy's resistances while up
+        let crusher = run(Some(WeaponEnchant::Crusher));
+        assert_eq!(breakdown_damage(&crusher, "Weapon Enchant"), 0.0);
+        assert!(crusher.total_damage > unenchanted.total_damage);
+
+        // Weakening only reduces the enemy's damage
+        let weakening = run(Some(WeaponEnchant::Weakening));
+        assert!((weakening.total_damage - unenchanted.total_damage).abs() < 1e-6);
+
+        // Absorb restores its resource on every proc
+        let absorb = run(Some(WeaponEnchant::AbsorbStamina));
+        let procs = absorb
+            .skill_breakdown
+            .iter()
+            .find(|b| b.skill_name == "Weapon Enchant")
+            .unwrap()
+            .cast_count;
+        assert_eq!(absorb.resources_restored.len(), 1);
+        assert_eq!(absorb.resources_restored[0].resource, Resource::Stamina);
+        assert!((absorb.resources_restored[0].restored - procs as f64 * 1_000.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_immune_window_deals_no_damage_and_adds_take_area_damage() {
+        let phase = |kind, start, duration| EncounterPhase {
+            kind,
+            start: PhaseStart::Time(start),
+            duration,
+            every: None,
+            clears_dots: false,
+        };
+        let result = simulator()
+            .with_downtime(vec![
+                phase(PhaseKind::Immune, 10.0, 20.0),
+                phase(
+                    PhaseKind::Adds {
+                        name: "Imp".to_string(),
+                        count: 2,
+                        hp: 5_000.0,
+                    },
+                    40.0,
+                    60.0,
+                ),
+            ])
+            .simulate(&two_bar_distribution());
+
+        // Nothing lands on the boss while it is immune, DoTs included
+        let damage_at = |time: f64| {
+            let sample = result.dps_curve.iter().find(|s| s.time == time).unwrap();
+            sample.dps * time
+        };
+        assert!(damage_at(10.0) > 0.0);
+        assert!((damage_at(30.0) - damage_at(10.0)).abs() < 1e-6);
+        assert!(damage_at(60.0) > damage_at(30.0));
+
+        // Area damage burns both adds down and stops once they are dead,
+        // long before the phase would have ended
+        assert!((result.add_damage - 2.0 * 5_000.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_synergies_trigger_passives_and_wait_out_downtime() {
+        let distribution = two_bar_distribution();
+        let trial = simulator()
+            .with_synergies(SynergyProfile::Trial)
+            .simulate(&distribution);
+        assert!(trial.synergy_damage > 0.0);
+        // Undaunted Command restores Health, Necrotic buffs damage after each activation
+        assert!(trial
+            .resources_restored
+            .iter()
+            .any(|r| r.resource == Resource::Health && r.restored > 0.0));
+        assert!(trial
+            .buff_uptimes
+            .iter()
+            .any(|b| b.name == "Necrotic" && b.uptime > 0.0));
+
+        // Nothing is activated while the boss is immune
+        let immune = simulator()
+            .with_synergies(SynergyProfile::Trial)
+            .with_downtime(vec![EncounterPhase {
+                kind: PhaseKind::Immune,
+                start: PhaseStart::Time(0.0),
+                duration: 100.0,
+                every: None,
+                clears_dots: false,
+            }])
+            .simulate(&distribution);
+        assert_eq!(immune.synergy_damage, 0.0);
+        assert!(immune.resources_restored.is_empty());
+    }
+
+    #[test]
+    fn test_health_scaled_skill_damage_grows_with_max_health() {
+        let mut skill = SkillData::parse("Lotus Fan").unwrap().clone();
+        skill.resource = Resource::Health;
+        let skill: &'static SkillData = Box::leak(Box::new(skill));
+        let distribution = BarDistribution {
+            bar1: WeaponBar {
+                weapon_type: WeaponType::DualWieldDagger,
+                skills: vec![skill],
+            },
+            bar2: bar(WeaponType::Bow, &[]),
+        };
+        let skill_damage = |max_health: f64| {
+            let stats = CharacterStats::default().with_max_health(max_health);
+            let result = FightSimulator::new(&stats, &[], HashSet::new())
+                .with_fight_target(FightTarget::Duration(30.0))
+                .simulate(&distribution);
+            breakdown_damage(&result, "Lotus Fan")
+        };
+        let low = skill_damage(16_000.0);
+        let high = skill_damage(40_000.0);
+        assert!(low > 0.0);
+        assert!(high > low * 1.1, "{} vs {}", high, low);
+    }
+
+    #[test]
+    fn test_stacks_scale_dots_and_grant_per_stack_bonuses() {
+        // Carve's bleed stacks raise its DoT too (hits left out to isolate the DoT)
+        let carve_dot = |damage_per_stack: f64| {
+            let mut skill = SkillData::parse("Carve").unwrap().clone();
+            skill.damage.as_mut().unwrap().hits = None;
+            skill.stacks[0].damage_per_stack = damage_per_stack;
+            let skill: &'static SkillData = Box::leak(Box::new(skill));
+            let distribution = BarDistribution {
+                bar1: WeaponBar {
+                    weapon_type: WeaponType::TwoHandedSword,
+                    skills: vec![skill, SkillData::parse("Uppercut").unwrap()],
+                },
+                bar2: bar(WeaponType::Bow, &[]),
+            };
+            breakdown_damage(&simulator().simulate(&distribution), "Carve")
+        };
+        let flat = carve_dot(0.0);
+        let stacked = carve_dot(0.1);
+        assert!(flat > 0.0);
+        assert!(stacked > flat * 1.1, "{} vs {}", stacked, flat);
+
+        // Seething Fury stacks grant Weapon and Spell Damage until Molten Whip spends them
+        let distribution = BarDistribution {
+            bar1: bar(
+                WeaponType::DualWieldDagger,
+                &["Molten Whip", "Engulfing Flames"],
+            ),
+            bar2: bar(WeaponType::Bow, &[]),
+        };
+        let result = simulator().simulate(&distribution);
+        let uptime = result
+            .buff_uptimes
+            .iter()
+            .find(|b| b.name == "Seething Fury")
+            .map_or(0.0, |b| b.uptime);
+        assert!(
+            uptime > 0.0 && uptime < 0.5,
+            "Seething Fury uptime {}",
+            uptime
+        );
+    }
+
+    #[test]
+    fn test_dynamic_dot_ticks_follow_buffs_and_snapshot_ticks_do_not() {
+        // Major Vulnerability from light attacks lasts 5s of every 10s, so it ends
+        // part way through each Endless Hail
+        let vulnerability = light_attack_proc(
+            "Vulnerability",
+            SetProcAction::EnemyDebuff {
+                debuff_name: "Major Vulnerability".to_string(),
+                target: BonusTarget::EnemyDamageTaken,
+                value: 0.10,
+                duration: 5.0,
+            },
+        );
+        let hail_ticks = |snapshot: DotSnapshot| {
+            let mut skill = SkillData::parse("Endless Hail").unwrap().clone();
+            skill.dot_snapshot = Some(snapshot);
+            let skill: &'static SkillData = Box::leak(Box::new(skill));
+            let distribution = BarDistribution {
+                bar1: WeaponBar {
+                    weapon_type: WeaponType::Bow,
+                    skills: vec![skill, SkillData::parse("Molten Whip").unwrap()],
+                },
+                bar2: bar(WeaponType::DualWieldDagger, &[]),
+            };
+            let result = simulator()
+                .with_fight_target(FightTarget::Duration(14.0))
+                .with_set_procs(vec![vulnerability.clone()])
+                .with_timeline(true)
+                .simulate(&distribution);
+            result
+                .timeline
+                .iter()
+                .filter_map(|e| e.description.strip_prefix("Tick Endless Hail "))
+                .map(|rest| rest.split(':').next().unwrap().parse::<f64>().unwrap())
+                .collect::<Vec<_>>()
+        };
+        let spread = |ticks: &[f64]| {
+            let max = ticks.iter().copied().fold(f64::MIN, f64::max);
+            let min = ticks.iter().copied().fold(f64::MAX, f64::min);
+            max / min
+        };
+
+        let snapshot = hail_ticks(DotSnapshot::all());
+        let dynamic = hail_ticks(DotSnapshot::empty());
+        assert!(snapshot.len() >= 10, "{:?}", snapshot);
+        assert_eq!(snapshot.len(), dynamic.len());
+        assert!(spread(&snapshot) < 1.001, "{:?}", snapshot);
+        assert!(spread(&dynamic) > 1.05, "{:?}", dynamic);
+    }
+
+    #[test]
+    fn test_poison_procs_on_its_bar_and_cooldown() {
+        let distribution = two_bar_distribution();
+        let run = |bar1: Option<Poison>, bar2: Option<Poison>| {
+            simulator().with_poisons(bar1, bar2).simulate(&distribution)
+        };
+        let poison_procs = |result: &SimulationResult, name: &str| {
+            result
+                .skill_breakdown
+                .iter()
+                .find(|b| b.skill_name == name)
+                .map_or(0, |b| b.cast_count)
+        };
+
+        let bar2_only = run(None, Some(Poison::DamageHealth));
+        let both_bars = run(Some(Poison::DamageHealth), Some(Poison::DamageHealth));
+        let bar2_procs = poison_procs(&bar2_only, "Damage Health Poison");
+        let both_procs = poison_procs(&both_bars, "Damage Health Poison");
+        assert!(bar2_procs > 0);
+        assert!(
+            both_procs <= 11,
+            "{} procs on a 10 second cooldown",
+            both_procs
+        );
+        assert!(
+            both_procs > bar2_procs,
+            "{} vs {} procs",
+            both_procs,
+            bar2_procs
+        );
+
+        // Crown Lethal's Minor Vulnerability raises the rest of the rotation's damage too
+        let unpoisoned = run(None, None);
+        let crown = run(Some(Poison::CrownLethal), Some(Poison::CrownLethal));
+        let crown_poison = breakdown_damage(&crown, "Crown Lethal Poison");
+        assert!(crown_poison > 0.0);
+        assert!(crown.total_damage - crown_poison > unpoisoned.total_damage);
+    }
+}
@@ -1,9 +1,13 @@
+use crate::data::skill_trees::weapon::dual_wield::dual_wield_passives::with_twin_blade_and_blunt_hands;
 use crate::domain::{
-    ArmorTrait, AttributeChoice, Build, BuildConfig, CharacterStats, Food, JewelryTrait,
-    MundusStone, Race, WeaponTrait, DPS_ARMOR_TRAITS, DPS_ATTRIBUTES, DPS_FOODS,
-    DPS_JEWELRY_TRAITS, DPS_MUNDUS_STONES, DPS_RACES, DPS_WEAPON_TRAITS,
+    ArmorGlyph, ArmorTrait, AttributeChoice, BonusData, Build, BuildConfig, CharacterStats, Food,
+    GearQuality, JewelryGlyph, JewelryTrait, MundusStone, Poison, Race, WeaponEnchant, WeaponTrait,
+    WeaponType, ATTRIBUTE_SPLIT_STEP, DPS_ARMOR_GLYPHS, DPS_ARMOR_TRAITS, DPS_ATTRIBUTES,
+    DPS_FOODS, DPS_JEWELRY_GLYPHS, DPS_JEWELRY_TRAITS, DPS_MUNDUS_STONES, DPS_POISONS, DPS_RACES,
+    DPS_WEAPON_ENCHANTS, DPS_WEAPON_TRAITS, DUAL_WIELD_WEAPONS,
 };
-use crate::infrastructure::logger;
+use crate::infrastructure::{format, logger, table};
+use crate::services::infer_weapons;
 
 pub struct GearOptimizerOptions {
     pub top_k: usize,
@@ -12,6 +16,8 @@ pub struct GearOptimizerOptions {
 
 impl GearOptimizerOptions {
     /// Returns true if all gear dimensions are pinned (nothing to optimize).
+    /// Glyphs, enchants, poisons and off-hands left unset use their defaults
+    /// rather than reopening the search.
     pub fn all_pinned(&self, baseline: &BuildConfig) -> bool {
         baseline.race.is_some()
             && baseline.mundus.is_some()
@@ -28,12 +34,45 @@ pub struct GearOptimizerResult {
     pub character_stats: CharacterStats,
 }
 
+/// DPC gained by raising a single slot to Legendary quality.
+pub struct QualityUpgrade {
+    pub slot: &'static str,
+    pub from: GearQuality,
+    pub dpc_gain: f64,
+    /// Gain relative to the current gear's DPC (%)
+    pub gain_pct: f64,
+}
+
+/// DPC lost by moving attribute points into health.
+pub struct HealthPointCost {
+    pub points: u8,
+    pub attributes: AttributeChoice,
+    pub dpc_loss: f64,
+    /// Loss relative to the current gear's DPC (%)
+    pub loss_pct: f64,
+}
+
+const ARMOR_SLOT_NAMES: [&str; 7] = [
+    "Chest",
+    "Head",
+    "Legs",
+    "Shoulders",
+    "Hands",
+    "Waist",
+    "Feet",
+];
+const JEWELRY_SLOT_NAMES: [&str; 3] = ["Necklace", "Ring 1", "Ring 2"];
+const WEAPON_SLOT_NAMES: [&str; 2] = ["Bar 1 Weapon", "Bar 2 Weapon"];
+
 pub struct GearOptimizer;
 
 impl GearOptimizer {
     /// Two-phase gear optimization:
     /// Phase 1A: Greedy - score coupled/independent dimensions keeping others at baseline.
     /// Phase 1B: Refine - cross-product top-K from each dimension group.
+    /// Phase 1C: Procs - rank per-bar weapon enchants and poisons on top of the winning gear.
+    /// Phase 1D: Pair - choose the main/off-hand one-handers and off-hand trait of a dual wield bar.
+    /// Phase 1E: Split - refine unpinned attributes into the best magicka/stamina/health split.
     pub fn optimize(
         builds: &[Build],
         options: &GearOptimizerOptions,
@@ -43,19 +82,10 @@ impl GearOptimizer {
         let top_k = options.top_k;
 
         // Helper: evaluate a BuildConfig by building a new Build and returning DPC
-        let score = |gear: &BuildConfig| -> f64 {
-            let stats = gear.compute_stats();
-            let build = Build::new_with_extra(
-                rep.skills().to_vec(),
-                rep.cp_bonuses(),
-                rep.passive_bonuses(),
-                &[], // no set bonuses during gear optimization
-                Vec::new(),
-                stats,
-                rep.extra_bonuses(),
-            );
-            build.total_damage_per_cast
+        let score_with_extra = |gear: &BuildConfig, extra_bonuses: &[BonusData]| -> f64 {
+            score_gear(rep, gear, rep.passive_bonuses(), extra_bonuses)
         };
+        let score = |gear: &BuildConfig| -> f64 { score_with_extra(gear, rep.extra_bonuses()) };
 
         // ── Phase 1A: Greedy scoring ──
 
@@ -134,7 +164,12 @@ impl GearOptimizer {
             ));
         }
 
-        // Coupled group 2: (Attributes, Food) - both affect resource pools
+        // Coupled group 2: (Attributes, Food, ArmorGlyphs) - all affect resource pools
+        let armor_glyph_arrays: Vec<[ArmorGlyph; 7]> = slot_combinations(
+            &baseline.armor_glyphs,
+            DPS_ARMOR_GLYPHS,
+            ArmorGlyph::Stamina,
+        );
         let attr_candidates: Vec<AttributeChoice> = match baseline.attributes {
             Some(a) => vec![a],
             None => DPS_ATTRIBUTES.to_vec(),
@@ -148,14 +183,18 @@ impl GearOptimizer {
             }
         };
 
-        let mut attr_food_scores: Vec<(f64, AttributeChoice, Option<Food>)> = Vec::new();
+        let mut attr_food_scores: Vec<(f64, AttributeChoice, Option<Food>, [ArmorGlyph; 7])> =
+            Vec::new();
         for &attr in &attr_candidates {
             for &food in &food_candidates {
-                let mut gear = baseline.clone();
-                gear.attributes = Some(attr);
-                gear.food = food;
-                let dpc = score(&gear);
-                attr_food_scores.push((dpc, attr, food));
+                for glyphs in &armor_glyph_arrays {
+                    let mut gear = baseline.clone();
+                    gear.attributes = Some(attr);
+                    gear.food = food;
+                    gear.armor_glyphs = glyphs.to_vec();
+                    let dpc = score(&gear);
+                    attr_food_scores.push((dpc, attr, food, *glyphs));
+                }
             }
         }
         attr_food_scores.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap());
@@ -163,15 +202,16 @@ impl GearOptimizer {
 
         if options.verbose {
             logger::dim(&format!(
-                "Gear Phase 1A: Scored {} attr+food combos, top-{}: {}",
-                attr_candidates.len() * food_candidates.len(),
+                "Gear Phase 1A: Scored {} attr+food+armor glyph combos, top-{}: {}",
+                attr_candidates.len() * food_candidates.len() * armor_glyph_arrays.len(),
                 top_k,
                 attr_food_scores
                     .iter()
-                    .map(|(dpc, a, f)| format!(
-                        "{}+{} ({:.0})",
+                    .map(|(dpc, a, f, g)| format!(
+                        "{}+{}+{} ({:.0})",
                         a,
                         f.map_or("None".to_string(), |f| f.to_string()),
+                        format_armor_glyphs(g),
                         dpc
                     ))
                     .collect::<Vec<_>>()
@@ -216,7 +256,7 @@ impl GearOptimizer {
             ));
         }
 
-        // Independent: JewelryTraits (per-slot)
+        // Coupled group 3: (JewelryTraits, JewelryGlyphs) - Infused amplifies the slot's glyph
         let pinned_jewelry = &baseline.jewelry_traits;
         let free_jewelry_slots = 3 - pinned_jewelry.len();
         let jewelry_trait_arrays: Vec<[JewelryTrait; 3]> = if free_jewelry_slots == 0 {
@@ -244,24 +284,38 @@ impl GearOptimizer {
             combos
         };
 
-        let mut jewelry_scores: Vec<(f64, [JewelryTrait; 3])> = Vec::new();
+        let jewelry_glyph_arrays: Vec<[JewelryGlyph; 3]> = slot_combinations(
+            &baseline.jewelry_glyphs,
+            DPS_JEWELRY_GLYPHS,
+            JewelryGlyph::PhysicalHarm,
+        );
+
+        let mut jewelry_scores: Vec<(f64, [JewelryTrait; 3], [JewelryGlyph; 3])> = Vec::new();
         for jewelry in &jewelry_trait_arrays {
-            let mut gear = baseline.clone();
-            gear.jewelry_traits = jewelry.to_vec();
-            let dpc = score(&gear);
-            jewelry_scores.push((dpc, *jewelry));
+            for glyphs in &jewelry_glyph_arrays {
+                let mut gear = baseline.clone();
+                gear.jewelry_traits = jewelry.to_vec();
+                gear.jewelry_glyphs = glyphs.to_vec();
+                let dpc = score(&gear);
+                jewelry_scores.push((dpc, *jewelry, *glyphs));
+            }
         }
         jewelry_scores.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap());
         jewelry_scores.truncate(top_k);
 
         if options.verbose {
             logger::dim(&format!(
-                "Gear Phase 1A: Scored {} jewelry trait combos, top-{}: {}",
-                jewelry_trait_arrays.len(),
+                "Gear Phase 1A: Scored {} jewelry trait+glyph combos, top-{}: {}",
+                jewelry_trait_arrays.len() * jewelry_glyph_arrays.len(),
                 top_k,
                 jewelry_scores
                     .iter()
-                    .map(|(dpc, j)| format!("{} ({:.0})", format_jewelry_traits(j), dpc))
+                    .map(|(dpc, j, g)| format!(
+                        "{}+{} ({:.0})",
+                        format_jewelry_traits(j),
+                        format_jewelry_glyphs(g),
+                        dpc
+                    ))
                     .collect::<Vec<_>>()
                     .join(", ")
             ));
@@ -324,9 +378,9 @@ impl GearOptimizer {
         let mut best_gear = baseline.clone();
 
         for &(_, armor, mundus) in &armor_mundus_scores {
-            for &(_, attr, food) in &attr_food_scores {
+            for &(_, attr, food, armor_glyphs) in &attr_food_scores {
                 for &(_, race) in &race_scores {
-                    for &(_, jewelry) in &jewelry_scores {
+                    for &(_, jewelry, jewelry_glyphs) in &jewelry_scores {
                         for &(_, weapon) in &weapon_scores {
                             let gear = BuildConfig {
                                 race,
@@ -335,6 +389,8 @@ impl GearOptimizer {
                                 armor_traits: armor.to_vec(),
                                 jewelry_traits: jewelry.to_vec(),
                                 weapon_traits: weapon.to_vec(),
+                                armor_glyphs: armor_glyphs.to_vec(),
+                                jewelry_glyphs: jewelry_glyphs.to_vec(),
                                 attributes: Some(attr),
                                 armor: baseline.armor,
                                 bar1_weapon: baseline.bar1_weapon,
@@ -351,19 +407,249 @@ impl GearOptimizer {
             }
         }
 
+        // ── Phase 1C: Weapon enchants and poisons ──
+        // Their buffs/debuffs only last part of the fight, so the DPC gain is
+        // scaled by uptime; proc damage is added as an estimated DPS like set procs.
+        let score_proc_effects = |bonuses: Vec<BonusData>, uptime: f64| -> f64 {
+            // Buffs already provided externally (e.g. trial dummy) add nothing
+            let mut extra = rep.extra_bonuses().to_vec();
+            for bonus in bonuses {
+                if !extra.iter().any(|b| b.name == bonus.name) {
+                    extra.push(bonus);
+                }
+            }
+            (score_with_extra(&best_gear, &extra) - best_dpc) * uptime
+        };
+
+        // Dual wield bars (pinned, or implied by the skills): 0 = bar1, 1 = bar2
+        let inferred_weapons = infer_weapons(rep.skills()).ok();
+        let bar_weapons = [
+            baseline.bar1_weapon.or(inferred_weapons.map(|(w1, _)| w1)),
+            baseline.bar2_weapon.or(inferred_weapons.map(|(_, w2)| w2)),
+        ];
+        let dual_wield_bars: Vec<usize> = (0..2)
+            .filter(|&bar| bar_weapons[bar].is_some_and(|w| w.is_dual_wield()))
+            .collect();
+        let needs_off_hand_enchant = dual_wield_bars.iter().any(|&bar| match bar {
+            0 => best_gear.bar1_off_hand_enchant.is_none(),
+            _ => best_gear.bar2_off_hand_enchant.is_none(),
+        });
+
+        let weapon_trait = best_gear.weapon_traits.first().copied();
+        let enchant_ranking: Vec<WeaponEnchant> = if best_gear.bar1_enchant.is_none()
+            || best_gear.bar2_enchant.is_none()
+            || needs_off_hand_enchant
+        {
+            let mut enchant_scores: Vec<(f64, WeaponEnchant)> = DPS_WEAPON_ENCHANTS
+                .iter()
+                .map(|&e| {
+                    (
+                        score_proc_effects(e.bonuses(), e.uptime(weapon_trait))
+                            + e.estimated_dps(weapon_trait),
+                        e,
+                    )
+                })
+                .collect();
+            enchant_scores.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap());
+
+            if options.verbose {
+                logger::dim(&format!(
+                    "Gear Phase 1C: Scored {} weapon enchants: {}",
+                    enchant_scores.len(),
+                    enchant_scores
+                        .iter()
+                        .map(|(s, e)| format!("{} ({:.0})", e, s))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ));
+            }
+            enchant_scores.into_iter().map(|(_, e)| e).collect()
+        } else {
+            Vec::new()
+        };
+        let best_enchant = enchant_ranking.first().copied();
+
+        let best_poison = if best_gear.bar1_poison.is_none() || best_gear.bar2_poison.is_none() {
+            let mut poison_scores: Vec<(f64, Poison)> = DPS_POISONS
+                .iter()
+                .map(|&p| {
+                    (
+                        score_proc_effects(p.bonuses(), p.uptime()) + p.estimated_dps(),
+                        p,
+                    )
+                })
+                .collect();
+            poison_scores.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap());
+
+            if options.verbose {
+                logger::dim(&format!(
+                    "Gear Phase 1C: Scored {} poisons: {}",
+                    poison_scores.len(),
+                    poison_scores
+                        .iter()
+                        .map(|(s, p)| format!("{} ({:.0})", p, s))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ));
+            }
+            poison_scores.first().map(|&(_, p)| p)
+        } else {
+            None
+        };
+
+        best_gear.bar1_enchant = best_gear.bar1_enchant.or(best_enchant);
+        best_gear.bar2_enchant = best_gear.bar2_enchant.or(best_enchant);
+        best_gear.bar1_poison = best_gear.bar1_poison.or(best_poison);
+        best_gear.bar2_poison = best_gear.bar2_poison.or(best_poison);
+
+        // Each off hand takes the best enchant its main hand doesn't already proc
+        for &bar in &dual_wield_bars {
+            let (main_enchant, off_hand_enchant) = match bar {
+                0 => (best_gear.bar1_enchant, &mut best_gear.bar1_off_hand_enchant),
+                _ => (best_gear.bar2_enchant, &mut best_gear.bar2_off_hand_enchant),
+            };
+            if off_hand_enchant.is_none() {
+                *off_hand_enchant = enchant_ranking
+                    .iter()
+                    .copied()
+                    .find(|&e| Some(e) != main_enchant);
+            }
+        }
+
+        // ── Phase 1D: Dual wield pairs ──
+        // Twin Blade and Blunt applies per hand; bar1's off-hand trait also adds stats.
+        // Bar2's off-hand trait isn't modeled in stats and follows bar2's main hand.
+        for &bar in &dual_wield_bars {
+            let (pinned_main, pinned_off) = if bar == 0 {
+                (baseline.bar1_weapon, baseline.bar1_off_hand)
+            } else {
+                (baseline.bar2_weapon, baseline.bar2_off_hand)
+            };
+            let mains: Vec<WeaponType> =
+                pinned_main.map_or(DUAL_WIELD_WEAPONS.to_vec(), |w| vec![w]);
+            let offs: Vec<WeaponType> = pinned_off.map_or(DUAL_WIELD_WEAPONS.to_vec(), |w| vec![w]);
+            let off_hand_traits: Vec<Option<WeaponTrait>> =
+                match (bar, baseline.off_hand_traits.get(bar)) {
+                    (_, Some(_)) => vec![None], // pinned
+                    (0, None) => DPS_WEAPON_TRAITS.iter().copied().map(Some).collect(),
+                    _ => vec![best_gear.weapon_traits.get(bar).copied()],
+                };
+
+            let mut pair_scores: Vec<(f64, WeaponType, WeaponType, Option<WeaponTrait>)> =
+                Vec::new();
+            for (i, &main) in mains.iter().enumerate() {
+                // Hands are interchangeable when both are free: skip mirrored pairs
+                let offs = if pinned_main.is_none() && pinned_off.is_none() {
+                    &offs[i..]
+                } else {
+                    &offs[..]
+                };
+                for &off in offs {
+                    let passives =
+                        with_twin_blade_and_blunt_hands(rep.passive_bonuses(), main, off);
+                    for &off_trait in &off_hand_traits {
+                        let mut gear = best_gear.clone();
+                        if bar == 0 {
+                            gear.bar1_weapon = Some(main);
+                            gear.bar1_off_hand = Some(off);
+                        } else {
+                            gear.bar2_weapon = Some(main);
+                            gear.bar2_off_hand = Some(off);
+                        }
+                        if let Some(t) = off_trait {
+                            set_off_hand_trait(&mut gear, bar, t);
+                        }
+                        let dpc = score_gear(rep, &gear, &passives, rep.extra_bonuses());
+                        pair_scores.push((dpc, main, off, off_trait));
+                    }
+                }
+            }
+            pair_scores.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap());
+
+            if options.verbose {
+                logger::dim(&format!(
+                    "Gear Phase 1D: Scored {} dual wield pairs, best: {}",
+                    pair_scores.len(),
+                    pair_scores
+                        .iter()
+                        .take(top_k)
+                        .map(|(dpc, main, off, t)| format!(
+                            "{}+{}{} ({:.0})",
+                            main,
+                            off,
+                            t.map_or(String::new(), |t| format!(" [{}]", t)),
+                            dpc
+                        ))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ));
+            }
+
+            if let Some(&(_, main, off, off_trait)) = pair_scores.first() {
+                if bar == 0 {
+                    best_gear.bar1_weapon = Some(main);
+                    best_gear.bar1_off_hand = Some(off);
+                } else {
+                    best_gear.bar2_weapon = Some(main);
+                    best_gear.bar2_off_hand = Some(off);
+                }
+                if let Some(t) = off_trait {
+                    set_off_hand_trait(&mut best_gear, bar, t);
+                }
+            }
+        }
+
+        // ── Phase 1E: Attribute split ──
+        // The current choice goes first so a tie keeps the all-in allocation
+        if baseline.attributes.is_none() {
+            let passives = gear_passives(rep, &best_gear);
+            let current = best_gear.attributes.unwrap_or_default();
+            let mut best_split = (f64::NEG_INFINITY, current);
+            let mut candidates = vec![current];
+            candidates.extend(
+                AttributeChoice::splits()
+                    .into_iter()
+                    .filter(|&a| a != current),
+            );
+            for &attr in &candidates {
+                let mut gear = best_gear.clone();
+                gear.attributes = Some(attr);
+                let dpc = score_gear(rep, &gear, &passives, rep.extra_bonuses());
+                if dpc > best_split.0 {
+                    best_split = (dpc, attr);
+                }
+            }
+
+            if options.verbose {
+                logger::dim(&format!(
+                    "Gear Phase 1E: Scored {} attribute splits, best: {} ({:.0})",
+                    candidates.len(),
+                    best_split.1,
+                    best_split.0
+                ));
+            }
+            best_gear.attributes = Some(best_split.1);
+        }
+
         let best_stats = best_gear.compute_stats();
 
         if options.verbose {
             logger::dim(&format!(
-                "Gear optimization result: DPC={:.0}, Race={}, Mundus={}, Food={}, Armor={}, Jewelry={}, Weapon={}, Attributes={}",
+                "Gear optimization result: DPC={:.0}, Race={}, Mundus={}, Food={}, Armor={} ({}), Jewelry={} ({}), Weapon={}, Attributes={}, Enchants={}/{}, Poisons={}/{}",
                 best_dpc,
                 best_gear.race.map_or("None".to_string(), |r| r.to_string()),
                 best_gear.mundus.map_or("None".to_string(), |m| m.to_string()),
                 best_gear.food.map_or("None".to_string(), |f| f.to_string()),
                 format_armor_traits(&best_gear.armor_traits),
+                format_armor_glyphs(&best_gear.armor_glyphs),
                 format_jewelry_traits(&best_gear.jewelry_traits),
+                format_jewelry_glyphs(&best_gear.jewelry_glyphs),
                 best_gear.weapon_traits.first().map_or("None".to_string(), |t| t.to_string()),
                 best_gear.attributes.map_or("None".to_string(), |a| a.to_string()),
+                best_gear.bar1_enchant.map_or("None".to_string(), |e| e.to_string()),
+                best_gear.bar2_enchant.map_or("None".to_string(), |e| e.to_string()),
+                best_gear.bar1_poison.map_or("None".to_string(), |p| p.to_string()),
+                best_gear.bar2_poison.map_or("None".to_string(), |p| p.to_string()),
             ));
         }
 
@@ -372,6 +658,188 @@ impl GearOptimizer {
             character_stats: best_stats,
         }
     }
+
+    /// Rank the non-Legendary slots of `gear` by the DPC gained from upgrading
+    /// that slot alone to Legendary, biggest gain first. Character stats only
+    /// model the bar 1 weapon, so slots without a gain (such as the bar 2
+    /// weapon) are left out.
+    pub fn rank_quality_upgrades(builds: &[Build], gear: &BuildConfig) -> Vec<QualityUpgrade> {
+        let Some(rep) = builds.first() else {
+            return Vec::new();
+        };
+        let passives = gear_passives(rep, gear);
+        let base_dpc = score_gear(rep, gear, &passives, rep.extra_bonuses());
+
+        type SlotQualities = fn(&mut BuildConfig) -> &mut Vec<GearQuality>;
+        let groups: [(&[&'static str], SlotQualities); 3] = [
+            (&ARMOR_SLOT_NAMES, |g| &mut g.armor_quality),
+            (&JEWELRY_SLOT_NAMES, |g| &mut g.jewelry_quality),
+            (&WEAPON_SLOT_NAMES, |g| &mut g.weapon_quality),
+        ];
+
+        let mut upgrades: Vec<QualityUpgrade> = Vec::new();
+        for (names, qualities) in groups {
+            for (slot, &name) in names.iter().enumerate() {
+                let mut upgraded = gear.clone();
+                let slot_qualities = qualities(&mut upgraded);
+                let from = slot_qualities.get(slot).copied().unwrap_or_default();
+                if from == GearQuality::Legendary {
+                    continue;
+                }
+                slot_qualities[slot] = GearQuality::Legendary;
+                let dpc_gain =
+                    score_gear(rep, &upgraded, &passives, rep.extra_bonuses()) - base_dpc;
+                if dpc_gain <= 0.0 {
+                    continue;
+                }
+                upgrades.push(QualityUpgrade {
+                    slot: name,
+                    from,
+                    dpc_gain,
+                    gain_pct: if base_dpc > 0.0 {
+                        dpc_gain / base_dpc * 100.0
+                    } else {
+                        0.0
+                    },
+                });
+            }
+        }
+        upgrades.sort_by(|a, b| b.dpc_gain.partial_cmp(&a.dpc_gain).unwrap());
+        upgrades
+    }
+
+    /// DPC lost by moving 8, 16, ... 64 attribute points of `gear` into health,
+    /// taken from the larger of magicka and stamina first.
+    pub fn health_point_costs(builds: &[Build], gear: &BuildConfig) -> Vec<HealthPointCost> {
+        let Some(rep) = builds.first() else {
+            return Vec::new();
+        };
+        let passives = gear_passives(rep, gear);
+        let base_dpc = score_gear(rep, gear, &passives, rep.extra_bonuses());
+        let current = gear.attributes.unwrap_or_default();
+        let (magicka, stamina, _) = current.points();
+
+        (ATTRIBUTE_SPLIT_STEP..=magicka + stamina)
+            .step_by(ATTRIBUTE_SPLIT_STEP as usize)
+            .map(|points| {
+                let attributes = current.with_health_points(points);
+                let mut moved = gear.clone();
+                moved.attributes = Some(attributes);
+                let dpc_loss = base_dpc - score_gear(rep, &moved, &passives, rep.extra_bonuses());
+                HealthPointCost {
+                    points,
+                    attributes,
+                    dpc_loss,
+                    loss_pct: if base_dpc > 0.0 {
+                        dpc_loss / base_dpc * 100.0
+                    } else {
+                        0.0
+                    },
+                }
+            })
+            .collect()
+    }
+}
+
+/// Passive bonuses of `rep` with Twin Blade and Blunt applied per hand of the gear's dual wield bar.
+fn gear_passives(rep: &Build, gear: &BuildConfig) -> Vec<BonusData> {
+    match gear.first_dual_wield_hands(gear.bar1_weapon, gear.bar2_weapon) {
+        Some((main, off)) => with_twin_blade_and_blunt_hands(rep.passive_bonuses(), main, off),
+        None => rep.passive_bonuses().to_vec(),
+    }
+}
+
+/// Set the off-hand trait of `bar`. A bar1 slot filled only to reach bar2
+/// takes bar1's main-hand trait; it isn't read unless bar1 dual wields.
+fn set_off_hand_trait(gear: &mut BuildConfig, bar: usize, weapon_trait: WeaponTrait) {
+    if gear.off_hand_traits.len() <= bar {
+        let fill = gear
+            .weapon_traits
+            .first()
+            .copied()
+            .unwrap_or(WeaponTrait::Nirnhoned);
+        gear.off_hand_traits.resize(bar + 1, fill);
+    }
+    gear.off_hand_traits[bar] = weapon_trait;
+}
+
+/// Evaluate a BuildConfig by building a new Build from `rep`'s skills and returning DPC.
+fn score_gear(
+    rep: &Build,
+    gear: &BuildConfig,
+    passives: &[BonusData],
+    extra_bonuses: &[BonusData],
+) -> f64 {
+    let stats = gear.compute_stats();
+    let build = Build::new_with_extra(
+        rep.skills().to_vec(),
+        rep.cp_bonuses(),
+        passives,
+        &[], // no set bonuses during gear optimization
+        Vec::new(),
+        stats,
+        extra_bonuses,
+    );
+    build.total_damage_per_cast
+}
+
+/// Format ranked quality upgrades as a table with absolute and relative DPC gain.
+pub fn format_quality_upgrades(upgrades: &[QualityUpgrade]) -> String {
+    let data: Vec<Vec<String>> = upgrades
+        .iter()
+        .enumerate()
+        .map(|(i, u)| {
+            vec![
+                format!("{}", i + 1),
+                u.slot.to_string(),
+                u.from.to_string(),
+                format::format_number(u.dpc_gain.max(0.0) as u64),
+                format!("{:.2}%", u.gain_pct),
+            ]
+        })
+        .collect();
+    table::table(
+        &data,
+        table::TableOptions {
+            title: Some("Upgrade to Legendary (best first)".to_string()),
+            columns: vec![
+                table::ColumnDefinition::new("#", 3).align_right(),
+                table::ColumnDefinition::new("Slot", 14),
+                table::ColumnDefinition::new("Quality", 10),
+                table::ColumnDefinition::new("DPC Gain", 10).align_right(),
+                table::ColumnDefinition::new("Gain", 8).align_right(),
+            ],
+            footer: None,
+        },
+    )
+}
+
+/// Format the DPC cost of moving attribute points into health as a table.
+pub fn format_health_point_costs(costs: &[HealthPointCost]) -> String {
+    let data: Vec<Vec<String>> = costs
+        .iter()
+        .map(|c| {
+            vec![
+                format!("{}", c.points),
+                c.attributes.to_string(),
+                format::format_number(c.dpc_loss.max(0.0) as u64),
+                format!("{:.2}%", c.loss_pct),
+            ]
+        })
+        .collect();
+    table::table(
+        &data,
+        table::TableOptions {
+            title: Some("Cost of Attribute Points in Health".to_string()),
+            columns: vec![
+                table::ColumnDefinition::new("Health", 6).align_right(),
+                table::ColumnDefinition::new("Attributes", 12),
+                table::ColumnDefinition::new("DPC Loss", 10).align_right(),
+                table::ColumnDefinition::new("Loss", 8).align_right(),
+            ],
+            footer: None,
+        },
+    )
 }
 
 /// Format armor trait slice as compact string like "5×Divines,2×Infused".
@@ -384,11 +852,46 @@ pub fn format_jewelry_traits(traits: &[JewelryTrait]) -> String {
     format_trait_counts(traits)
 }
 
+/// Format armor glyph slice as compact string like "7×Stamina".
+pub fn format_armor_glyphs(glyphs: &[ArmorGlyph]) -> String {
+    format_trait_counts(glyphs)
+}
+
+/// Format jewelry glyph slice as compact string like "3×Physical Harm".
+pub fn format_jewelry_glyphs(glyphs: &[JewelryGlyph]) -> String {
+    format_trait_counts(glyphs)
+}
+
 /// Format weapon trait slice as compact string.
 pub fn format_weapon_traits(traits: &[WeaponTrait]) -> String {
     format_trait_counts(traits)
 }
 
+/// All per-slot arrays keeping the pinned prefix and filling free slots from `candidates`.
+fn slot_combinations<T: Copy, const N: usize>(
+    pinned: &[T],
+    candidates: &[T],
+    filler: T,
+) -> Vec<[T; N]> {
+    let mut base = [filler; N];
+    for (i, t) in pinned.iter().take(N).enumerate() {
+        base[i] = *t;
+    }
+    let free_slots = N.saturating_sub(pinned.len());
+    let total = candidates.len().pow(free_slots as u32);
+    (0..total)
+        .map(|i| {
+            let mut arr = base;
+            let mut idx = i;
+            for slot in arr.iter_mut().skip(pinned.len()) {
+                *slot = candidates[idx % candidates.len()];
+                idx /= candidates.len();
+            }
+            arr
+        })
+        .collect()
+}
+
 fn format_trait_counts<T: std::fmt::Display + Eq + std::hash::Hash + Copy>(traits: &[T]) -> String {
     // Preserve order of first appearance
     let mut seen: Vec<T> = Vec::new();
@@ -428,10 +931,151 @@ pub fn stats_differ_significantly(a: &CharacterStats, b: &CharacterStats, thresh
     check(
         a.max_magicka.max(a.max_stamina),
         b.max_magicka.max(b.max_stamina),
-    ) || check(
-        a.weapon_damage.max(a.spell_damage),
-        b.weapon_damage.max(b.spell_damage),
-    ) || check(a.critical_rating, b.critical_rating)
+    ) || check(a.max_health, b.max_health)
+        || check(
+            a.weapon_damage.max(a.spell_damage),
+            b.weapon_damage.max(b.spell_damage),
+        )
+        || check(
+            a.weapon_critical_rating_total(),
+            b.weapon_critical_rating_total(),
+        )
+        || check(
+            a.spell_critical_rating_total(),
+            b.spell_critical_rating_total(),
+        )
         || check(a.critical_damage, b.critical_damage)
         || check(a.penetration, b.penetration)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::skill_trees::weapon::dual_wield::dual_wield_passives::DUAL_WIELD_PASSIVES;
+    use crate::domain::{BonusTarget, ResolveContext, SkillData};
+
+    fn dual_wield_passives() -> Vec<BonusData> {
+        DUAL_WIELD_PASSIVES
+            .iter()
+            .flat_map(|p| p.bonuses.iter().cloned())
+            .collect()
+    }
+
+    fn bonus_total(bonuses: &[BonusData], target: BonusTarget) -> f64 {
+        let ctx = ResolveContext::new(CharacterStats::default());
+        bonuses
+            .iter()
+            .map(|b| b.resolve(&ctx))
+            .filter(|v| v.target == target)
+            .map(|v| v.value)
+            .sum()
+    }
+
+    #[test]
+    fn test_slot_combinations_keep_pinned_slots_and_mix_free_ones() {
+        let combos: Vec<[ArmorGlyph; 4]> =
+            slot_combinations(&[ArmorGlyph::Health], DPS_ARMOR_GLYPHS, ArmorGlyph::Stamina);
+
+        assert_eq!(combos.len(), DPS_ARMOR_GLYPHS.len().pow(3));
+        assert!(combos.iter().all(|c| c[0] == ArmorGlyph::Health));
+        for free in [ArmorGlyph::Magicka, ArmorGlyph::Stamina] {
+            for a in [ArmorGlyph::Magicka, ArmorGlyph::Stamina] {
+                for b in [ArmorGlyph::Magicka, ArmorGlyph::Stamina] {
+                    let expected = [ArmorGlyph::Health, free, a, b];
+                    assert!(combos.contains(&expected), "missing {:?}", expected);
+                }
+            }
+        }
+
+        let pinned = [ArmorGlyph::Magicka; 4];
+        let combos: Vec<[ArmorGlyph; 4]> =
+            slot_combinations(&pinned, DPS_ARMOR_GLYPHS, ArmorGlyph::Stamina);
+        assert_eq!(combos, vec![pinned]);
+    }
+
+    #[test]
+    fn test_mixed_pair_gets_half_of_each_twin_blade_and_blunt_bonus() {
+        let passives = dual_wield_passives();
+        let hands = |main, off| with_twin_blade_and_blunt_hands(&passives, main, off);
+        let daggers = hands(WeaponType::DualWieldDagger, WeaponType::DualWieldDagger);
+        let maces = hands(WeaponType::DualWieldMace, WeaponType::DualWieldMace);
+        let mixed = hands(WeaponType::DualWieldDagger, WeaponType::DualWieldMace);
+
+        let crit = BonusTarget::CriticalRating;
+        let pen = BonusTarget::PhysicalAndSpellPenetration;
+        assert_eq!(bonus_total(&mixed, crit), bonus_total(&daggers, crit) / 2.0);
+        assert_eq!(bonus_total(&mixed, pen), bonus_total(&maces, pen) / 2.0);
+        assert_eq!(bonus_total(&daggers, pen), 0.0);
+        assert_eq!(bonus_total(&maces, crit), 0.0);
+        // Other dual wield passives are kept
+        assert!(mixed.iter().any(|b| b.name == "Slaughter"));
+    }
+
+    #[test]
+    fn test_optimizer_picks_the_best_dual_wield_pair() {
+        let skills: Vec<&'static SkillData> = ["Flying Blade", "Rapid Strikes", "Steel Tornado"]
+            .iter()
+            .map(|name| SkillData::parse(name).unwrap())
+            .collect();
+        let rep = Build::new(
+            skills,
+            &[],
+            &dual_wield_passives(),
+            &[],
+            Vec::new(),
+            CharacterStats::default(),
+        );
+        let baseline = BuildConfig {
+            race: Some(DPS_RACES[0]),
+            mundus: Some(DPS_MUNDUS_STONES[0]),
+            food: Some(DPS_FOODS[0]),
+            attributes: Some(AttributeChoice::Stamina),
+            armor_traits: vec![ArmorTrait::Divines; 7],
+            jewelry_traits: vec![JewelryTrait::Bloodthirsty; 3],
+            weapon_traits: vec![WeaponTrait::Nirnhoned; 2],
+            armor_glyphs: vec![ArmorGlyph::Stamina; 7],
+            jewelry_glyphs: vec![JewelryGlyph::PhysicalHarm; 3],
+            bar1_enchant: Some(WeaponEnchant::Berserker),
+            bar2_enchant: Some(WeaponEnchant::Berserker),
+            bar1_off_hand_enchant: Some(WeaponEnchant::Flame),
+            bar2_off_hand_enchant: Some(WeaponEnchant::Flame),
+            bar1_poison: Some(DPS_POISONS[0]),
+            bar2_poison: Some(DPS_POISONS[0]),
+            ..BuildConfig::default()
+        };
+        let options = GearOptimizerOptions {
+            top_k: 2,
+            verbose: false,
+        };
+        let chosen =
+            GearOptimizer::optimize(std::slice::from_ref(&rep), &options, &baseline).build_config;
+        let (main, off) = (
+            chosen.bar1_weapon.expect("bar1 main hand chosen"),
+            chosen.bar1_off_hand.expect("bar1 off hand chosen"),
+        );
+
+        let pair_score = |main: WeaponType, off: WeaponType| {
+            let mut gear = chosen.clone();
+            gear.bar1_weapon = Some(main);
+            gear.bar1_off_hand = Some(off);
+            let passives = with_twin_blade_and_blunt_hands(rep.passive_bonuses(), main, off);
+            score_gear(&rep, &gear, &passives, rep.extra_bonuses())
+        };
+        let best = pair_score(main, off);
+        for &other_main in DUAL_WIELD_WEAPONS {
+            for &other_off in DUAL_WIELD_WEAPONS {
+                let score = pair_score(other_main, other_off);
+                assert!(
+                    score <= best + 1e-9,
+                    "{}+{} ({:.1}) beats chosen {}+{} ({:.1})",
+                    other_main,
+                    other_off,
+                    score,
+                    main,
+                    off,
+                    best
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,349 @@
+use crate::data::bonuses::GROUP_DUTIES;
+use crate::data::sets::ALL_SETS;
+use crate::domain::{BuildConfig, GroupConfig, GroupMember};
+use crate::infrastructure::{format, logger, table};
+use crate::services::{OptimizePipeline, OptimizePipelineOptions, OptimizePipelineResult};
+use std::collections::HashMap;
+use std::fmt;
+
+pub struct GroupOptimizerOptions {
+    pub group: GroupConfig,
+    /// Shared optimize options (fight target, trial, parallelism...). The baseline is
+    /// replaced by each player's own pins; its enemy and position apply to players
+    /// without their own.
+    pub pipeline: OptimizePipelineOptions,
+}
+
+pub struct GroupMemberResult {
+    pub name: String,
+    /// Group duties this player provides
+    pub duties: Vec<&'static str>,
+    pub result: OptimizePipelineResult,
+}
+
+impl GroupMemberResult {
+    pub fn dps(&self) -> f64 {
+        pipeline_dps(&self.result)
+    }
+}
+
+pub struct GroupOptimizerResult {
+    pub members: Vec<GroupMemberResult>,
+}
+
+impl GroupOptimizerResult {
+    pub fn total_dps(&self) -> f64 {
+        self.members.iter().map(|m| m.dps()).sum()
+    }
+
+    /// Optimized builds in the group file format.
+    pub fn group_config(&self) -> GroupConfig {
+        GroupConfig {
+            players: self
+                .members
+                .iter()
+                .map(|m| GroupMember {
+                    name: m.name.clone(),
+                    config: m.result.build_config.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for GroupOptimizerResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let data: Vec<Vec<String>> = self
+            .members
+            .iter()
+            .map(|m| {
+                vec![
+                    m.name.clone(),
+                    if m.duties.is_empty() {
+                        "-".to_string()
+                    } else {
+                        m.duties.join(", ")
+                    },
+                    m.result.build_config.sets.join(", "),
+                    format::format_number(m.dps() as u64),
+                ]
+            })
+            .collect();
+        let group_table = table::table(
+            &data,
+            table::TableOptions {
+                title: Some("Group Builds".to_string()),
+                columns: vec![
+                    table::ColumnDefinition::new("Player", 16),
+                    table::ColumnDefinition::new("Duties", 32),
+                    table::ColumnDefinition::new("Sets", 72),
+                    table::ColumnDefinition::new("DPS", 10).align_right(),
+                ],
+                footer: Some(std::format!(
+                    "Group DPS: {}",
+                    format::format_number(self.total_dps() as u64)
+                )),
+            },
+        );
+        write!(f, "{}", group_table)
+    }
+}
+
+/// Optimizes a DPS roster together. Each unique group buff (`GROUP_DUTIES`) is carried
+/// by exactly one player, picked so the group's total DPS is highest; nobody else wears
+/// a set that provides it.
+///
+/// Every player still receives the duties their teammates carry through the trial
+/// dummy buffs. Without them (`--no-trial`) those buffs are not modelled at all, so
+/// each player is scored as if the rest of the group provided none of the duties.
+pub struct GroupOptimizer;
+
+impl GroupOptimizer {
+    pub fn run(options: GroupOptimizerOptions) -> Result<GroupOptimizerResult, String> {
+        let members = &options.group.players;
+        if !options.pipeline.trial {
+            logger::warn(
+                "Group: trial buffs are off; duties carried by teammates are not modelled",
+            );
+        }
+
+        // Duties players already provide through their own pins
+        let mut providers: Vec<Option<usize>> = vec![None; GROUP_DUTIES.len()];
+        for (duty_idx, duty) in GROUP_DUTIES.iter().enumerate() {
+            let mut pinned = members
+                .iter()
+                .enumerate()
+                .filter(|(_, m)| duty.is_provided_by(&m.config));
+            providers[duty_idx] = pinned.next().map(|(idx, _)| idx);
+            if let Some((second, _)) = pinned.next() {
+                return Err(std::format!(
+                    "{} is provided by both {} and {}; unique buffs should come from one player",
+                    duty.name,
+                    members[providers[duty_idx].unwrap()].name,
+                    members[second].name
+                ));
+            }
+        }
+        let open_duties: Vec<usize> = (0..GROUP_DUTIES.len())
+            .filter(|&d| providers[d].is_none())
+            .collect();
+        let free_members: Vec<usize> = (0..members.len())
+            .filter(|m| !providers.contains(&Some(*m)))
+            .collect();
+        if open_duties.len() > free_members.len() {
+            return Err(std::format!(
+                "{} group duties need a provider but only {} players are free",
+                open_duties.len(),
+                free_members.len()
+            ));
+        }
+
+        // Check every open duty fits on someone before running any optimization
+        let fits = |member_idx: usize, duty_idx: Option<usize>| match duty_idx {
+            Some(d) => GROUP_DUTIES[d]
+                .assign_to(&members[member_idx].config)
+                .map(|_| 0.0),
+            None => Some(0.0),
+        };
+        if best_assignment(&open_duties, &free_members, &mut Vec::new(), &fits).is_none() {
+            let names: Vec<&str> = open_duties.iter().map(|&d| GROUP_DUTIES[d].name).collect();
+            return Err(std::format!(
+                "No assignment fits {} on separate players; leave a set slot or weapon bar unpinned",
+                names.join(", ")
+            ));
+        }
+
+        // Optimize every free player with each duty they could carry, and without one
+        // when there are more free players than duties
+        let mut runs: HashMap<(usize, Option<usize>), OptimizePipelineResult> = HashMap::new();
+        for &member_idx in &free_members {
+            let member = &members[member_idx];
+            if free_members.len() > open_duties.len() {
+                logger::info(&std::format!(
+                    "Group: optimizing {} (no duty)...",
+                    member.name
+                ));
+                let result = Self::optimize_member(&options, &member.config, &[]);
+                runs.insert((member_idx, None), result);
+            }
+            for &duty_idx in &open_duties {
+                let duty = &GROUP_DUTIES[duty_idx];
+                if let Some(config) = duty.assign_to(&member.config) {
+                    logger::info(&std::format!(
+                        "Group: optimizing {} providing {}...",
+                        member.name,
+                        duty.name
+                    ));
+                    let result = Self::optimize_member(&options, &config, &[duty_idx]);
+                    runs.insert((member_idx, Some(duty_idx)), result);
+                }
+            }
+        }
+
+        let score = |member_idx: usize, duty_idx: Option<usize>| {
+            runs.get(&(member_idx, duty_idx)).map(pipeline_dps)
+        };
+        let (_, assignment) = best_assignment(&open_duties, &free_members, &mut Vec::new(), &score)
+            .expect("a fitting assignment exists");
+
+        let mut results = Vec::with_capacity(members.len());
+        for (member_idx, member) in members.iter().enumerate() {
+            let duties = member_duties(member_idx, &providers, &assignment);
+            let result = if providers.contains(&Some(member_idx)) {
+                logger::info(&std::format!("Group: optimizing {}...", member.name));
+                Self::optimize_member(&options, &member.config, &duties)
+            } else {
+                runs.remove(&(member_idx, duties.first().copied()))
+                    .expect("assigned run exists")
+            };
+            results.push(GroupMemberResult {
+                name: member.name.clone(),
+                duties: duties.iter().map(|&d| GROUP_DUTIES[d].name).collect(),
+                result,
+            });
+        }
+
+        Ok(GroupOptimizerResult { members: results })
+    }
+
+    /// Run the optimize pipeline for one player. Sets providing duties other than
+    /// `own_duties` are excluded so no unique buff is duplicated.
+    fn optimize_member(
+        options: &GroupOptimizerOptions,
+        config: &BuildConfig,
+        own_duties: &[usize],
+    ) -> OptimizePipelineResult {
+        let mut pipeline = options.pipeline.clone();
+        let enemy = config
+            .enemy
+            .clone()
+            .or_else(|| pipeline.baseline.enemy.clone());
+        let position = config.position.or(pipeline.baseline.position);
+        pipeline.baseline = BuildConfig {
+            enemy,
+            position,
+            ..config.clone()
+        };
+        pipeline.excluded_sets = ALL_SETS
+            .iter()
+            .filter(|set| {
+                GROUP_DUTIES
+                    .iter()
+                    .enumerate()
+                    .any(|(d, duty)| !own_duties.contains(&d) && duty.is_provided_by_set(&set.name))
+            })
+            .map(|set| set.name.clone())
+            .collect();
+        OptimizePipeline::run(pipeline)
+    }
+}
+
+fn pipeline_dps(result: &OptimizePipelineResult) -> f64 {
+    result.simulation.as_ref().map_or(0.0, |s| s.result.dps)
+}
+
+/// Duties `member` carries: every duty its own pins provide, else the one it was assigned.
+fn member_duties(
+    member: usize,
+    providers: &[Option<usize>],
+    assignment: &[(usize, usize)],
+) -> Vec<usize> {
+    let own: Vec<usize> = (0..providers.len())
+        .filter(|&d| providers[d] == Some(member))
+        .collect();
+    if !own.is_empty() {
+        return own;
+    }
+    assignment
+        .iter()
+        .filter(|(m, _)| *m == member)
+        .map(|(_, d)| *d)
+        .collect()
+}
+
+/// Highest total DPS assignment of `duties` to distinct `members` (one duty each).
+/// Members left without a duty score their duty-free run.
+fn best_assignment(
+    duties: &[usize],
+    members: &[usize],
+    used: &mut Vec<usize>,
+    score: &dyn Fn(usize, Option<usize>) -> Option<f64>,
+) -> Option<(f64, Vec<(usize, usize)>)> {
+    let (&duty, rest) = match duties.split_first() {
+        Some(split) => split,
+        None => {
+            let total = members
+                .iter()
+                .filter(|m| !used.contains(m))
+                .map(|&m| score(m, None))
+                .sum::<Option<f64>>()?;
+            return Some((total, Vec::new()));
+        }
+    };
+
+    let mut best: Option<(f64, Vec<(usize, usize)>)> = None;
+    for &member in members {
+        if used.contains(&member) {
+            continue;
+        }
+        let dps = match score(member, Some(duty)) {
+            Some(dps) => dps,
+            None => continue,
+        };
+        used.push(member);
+        if let Some((total, mut pairs)) = best_assignment(rest, members, used, score) {
+            let total = total + dps;
+            if best
+                .as_ref()
+                .is_none_or(|(best_total, _)| total > *best_total)
+            {
+                pairs.push((member, duty));
+                best = Some((total, pairs));
+            }
+        }
+        used.pop();
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_best_assignment_maximizes_total() {
+        // Member 0 loses little carrying duty 0, member 1 loses little carrying duty 1
+        let dps = |member: usize, duty: Option<usize>| match (member, duty) {
+            (0, None) => Some(100.0),
+            (0, Some(0)) => Some(95.0),
+            (0, Some(1)) => Some(80.0),
+            (1, None) => Some(100.0),
+            (1, Some(0)) => Some(70.0),
+            (1, Some(1)) => Some(98.0),
+            (2, None) => Some(100.0),
+            (2, Some(_)) => None, // no room
+            _ => None,
+        };
+        let (total, mut pairs) =
+            best_assignment(&[0, 1], &[0, 1, 2], &mut Vec::new(), &dps).expect("assignment exists");
+        pairs.sort();
+        assert_eq!(pairs, vec![(0, 0), (1, 1)]);
+        assert_eq!(total, 95.0 + 98.0 + 100.0);
+    }
+
+    #[test]
+    fn test_best_assignment_needs_a_provider() {
+        let dps = |_: usize, duty: Option<usize>| if duty.is_some() { None } else { Some(1.0) };
+        assert!(best_assignment(&[0], &[0, 1], &mut Vec::new(), &dps).is_none());
+    }
+
+    #[test]
+    fn test_member_duties_lists_every_pinned_duty() {
+        // Member 0 pins duties 0 and 2, member 1 is assigned duty 1, member 2 carries none
+        let providers = [Some(0), None, Some(0)];
+        let assignment = [(1, 1)];
+        assert_eq!(member_duties(0, &providers, &assignment), vec![0, 2]);
+        assert_eq!(member_duties(1, &providers, &assignment), vec![1]);
+        assert!(member_duties(2, &providers, &assignment).is_empty());
+    }
+}
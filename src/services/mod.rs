@@ -3,6 +3,7 @@ pub mod breakpoints_pipeline;
 pub mod build_optimizer;
 pub mod fight_simulator;
 pub mod gear_optimizer;
+pub mod group_optimizer;
 pub mod optimize_pipeline;
 pub mod passives_service;
 pub mod set_optimizer;
@@ -10,13 +11,20 @@ pub mod sets_service;
 pub mod simulate_pipeline;
 pub mod skills_service;
 
-pub use bar_distribution::{generate_distributions, infer_weapons, BarDistribution};
+pub use bar_distribution::{
+    generate_distributions, generate_single_bar_distribution, infer_weapons, BarDistribution,
+};
 pub use breakpoints_pipeline::BreakpointsPipeline;
 pub use build_optimizer::{BuildOptimizer, BuildOptimizerOptions};
 pub use fight_simulator::{FightSimulator, DEFAULT_AVG_RESOURCE_PCT};
 pub use gear_optimizer::{
-    format_armor_traits, format_jewelry_traits, format_weapon_traits, stats_differ_significantly,
-    GearOptimizer, GearOptimizerOptions,
+    format_armor_glyphs, format_armor_traits, format_health_point_costs, format_jewelry_glyphs,
+    format_jewelry_traits, format_quality_upgrades, format_weapon_traits,
+    stats_differ_significantly, GearOptimizer, GearOptimizerOptions, HealthPointCost,
+    QualityUpgrade,
+};
+pub use group_optimizer::{
+    GroupMemberResult, GroupOptimizer, GroupOptimizerOptions, GroupOptimizerResult,
 };
 pub use optimize_pipeline::{OptimizePipeline, OptimizePipelineOptions, OptimizePipelineResult};
 pub use passives_service::{PassivesService, PassivesServiceOptions};
@@ -1,21 +1,29 @@
-use crate::data::bonuses::{TRIAL_BUFF_NAMES, TRIAL_DUMMY_BUFFS};
+use crate::data::bonuses::{
+    with_oakensoul_buffs, OAKENSOUL_BUFFS, OAKENSOUL_RING, TRIAL_BUFF_NAMES, TRIAL_DUMMY_BUFFS,
+};
 use crate::data::sets::ALL_SETS;
 use crate::data::skill_trees::armor::armor_passives;
 use crate::data::skill_trees::guild::undaunted::undaunted_passives::undaunted_mettle_bonuses;
+use crate::data::skill_trees::weapon::dual_wield::dual_wield_passives::with_twin_blade_and_blunt_hands;
 use crate::domain::{
     ArmorDistribution, ArmorWeight, BonusData, Build, BuildConfig, BuildMetadata, CharacterStats,
-    SkillTree, Potion, SetData, SetProcEffect, SimulationResult, SkillData, WeaponEnchant,
+    DotSnapshot, Encounter, EncounterPhase, Enemy, FightTarget, Opener, SkillTree, Potion, SetData, SetProcEffect, SetType,
+    SimulationResult, SkillData, SynergyProfile, WeaponEnchant, DPS_POTIONS, DPS_WEAPON_ENCHANTS,
+    SINGLE_BAR_CONSTRAINTS,
 };
 use crate::infrastructure::{format, logger};
 use crate::services::{
-    format_armor_traits, format_jewelry_traits, generate_distributions, infer_weapons,
-    stats_differ_significantly, BarDistribution, BuildOptimizer, BuildOptimizerOptions,
-    FightSimulator, GearOptimizer, GearOptimizerOptions, SetOptimizer, SetOptimizerOptions,
+    format_armor_glyphs, format_armor_traits, format_health_point_costs, format_jewelry_glyphs,
+    format_jewelry_traits, format_quality_upgrades, generate_distributions,
+    generate_single_bar_distribution, infer_weapons, stats_differ_significantly, BarDistribution,
+    BuildOptimizer, BuildOptimizerOptions, FightSimulator, GearOptimizer, GearOptimizerOptions,
+    SetOptimizer, SetOptimizerOptions,
 };
 use rayon::prelude::*;
 use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::time::Instant;
 
+#[derive(Clone)]
 pub struct OptimizePipelineOptions {
     pub verbose: bool,
     pub pure: bool,
@@ -24,7 +32,37 @@ pub struct OptimizePipelineOptions {
     pub baseline: BuildConfig,
     pub trial: bool,
     pub avg_resource_pct: f64,
+    /// Encounter length the simulation phase optimizes for
+    pub fight_target: FightTarget,
+    /// Boss fight script the simulation phase plays out instead of the fight target
+    pub encounter: Option<Encounter>,
+    /// Extra downtime windows (immune, movement, bar-locked) for the simulation phase
+    pub downtime: Vec<EncounterPhase>,
+    /// Synergies the rest of the group offers during the simulation phase
+    pub synergies: SynergyProfile,
+    /// Pre-pull opener; custom skills not slotted in a candidate build are skipped
+    pub opener: Opener,
+    /// DoT parts locked in at cast time for skills without their own setting
+    pub dot_snapshot: DotSnapshot,
     pub required_weapon_skill_lines: Vec<crate::domain::SkillLineName>,
+    /// Rank which non-Legendary slots are worth upgrading to gold first
+    pub budget: bool,
+    /// Report the DPC cost of moving attribute points into health
+    pub health_cost: bool,
+    /// Also optimize a one-bar Oakensoul build and keep whichever simulates higher
+    pub oakensoul: bool,
+    /// Sets the set optimizer may not pick
+    pub excluded_sets: Vec<String>,
+}
+
+impl OptimizePipelineOptions {
+    /// Enemy the build is optimized against (`--enemy`, else the trial or target dummy).
+    pub fn enemy(&self) -> Enemy {
+        self.baseline
+            .enemy
+            .clone()
+            .unwrap_or_else(|| Enemy::default_for(self.trial))
+    }
 }
 
 /// Result of the optimization pipeline. Serializes to the same JSON shape as BuildConfig.
@@ -44,6 +82,48 @@ pub struct OptimizePipeline;
 
 impl OptimizePipeline {
     pub fn run(options: OptimizePipelineOptions) -> OptimizePipelineResult {
+        if !options.oakensoul || options.baseline.single_bar {
+            return Self::run_build(&options);
+        }
+        if let Some(reason) = single_bar_conflict(&options) {
+            logger::warn(&std::format!(
+                "Skipping the one-bar Oakensoul comparison: {}",
+                reason
+            ));
+            return Self::run_build(&options);
+        }
+
+        logger::info("Optimizing a regular two-bar build...");
+        let two_bar = Self::run_build(&options);
+
+        logger::info("Optimizing a one-bar Oakensoul build...");
+        let mut one_bar_options = options.clone();
+        let baseline = &mut one_bar_options.baseline;
+        baseline.single_bar = true;
+        baseline.bar2_weapon = None;
+        baseline.sets.push(OAKENSOUL_RING.to_string());
+        let one_bar = Self::run_build(&one_bar_options);
+
+        let dps = |r: &OptimizePipelineResult| r.simulation.as_ref().map_or(0.0, |s| s.result.dps);
+        let (two_bar_dps, one_bar_dps) = (dps(&two_bar), dps(&one_bar));
+        logger::success(&std::format!(
+            "Two-bar DPS: {} | One-bar Oakensoul DPS: {} -> keeping the {} build",
+            format::format_number(two_bar_dps as u64),
+            format::format_number(one_bar_dps as u64),
+            if one_bar_dps > two_bar_dps {
+                "one-bar"
+            } else {
+                "two-bar"
+            }
+        ));
+        if one_bar_dps > two_bar_dps {
+            one_bar
+        } else {
+            two_bar
+        }
+    }
+
+    fn run_build(options: &OptimizePipelineOptions) -> OptimizePipelineResult {
         let character_stats = options.baseline.compute_stats();
         let baseline_stats = character_stats.clone();
 
@@ -88,14 +168,22 @@ impl OptimizePipeline {
         };
 
         // Resolve pinned set bonuses for Phase 0
-        let (set_bonuses, set_names, _set_proc_effects) = resolve_set_bonuses(&pinned_sets);
+        let set_bonus_scale = options.baseline.set_bonus_scale();
+        let (set_bonuses, set_names, _set_proc_effects) =
+            resolve_set_bonuses(&pinned_sets, set_bonus_scale);
 
-        // Resolve trial dummy buffs
-        let extra_bonuses = if options.trial {
+        // Resolve trial dummy buffs and the enemy's armor debuffs
+        let mut extra_bonuses = if options.trial {
             TRIAL_DUMMY_BUFFS.clone()
         } else {
             Vec::new()
         };
+        extra_bonuses.extend(options.enemy().armor_debuff_bonuses());
+
+        // A one-bar build wearing Oakensoul keeps its buff package up permanently
+        if wears_oakensoul(&options.baseline, &set_names) {
+            extra_bonuses = with_oakensoul_buffs(&extra_bonuses);
+        }
 
         // Resolve armor passives and potion bonuses
         let completions = options.baseline.armor.completions();
@@ -146,6 +234,7 @@ impl OptimizePipeline {
             set_names,
             extra_bonuses: extra_bonuses.clone(),
             armor_passive_bonuses: armor_passive_bonuses.clone(),
+            single_bar: options.baseline.single_bar,
         });
 
         let start = Instant::now();
@@ -227,14 +316,20 @@ impl OptimizePipeline {
 
             let g = &result.build_config;
             logger::success(&std::format!(
-                "Best gear: Race={}, Mundus={}, Food={}, Armor={}, Jewelry={}, Weapon={}, Attributes={}",
+                "Best gear: Race={}, Mundus={}, Food={}, Armor={} ({}), Jewelry={} ({}), Weapon={}, Attributes={}, Enchants={}/{}, Poisons={}/{}",
                 g.race.map_or("None".to_string(), |r| r.to_string()),
                 g.mundus.map_or("None".to_string(), |m| m.to_string()),
                 g.food.map_or("None".to_string(), |f| f.to_string()),
                 format_armor_traits(&g.armor_traits),
+                format_armor_glyphs(&g.armor_glyphs),
                 format_jewelry_traits(&g.jewelry_traits),
+                format_jewelry_glyphs(&g.jewelry_glyphs),
                 g.weapon_traits.first().map_or("None".to_string(), |t| t.to_string()),
                 g.attributes.map_or("None".to_string(), |a| a.to_string()),
+                g.bar1_enchant.map_or("None".to_string(), |e| e.to_string()),
+                g.bar2_enchant.map_or("None".to_string(), |e| e.to_string()),
+                g.bar1_poison.map_or("None".to_string(), |p| p.to_string()),
+                g.bar2_poison.map_or("None".to_string(), |p| p.to_string()),
             ));
             logger::info(&std::format!("Phase 1 completed in {:.2?}", gear_elapsed));
             Some(result)
@@ -247,7 +342,8 @@ impl OptimizePipeline {
             if stats_differ_significantly(&baseline_stats, &new_stats, 0.05) {
                 logger::info("Phase 2: Gear stats changed >5%, re-running build optimizer...");
 
-                let (set_bonuses, set_names, _set_proc_effects) = resolve_set_bonuses(&pinned_sets);
+                let (set_bonuses, set_names, _set_proc_effects) =
+                    resolve_set_bonuses(&pinned_sets, set_bonus_scale);
                 let rerun_optimizer = BuildOptimizer::new(BuildOptimizerOptions {
                     character_stats: new_stats,
                     verbose: options.verbose,
@@ -262,6 +358,7 @@ impl OptimizePipeline {
                     set_names,
                     extra_bonuses: extra_bonuses.clone(),
                     armor_passive_bonuses: armor_passive_bonuses.clone(),
+                    single_bar: options.baseline.single_bar,
                 });
 
                 let rerun_start = Instant::now();
@@ -293,14 +390,27 @@ impl OptimizePipeline {
                 pinned_mythic: pinned_mythic_vec.into_iter().next(),
                 parallelism: options.parallelism,
                 verbose: options.verbose,
+                set_bonus_scale,
+                excluded: options.excluded_sets.clone(),
             },
         );
+        // Twin Blade and Blunt applies per hand of the chosen dual wield pair
+        let dual_wield_hands = winning_gear.as_ref().and_then(|g| {
+            let gear = &g.build_config;
+            gear.first_dual_wield_hands(gear.bar1_weapon, gear.bar2_weapon)
+        });
         let builds = if let Some(result) = set_result {
             let source = &builds[result.build_idx];
+            let passives = match dual_wield_hands {
+                Some((main, off)) => {
+                    with_twin_blade_and_blunt_hands(source.passive_bonuses(), main, off)
+                }
+                None => source.passive_bonuses().to_vec(),
+            };
             let best_with_sets = Build::new_with_extra(
                 source.skills().to_vec(),
                 source.cp_bonuses(),
-                source.passive_bonuses(),
+                &passives,
                 &result.set_bonuses,
                 result.set_names,
                 source.character_stats().clone(),
@@ -314,30 +424,52 @@ impl OptimizePipeline {
         };
 
         // ── Phase 4: Fight Simulation ──
-        let sim_result = run_simulation(&options, &builds);
+        // Enchants and poisons picked by the gear phase (or pinned) seed the simulation
+        let sim_gear = winning_gear
+            .as_ref()
+            .map(|g| &g.build_config)
+            .unwrap_or(&options.baseline);
+        let sim_result = run_simulation(options, &builds, sim_gear);
+
+        // Base weapon damage needs a bar1 weapon; fall back to the one the skills imply
+        let mut report_gear = sim_gear.clone();
+        if report_gear.bar1_weapon.is_none() {
+            report_gear.bar1_weapon = infer_weapons(builds[0].skills()).ok().map(|(w1, _)| w1);
+        }
+
+        if options.budget {
+            let upgrades = GearOptimizer::rank_quality_upgrades(&builds, &report_gear);
+            if upgrades.is_empty() {
+                logger::info("Budget: no slot gains DPC from a Legendary upgrade.");
+            } else {
+                logger::info(&format_quality_upgrades(&upgrades));
+            }
+        }
+
+        if options.health_cost {
+            let costs = GearOptimizer::health_point_costs(&builds, &report_gear);
+            if costs.is_empty() {
+                logger::info("Health cost: no magicka or stamina attribute points to move.");
+            } else {
+                logger::info(&format_health_point_costs(&costs));
+            }
+        }
 
         let best_build = &builds[0];
         let export_build = sim_result
             .as_ref()
-            .map(|(build_idx, _, _, _, _, _)| &builds[*build_idx])
+            .map(|o| &builds[o.build_idx])
             .unwrap_or(best_build);
-        let sim_data = sim_result
-            .as_ref()
-            .map(|(_, dist, result, _, _, _)| (dist, result));
-        let buffed_stats = sim_result.as_ref().map(|(_, _, _, _, _, stats)| stats);
+        let sim_data = sim_result.as_ref().map(|o| (&o.distribution, &o.result));
+        let buffed_stats = sim_result.as_ref().map(|o| &o.buffed_stats);
 
-        let (winning_bar1, winning_bar2) = sim_result
+        let (winning_bar1, winning_bar2, winning_potion) = sim_result
             .as_ref()
-            .map(|(_, _, _, e1, e2, _)| (*e1, *e2))
+            .map(|o| (o.bar1_enchant, o.bar2_enchant, o.potion))
             .unwrap_or((
-                options
-                    .baseline
-                    .bar1_enchant
-                    .unwrap_or(WeaponEnchant::Flame),
-                options
-                    .baseline
-                    .bar2_enchant
-                    .unwrap_or(WeaponEnchant::Flame),
+                sim_gear.bar1_enchant.unwrap_or(WeaponEnchant::Flame),
+                sim_gear.bar2_enchant.unwrap_or(WeaponEnchant::Flame),
+                potion,
             ));
 
         let winning_build_config = winning_gear.as_ref().map(|g| &g.build_config);
@@ -371,8 +503,11 @@ impl OptimizePipeline {
                 .map(|(name, _)| name.clone())
                 .collect(),
             classes: options.baseline.classes.clone(),
-            bar1_weapon: options.baseline.bar1_weapon,
-            bar2_weapon: options.baseline.bar2_weapon,
+            bar1_weapon: sim_gear.bar1_weapon,
+            bar2_weapon: sim_gear.bar2_weapon,
+            bar1_off_hand: sim_gear.bar1_off_hand,
+            bar2_off_hand: sim_gear.bar2_off_hand,
+            single_bar: options.baseline.single_bar,
             character_stats: export_build.character_stats().clone(),
             race: winning_build_config.and_then(|g| g.race),
             mundus: winning_build_config.and_then(|g| g.mundus),
@@ -386,26 +521,40 @@ impl OptimizePipeline {
             weapon_traits: winning_build_config
                 .map(|g| g.weapon_traits.clone())
                 .unwrap_or_else(|| options.baseline.weapon_traits.clone()),
+            off_hand_traits: sim_gear.off_hand_traits.clone(),
+            armor_glyphs: winning_build_config
+                .map(|g| g.armor_glyphs.clone())
+                .unwrap_or_else(|| options.baseline.armor_glyphs.clone()),
+            jewelry_glyphs: winning_build_config
+                .map(|g| g.jewelry_glyphs.clone())
+                .unwrap_or_else(|| options.baseline.jewelry_glyphs.clone()),
             bar1_enchant: Some(winning_bar1),
             bar2_enchant: Some(winning_bar2),
+            bar1_off_hand_enchant: sim_gear.bar1_off_hand_enchant,
+            bar2_off_hand_enchant: sim_gear.bar2_off_hand_enchant,
+            bar1_poison: sim_gear.bar1_poison,
+            bar2_poison: sim_gear.bar2_poison,
+            armor_quality: options.baseline.armor_quality.clone(),
+            jewelry_quality: options.baseline.jewelry_quality.clone(),
+            weapon_quality: options.baseline.weapon_quality.clone(),
+            gear_level: options.baseline.gear_level,
             armor: winning_armor,
-            potion: Some(potion),
+            potion: Some(winning_potion),
             attributes: winning_build_config
                 .and_then(|g| g.attributes)
                 .or(options.baseline.attributes),
+            enemy: options.baseline.enemy.clone(),
+            position: options.baseline.position,
             metadata,
         };
 
         // Build simulation summary for display
-        let simulation =
-            sim_result.map(
-                |(best_build_idx, best_dist, result, _, _, _)| SimulationSummary {
-                    bar_distribution: best_dist,
-                    result,
-                    distributions_tested: 0, // set below if needed
-                    set_names: builds[best_build_idx].set_names().to_vec(),
-                },
-            );
+        let simulation = sim_result.map(|o| SimulationSummary {
+            bar_distribution: o.distribution,
+            result: o.result,
+            distributions_tested: 0, // set below if needed
+            set_names: builds[o.build_idx].set_names().to_vec(),
+        });
 
         OptimizePipelineResult {
             build_config,
@@ -414,36 +563,139 @@ impl OptimizePipeline {
     }
 }
 
+/// Whether a one-bar build wears the Oakensoul Ring (by resolved set names).
+fn wears_oakensoul(config: &BuildConfig, set_names: &[(String, u8)]) -> bool {
+    config.single_bar && set_names.iter().any(|(name, _)| name == OAKENSOUL_RING)
+}
+
+/// Why the baseline cannot also be optimized as a one-bar Oakensoul build, if it can't.
+fn single_bar_conflict(options: &OptimizePipelineOptions) -> Option<String> {
+    let baseline = &options.baseline;
+    if baseline.skills.len() > SINGLE_BAR_CONSTRAINTS.skill_count {
+        return Some(std::format!(
+            "{} required skills do not fit on one bar of {}",
+            baseline.skills.len(),
+            SINGLE_BAR_CONSTRAINTS.skill_count
+        ));
+    }
+    let weapon_lines: std::collections::HashSet<_> = [baseline.bar1_weapon, baseline.bar2_weapon]
+        .iter()
+        .filter_map(|w| w.map(|wt| wt.skill_line()))
+        .chain(options.required_weapon_skill_lines.iter().copied())
+        .collect();
+    if weapon_lines.len() > SINGLE_BAR_CONSTRAINTS.weapon_skill_line_count {
+        return Some("two different weapon skill lines are required".to_string());
+    }
+    let pinned_mythic = baseline.sets.iter().find(|name| {
+        SetData::parse(name)
+            .is_ok_and(|s| s.set_type == SetType::Mythic && s.name != OAKENSOUL_RING)
+    });
+    pinned_mythic.map(|name| std::format!("mythic '{}' is pinned", name))
+}
+
 pub(crate) fn resolve_set_bonuses(
     sets: &[&'static SetData],
+    set_bonus_scale: f64,
 ) -> (Vec<BonusData>, Vec<(String, u8)>, Vec<SetProcEffect>) {
     let mut set_bonuses: Vec<BonusData> = Vec::new();
     let mut set_names: Vec<(String, u8)> = Vec::new();
     let mut set_proc_effects: Vec<SetProcEffect> = Vec::new();
     for set in sets {
         let piece_count = set.set_type.max_pieces();
-        let bonuses = set.bonuses_at(piece_count);
-        set_bonuses.extend(bonuses.into_iter().cloned());
+        set_bonuses.extend(set.scaled_bonuses_at(piece_count, set_bonus_scale));
         set_proc_effects.extend(set.proc_effects_at(piece_count).into_iter().cloned());
         set_names.push((set.name.clone(), piece_count));
     }
     (set_bonuses, set_names, set_proc_effects)
 }
 
+/// Outcome of the fight simulation phase (Phase 4).
+struct SimulationOutcome {
+    build_idx: usize,
+    distribution: BarDistribution,
+    result: SimulationResult,
+    bar1_enchant: WeaponEnchant,
+    bar2_enchant: WeaponEnchant,
+    potion: Potion,
+    buffed_stats: CharacterStats,
+}
+
+/// Re-create a build without static potion bonuses.
+/// The simulator drinks the potion on cooldown, so the static optimizer-phase
+/// approximation must not be counted twice.
+fn without_potion_bonuses(build: &Build) -> Build {
+    let potion_bonuses: Vec<BonusData> = DPS_POTIONS.iter().flat_map(|p| p.bonuses()).collect();
+    build.without_bonuses(&potion_bonuses)
+}
+
+/// Create a fight simulator for a build (already stripped of potion bonuses).
+/// Poisons and off-hand enchants come from `gear`.
+fn create_simulator(
+    options: &OptimizePipelineOptions,
+    build: &Build,
+    bar1_enchant: WeaponEnchant,
+    bar2_enchant: WeaponEnchant,
+    potion: Potion,
+    gear: &BuildConfig,
+) -> FightSimulator {
+    let mut suppressed = if options.trial {
+        TRIAL_BUFF_NAMES.clone()
+    } else {
+        std::collections::HashSet::new()
+    };
+    suppressed.extend(options.enemy().armor_debuff_names());
+    if wears_oakensoul(gear, build.set_names()) {
+        suppressed.extend(OAKENSOUL_BUFFS.iter().map(|b| b.name.clone()));
+    }
+    let proc_effects: Vec<SetProcEffect> = build
+        .set_names()
+        .iter()
+        .flat_map(|(name, _)| {
+            ALL_SETS
+                .iter()
+                .filter(move |s| s.name == *name)
+                .flat_map(|s| {
+                    s.proc_effects_at(s.set_type.max_pieces())
+                        .into_iter()
+                        .cloned()
+                })
+        })
+        .collect();
+    FightSimulator::new(
+        build.effective_stats(),
+        build.resolved_bonuses(),
+        suppressed,
+    )
+    .with_enchants(Some(bar1_enchant), Some(bar2_enchant))
+    .with_off_hand_enchants(gear.bar1_off_hand_enchant, gear.bar2_off_hand_enchant)
+    .with_weapon_traits(
+        gear.weapon_traits.first().copied(),
+        gear.weapon_traits.get(1).copied(),
+    )
+    .with_off_hand_traits(
+        gear.off_hand_traits.first().copied(),
+        gear.off_hand_traits.get(1).copied(),
+    )
+    .with_poisons(gear.bar1_poison, gear.bar2_poison)
+    .with_potion(Some(potion))
+    .with_set_procs(proc_effects)
+    .with_avg_resource_pct(options.avg_resource_pct)
+    .with_fight_target(options.fight_target)
+    .with_encounter(options.encounter.as_ref())
+    .with_downtime(options.downtime.clone())
+    .with_synergies(options.synergies)
+    .with_opener(options.opener.clone())
+    .with_dot_snapshot(options.dot_snapshot)
+}
+
 fn run_simulation(
     options: &OptimizePipelineOptions,
     builds: &[Build],
-) -> Option<(
-    usize,
-    BarDistribution,
-    SimulationResult,
-    WeaponEnchant,
-    WeaponEnchant,
-    CharacterStats,
-)> {
-    // Derive bar weapons from options, or infer from skills
-    let pinned_bar1 = options.baseline.bar1_weapon;
-    let pinned_bar2 = options.baseline.bar2_weapon;
+    gear: &BuildConfig,
+) -> Option<SimulationOutcome> {
+    // Derive bar weapons from the gear (pinned or chosen dual wield pair), or infer from skills
+    let pinned_bar1 = gear.bar1_weapon;
+    let pinned_bar2 = gear.bar2_weapon;
 
     let inferred = {
         let top_skills = builds[0].skills();
@@ -478,57 +730,51 @@ fn run_simulation(
         },
     };
 
-    logger::info(&std::format!(
-        "Phase 4: Running fight simulation on top {} candidates (Bar1: {}, Bar2: {})...",
-        builds.len(),
-        bar1_weapon,
-        bar2_weapon
-    ));
+    if gear.single_bar {
+        logger::info(&std::format!(
+            "Phase 4: Running fight simulation on top {} candidates (one bar: {})...",
+            builds.len(),
+            bar1_weapon
+        ));
+    } else {
+        logger::info(&std::format!(
+            "Phase 4: Running fight simulation on top {} candidates (Bar1: {}, Bar2: {})...",
+            builds.len(),
+            bar1_weapon,
+            bar2_weapon
+        ));
+    }
 
     let sim_start = Instant::now();
 
+    let default_bar1 = gear.bar1_enchant.unwrap_or(WeaponEnchant::Flame);
+    let default_bar2 = gear.bar2_enchant.unwrap_or(WeaponEnchant::Flame);
+    let default_potion = options.baseline.potion.unwrap_or(Potion::WeaponPower);
+
+    // Builds as the simulator sees them (potion modeled as a timed consumable)
+    let sim_builds: Vec<Build> = builds.iter().map(without_potion_bonuses).collect();
+
     // Pre-compute work items
-    let work: Vec<(usize, FightSimulator, Vec<BarDistribution>)> = builds
+    let work: Vec<(usize, FightSimulator, Vec<BarDistribution>)> = sim_builds
         .iter()
         .enumerate()
         .filter_map(|(build_idx, build)| {
-            let distributions = generate_distributions(build.skills(), bar1_weapon, bar2_weapon);
-            if distributions.is_empty() {
-                return None;
-            }
-            let mut suppressed = if options.trial {
-                TRIAL_BUFF_NAMES.clone()
+            let distributions = if gear.single_bar {
+                generate_single_bar_distribution(build.skills(), bar1_weapon)
             } else {
-                std::collections::HashSet::new()
+                generate_distributions(build.skills(), bar1_weapon, bar2_weapon)
             };
-            let potion = options.baseline.potion.unwrap_or(Potion::WeaponPower);
-            for bonus in potion.bonuses() {
-                suppressed.insert(bonus.name.clone());
+            if distributions.is_empty() {
+                return None;
             }
-            let bar1_enchant = options.baseline.bar1_enchant.or(Some(WeaponEnchant::Flame));
-            let bar2_enchant = options.baseline.bar2_enchant.or(Some(WeaponEnchant::Flame));
-            let proc_effects: Vec<SetProcEffect> = build
-                .set_names()
-                .iter()
-                .flat_map(|(name, _)| {
-                    ALL_SETS
-                        .iter()
-                        .filter(move |s| s.name == *name)
-                        .flat_map(|s| {
-                            s.proc_effects_at(s.set_type.max_pieces())
-                                .into_iter()
-                                .cloned()
-                        })
-                })
-                .collect();
-            let simulator = FightSimulator::new(
-                build.effective_stats(),
-                build.resolved_bonuses(),
-                suppressed,
-            )
-            .with_enchants(bar1_enchant, bar2_enchant)
-            .with_set_procs(proc_effects)
-            .with_avg_resource_pct(options.avg_resource_pct);
+            let simulator = create_simulator(
+                options,
+                build,
+                default_bar1,
+                default_bar2,
+                default_potion,
+                gear,
+            );
             Some((build_idx, simulator, distributions))
         })
         .collect();
@@ -607,92 +853,61 @@ fn run_simulation(
         }
         let best_dist = distributions[best_dist_idx].clone();
 
-        // ── Enchant optimization sweep ──
+        // ── Enchant and potion optimization sweep ──
         let bar1_pinned = options.baseline.bar1_enchant.is_some();
-        let bar2_pinned = options.baseline.bar2_enchant.is_some();
-        let mut winning_bar1 = options
-            .baseline
-            .bar1_enchant
-            .unwrap_or(WeaponEnchant::Flame);
-        let mut winning_bar2 = options
-            .baseline
-            .bar2_enchant
-            .unwrap_or(WeaponEnchant::Flame);
-
-        if !bar1_pinned || !bar2_pinned {
-            let all_enchants = [
-                WeaponEnchant::Flame,
-                WeaponEnchant::Poison,
-                WeaponEnchant::Shock,
-                WeaponEnchant::Berserker,
-            ];
+        // A one-bar build never equips bar 2, so its enchant is irrelevant
+        let bar2_pinned = options.baseline.bar2_enchant.is_some() || gear.single_bar;
+        let potion_pinned = options.baseline.potion.is_some();
+        let mut winning_bar1 = default_bar1;
+        let mut winning_bar2 = default_bar2;
+        let mut winning_potion = default_potion;
+
+        if !bar1_pinned || !bar2_pinned || !potion_pinned {
             let bar1_candidates: Vec<WeaponEnchant> = if bar1_pinned {
                 vec![winning_bar1]
             } else {
-                all_enchants.to_vec()
+                DPS_WEAPON_ENCHANTS.to_vec()
             };
             let bar2_candidates: Vec<WeaponEnchant> = if bar2_pinned {
                 vec![winning_bar2]
             } else {
-                all_enchants.to_vec()
+                DPS_WEAPON_ENCHANTS.to_vec()
             };
-
-            let build = &builds[best_build_idx];
-            let mut suppressed = if options.trial {
-                TRIAL_BUFF_NAMES.clone()
+            let potion_candidates: Vec<Potion> = if potion_pinned {
+                vec![winning_potion]
             } else {
-                std::collections::HashSet::new()
+                DPS_POTIONS.to_vec()
             };
-            let potion = options.baseline.potion.unwrap_or(Potion::WeaponPower);
-            for bonus in potion.bonuses() {
-                suppressed.insert(bonus.name.clone());
-            }
-            let proc_effects: Vec<SetProcEffect> = build
-                .set_names()
-                .iter()
-                .flat_map(|(name, _)| {
-                    ALL_SETS
-                        .iter()
-                        .filter(move |s| s.name == *name)
-                        .flat_map(|s| {
-                            s.proc_effects_at(s.set_type.max_pieces())
-                                .into_iter()
-                                .cloned()
-                        })
-                })
-                .collect();
 
-            let combo_count = bar1_candidates.len() * bar2_candidates.len();
+            let build = &sim_builds[best_build_idx];
+            let combo_count =
+                bar1_candidates.len() * bar2_candidates.len() * potion_candidates.len();
             let mut best_enchant_dps = result.dps;
 
             for &e1 in &bar1_candidates {
                 for &e2 in &bar2_candidates {
-                    if e1 == winning_bar1 && e2 == winning_bar2 {
-                        continue;
-                    }
-                    let sim = FightSimulator::new(
-                        build.effective_stats(),
-                        build.resolved_bonuses(),
-                        suppressed.clone(),
-                    )
-                    .with_enchants(Some(e1), Some(e2))
-                    .with_set_procs(proc_effects.clone())
-                    .with_avg_resource_pct(options.avg_resource_pct);
-
-                    let r = sim.simulate(&best_dist);
-                    if r.dps > best_enchant_dps {
-                        best_enchant_dps = r.dps;
-                        winning_bar1 = e1;
-                        winning_bar2 = e2;
-                        result = r;
+                    for &potion in &potion_candidates {
+                        if e1 == default_bar1 && e2 == default_bar2 && potion == default_potion {
+                            continue;
+                        }
+                        let sim = create_simulator(options, build, e1, e2, potion, gear);
+                        let r = sim.simulate(&best_dist);
+                        if r.dps > best_enchant_dps {
+                            best_enchant_dps = r.dps;
+                            winning_bar1 = e1;
+                            winning_bar2 = e2;
+                            winning_potion = potion;
+                            result = r;
+                        }
                     }
                 }
             }
 
             logger::success(&std::format!(
-                "Best enchants: Bar1={}, Bar2={} (optimized from {} combos)",
+                "Best enchants: Bar1={}, Bar2={}, Potion={} (optimized from {} combos)",
                 winning_bar1,
                 winning_bar2,
+                winning_potion,
                 combo_count
             ));
         }
@@ -708,54 +923,35 @@ fn run_simulation(
                 set_names: builds[best_build_idx].set_names().to_vec(),
                 buffed_stats: None,
                 warnings: Vec::new(),
+                fight_target: options.fight_target,
+                encounter: options.encounter.clone(),
+                enemy: options.enemy(),
+                dot_snapshot: options.dot_snapshot,
             };
             logger::info(&display_result.to_string());
         }
         logger::info(&std::format!("Simulation completed in {:.2?}", sim_elapsed));
 
         // Compute buffed stats for export metadata
-        let build = &builds[best_build_idx];
-        let mut suppressed_final = if options.trial {
-            TRIAL_BUFF_NAMES.clone()
-        } else {
-            std::collections::HashSet::new()
-        };
-        let potion = options.baseline.potion.unwrap_or(Potion::WeaponPower);
-        for bonus in potion.bonuses() {
-            suppressed_final.insert(bonus.name.clone());
-        }
-        let proc_effects_final: Vec<SetProcEffect> = build
-            .set_names()
-            .iter()
-            .flat_map(|(name, _)| {
-                ALL_SETS
-                    .iter()
-                    .filter(move |s| s.name == *name)
-                    .flat_map(|s| {
-                        s.proc_effects_at(s.set_type.max_pieces())
-                            .into_iter()
-                            .cloned()
-                    })
-            })
-            .collect();
-        let final_sim = FightSimulator::new(
-            build.effective_stats(),
-            build.resolved_bonuses(),
-            suppressed_final,
-        )
-        .with_enchants(Some(winning_bar1), Some(winning_bar2))
-        .with_set_procs(proc_effects_final)
-        .with_avg_resource_pct(options.avg_resource_pct);
+        let final_sim = create_simulator(
+            options,
+            &sim_builds[best_build_idx],
+            winning_bar1,
+            winning_bar2,
+            winning_potion,
+            gear,
+        );
         let buffed_stats = final_sim.compute_buffed_stats(&best_dist);
 
-        return Some((
-            best_build_idx,
-            best_dist,
+        return Some(SimulationOutcome {
+            build_idx: best_build_idx,
+            distribution: best_dist,
             result,
-            winning_bar1,
-            winning_bar2,
+            bar1_enchant: winning_bar1,
+            bar2_enchant: winning_bar2,
+            potion: winning_potion,
             buffed_stats,
-        ));
+        });
     }
 
     None
@@ -13,6 +13,10 @@ pub struct SetOptimizerOptions {
     pub pinned_mythic: Option<&'static SetData>,
     pub parallelism: u8,
     pub verbose: bool,
+    /// Item level scale applied to flat set bonus stats (1.0 at CP160)
+    pub set_bonus_scale: f64,
+    /// Sets that may not be picked (e.g. group duties carried by another player)
+    pub excluded: Vec<String>,
 }
 
 pub struct SetOptimizerResult {
@@ -46,13 +50,16 @@ impl SetOptimizer {
             .filter(|s| {
                 (s.set_type == SetType::Normal || s.set_type == SetType::Arena)
                     && !pinned_normal_names.contains(&s.name.as_str())
+                    && !options.excluded.contains(&s.name)
             })
             .copied()
             .collect();
         let available_monsters: Vec<&'static SetData> = ALL_SETS
             .iter()
             .filter(|s| {
-                s.set_type == SetType::Monster && !pinned_monster_names.contains(&s.name.as_str())
+                s.set_type == SetType::Monster
+                    && !pinned_monster_names.contains(&s.name.as_str())
+                    && !options.excluded.contains(&s.name)
             })
             .copied()
             .collect();
@@ -61,7 +68,7 @@ impl SetOptimizer {
         } else {
             ALL_SETS
                 .iter()
-                .filter(|s| s.set_type == SetType::Mythic)
+                .filter(|s| s.set_type == SetType::Mythic && !options.excluded.contains(&s.name))
                 .copied()
                 .collect()
         };
@@ -79,16 +86,13 @@ impl SetOptimizer {
         }
 
         // Pre-resolve pinned set bonuses (always included)
+        let set_bonus_scale = options.set_bonus_scale;
         let pinned_bonuses: Vec<BonusData> = options
             .pinned_normal
             .iter()
             .chain(options.pinned_monster.iter())
             .chain(options.pinned_mythic.iter())
-            .flat_map(|set| {
-                set.bonuses_at(set.set_type.max_pieces())
-                    .into_iter()
-                    .cloned()
-            })
+            .flat_map(|set| set.scaled_bonuses_at(set.set_type.max_pieces(), set_bonus_scale))
             .collect();
         let pool = ThreadPoolBuilder::new()
             .num_threads(options.parallelism as usize)
@@ -133,7 +137,7 @@ impl SetOptimizer {
                     let score_set = |set: &'static SetData| -> f64 {
                         let mut bonuses = pinned_bonuses.clone();
                         let pc = set.set_type.max_pieces();
-                        bonuses.extend(set.bonuses_at(pc).into_iter().cloned());
+                        bonuses.extend(set.scaled_bonuses_at(pc, set_bonus_scale));
                         let b = Build::new_with_extra(
                             skills.clone(),
                             cp,
@@ -285,41 +289,47 @@ impl SetOptimizer {
 
                                 // Always include pinned sets
                                 for &s in pinned_normal.iter() {
-                                    loadout_bonuses.extend(
-                                        s.bonuses_at(s.set_type.max_pieces()).into_iter().cloned(),
-                                    );
+                                    loadout_bonuses.extend(s.scaled_bonuses_at(
+                                        s.set_type.max_pieces(),
+                                        set_bonus_scale,
+                                    ));
                                     loadout_names.push((s.name.clone(), s.set_type.max_pieces()));
                                 }
                                 for &s in pinned_monster.iter() {
-                                    loadout_bonuses.extend(
-                                        s.bonuses_at(s.set_type.max_pieces()).into_iter().cloned(),
-                                    );
+                                    loadout_bonuses.extend(s.scaled_bonuses_at(
+                                        s.set_type.max_pieces(),
+                                        set_bonus_scale,
+                                    ));
                                     loadout_names.push((s.name.clone(), s.set_type.max_pieces()));
                                 }
                                 if let Some(s) = pinned_mythic {
-                                    loadout_bonuses.extend(
-                                        s.bonuses_at(s.set_type.max_pieces()).into_iter().cloned(),
-                                    );
+                                    loadout_bonuses.extend(s.scaled_bonuses_at(
+                                        s.set_type.max_pieces(),
+                                        set_bonus_scale,
+                                    ));
                                     loadout_names.push((s.name.clone(), s.set_type.max_pieces()));
                                 }
 
                                 // Add variable sets
                                 for &s in normal_fill {
-                                    loadout_bonuses.extend(
-                                        s.bonuses_at(s.set_type.max_pieces()).into_iter().cloned(),
-                                    );
+                                    loadout_bonuses.extend(s.scaled_bonuses_at(
+                                        s.set_type.max_pieces(),
+                                        set_bonus_scale,
+                                    ));
                                     loadout_names.push((s.name.clone(), s.set_type.max_pieces()));
                                 }
                                 if let Some(s) = var_monster {
-                                    loadout_bonuses.extend(
-                                        s.bonuses_at(s.set_type.max_pieces()).into_iter().cloned(),
-                                    );
+                                    loadout_bonuses.extend(s.scaled_bonuses_at(
+                                        s.set_type.max_pieces(),
+                                        set_bonus_scale,
+                                    ));
                                     loadout_names.push((s.name.clone(), s.set_type.max_pieces()));
                                 }
                                 if let Some(s) = var_mythic {
-                                    loadout_bonuses.extend(
-                                        s.bonuses_at(s.set_type.max_pieces()).into_iter().cloned(),
-                                    );
+                                    loadout_bonuses.extend(s.scaled_bonuses_at(
+                                        s.set_type.max_pieces(),
+                                        set_bonus_scale,
+                                    ));
                                     loadout_names.push((s.name.clone(), s.set_type.max_pieces()));
                                 }
 
@@ -436,6 +446,7 @@ mod tests {
             set_names: vec![],
             extra_bonuses: vec![],
             armor_passive_bonuses: vec![],
+            single_bar: false,
         });
         optimizer.find_optimal_build()
     }
@@ -454,6 +465,8 @@ mod tests {
                 pinned_mythic: None,
                 parallelism: 2,
                 verbose: false,
+                set_bonus_scale: 1.0,
+                excluded: vec![],
             },
         );
 
@@ -480,6 +493,8 @@ mod tests {
                 pinned_mythic: None,
                 parallelism: 2,
                 verbose: false,
+                set_bonus_scale: 1.0,
+                excluded: vec![],
             },
         );
 
@@ -508,6 +523,8 @@ mod tests {
                 pinned_mythic: Some(kilt),
                 parallelism: 2,
                 verbose: false,
+                set_bonus_scale: 1.0,
+                excluded: vec![],
             },
         );
 
@@ -536,6 +553,8 @@ mod tests {
                 pinned_mythic: None,
                 parallelism: 2,
                 verbose: false,
+                set_bonus_scale: 1.0,
+                excluded: vec![],
             },
         );
 
@@ -565,6 +584,8 @@ mod tests {
                 pinned_mythic: Some(kilt),
                 parallelism: 2,
                 verbose: false,
+                set_bonus_scale: 1.0,
+                excluded: vec![],
             },
         );
 
@@ -584,4 +605,32 @@ mod tests {
             .iter()
             .any(|(name, _)| name == "Harpooner's Wading Kilt"));
     }
+
+    #[test]
+    fn test_set_optimizer_skips_excluded_sets() {
+        let builds = make_test_builds();
+        let options = |excluded: Vec<String>| SetOptimizerOptions {
+            top_k: 3,
+            pinned_normal: vec![],
+            pinned_monster: vec![],
+            pinned_mythic: None,
+            parallelism: 2,
+            verbose: false,
+            set_bonus_scale: 1.0,
+            excluded,
+        };
+
+        let best =
+            SetOptimizer::optimize(&builds[..1], &options(vec![])).expect("Should find a loadout");
+        let (excluded, _) = best.set_names[0].clone();
+
+        let result = SetOptimizer::optimize(&builds[..1], &options(vec![excluded.clone()]))
+            .expect("Should find a loadout without the excluded set");
+        assert!(
+            !result.set_names.iter().any(|(name, _)| *name == excluded),
+            "Excluded set {} should not be picked: {:?}",
+            excluded,
+            result.set_names
+        );
+    }
 }
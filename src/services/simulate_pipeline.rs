@@ -1,14 +1,18 @@
-use crate::data::bonuses::{TRIAL_BUFF_NAMES, TRIAL_DUMMY_BUFFS};
+use crate::data::bonuses::{
+    with_oakensoul_buffs, OAKENSOUL_BUFFS, OAKENSOUL_RING, TRIAL_BUFF_NAMES, TRIAL_DUMMY_BUFFS,
+};
 use crate::data::skill_trees::armor::armor_passives;
 use crate::data::skill_trees::guild::undaunted::undaunted_passives::undaunted_mettle_bonuses;
+use crate::data::skill_trees::weapon::dual_wield::dual_wield_passives::with_twin_blade_and_blunt_hands;
 use crate::domain::{
-    BonusData, Build, BuildConfig, CharacterStats, Potion, SetData, SimulationResult, SkillData,
-    SkillLineName, WeaponEnchant, BUILD_CONSTRAINTS,
+    BonusData, Build, BuildConfig, BuildConstraints, CharacterStats, DotSnapshot, Encounter,
+    EncounterPhase, Enemy, FightTarget, Opener, Potion, SetData, SimulationResult, SkillData,
+    SkillLineName, SynergyProfile, WeaponEnchant, BUILD_CONSTRAINTS,
 };
 use crate::infrastructure::format;
 use crate::services::{
-    generate_distributions, infer_weapons, BarDistribution, FightSimulator, PassivesService,
-    PassivesServiceOptions,
+    generate_distributions, generate_single_bar_distribution, infer_weapons, BarDistribution,
+    FightSimulator, PassivesService, PassivesServiceOptions,
 };
 use std::collections::HashSet;
 use std::fmt;
@@ -20,6 +24,21 @@ pub struct SimulatePipelineOptions {
     pub trial: bool,
     pub verbose: bool,
     pub avg_resource_pct: f64,
+    pub fight_target: FightTarget,
+    /// Boss fight script played out instead of the fight target
+    pub encounter: Option<Encounter>,
+    /// Extra downtime windows (immune, movement, bar-locked)
+    pub downtime: Vec<EncounterPhase>,
+    /// Synergies the rest of the group offers
+    pub synergies: SynergyProfile,
+    pub opener: Opener,
+    /// DoT parts locked in at cast time for skills without their own setting
+    pub dot_snapshot: DotSnapshot,
+    /// Record every action into the result timeline
+    pub timeline: bool,
+    /// Re-run the best distribution this many times with rolled procs
+    pub monte_carlo_runs: Option<usize>,
+    pub seed: u64,
 }
 
 pub struct SimulatePipelineResult {
@@ -30,6 +49,11 @@ pub struct SimulatePipelineResult {
     pub set_names: Vec<(String, u8)>,
     pub buffed_stats: Option<CharacterStats>,
     pub warnings: Vec<String>,
+    pub fight_target: FightTarget,
+    pub encounter: Option<Encounter>,
+    /// Enemy the build was simulated against (its combat mode included)
+    pub enemy: Enemy,
+    pub dot_snapshot: DotSnapshot,
 }
 
 impl fmt::Display for SimulatePipelineResult {
@@ -54,13 +78,47 @@ impl fmt::Display for SimulatePipelineResult {
         writeln!(f)?;
         writeln!(f, "Fight Simulation Results")?;
         writeln!(f, "{}", divider)?;
-        writeln!(f, "Target:           21M HP Trial Dummy")?;
+        match &self.encounter {
+            Some(encounter) => writeln!(
+                f,
+                "Target:           {} ({} HP)",
+                encounter,
+                format::format_number(encounter.hp as u64)
+            )?,
+            None => match self.fight_target {
+                FightTarget::TargetHp(hp) => writeln!(
+                    f,
+                    "Target:           {} ({} HP)",
+                    self.enemy,
+                    format::format_number(hp as u64)
+                )?,
+                FightTarget::Duration(_) => writeln!(
+                    f,
+                    "Target:           {} ({})",
+                    self.enemy, self.fight_target
+                )?,
+            },
+        }
+        writeln!(f, "Combat Mode:      {}", self.enemy.combat_mode)?;
         writeln!(
             f,
             "Fight Duration:   {}:{:05.2}",
             (self.simulation.fight_duration as u64) / 60,
             self.simulation.fight_duration % 60.0
         )?;
+        if let Some(ttk) = self.simulation.time_to_kill {
+            writeln!(f, "Time to Kill:     {:.1}s", ttk)?;
+        }
+        if self.simulation.opener_duration > 0.0 {
+            writeln!(
+                f,
+                "Opener:           {:.1}s before the pull",
+                self.simulation.opener_duration
+            )?;
+        }
+        if self.dot_snapshot != DotSnapshot::all() {
+            writeln!(f, "DoT Snapshot:     {}", self.dot_snapshot)?;
+        }
         writeln!(
             f,
             "Total Damage:     {}",
@@ -71,6 +129,39 @@ impl fmt::Display for SimulatePipelineResult {
             "DPS:              {}",
             format::format_number(self.simulation.dps as u64)
         )?;
+        if self.simulation.downtime > 0.0 {
+            writeln!(
+                f,
+                "Uptime DPS:       {} ({:.1}% uptime)",
+                format::format_number(self.simulation.uptime_dps() as u64),
+                self.simulation.uptime_pct() * 100.0
+            )?;
+        }
+        if self.simulation.add_damage > 0.0 {
+            writeln!(
+                f,
+                "Add Damage:       {} (not counted in DPS)",
+                format::format_number(self.simulation.add_damage as u64)
+            )?;
+        }
+        if self.simulation.synergy_damage > 0.0 {
+            writeln!(
+                f,
+                "Synergy Damage:   {}",
+                format::format_number(self.simulation.synergy_damage as u64)
+            )?;
+        }
+        if let Some(mc) = &self.simulation.monte_carlo {
+            writeln!(
+                f,
+                "Monte Carlo:      {} ± {} over {} runs (min {}, max {})",
+                format::format_number(mc.mean_dps as u64),
+                format::format_number(mc.std_dev as u64),
+                mc.runs,
+                format::format_number(mc.min_dps as u64),
+                format::format_number(mc.max_dps as u64)
+            )?;
+        }
         writeln!(f)?;
         writeln!(
             f,
@@ -78,12 +169,16 @@ impl fmt::Display for SimulatePipelineResult {
             self.best_distribution.bar1.weapon_type,
             bar1_names.join(", ")
         )?;
-        writeln!(
-            f,
-            "Bar 2 ({}): {}",
-            self.best_distribution.bar2.weapon_type,
-            bar2_names.join(", ")
-        )?;
+        if self.best_distribution.is_single_bar() {
+            writeln!(f, "Bar 2:           none (one-bar build)")?;
+        } else {
+            writeln!(
+                f,
+                "Bar 2 ({}): {}",
+                self.best_distribution.bar2.weapon_type,
+                bar2_names.join(", ")
+            )?;
+        }
 
         if !self.set_names.is_empty() {
             let formatted: Vec<String> = self
@@ -132,21 +227,37 @@ impl SimulatePipeline {
             .collect::<Result<Vec<_>, _>>()?;
 
         // Validate counts
-        if skills.len() != BUILD_CONSTRAINTS.skill_count {
+        let constraints = BuildConstraints::for_bars(config.single_bar);
+        if skills.len() != constraints.skill_count {
             return Err(format!(
                 "Exactly {} skills required, got {}",
-                BUILD_CONSTRAINTS.skill_count,
+                constraints.skill_count,
                 skills.len()
             ));
         }
-        if champion_points.len() != BUILD_CONSTRAINTS.champion_point_count {
+        let warfare_count = champion_points
+            .iter()
+            .filter(|cp| !cp.is_fitness_champion_point())
+            .count();
+        if warfare_count != BUILD_CONSTRAINTS.champion_point_count {
             return Err(format!(
-                "Exactly {} champion points required, got {}",
-                BUILD_CONSTRAINTS.champion_point_count,
-                champion_points.len()
+                "Exactly {} champion points required (excluding fitness), got {}",
+                BUILD_CONSTRAINTS.champion_point_count, warfare_count
             ));
         }
 
+        // Validate opener skills
+        if let Opener::Custom(names) = &options.opener {
+            for name in names {
+                if !skills.iter().any(|s| s.name.eq_ignore_ascii_case(name)) {
+                    return Err(format!(
+                        "Opener skill '{}' is not slotted in this build",
+                        name
+                    ));
+                }
+            }
+        }
+
         // Warnings
         if !skills.iter().any(|s| s.spammable) {
             warnings.push(
@@ -185,30 +296,31 @@ impl SimulatePipeline {
         passive_bonuses.extend(potion.bonuses());
 
         // Resolve set bonuses
-        let (set_bonuses, set_names, set_proc_effects) = resolve_set_bonuses(&sets);
-
-        // Trial buffs
-        let extra_bonuses: Vec<BonusData> = if options.trial {
+        let (set_bonuses, set_names, set_proc_effects) =
+            resolve_set_bonuses(&sets, config.set_bonus_scale());
+
+        // Trial buffs and the enemy's armor debuffs
+        let enemy = config
+            .enemy
+            .clone()
+            .unwrap_or_else(|| Enemy::default_for(options.trial));
+        let mut extra_bonuses: Vec<BonusData> = if options.trial {
             TRIAL_DUMMY_BUFFS.clone()
         } else {
             Vec::new()
         };
+        extra_bonuses.extend(enemy.armor_debuff_bonuses());
 
-        // Compute character stats
-        let character_stats = config.character_stats.clone();
-
-        // Build
-        let build = Build::new_with_extra(
-            skills.clone(),
-            &champion_points,
-            &passive_bonuses,
-            &set_bonuses,
-            set_names.clone(),
-            character_stats,
-            &extra_bonuses,
-        );
-
-        let build_summary = build.to_string();
+        // Oakensoul buffs only hold while the build runs a single bar
+        let wears_oakensoul = set_names.iter().any(|(name, _)| name == OAKENSOUL_RING);
+        if wears_oakensoul && config.single_bar {
+            extra_bonuses = with_oakensoul_buffs(&extra_bonuses);
+        } else if wears_oakensoul {
+            warnings.push(
+                "Oakensoul Ring grants nothing on a two-bar build. Set single_bar to run one bar."
+                    .to_string(),
+            );
+        }
 
         // Resolve weapons: pinned from config → infer from skills
         let pinned_bar1 = config.bar1_weapon;
@@ -236,24 +348,71 @@ impl SimulatePipeline {
             },
         };
 
+        // Twin Blade and Blunt applies per hand of the dual wield bar
+        let equipped_bar2 = Some(bar2_weapon).filter(|_| !config.single_bar);
+        if let Some((main_hand, off_hand)) =
+            config.first_dual_wield_hands(Some(bar1_weapon), equipped_bar2)
+        {
+            passive_bonuses =
+                with_twin_blade_and_blunt_hands(&passive_bonuses, main_hand, off_hand);
+        }
+
+        // Compute character stats (a configured enemy or position replaces the stored ones)
+        let mut character_stats = config.character_stats.clone();
+        if let Some(enemy) = &config.enemy {
+            enemy.apply_to(&mut character_stats);
+        }
+        if config.position.is_some() {
+            character_stats.position = config.position;
+        }
+
+        // Build
+        let build = Build::new_with_extra(
+            skills.clone(),
+            &champion_points,
+            &passive_bonuses,
+            &set_bonuses,
+            set_names.clone(),
+            character_stats,
+            &extra_bonuses,
+        );
+
+        let build_summary = build.to_string();
+
+        let overcap = build.effective_stats().penetration_overcap();
+        if overcap > 0.0 {
+            warnings.push(format!(
+                "Penetration is {} over the cap against {}; it adds no damage.",
+                format::format_number(overcap as u64),
+                enemy
+            ));
+        }
+
         // Generate distributions and simulate
-        let distributions = generate_distributions(&skills, bar1_weapon, bar2_weapon);
+        let distributions = if config.single_bar {
+            generate_single_bar_distribution(&skills, bar1_weapon)
+        } else {
+            generate_distributions(&skills, bar1_weapon, bar2_weapon)
+        };
         if distributions.is_empty() {
             return Err(
                 "No valid bar distributions found for this skill/weapon combination.".to_string(),
             );
         }
 
-        let effective_stats = build.effective_stats();
-        let resolved_bonuses = build.resolved_bonuses();
+        // The simulator drinks the potion on cooldown instead of applying it statically
+        let sim_build = build.without_bonuses(&potion.bonuses());
+        let effective_stats = sim_build.effective_stats();
+        let resolved_bonuses = sim_build.resolved_bonuses();
 
         let mut suppressed = if options.trial {
             TRIAL_BUFF_NAMES.clone()
         } else {
             HashSet::new()
         };
-        for bonus in potion.bonuses() {
-            suppressed.insert(bonus.name.clone());
+        suppressed.extend(enemy.armor_debuff_names());
+        if wears_oakensoul && config.single_bar {
+            suppressed.extend(OAKENSOUL_BUFFS.iter().map(|b| b.name.clone()));
         }
 
         let bar1_enchant = config.bar1_enchant.or(Some(WeaponEnchant::Flame));
@@ -261,8 +420,26 @@ impl SimulatePipeline {
 
         let simulator = FightSimulator::new(effective_stats, resolved_bonuses, suppressed)
             .with_enchants(bar1_enchant, bar2_enchant)
+            .with_off_hand_enchants(config.bar1_off_hand_enchant, config.bar2_off_hand_enchant)
+            .with_weapon_traits(
+                config.weapon_traits.first().copied(),
+                config.weapon_traits.get(1).copied(),
+            )
+            .with_off_hand_traits(
+                config.off_hand_traits.first().copied(),
+                config.off_hand_traits.get(1).copied(),
+            )
+            .with_poisons(config.bar1_poison, config.bar2_poison)
+            .with_potion(Some(potion))
             .with_set_procs(set_proc_effects)
-            .with_avg_resource_pct(options.avg_resource_pct);
+            .with_avg_resource_pct(options.avg_resource_pct)
+            .with_fight_target(options.fight_target)
+            .with_encounter(options.encounter.as_ref())
+            .with_downtime(options.downtime.clone())
+            .with_synergies(options.synergies)
+            .with_opener(options.opener.clone())
+            .with_dot_snapshot(options.dot_snapshot)
+            .with_timeline(options.timeline);
 
         // Compute buffed stats if verbose
         let buffed_stats = if options.verbose {
@@ -280,7 +457,13 @@ impl SimulatePipeline {
 
         results.sort_by(|a, b| b.1.dps.partial_cmp(&a.1.dps).unwrap());
 
-        let (best_idx, best_result) = results.into_iter().next().ok_or("No simulation results")?;
+        let (best_idx, mut best_result) =
+            results.into_iter().next().ok_or("No simulation results")?;
+
+        if let Some(runs) = options.monte_carlo_runs {
+            best_result =
+                simulator.simulate_monte_carlo(&distributions[best_idx], runs, options.seed);
+        }
 
         Ok(SimulatePipelineResult {
             build_summary,
@@ -290,6 +473,10 @@ impl SimulatePipeline {
             set_names,
             buffed_stats,
             warnings,
+            fight_target: options.fight_target,
+            encounter: options.encounter.clone(),
+            enemy,
+            dot_snapshot: options.dot_snapshot,
         })
     }
 }
@@ -55,6 +55,7 @@ fn nightblade_bow_2h_all_cp_forced() {
         set_names: vec![],
         extra_bonuses: vec![],
         armor_passive_bonuses: vec![],
+        single_bar: false,
     });
 
     let builds = optimizer.find_optimal_build();
@@ -62,7 +63,7 @@ fn nightblade_bow_2h_all_cp_forced() {
     let build = &builds[0];
 
     assert_eq!(
-        build.total_damage_per_cast as u64, 13_914,
+        build.total_damage_per_cast as u64, 13_743,
         "Total damage changed - optimization may have introduced a regression"
     );
 
@@ -122,6 +123,7 @@ fn nightblade_bow_2h_one_cp_forced() {
         set_names: vec![],
         extra_bonuses: vec![],
         armor_passive_bonuses: vec![],
+        single_bar: false,
     });
 
     let builds = optimizer.find_optimal_build();
@@ -129,7 +131,7 @@ fn nightblade_bow_2h_one_cp_forced() {
     let build = &builds[0];
 
     assert_eq!(
-        build.total_damage_per_cast as u64, 14_064,
+        build.total_damage_per_cast as u64, 13_868,
         "Total damage changed - optimization may have introduced a regression"
     );
 
@@ -199,6 +201,7 @@ fn optimizer_with_fixed_sets_increases_damage() {
             set_names: names,
             extra_bonuses: vec![],
             armor_passive_bonuses: vec![],
+            single_bar: false,
         })
     };
 
@@ -266,6 +269,7 @@ fn optimizer_with_fixed_sets_same_skills() {
             set_names: names,
             extra_bonuses: vec![],
             armor_passive_bonuses: vec![],
+            single_bar: false,
         })
     };
 